@@ -1,6 +1,7 @@
 use clap::{Parser, Subcommand};
+use std::path::PathBuf;
 
-use crate::commands::{down, launch, reset, start, status, tower};
+use crate::commands::{doctor, down, launch, prune, reset, start, status, stop, tower};
 
 #[derive(Parser)]
 #[command(name = "macot")]
@@ -9,6 +10,14 @@ use crate::commands::{down, launch, reset, start, status, tower};
 pub struct Cli {
     #[command(subcommand)]
     pub command: Commands,
+
+    /// Write logs to this file (overrides MACOT_LOG_FILE)
+    #[arg(long, global = true)]
+    pub log_file: Option<PathBuf>,
+
+    /// Log level: error, warn, info, debug or trace (overrides MACOT_LOG)
+    #[arg(long, global = true)]
+    pub log_level: Option<tracing::Level>,
 }
 
 #[derive(Subcommand)]
@@ -33,4 +42,13 @@ pub enum Commands {
 
     /// Reset expert context and instructions
     Reset(reset::Args),
+
+    /// Interrupt an expert's current action (Ctrl+C) without exiting the agent
+    Stop(stop::Args),
+
+    /// Remove leftover and unreadable files from a project's message queue
+    Prune(prune::Args),
+
+    /// Check the environment and project setup, printing a report for bug reports
+    Doctor(doctor::Args),
 }
@@ -8,15 +8,17 @@ use crate::instructions::{
     generate_hooks_settings, load_instruction_with_template, write_agents_file,
     write_instruction_file, write_settings_file,
 };
+use crate::models::EffortLevel;
 use crate::queue::QueueManager;
-use crate::session::{ClaudeManager, ExpertStateDetector, SessionMetadata, TmuxManager};
+use crate::session::{
+    agent_backend, ClaudeManager, ExpertStateDetector, SessionMetadata, TmuxManager, IDLE_MARKER,
+};
 use crate::utils::{compute_path_hash, path_to_str};
 
-/// Try to find a running session that matches the current directory's hash.
+/// Try to find a running session that matches `dir`'s hash.
 /// Returns the session name if exactly one match is found.
-async fn resolve_session_by_cwd() -> Result<Option<String>> {
-    let cwd = std::env::current_dir()?;
-    let hash = compute_path_hash(&cwd);
+async fn resolve_session_by_path(dir: &Path) -> Result<Option<String>> {
+    let hash = compute_path_hash(dir);
     let expected_suffix = format!("-{hash}");
 
     let sessions = TmuxManager::list_all_macot_sessions().await?;
@@ -37,7 +39,13 @@ async fn resolve_session_by_cwd() -> Result<Option<String>> {
 /// 1. Compute hash from current working directory and find a matching session
 /// 2. If no match by hash, fall back to single-session auto-detection
 pub async fn resolve_single_session(no_sessions_msg: &str) -> Result<String> {
-    if let Some(name) = resolve_session_by_cwd().await? {
+    resolve_single_session_in(&std::env::current_dir()?, no_sessions_msg).await
+}
+
+/// Resolve a single macot session as [`resolve_single_session`] does, matching
+/// the hash of `dir` instead of the current directory
+pub async fn resolve_single_session_in(dir: &Path, no_sessions_msg: &str) -> Result<String> {
+    if let Some(name) = resolve_session_by_path(dir).await? {
         return Ok(name);
     }
 
@@ -84,9 +92,15 @@ pub async fn init_session(config: &Config, project_path: &Path) -> Result<Sessio
     let detector = ExpertStateDetector::new(config.queue_path.join("status"));
     for i in 0..config.num_experts() {
         detector
-            .set_marker(i, "pending")
+            .set_marker(i, IDLE_MARKER)
+            .context("Failed to initialize expert status")?;
+        detector
+            .set_not_started(i, false)
             .context("Failed to initialize expert status")?;
     }
+    detector
+        .write_protocol_doc()
+        .context("Failed to write status marker protocol")?;
 
     let context_store = ContextStore::new(config.queue_path.clone());
     context_store
@@ -95,18 +109,40 @@ pub async fn init_session(config: &Config, project_path: &Path) -> Result<Sessio
         .context("Failed to initialize context store")?;
 
     let project_str = path_to_str(project_path)?;
-    tmux.create_session(config.num_experts(), project_str)
+    tmux.create_session(config.num_experts(), &config.shell_path(project_path)?)
         .await
         .context("Failed to create tmux session")?;
 
     tmux.init_session_metadata(project_str, config.num_experts())
         .await?;
+    if let Some(ref subproject) = config.subproject {
+        tmux.set_env("MACOT_SUBPROJECT", path_to_str(subproject)?)
+            .await?;
+    }
 
-    let claude = ClaudeManager::new(config.session_name());
+    let claude = ClaudeManager::new(config.session_name())
+        .with_backend(agent_backend(config.agent.as_ref()))
+        .with_expert_env(config.expert_envs())
+        .with_expert_models(config.expert_models())
+        .with_expert_efforts(config.expert_efforts());
 
     Ok(SessionManagers { tmux, claude })
 }
 
+/// `launch: lazy`: title each expert's window and flag it not started,
+/// leaving the tower to launch its agent on first use
+pub async fn defer_expert_launches(config: &Config, tmux: &TmuxManager) -> Result<()> {
+    let detector = ExpertStateDetector::new(config.queue_path.join("status"));
+    for (i, expert) in config.experts.iter().enumerate() {
+        let expert_id = i as u32;
+        tmux.set_pane_title(expert_id, &expert.name).await?;
+        detector
+            .set_not_started(expert_id, true)
+            .context("Failed to flag expert as not started")?;
+    }
+    Ok(())
+}
+
 pub struct PreparedExpertFiles {
     pub instruction_file: Option<PathBuf>,
     pub agents_file: Option<PathBuf>,
@@ -116,12 +152,13 @@ pub struct PreparedExpertFiles {
 }
 
 /// Load instruction template and write instruction/agents/settings files for a single expert
-/// using an explicitly provided role name and optional worktree path.
+/// using an explicitly provided role name, optional worktree path, and effort level.
 pub fn prepare_expert_files_with_role(
     config: &Config,
     expert_id: u32,
     role: &str,
     worktree_path: Option<&str>,
+    effort_level: EffortLevel,
 ) -> Result<PreparedExpertFiles> {
     // Validate that the expert ID exists in the configuration
     config
@@ -130,9 +167,12 @@ pub fn prepare_expert_files_with_role(
 
     let expert_name = config.get_expert_name(expert_id);
     let manifest_path = config.queue_path.join("experts_manifest.json");
-    let manifest_path_str = manifest_path.to_string_lossy();
+    let manifest_path_str = config.shell_path_style.to_shell_lossy(&manifest_path);
     let status_dir = config.queue_path.join("status");
-    let status_dir_str = status_dir.to_string_lossy();
+    let status_dir_str = config.shell_path_style.to_shell_lossy(&status_dir);
+    let scratchpad_dir = config.queue_path.join("scratchpad");
+    let scratchpad_dir_str = config.shell_path_style.to_shell_lossy(&scratchpad_dir);
+    let worktree_path = worktree_path.map(|p| config.shell_path_style.to_shell_lossy(Path::new(p)));
 
     let instruction_result = load_instruction_with_template(
         &config.core_instructions_path,
@@ -141,9 +181,11 @@ pub fn prepare_expert_files_with_role(
         expert_id,
         &expert_name,
         &config.status_file_path(expert_id),
-        worktree_path,
+        worktree_path.as_deref(),
         &manifest_path_str,
         &status_dir_str,
+        &scratchpad_dir_str,
+        effort_level,
     )?;
 
     let instruction_file = if !instruction_result.content.is_empty() {
@@ -184,7 +226,13 @@ pub fn prepare_expert_files(
     expert_id: u32,
 ) -> Result<(Option<PathBuf>, Option<PathBuf>, Option<PathBuf>)> {
     let role_name = config.get_expert_role(expert_id);
-    let prepared = prepare_expert_files_with_role(config, expert_id, &role_name, None)?;
+    let prepared = prepare_expert_files_with_role(
+        config,
+        expert_id,
+        &role_name,
+        None,
+        config.get_expert_effort(expert_id).unwrap_or_default(),
+    )?;
     Ok((
         prepared.instruction_file,
         prepared.agents_file,
@@ -202,11 +250,32 @@ pub async fn exit_expert_and_set_pending(
     claude.send_exit(expert_id).await?;
     tokio::time::sleep(std::time::Duration::from_secs(3)).await;
     detector
-        .set_marker(expert_id, "pending")
+        .set_marker(expert_id, IDLE_MARKER)
         .context("Failed to set expert status to pending")?;
     Ok(())
 }
 
+/// Resolve and validate an existing session, returning its TmuxManager and metadata.
+///
+/// Handles the common pattern across commands: resolve session name, check existence, load metadata.
+pub async fn resolve_existing_session(
+    session_name: Option<String>,
+) -> Result<(TmuxManager, SessionMetadata)> {
+    let session_name = match session_name {
+        Some(name) => name,
+        None => resolve_single_session_default().await?,
+    };
+
+    let tmux = TmuxManager::new(session_name.clone());
+
+    if !tmux.session_exists().await {
+        bail!("Session {session_name} does not exist. Is it still running? Check with 'macot status'.");
+    }
+
+    let metadata = tmux.load_session_metadata().await?;
+    Ok((tmux, metadata))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -240,7 +309,9 @@ mod tests {
         std::fs::create_dir_all(config.queue_path.join("system_prompt")).ok();
         std::fs::create_dir_all(config.queue_path.join("status")).ok();
 
-        let prepared = prepare_expert_files_with_role(&config, 0, "general", None).unwrap();
+        let prepared =
+            prepare_expert_files_with_role(&config, 0, "general", None, EffortLevel::default())
+                .unwrap();
 
         let content = std::fs::read_to_string(prepared.instruction_file.unwrap()).unwrap();
         assert!(
@@ -253,24 +324,3 @@ mod tests {
         );
     }
 }
-
-/// Resolve and validate an existing session, returning its TmuxManager and metadata.
-///
-/// Handles the common pattern across commands: resolve session name, check existence, load metadata.
-pub async fn resolve_existing_session(
-    session_name: Option<String>,
-) -> Result<(TmuxManager, SessionMetadata)> {
-    let session_name = match session_name {
-        Some(name) => name,
-        None => resolve_single_session_default().await?,
-    };
-
-    let tmux = TmuxManager::new(session_name.clone());
-
-    if !tmux.session_exists().await {
-        bail!("Session {session_name} does not exist. Is it still running? Check with 'macot status'.");
-    }
-
-    let metadata = tmux.load_session_metadata().await?;
-    Ok((tmux, metadata))
-}
@@ -0,0 +1,459 @@
+use anyhow::{bail, Context, Result};
+use clap::Args as ClapArgs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use crate::commands::preflight::{self, MIN_TMUX_VERSION};
+use crate::config::Config;
+use crate::instructions::manifest::read_expert_manifest;
+use crate::session::BUSY_MARKER;
+
+#[derive(ClapArgs)]
+pub struct Args {
+    /// Project to diagnose
+    #[arg(default_value = ".")]
+    pub project_path: PathBuf,
+
+    /// Custom config file path
+    #[arg(short, long)]
+    pub config: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl CheckStatus {
+    fn label(self) -> &'static str {
+        match self {
+            CheckStatus::Pass => "PASS",
+            CheckStatus::Warn => "WARN",
+            CheckStatus::Fail => "FAIL",
+        }
+    }
+}
+
+/// Outcome of one diagnostic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub detail: String,
+}
+
+impl Check {
+    fn new(name: &'static str, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Queue subdirectories a started session creates
+const QUEUE_DIRS: [&str; 4] = ["messages/queue", "messages/outbox", "reports", "status"];
+
+/// Print an environment report for bug reports. Nothing is created or changed.
+pub async fn execute(args: Args) -> Result<()> {
+    let project_path = args
+        .project_path
+        .canonicalize()
+        .context("Failed to resolve project path")?;
+
+    let mut checks = Vec::new();
+    checks.push(check_tmux(
+        preflight::probe_version("tmux", "-V").await.as_deref(),
+    ));
+
+    let config = match Config::load(args.config) {
+        Ok(config) => config.with_project_path(project_path),
+        Err(e) => {
+            checks.push(Check::new("config", CheckStatus::Fail, format!("{e:#}")));
+            Config::default().with_project_path(project_path)
+        }
+    };
+
+    let agent = preflight::agent_program(&config);
+    checks.push(check_agent(
+        &agent,
+        preflight::probe_version(&agent, "--version")
+            .await
+            .as_deref(),
+    ));
+    if !checks.iter().any(|c| c.name == "config") {
+        checks.push(check_config(&config));
+    }
+    checks.extend(check_queue_dirs(&config.queue_path));
+    checks.push(check_manifest(&config.queue_path));
+    checks.push(check_worktrees(&config.queue_path.join("worktrees")));
+    checks.push(check_status_markers(
+        &config.queue_path.join("status"),
+        Duration::from_secs(config.timeouts.task_completion),
+        SystemTime::now(),
+    ));
+
+    println!("macot {}", env!("CARGO_PKG_VERSION"));
+    println!("Project: {}", config.project_path.display());
+    println!();
+    for check in &checks {
+        println!(
+            "[{}] {}: {}",
+            check.status.label(),
+            check.name,
+            check.detail
+        );
+    }
+
+    let failed = checks
+        .iter()
+        .filter(|c| c.status == CheckStatus::Fail)
+        .count();
+    if failed > 0 {
+        bail!("{failed} check(s) failed");
+    }
+    Ok(())
+}
+
+/// Executable the experts run: `claude`, or the first word of the configured agent command
+pub fn check_tmux(version: Option<&str>) -> Check {
+    let Some(version) = version else {
+        return Check::new("tmux", CheckStatus::Fail, "not installed or not on PATH");
+    };
+    match preflight::parse_tmux_version(version) {
+        Some(parsed) if parsed < MIN_TMUX_VERSION => {
+            let (major, minor) = MIN_TMUX_VERSION;
+            Check::new(
+                "tmux",
+                CheckStatus::Fail,
+                format!("{version} is older than {major}.{minor}"),
+            )
+        }
+        Some(_) => Check::new("tmux", CheckStatus::Pass, version),
+        None => Check::new(
+            "tmux",
+            CheckStatus::Warn,
+            format!("{version} (version not recognised)"),
+        ),
+    }
+}
+
+pub fn check_agent(program: &str, version: Option<&str>) -> Check {
+    match version {
+        Some(version) if !version.is_empty() => {
+            Check::new("agent", CheckStatus::Pass, format!("{program} {version}"))
+        }
+        Some(_) => Check::new("agent", CheckStatus::Pass, program.to_string()),
+        None => Check::new(
+            "agent",
+            CheckStatus::Fail,
+            format!("`{program}` not found on PATH"),
+        ),
+    }
+}
+
+pub fn check_config(config: &Config) -> Check {
+    match config.validate() {
+        Ok(()) => Check::new(
+            "config",
+            CheckStatus::Pass,
+            format!("{} experts configured", config.num_experts()),
+        ),
+        Err(e) => Check::new("config", CheckStatus::Fail, format!("{e:#}")),
+    }
+}
+
+/// Existence and writability of the queue directories. A project that has never
+/// had a session only gets a warning.
+pub fn check_queue_dirs(queue_path: &Path) -> Vec<Check> {
+    if !queue_path.exists() {
+        return vec![Check::new(
+            "queue",
+            CheckStatus::Warn,
+            format!(
+                "{} does not exist yet (created by `macot start`)",
+                queue_path.display()
+            ),
+        )];
+    }
+
+    let mut missing = Vec::new();
+    let mut read_only = Vec::new();
+    for dir in std::iter::once(queue_path.to_path_buf())
+        .chain(QUEUE_DIRS.iter().map(|d| queue_path.join(d)))
+    {
+        match std::fs::metadata(&dir) {
+            Ok(meta) if meta.permissions().readonly() => read_only.push(dir),
+            Ok(_) => {}
+            Err(_) => missing.push(dir),
+        }
+    }
+
+    let mut checks = Vec::new();
+    if !read_only.is_empty() {
+        checks.push(Check::new(
+            "queue",
+            CheckStatus::Fail,
+            format!("not writable: {}", join_paths(&read_only)),
+        ));
+    }
+    if !missing.is_empty() {
+        checks.push(Check::new(
+            "queue",
+            CheckStatus::Warn,
+            format!("missing: {}", join_paths(&missing)),
+        ));
+    }
+    if checks.is_empty() {
+        checks.push(Check::new(
+            "queue",
+            CheckStatus::Pass,
+            format!("{} is writable", queue_path.display()),
+        ));
+    }
+    checks
+}
+
+/// Worktree directories git no longer tracks: no `.git` file, or one whose
+/// `gitdir:` target has been removed
+pub fn check_worktrees(worktree_dir: &Path) -> Check {
+    let Ok(entries) = std::fs::read_dir(worktree_dir) else {
+        return Check::new("worktrees", CheckStatus::Pass, "none");
+    };
+
+    let mut total = 0;
+    let mut orphaned = Vec::new();
+    for path in entries.flatten().map(|e| e.path()).filter(|p| p.is_dir()) {
+        total += 1;
+        let tracked = std::fs::read_to_string(path.join(".git"))
+            .ok()
+            .and_then(|content| {
+                content
+                    .strip_prefix("gitdir:")
+                    .map(|dir| path.join(dir.trim()).exists())
+            })
+            .unwrap_or(false);
+        if !tracked {
+            orphaned.push(path);
+        }
+    }
+
+    if orphaned.is_empty() {
+        Check::new("worktrees", CheckStatus::Pass, format!("{total} tracked"))
+    } else {
+        orphaned.sort();
+        Check::new(
+            "worktrees",
+            CheckStatus::Warn,
+            format!(
+                "{} of {total} orphaned: {}",
+                orphaned.len(),
+                join_paths(&orphaned)
+            ),
+        )
+    }
+}
+
+/// `experts_manifest.json` parses; a corrupted one is rewritten when the tower starts
+pub fn check_manifest(queue_path: &Path) -> Check {
+    match read_expert_manifest(queue_path) {
+        Ok(None) => Check::new("manifest", CheckStatus::Pass, "not written yet"),
+        Ok(Some(entries)) => Check::new(
+            "manifest",
+            CheckStatus::Pass,
+            format!("{} experts listed", entries.len()),
+        ),
+        Err(e) => Check::new(
+            "manifest",
+            CheckStatus::Warn,
+            format!("{e:#}; restart the tower to regenerate it"),
+        ),
+    }
+}
+
+/// Status markers left at `processing` for longer than `stale_after`
+pub fn check_status_markers(status_dir: &Path, stale_after: Duration, now: SystemTime) -> Check {
+    let Ok(entries) = std::fs::read_dir(status_dir) else {
+        return Check::new("status markers", CheckStatus::Pass, "none");
+    };
+
+    let mut stuck: Vec<String> = entries
+        .flatten()
+        .filter(|entry| {
+            let processing = std::fs::read_to_string(entry.path())
+                .is_ok_and(|content| content.trim() == BUSY_MARKER);
+            let age = entry
+                .metadata()
+                .and_then(|meta| meta.modified())
+                .ok()
+                .and_then(|modified| now.duration_since(modified).ok());
+            processing && age.is_some_and(|age| age > stale_after)
+        })
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .collect();
+
+    if stuck.is_empty() {
+        return Check::new("status markers", CheckStatus::Pass, "none stuck");
+    }
+    stuck.sort();
+    Check::new(
+        "status markers",
+        CheckStatus::Warn,
+        format!(
+            "processing for over {}s: {}",
+            stale_after.as_secs(),
+            stuck.join(", ")
+        ),
+    )
+}
+
+fn join_paths(paths: &[PathBuf]) -> String {
+    paths
+        .iter()
+        .map(|p| p.display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn check_tmux_reports_missing_old_and_current() {
+        assert_eq!(check_tmux(None).status, CheckStatus::Fail);
+        assert_eq!(
+            check_tmux(Some("tmux 2.9")).status,
+            CheckStatus::Fail,
+            "check_tmux: old tmux should fail"
+        );
+        assert_eq!(check_tmux(Some("tmux 3.4")).status, CheckStatus::Pass);
+        assert_eq!(check_tmux(Some("tmux master")).status, CheckStatus::Warn);
+    }
+
+    #[test]
+    fn check_agent_reports_missing_cli() {
+        let check = check_agent("claude", None);
+        assert_eq!(check.status, CheckStatus::Fail);
+        assert!(check.detail.contains("`claude`"));
+        assert_eq!(check_agent("claude", Some("1.0.0")).detail, "claude 1.0.0");
+    }
+
+    #[test]
+    fn check_config_reports_validation_errors() {
+        let temp = TempDir::new().unwrap();
+        let mut config = Config::default().with_project_path(temp.path().to_path_buf());
+        config.experts.clear();
+        let check = check_config(&config);
+        assert_eq!(check.status, CheckStatus::Fail);
+        assert!(
+            check.detail.contains("no experts configured"),
+            "check_config: validation message should be shown, got: {}",
+            check.detail
+        );
+    }
+
+    #[test]
+    fn check_queue_dirs_warns_for_fresh_project_and_missing_dirs() {
+        let temp = TempDir::new().unwrap();
+        let queue = temp.path().join(".macot");
+        let checks = check_queue_dirs(&queue);
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].status, CheckStatus::Warn);
+        assert!(
+            !queue.exists(),
+            "check_queue_dirs: must not create anything"
+        );
+
+        for dir in QUEUE_DIRS {
+            std::fs::create_dir_all(queue.join(dir)).unwrap();
+        }
+        assert_eq!(check_queue_dirs(&queue)[0].status, CheckStatus::Pass);
+
+        std::fs::remove_dir(queue.join("reports")).unwrap();
+        let checks = check_queue_dirs(&queue);
+        assert_eq!(checks[0].status, CheckStatus::Warn);
+        assert!(
+            checks[0].detail.contains("reports"),
+            "check_queue_dirs: missing dir should be named, got: {}",
+            checks[0].detail
+        );
+    }
+
+    #[test]
+    fn check_worktrees_flags_untracked_directories() {
+        let temp = TempDir::new().unwrap();
+        let worktrees = temp.path().join("worktrees");
+        assert_eq!(check_worktrees(&worktrees).status, CheckStatus::Pass);
+
+        let gitdir = temp.path().join("git").join("worktrees").join("live");
+        std::fs::create_dir_all(&gitdir).unwrap();
+        std::fs::create_dir_all(worktrees.join("live")).unwrap();
+        std::fs::write(
+            worktrees.join("live").join(".git"),
+            format!("gitdir: {}\n", gitdir.display()),
+        )
+        .unwrap();
+        std::fs::create_dir_all(worktrees.join("leftover")).unwrap();
+        std::fs::create_dir_all(worktrees.join("pruned")).unwrap();
+        std::fs::write(
+            worktrees.join("pruned").join(".git"),
+            "gitdir: /nonexistent/worktrees/pruned\n",
+        )
+        .unwrap();
+
+        let check = check_worktrees(&worktrees);
+        assert_eq!(check.status, CheckStatus::Warn);
+        assert!(
+            check.detail.starts_with("2 of 3 orphaned")
+                && check.detail.contains("leftover")
+                && check.detail.contains("pruned")
+                && !check.detail.contains("live"),
+            "check_worktrees: only untracked worktrees should be listed, got: {}",
+            check.detail
+        );
+    }
+
+    #[test]
+    fn check_manifest_warns_on_corrupted_file() {
+        let temp = TempDir::new().unwrap();
+        assert_eq!(check_manifest(temp.path()).status, CheckStatus::Pass);
+
+        std::fs::write(
+            temp.path().join("experts_manifest.json"),
+            "[{\"expert_id\":",
+        )
+        .unwrap();
+        let check = check_manifest(temp.path());
+        assert_eq!(
+            check.status,
+            CheckStatus::Warn,
+            "check_manifest: truncated manifest should be flagged"
+        );
+        assert!(check.detail.contains("Corrupted expert manifest"));
+    }
+
+    #[test]
+    fn check_status_markers_flags_old_processing_markers() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(temp.path().join("expert0"), "processing").unwrap();
+        std::fs::write(temp.path().join("expert1"), "pending").unwrap();
+        let stale_after = Duration::from_secs(600);
+
+        let now = SystemTime::now();
+        assert_eq!(
+            check_status_markers(temp.path(), stale_after, now).status,
+            CheckStatus::Pass,
+            "check_status_markers: fresh markers are not stuck"
+        );
+
+        let later = now + Duration::from_secs(3600);
+        let check = check_status_markers(temp.path(), stale_after, later);
+        assert_eq!(check.status, CheckStatus::Warn);
+        assert_eq!(check.detail, "processing for over 600s: expert0");
+    }
+}
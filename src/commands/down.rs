@@ -1,11 +1,15 @@
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use clap::Args as ClapArgs;
+use std::io::{BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
 use tokio::time::{sleep, Duration};
 
 use crate::commands::common;
 use crate::config::Config;
 use crate::context::ContextStore;
-use crate::session::ClaudeManager;
+use crate::feature::progress::FeatureProgress;
+use crate::models::ExpertState;
+use crate::session::{agent_backend, ClaudeManager, ExpertStateDetector, WorktreeManager};
 
 #[derive(ClapArgs)]
 pub struct Args {
@@ -19,20 +23,162 @@ pub struct Args {
     /// Clean up context and queue files
     #[arg(long)]
     pub cleanup: bool,
+
+    /// Tear down without asking, even with busy experts, unfinished feature
+    /// runs or uncommitted worktree changes
+    #[arg(short, long)]
+    pub yes: bool,
+
+    /// Custom config file path, used to pick the agent's exit command
+    #[arg(short, long)]
+    pub config: Option<PathBuf>,
+}
+
+/// Work a teardown would interrupt or lose
+#[derive(Debug, Default, PartialEq, Eq)]
+struct TeardownRisks {
+    /// Features with batches sent but not all tasks done
+    feature_runs: Vec<String>,
+    busy_experts: Vec<u32>,
+    /// Worktrees with staged, unstaged or untracked changes
+    dirty_worktrees: Vec<PathBuf>,
+}
+
+impl TeardownRisks {
+    async fn collect(
+        queue_path: &Path,
+        num_experts: u32,
+        worktrees: Option<&WorktreeManager>,
+    ) -> Self {
+        let detector = ExpertStateDetector::new(queue_path.join("status"));
+        let busy_experts = (0..num_experts)
+            .filter(|&id| {
+                !detector.is_not_started(id) && detector.detect_state(id) == ExpertState::Busy
+            })
+            .collect();
+
+        let mut dirty_worktrees = Vec::new();
+        if let Some(manager) = worktrees {
+            let paths = manager.list_worktrees().unwrap_or_else(|e| {
+                eprintln!("Warning: {e:#}");
+                Vec::new()
+            });
+            for path in paths {
+                match manager.has_uncommitted_changes(&path).await {
+                    Ok(true) => dirty_worktrees.push(path),
+                    Ok(false) => {}
+                    Err(e) => eprintln!(
+                        "Warning: Could not check {} for changes: {e:#}",
+                        path.display()
+                    ),
+                }
+            }
+        }
+
+        Self {
+            feature_runs: active_feature_runs(&queue_path.join("specs")),
+            busy_experts,
+            dirty_worktrees,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.feature_runs.is_empty()
+            && self.busy_experts.is_empty()
+            && self.dirty_worktrees.is_empty()
+    }
+
+    fn summary(&self) -> String {
+        let mut lines = vec!["Stopping this session would interrupt work in progress:".to_string()];
+        for feature in &self.feature_runs {
+            lines.push(format!("  - feature run '{feature}' has unfinished tasks"));
+        }
+        for id in &self.busy_experts {
+            lines.push(format!("  - expert {id} is busy"));
+        }
+        for path in &self.dirty_worktrees {
+            lines.push(format!(
+                "  - worktree {} has uncommitted changes",
+                path.display()
+            ));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Features whose progress record shows batches sent but the run not finished
+fn active_feature_runs(specs_dir: &Path) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(specs_dir) else {
+        return Vec::new();
+    };
+    let mut features: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .ends_with("-progress.json")
+        })
+        .filter_map(|entry| FeatureProgress::load(&entry.path()).ok().flatten())
+        .filter(|progress| !progress.finished && !progress.sent_batches.is_empty())
+        .map(|progress| progress.feature)
+        .collect();
+    features.sort();
+    features
+}
+
+/// Ask on the terminal; without one, refuse rather than guess
+fn confirm_teardown() -> Result<bool> {
+    if !std::io::stdin().is_terminal() {
+        bail!("Refusing to stop a session with work in progress; re-run with --yes to proceed");
+    }
+    print!("Stop it anyway? [y/N] ");
+    std::io::stdout().flush()?;
+    let mut answer = String::new();
+    std::io::stdin().lock().read_line(&mut answer)?;
+    Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
 }
 
 pub async fn execute(args: Args) -> Result<()> {
     let (tmux, metadata) = common::resolve_existing_session(args.session_name).await?;
     let session_name = tmux.session_name().to_string();
     let num_experts = metadata.num_experts.unwrap_or(4);
-    let project_path = metadata.project_path.unwrap_or_else(|| ".".to_string());
+    let project_path = metadata
+        .project_path
+        .clone()
+        .unwrap_or_else(|| ".".to_string());
+
+    if !args.yes {
+        let worktrees = WorktreeManager::resolve(PathBuf::from(&project_path))
+            .await
+            .ok()
+            .map(|manager| match metadata.subproject {
+                Some(_) => manager.with_macot_path(PathBuf::from(&metadata.queue_path)),
+                None => manager,
+            });
+        let risks = TeardownRisks::collect(
+            Path::new(&metadata.queue_path),
+            num_experts,
+            worktrees.as_ref(),
+        )
+        .await;
+        if !risks.is_empty() {
+            println!("{}", risks.summary());
+            if !confirm_teardown()? {
+                println!("Aborted; session {session_name} is still running");
+                return Ok(());
+            }
+        }
+    }
 
     println!("Stopping session: {session_name}");
 
     if !args.force {
         println!("Sending exit commands to {num_experts} agents...");
 
-        let claude = ClaudeManager::new(session_name.clone());
+        let config = Config::load(args.config)?;
+        let claude = ClaudeManager::new(session_name.clone())
+            .with_backend(agent_backend(config.agent.as_ref()));
 
         for i in 0..num_experts {
             if let Err(e) = claude.send_exit(i).await {
@@ -58,7 +204,7 @@ pub async fn execute(args: Args) -> Result<()> {
 
         let session_hash = session_name.strip_prefix("macot-").unwrap_or(&session_name);
 
-        let config = Config::default().with_project_path(std::path::PathBuf::from(&project_path));
+        let config = Config::default().with_project_path(PathBuf::from(&project_path));
         let context_store = ContextStore::new(config.queue_path.clone());
 
         if let Err(e) = context_store.cleanup_session(session_hash).await {
@@ -69,3 +215,82 @@ pub async fn execute(args: Args) -> Result<()> {
     println!("Session {session_name} stopped successfully");
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::{BUSY_MARKER, IDLE_MARKER};
+
+    fn git(args: &[&str], dir: &Path) {
+        std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn collect_flags_only_dirty_worktrees() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path().canonicalize().unwrap();
+        git(&["init"], &repo);
+        std::fs::write(repo.join("README.md"), "base\n").unwrap();
+        git(&["add", "README.md"], &repo);
+        git(&["commit", "-m", "init"], &repo);
+
+        let manager = WorktreeManager::new(repo.clone());
+        let clean = manager.create_worktree("clean").await.unwrap();
+        let dirty = manager.create_worktree("dirty").await.unwrap();
+        std::fs::create_dir_all(repo.join(".macot")).unwrap();
+        manager.setup_macot_symlink(&clean).await.unwrap();
+        std::fs::write(dirty.join("README.md"), "edited\n").unwrap();
+
+        let queue_path = repo.join(".macot");
+        let detector = ExpertStateDetector::new(queue_path.join("status"));
+        detector.ensure_status_dir().unwrap();
+        for id in 0..2 {
+            detector.set_marker(id, IDLE_MARKER).unwrap();
+        }
+        let risks = TeardownRisks::collect(&queue_path, 2, Some(&manager)).await;
+        assert_eq!(
+            risks.dirty_worktrees,
+            vec![dirty.clone()],
+            "collect: only the worktree with edits should block teardown"
+        );
+        assert!(!risks.is_empty());
+        assert!(risks.summary().contains("has uncommitted changes"));
+
+        git(&["commit", "-am", "save"], &dirty);
+        let risks = TeardownRisks::collect(&queue_path, 2, Some(&manager)).await;
+        assert!(
+            risks.is_empty(),
+            "collect: committed work needs no confirmation, got {risks:?}"
+        );
+
+        detector.set_marker(1, BUSY_MARKER).unwrap();
+        let risks = TeardownRisks::collect(&queue_path, 2, Some(&manager)).await;
+        assert_eq!(risks.busy_experts, vec![1]);
+    }
+
+    #[test]
+    fn active_feature_runs_skip_finished_and_unstarted() {
+        let tmp = tempfile::tempdir().unwrap();
+        let specs = tmp.path();
+        let mut running = FeatureProgress::new("auth");
+        running.record_batch(vec!["1.1".to_string()]);
+        running
+            .save(&FeatureProgress::path_for(specs, "auth"))
+            .unwrap();
+        let mut done = FeatureProgress::new("billing");
+        done.record_batch(vec!["1.1".to_string()]);
+        done.finished = true;
+        done.save(&FeatureProgress::path_for(specs, "billing"))
+            .unwrap();
+        FeatureProgress::new("search")
+            .save(&FeatureProgress::path_for(specs, "search"))
+            .unwrap();
+
+        assert_eq!(active_feature_runs(specs), vec!["auth".to_string()]);
+        assert!(active_feature_runs(&specs.join("missing")).is_empty());
+    }
+}
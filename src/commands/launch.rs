@@ -2,11 +2,10 @@ use anyhow::{Context, Result};
 use clap::Args as ClapArgs;
 use std::path::PathBuf;
 
-use crate::commands::common;
-use crate::config::Config;
+use crate::commands::{common, preflight};
+use crate::config::{Config, LaunchMode};
 use crate::session::{TmuxManager, WorktreeManager};
 use crate::tower::TowerApp;
-use crate::utils::path_to_str;
 
 #[derive(ClapArgs)]
 pub struct Args {
@@ -29,9 +28,10 @@ pub async fn execute(args: Args) -> Result<()> {
         .canonicalize()
         .context("Failed to resolve project path")?;
 
-    println!("Launching macot session for: {}", project_path.display());
-
     let mut config = Config::load(args.config)?.with_project_path(project_path.clone());
+    preflight::run(Some(&preflight::agent_program(&config))).await?;
+
+    println!("Launching macot session for: {}", project_path.display());
 
     if let Some(n) = args.num_experts {
         config = config.with_num_experts(n);
@@ -51,21 +51,29 @@ pub async fn execute(args: Args) -> Result<()> {
             }
         }
         println!("Number of experts: {}", config.num_experts());
-        let worktree_manager = WorktreeManager::resolve(project_path).await?;
-        let mut app = TowerApp::new(config, worktree_manager);
-        app.run().await?;
-        return Ok(());
+        return run_tower(config, project_path).await;
     }
 
+    config.validate()?;
+    preflight::warn_expert_count(config.num_experts(), config.expert_limits.soft_max);
+
     println!("Creating session: {}", config.session_name());
     println!("Number of experts: {}", config.num_experts());
 
     let managers = common::init_session(&config, &project_path).await?;
 
+    if config.launch == LaunchMode::Lazy {
+        common::defer_expert_launches(&config, &managers.tmux).await?;
+        println!(
+            "Session infrastructure ready. Experts launch when first jumped to or assigned (launch: lazy)."
+        );
+        return run_tower(config, project_path).await;
+    }
+
     let config_clone = config.clone();
     let tmux_clone = managers.tmux.clone();
     let claude_clone = managers.claude.clone();
-    let working_dir = path_to_str(&project_path)?.to_string();
+    let working_dir = config.shell_path(&project_path)?;
 
     tokio::spawn(async move {
         let config = config_clone;
@@ -121,9 +129,14 @@ pub async fn execute(args: Args) -> Result<()> {
 
     println!("Session infrastructure ready. Launching experts in background...");
 
-    let worktree_manager = WorktreeManager::resolve(project_path).await?;
-    let mut app = TowerApp::new(config, worktree_manager);
-    app.run().await?;
+    run_tower(config, project_path).await
+}
 
-    Ok(())
+/// Run the control tower UI against the project's git repository
+async fn run_tower(config: Config, project_path: PathBuf) -> Result<()> {
+    let worktree_manager = WorktreeManager::resolve(project_path)
+        .await?
+        .with_shell_path_style(config.shell_path_style);
+    let mut app = TowerApp::new(config, worktree_manager);
+    app.run().await
 }
@@ -1,8 +1,12 @@
 pub mod common;
+pub mod doctor;
 pub mod down;
 pub mod launch;
+pub mod preflight;
+pub mod prune;
 pub mod reset;
 pub mod sessions;
 pub mod start;
 pub mod status;
+pub mod stop;
 pub mod tower;
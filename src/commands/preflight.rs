@@ -0,0 +1,244 @@
+use anyhow::{bail, Result};
+use tokio::process::Command;
+
+use crate::config::Config;
+
+/// Oldest tmux release macot is tested against
+pub const MIN_TMUX_VERSION: (u32, u32) = (3, 0);
+
+const TMUX_INSTALL_HINT: &str =
+    "Install it with your package manager (e.g. `brew install tmux` or `sudo apt install tmux`).";
+const CLAUDE_INSTALL_HINT: &str =
+    "Install it with `npm install -g @anthropic-ai/claude-code` and make sure it is on PATH.";
+
+/// Run `<program> <flag>` and return its stdout, or `None` if it cannot be run
+pub async fn probe_version(program: &str, flag: &str) -> Option<String> {
+    let output = Command::new(program).arg(flag).output().await.ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Parse `tmux -V` output such as `tmux 3.3a` or `tmux next-3.4` into (major, minor).
+/// Returns `None` for unversioned builds like `tmux master`.
+pub fn parse_tmux_version(output: &str) -> Option<(u32, u32)> {
+    let version = output.split_whitespace().nth(1)?;
+    let version = version.rsplit('-').next()?;
+    let (major, rest) = version.split_once('.')?;
+    let minor: String = rest.chars().take_while(char::is_ascii_digit).collect();
+    Some((major.parse().ok()?, minor.parse().ok()?))
+}
+
+/// Program the experts' agent runs as: the first word of `agent.command`, or `claude`
+pub fn agent_program(config: &Config) -> String {
+    config
+        .agent
+        .as_ref()
+        .and_then(|agent| agent.command.split_whitespace().next())
+        .unwrap_or("claude")
+        .to_string()
+}
+
+/// Validate probed tool versions; `None` means the tool could not be run.
+/// `agent` is the agent program and its probed version, when one is required.
+fn check_tools(tmux_version: Option<&str>, agent: Option<(&str, Option<&str>)>) -> Result<()> {
+    let Some(tmux_version) = tmux_version else {
+        bail!("tmux is not installed or not on PATH; macot runs experts in tmux windows.\n{TMUX_INSTALL_HINT}");
+    };
+
+    if let Some((major, minor)) = parse_tmux_version(tmux_version) {
+        if (major, minor) < MIN_TMUX_VERSION {
+            let (min_major, min_minor) = MIN_TMUX_VERSION;
+            bail!(
+                "{tmux_version} is too old; macot requires tmux {min_major}.{min_minor} or newer.\n{TMUX_INSTALL_HINT}"
+            );
+        }
+    } else {
+        tracing::debug!("Could not parse tmux version from '{}'", tmux_version);
+    }
+
+    match agent {
+        Some(("claude", None)) => {
+            bail!("The `claude` CLI was not found; experts cannot be launched without it.\n{CLAUDE_INSTALL_HINT}");
+        }
+        Some((program, None)) => {
+            bail!("The `{program}` CLI set in `agent.command` was not found; experts cannot be launched without it.\nCheck that it is installed and on PATH.");
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Rough memory footprint of one expert's agent process
+const MIB_PER_EXPERT: u64 = 500;
+
+/// Advisory warnings for running `num_experts` at once; `cpus` and
+/// `mem_available_mib` are `None` when the host could not be probed
+fn expert_count_warnings(
+    num_experts: u32,
+    soft_max: u32,
+    cpus: Option<usize>,
+    mem_available_mib: Option<u64>,
+) -> Vec<String> {
+    let mut warnings = Vec::new();
+    if num_experts > soft_max {
+        warnings.push(format!(
+            "{num_experts} experts exceeds `expert_limits.soft_max` ({soft_max}); the tower and tmux may become sluggish"
+        ));
+    }
+    if let Some(cpus) = cpus {
+        if num_experts as usize > cpus * 2 {
+            warnings.push(format!(
+                "{num_experts} experts on {cpus} CPUs; agents will compete for CPU time"
+            ));
+        }
+    }
+    if let Some(available) = mem_available_mib {
+        let needed = u64::from(num_experts) * MIB_PER_EXPERT;
+        if needed > available {
+            warnings.push(format!(
+                "{num_experts} experts may need about {needed} MiB of memory, but only {available} MiB is available"
+            ));
+        }
+    }
+    warnings
+}
+
+/// `MemAvailable` from /proc/meminfo, in MiB
+fn mem_available_mib() -> Option<u64> {
+    let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+    let line = meminfo.lines().find(|l| l.starts_with("MemAvailable:"))?;
+    let kib: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kib / 1024)
+}
+
+/// Print advisory warnings when the expert count looks too large for this host
+pub fn warn_expert_count(num_experts: u32, soft_max: u32) {
+    let cpus = std::thread::available_parallelism().ok().map(|n| n.get());
+    for warning in expert_count_warnings(num_experts, soft_max, cpus, mem_available_mib()) {
+        eprintln!("Warning: {warning}");
+    }
+}
+
+/// Check that tmux (and, when experts will be launched, the `agent` program)
+/// are usable before any session is touched.
+pub async fn run(agent: Option<&str>) -> Result<()> {
+    let tmux_version = probe_version("tmux", "-V").await;
+    let agent_version = match agent {
+        Some(program) => probe_version(program, "--version").await,
+        None => None,
+    };
+    check_tools(
+        tmux_version.as_deref(),
+        agent.map(|program| (program, agent_version.as_deref())),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_tmux_version_handles_release_formats() {
+        assert_eq!(parse_tmux_version("tmux 3.3a"), Some((3, 3)));
+        assert_eq!(parse_tmux_version("tmux 2.9"), Some((2, 9)));
+        assert_eq!(parse_tmux_version("tmux next-3.5"), Some((3, 5)));
+        assert_eq!(
+            parse_tmux_version("tmux master"),
+            None,
+            "parse_tmux_version: unversioned builds should not parse"
+        );
+    }
+
+    #[test]
+    fn check_tools_missing_tmux_gives_install_guidance() {
+        let err = check_tools(None, Some(("claude", Some("1.0.0"))))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("tmux is not installed"),
+            "check_tools: missing tmux should be reported plainly, got: {err}"
+        );
+        assert!(
+            err.contains(TMUX_INSTALL_HINT),
+            "check_tools: missing tmux should include install guidance"
+        );
+    }
+
+    #[test]
+    fn check_tools_rejects_old_tmux() {
+        let err = check_tools(Some("tmux 2.9"), Some(("claude", Some("1.0.0"))))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("too old") && err.contains("3.0"),
+            "check_tools: old tmux should name the minimum version, got: {err}"
+        );
+    }
+
+    #[test]
+    fn check_tools_missing_claude_gives_install_guidance() {
+        let err = check_tools(Some("tmux 3.4"), Some(("claude", None)))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("`claude` CLI was not found") && err.contains(CLAUDE_INSTALL_HINT),
+            "check_tools: missing claude should be reported with guidance, got: {err}"
+        );
+    }
+
+    #[test]
+    fn check_tools_checks_the_configured_agent_instead_of_claude() {
+        let err = check_tools(Some("tmux 3.4"), Some(("codex", None)))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("`codex` CLI set in `agent.command`")
+                && !err.contains(CLAUDE_INSTALL_HINT),
+            "check_tools: a missing custom agent should be named, got: {err}"
+        );
+        assert!(
+            check_tools(Some("tmux 3.4"), None).is_ok(),
+            "check_tools: no agent is needed when none will be launched"
+        );
+
+        let mut config = Config::default();
+        assert_eq!(agent_program(&config), "claude");
+        config.agent = Some(crate::config::AgentConfig {
+            command: "codex --full-auto".to_string(),
+            ready_marker: ">".to_string(),
+            exit_command: "/quit".to_string(),
+            instruction_flag: None,
+            model_flag: None,
+            effort_flag: None,
+        });
+        assert_eq!(agent_program(&config), "codex");
+    }
+
+    #[test]
+    fn expert_count_warnings_flag_soft_cap_and_host_resources() {
+        assert!(
+            expert_count_warnings(4, 8, Some(8), Some(16_000)).is_empty(),
+            "expert_count_warnings: a modest team on a roomy host needs no warning"
+        );
+
+        let warnings = expert_count_warnings(12, 8, Some(4), Some(2_000));
+        assert_eq!(warnings.len(), 3, "expert_count_warnings: got {warnings:?}");
+        assert!(warnings[0].contains("expert_limits.soft_max"));
+        assert!(warnings[1].contains("4 CPUs"));
+        assert!(warnings[2].contains("6000 MiB"));
+
+        assert!(
+            expert_count_warnings(4, 8, None, None).is_empty(),
+            "expert_count_warnings: unknown host resources are not a warning"
+        );
+    }
+
+    #[test]
+    fn check_tools_accepts_supported_and_unversioned_tmux() {
+        assert!(check_tools(Some("tmux 3.0"), Some(("claude", Some("1.0.0")))).is_ok());
+        assert!(check_tools(Some("tmux master"), Some(("claude", Some("1.0.0")))).is_ok());
+    }
+}
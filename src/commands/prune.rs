@@ -0,0 +1,39 @@
+use anyhow::{Context, Result};
+use clap::Args as ClapArgs;
+use std::path::PathBuf;
+
+use crate::config::Config;
+use crate::queue::QueueManager;
+
+#[derive(ClapArgs)]
+pub struct Args {
+    /// Project whose message queue should be compacted
+    #[arg(default_value = ".")]
+    pub project_path: PathBuf,
+}
+
+pub async fn execute(args: Args) -> Result<()> {
+    let project_path = args
+        .project_path
+        .canonicalize()
+        .context("Failed to resolve project path")?;
+    let config = Config::default().with_project_path(project_path);
+
+    if !config.queue_path.exists() {
+        println!("No message queue at {}", config.queue_path.display());
+        return Ok(());
+    }
+
+    let report = QueueManager::new(config.queue_path.clone())
+        .compact()
+        .await?;
+
+    println!(
+        "Reclaimed {} file(s): {} temp, {} unreadable; {} message file(s) renamed",
+        report.reclaimed(),
+        report.temp_files,
+        report.unreadable,
+        report.renamed
+    );
+    Ok(())
+}
@@ -5,7 +5,7 @@ use std::path::PathBuf;
 use crate::commands::common::{self, exit_expert_and_set_pending, prepare_expert_files_with_role};
 use crate::config::Config;
 use crate::context::ContextStore;
-use crate::session::{ClaudeManager, ExpertStateDetector};
+use crate::session::{agent_backend, ClaudeManager, ExpertStateDetector};
 
 #[derive(ClapArgs)]
 pub struct Args {
@@ -31,6 +31,10 @@ pub enum ResetCommand {
         /// Full reset including Claude session restart
         #[arg(long)]
         full: bool,
+
+        /// Custom config file path
+        #[arg(short, long)]
+        config: Option<PathBuf>,
     },
 }
 
@@ -41,7 +45,8 @@ pub async fn execute(args: Args) -> Result<()> {
             session,
             keep_history,
             full,
-        } => reset_expert(expert, session, keep_history, full).await,
+            config,
+        } => reset_expert(expert, session, keep_history, full, config).await,
     }
 }
 
@@ -50,14 +55,15 @@ async fn reset_expert(
     session: Option<String>,
     keep_history: bool,
     full: bool,
+    config_path: Option<PathBuf>,
 ) -> Result<()> {
     let (tmux, metadata) = common::resolve_existing_session(session).await?;
     let session_name = tmux.session_name().to_string();
     let project_path = metadata.project_path.unwrap_or_else(|| ".".to_string());
     let num_experts = metadata.num_experts.unwrap_or(4);
 
-    let config = Config::default()
-        .with_project_path(PathBuf::from(&project_path))
+    let config = Config::load(config_path)?
+        .with_session_project(PathBuf::from(&project_path), metadata.subproject.as_deref())?
         .with_num_experts(num_experts);
 
     let expert_id = config.resolve_expert_id(&expert)?;
@@ -67,7 +73,11 @@ async fn reset_expert(
 
     let session_hash = session_name.strip_prefix("macot-").unwrap_or(&session_name);
     let context_store = ContextStore::new(config.queue_path.clone());
-    let claude = ClaudeManager::new(session_name.clone());
+    let mut claude = ClaudeManager::new(session_name.clone())
+        .with_backend(agent_backend(config.agent.as_ref()))
+        .with_expert_env(config.expert_envs())
+        .with_expert_models(config.expert_models())
+        .with_expert_efforts(config.expert_efforts());
 
     // Load session roles to get current role for instruction loading
     let instruction_role = match context_store.load_session_roles(session_hash).await {
@@ -82,7 +92,28 @@ async fn reset_expert(
         }
     };
 
-    println!("  Sending /exit to Claude...");
+    let (effort_level, model) = match context_store
+        .load_expert_context(session_hash, expert_id)
+        .await
+    {
+        Ok(Some(ctx)) => (ctx.effort_level, ctx.model),
+        Ok(None) => (None, None),
+        Err(e) => {
+            eprintln!("Warning: Failed to load expert context: {e}");
+            (None, None)
+        }
+    };
+    if model.is_some() {
+        claude.set_expert_model(expert_id, model);
+    }
+    if let Some(level) = effort_level {
+        claude.set_expert_effort(expert_id, level);
+    }
+    let effort_level = effort_level
+        .or(config.get_expert_effort(expert_id))
+        .unwrap_or_default();
+
+    println!("  Sending exit command to the agent...");
     let detector = ExpertStateDetector::new(config.queue_path.join("status"));
     exit_expert_and_set_pending(&claude, &detector, expert_id).await?;
 
@@ -108,7 +139,8 @@ async fn reset_expert(
     }
 
     println!("  Loading instructions (role: {instruction_role})...");
-    let prepared = prepare_expert_files_with_role(&config, expert_id, &instruction_role, None)?;
+    let prepared =
+        prepare_expert_files_with_role(&config, expert_id, &instruction_role, None, effort_level)?;
     if prepared.used_general_fallback {
         println!(
             "  Warning: Role '{}' not found, using 'general' instructions",
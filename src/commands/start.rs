@@ -3,9 +3,8 @@ use clap::Args as ClapArgs;
 use std::path::PathBuf;
 use tokio::task::JoinSet;
 
-use crate::commands::common;
-use crate::config::Config;
-use crate::utils::path_to_str;
+use crate::commands::{common, preflight};
+use crate::config::{Config, LaunchMode};
 
 #[derive(ClapArgs)]
 pub struct Args {
@@ -20,6 +19,10 @@ pub struct Args {
     /// Custom config file path
     #[arg(short, long)]
     pub config: Option<PathBuf>,
+
+    /// Scope experts and the `.macot` queue to this subdirectory of the project
+    #[arg(long)]
+    pub subproject: Option<PathBuf>,
 }
 
 pub async fn execute(args: Args) -> Result<()> {
@@ -28,19 +31,42 @@ pub async fn execute(args: Args) -> Result<()> {
         .canonicalize()
         .context("Failed to resolve project path")?;
 
-    println!("Starting macot session for: {}", project_path.display());
+    let mut config = Config::load(args.config)?.with_project_path(project_path);
+    preflight::run(Some(&preflight::agent_program(&config))).await?;
+    if let Some(ref subproject) = args.subproject {
+        config = config.with_subproject(subproject)?;
+    }
+    // Experts work in the subproject when one is given
+    let project_path = config.project_path.clone();
 
-    let mut config = Config::load(args.config)?.with_project_path(project_path.clone());
+    println!("Starting macot session for: {}", project_path.display());
 
     if let Some(n) = args.num_experts {
         config = config.with_num_experts(n);
     }
 
+    config.validate()?;
+    preflight::warn_expert_count(config.num_experts(), config.expert_limits.soft_max);
+
     println!("Creating session: {}", config.session_name());
     println!("Number of experts: {}", config.num_experts());
 
     let managers = common::init_session(&config, &project_path).await?;
 
+    if config.launch == LaunchMode::Lazy {
+        common::defer_expert_launches(&config, &managers.tmux).await?;
+        for (expert_id, expert) in config.experts.iter().enumerate() {
+            println!("  [{expert_id}] {} - Not started", expert.name);
+        }
+        println!("\nSession started successfully (launch: lazy)!");
+        println!("Experts launch when first jumped to (Alt+<id>) or assigned in 'macot tower'");
+        println!(
+            "Run 'tmux attach -t {}' to view agents directly",
+            config.session_name()
+        );
+        return Ok(());
+    }
+
     println!("Launching {} experts in parallel...", config.num_experts());
 
     let mut tasks: JoinSet<Result<(u32, String, bool)>> = JoinSet::new();
@@ -50,7 +76,7 @@ pub async fn execute(args: Args) -> Result<()> {
         let expert_name = expert.name.clone();
         let tmux = managers.tmux.clone();
         let claude = managers.claude.clone();
-        let working_dir = path_to_str(&project_path)?.to_string();
+        let working_dir = config.shell_path(&project_path)?;
         let timeout = config.timeouts.agent_ready;
 
         let (instruction_file, agents_file, settings_file) =
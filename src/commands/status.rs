@@ -1,17 +1,33 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, Utc};
 use clap::Args as ClapArgs;
+use std::path::PathBuf;
 
 use crate::commands::common;
 use crate::config::Config;
+use crate::models::{DeliveredMessage, Report};
+use crate::queue::QueueManager;
 use crate::session::ExpertStateDetector;
+use crate::utils::{parse_since, truncate_str};
 
 #[derive(ClapArgs)]
 pub struct Args {
     /// Session name to check
     pub session_name: Option<String>,
+
+    /// Also list reports and delivered messages from this point on: a
+    /// duration back from now (30m, 2h, 1d), an RFC 3339 timestamp or a date
+    #[arg(long, value_name = "WHEN")]
+    pub since: Option<String>,
 }
 
 pub async fn execute(args: Args) -> Result<()> {
+    let since = args
+        .since
+        .as_deref()
+        .map(|since| parse_since(since, Utc::now()))
+        .transpose()
+        .context("Invalid --since")?;
     let (tmux, metadata) = common::resolve_existing_session(args.session_name).await?;
 
     let project_path = metadata.project_path.as_deref().unwrap_or("unknown");
@@ -39,5 +55,143 @@ pub async fn execute(args: Args) -> Result<()> {
         );
     }
 
+    let Some(since) = since else {
+        return Ok(());
+    };
+    let queue = QueueManager::new(PathBuf::from(&metadata.queue_path));
+
+    let reports = reports_since(queue.list_reports().await?, since);
+    println!("\nReports since {}:", local_time(since));
+    if reports.is_empty() {
+        println!("  (none)");
+    }
+    for report in &reports {
+        println!(
+            "  {} [{}] {:<12} {:?} - {}",
+            local_time(report.last_activity()),
+            report.expert_id,
+            report.expert_name,
+            report.status,
+            truncate_str(&report.summary, 60)
+        );
+    }
+
+    let delivered = delivered_since(queue.list_delivered().await?, since);
+    println!("\nMessages delivered since {}:", local_time(since));
+    if delivered.is_empty() {
+        println!("  (none)");
+    }
+    for message in &delivered {
+        println!(
+            "  {} {} -> {} {:?} - {}",
+            local_time(message.delivered_at),
+            message.from_expert_id,
+            message.to_expert_id,
+            message.message_type,
+            truncate_str(&message.subject, 60)
+        );
+    }
+
     Ok(())
 }
+
+fn local_time(time: DateTime<Utc>) -> String {
+    time.with_timezone(&Local)
+        .format("%Y-%m-%d %H:%M:%S")
+        .to_string()
+}
+
+/// Reports active at or after `since`, oldest first
+fn reports_since(reports: Vec<Report>, since: DateTime<Utc>) -> Vec<Report> {
+    let mut reports: Vec<Report> = reports
+        .into_iter()
+        .filter(|report| report.last_activity() >= since)
+        .collect();
+    reports.sort_by_key(Report::last_activity);
+    reports
+}
+
+/// Delivery records at or after `since`, in log order
+fn delivered_since(
+    delivered: Vec<DeliveredMessage>,
+    since: DateTime<Utc>,
+) -> Vec<DeliveredMessage> {
+    delivered
+        .into_iter()
+        .filter(|message| message.delivered_at >= since)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Message, MessageContent, MessageRecipient, MessageType};
+    use chrono::Duration;
+
+    fn at(minutes_ago: i64, now: DateTime<Utc>) -> DateTime<Utc> {
+        now - Duration::minutes(minutes_ago)
+    }
+
+    fn report(task: &str, started: DateTime<Utc>, completed: Option<DateTime<Utc>>) -> Report {
+        let mut report = Report::new(task.to_string(), 0, "alice".to_string());
+        report.started_at = started;
+        report.completed_at = completed;
+        report
+    }
+
+    fn delivered(subject: &str, delivered_at: DateTime<Utc>) -> DeliveredMessage {
+        let content = MessageContent {
+            subject: subject.to_string(),
+            body: String::new(),
+            attachments: Vec::new(),
+        };
+        let message = Message::new(
+            0,
+            MessageRecipient::expert_id(1),
+            MessageType::Notify,
+            content,
+        );
+        let mut record = DeliveredMessage::new(&message, 1);
+        record.delivered_at = delivered_at;
+        record
+    }
+
+    #[test]
+    fn reports_since_keeps_boundary_and_newer_by_last_activity() {
+        let now = Utc::now();
+        let cutoff = at(60, now);
+        let reports = vec![
+            report("old", at(180, now), Some(at(61, now))),
+            report("finished-late", at(180, now), Some(at(5, now))),
+            report("boundary", at(60, now), None),
+            report("before-cutoff", at(90, now), None),
+        ];
+
+        let tasks: Vec<String> = reports_since(reports, cutoff)
+            .into_iter()
+            .map(|r| r.task_id)
+            .collect();
+        assert_eq!(
+            tasks,
+            vec!["boundary", "finished-late"],
+            "reports_since: the cutoff is inclusive and completion time counts"
+        );
+    }
+
+    #[test]
+    fn delivered_since_keeps_boundary_and_newer() {
+        let now = Utc::now();
+        let cutoff = parse_since("1h", now).unwrap();
+        let log = vec![
+            delivered("older", at(61, now)),
+            delivered("boundary", at(60, now)),
+            delivered("newer", at(1, now)),
+        ];
+
+        let subjects: Vec<String> = delivered_since(log, cutoff)
+            .into_iter()
+            .map(|m| m.subject)
+            .collect();
+        assert_eq!(subjects, vec!["boundary", "newer"]);
+    }
+}
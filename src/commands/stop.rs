@@ -0,0 +1,50 @@
+use anyhow::Result;
+use clap::Args as ClapArgs;
+use std::path::PathBuf;
+
+use crate::commands::common;
+use crate::config::Config;
+use crate::models::ExpertState;
+use crate::session::{ClaudeManager, ExpertStateDetector};
+
+#[derive(ClapArgs)]
+pub struct Args {
+    /// Expert ID or name
+    pub expert: String,
+
+    /// Session name (optional if only one session)
+    #[arg(short, long)]
+    pub session: Option<String>,
+
+    /// Custom config file path
+    #[arg(short, long)]
+    pub config: Option<PathBuf>,
+}
+
+pub async fn execute(args: Args) -> Result<()> {
+    let (tmux, metadata) = common::resolve_existing_session(args.session).await?;
+    let session_name = tmux.session_name().to_string();
+    let project_path = metadata.project_path.unwrap_or_else(|| ".".to_string());
+    let num_experts = metadata.num_experts.unwrap_or(4);
+
+    let config = Config::load(args.config)?
+        .with_session_project(PathBuf::from(&project_path), metadata.subproject.as_deref())?
+        .with_num_experts(num_experts);
+
+    let expert_id = config.resolve_expert_id(&args.expert)?;
+    let expert_name = config.get_expert_name(expert_id);
+
+    // Ctrl-C at an idle prompt would quit the agent, not stop a turn
+    let detector = ExpertStateDetector::new(config.queue_path.join("status"));
+    if detector.detect_state(expert_id) != ExpertState::Busy {
+        println!("Expert {expert_id} ({expert_name}) is not busy; nothing to interrupt.");
+        return Ok(());
+    }
+
+    ClaudeManager::new(session_name)
+        .send_interrupt(expert_id)
+        .await?;
+    println!("Interrupted expert {expert_id} ({expert_name}); the agent is still running.");
+
+    Ok(())
+}
@@ -1,11 +1,11 @@
 use anyhow::{bail, Context, Result};
 use clap::Args as ClapArgs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use crate::commands::common;
+use crate::commands::{common, preflight};
 use crate::config::Config;
 use crate::session::{TmuxManager, WorktreeManager};
-use crate::tower::TowerApp;
+use crate::tower::{PerfLog, TowerApp, PERF_LOG_MAX_BYTES};
 
 #[derive(ClapArgs)]
 pub struct Args {
@@ -15,15 +15,43 @@ pub struct Args {
     /// Custom config file path
     #[arg(short, long)]
     pub config: Option<PathBuf>,
+
+    /// Observe the tower without sending tasks or keys to experts
+    #[arg(long)]
+    pub readonly: bool,
+
+    /// Allow toggling a loop-timing debug overlay with F3
+    #[arg(long)]
+    pub debug_overlay: bool,
+
+    /// Connect to the session scoped to this subdirectory of the current directory
+    #[arg(long)]
+    pub subproject: Option<PathBuf>,
+
+    /// Log feature-execution batch prompts and pane snapshots under .macot/logs/
+    #[arg(long)]
+    pub verbose: bool,
+    /// Run without the TUI, printing state changes, deliveries, reports and
+    /// feature progress to stdout as JSON lines until interrupted
+    #[arg(long)]
+    pub events: bool,
+
+    /// Append every main-loop iteration's phase timings to this CSV file
+    #[arg(long, value_name = "PATH")]
+    pub perf_log: Option<PathBuf>,
 }
 
 pub async fn execute(args: Args) -> Result<()> {
-    let session_name = match args.session_name {
-        Some(name) => name,
-        None => {
-            common::resolve_single_session("No macot sessions running. Run 'macot start' first.")
-                .await?
+    preflight::run(None).await?;
+
+    let no_sessions_msg = "No macot sessions running. Run 'macot start' first.";
+    let session_name = match (args.session_name, &args.subproject) {
+        (Some(name), _) => name,
+        (None, Some(subproject)) => {
+            let dir = std::env::current_dir()?.join(subproject);
+            common::resolve_single_session_in(&dir, no_sessions_msg).await?
         }
+        (None, None) => common::resolve_single_session(no_sessions_msg).await?,
     };
 
     let tmux = TmuxManager::new(session_name.clone());
@@ -39,14 +67,47 @@ pub async fn execute(args: Args) -> Result<()> {
     let project_path_buf = PathBuf::from(&project_path);
     let num_experts = metadata.num_experts.unwrap_or(4);
 
-    let worktree_manager = WorktreeManager::resolve(project_path_buf.clone()).await?;
+    if let Some(ref subproject) = args.subproject {
+        if metadata.subproject.as_deref().map(Path::new) != Some(subproject.as_path()) {
+            bail!(
+                "Session {session_name} is not scoped to subproject {}",
+                subproject.display()
+            );
+        }
+    }
 
-    let config = Config::load(args.config)?
-        .with_project_path(project_path_buf)
+    let mut config = Config::load(args.config)?
+        .with_session_project(project_path_buf.clone(), metadata.subproject.as_deref())?
         .with_num_experts(num_experts);
+    if args.verbose {
+        config.feature_execution.verbose = true;
+    }
+
+    // Git operations stay at the repository root; worktrees share the subproject's queue
+    let mut worktree_manager = WorktreeManager::resolve(project_path_buf)
+        .await?
+        .with_shell_path_style(config.shell_path_style);
+    if config.subproject.is_some() {
+        worktree_manager = worktree_manager.with_macot_path(config.queue_path.clone());
+    }
+
+    let perf_log = args
+        .perf_log
+        .map(|path| {
+            PerfLog::open(&path, PERF_LOG_MAX_BYTES)
+                .with_context(|| format!("Failed to open perf log {}", path.display()))
+        })
+        .transpose()?;
 
-    let mut app = TowerApp::new(config, worktree_manager);
-    app.run().await?;
+    let mut app = TowerApp::new(config, worktree_manager)
+        .with_readonly(args.readonly)
+        .with_debug_overlay(args.debug_overlay)
+        .with_perf_log(perf_log);
+    if args.events {
+        app.run_headless().await?;
+    } else {
+        app.run().await?;
+    }
 
     Ok(())
 }
@@ -0,0 +1,513 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use anyhow::{bail, Context, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Tower commands whose key chord can be changed under `keybindings` in the config
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyAction {
+    AssignTask,
+    ResetExpert,
+    ChangeRole,
+    ApplyRolePreset,
+    Worktree,
+    ViewDiff,
+    MergeWorktree,
+    RemoveWorktree,
+    FeatureExecution,
+    PlanFeature,
+    ViewReport,
+    CycleEffort,
+    SwitchFocus,
+    ToggleExpertPanel,
+    ReplyToMessage,
+    EditNote,
+    MessageHistory,
+    MessageTemplate,
+    PingExpert,
+    PinExpertPanel,
+    InterruptExpert,
+    CycleExpertFilter,
+    CycleModel,
+    RetryFailedMessages,
+    DismissFailedMessages,
+    ZoomExpertPanel,
+    MoveExpertUp,
+    MoveExpertDown,
+    NextRole,
+    PrevRole,
+    ReplayLastMessage,
+    CommandPalette,
+    ToggleCompactStatus,
+    ToggleFollowMessages,
+    AssignBatch,
+    Scratchpad,
+    AssignAndWatch,
+    MessageQueue,
+}
+
+impl KeyAction {
+    pub const ALL: [KeyAction; 38] = [
+        KeyAction::AssignTask,
+        KeyAction::ResetExpert,
+        KeyAction::ChangeRole,
+        KeyAction::ApplyRolePreset,
+        KeyAction::Worktree,
+        KeyAction::ViewDiff,
+        KeyAction::MergeWorktree,
+        KeyAction::RemoveWorktree,
+        KeyAction::FeatureExecution,
+        KeyAction::PlanFeature,
+        KeyAction::ViewReport,
+        KeyAction::CycleEffort,
+        KeyAction::SwitchFocus,
+        KeyAction::ToggleExpertPanel,
+        KeyAction::ReplyToMessage,
+        KeyAction::EditNote,
+        KeyAction::MessageHistory,
+        KeyAction::MessageTemplate,
+        KeyAction::PingExpert,
+        KeyAction::PinExpertPanel,
+        KeyAction::InterruptExpert,
+        KeyAction::CycleExpertFilter,
+        KeyAction::CycleModel,
+        KeyAction::RetryFailedMessages,
+        KeyAction::DismissFailedMessages,
+        KeyAction::ZoomExpertPanel,
+        KeyAction::MoveExpertUp,
+        KeyAction::MoveExpertDown,
+        KeyAction::NextRole,
+        KeyAction::PrevRole,
+        KeyAction::ReplayLastMessage,
+        KeyAction::CommandPalette,
+        KeyAction::ToggleCompactStatus,
+        KeyAction::ToggleFollowMessages,
+        KeyAction::AssignBatch,
+        KeyAction::Scratchpad,
+        KeyAction::AssignAndWatch,
+        KeyAction::MessageQueue,
+    ];
+
+    /// Name used as the key under `keybindings`
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeyAction::AssignTask => "assign_task",
+            KeyAction::ResetExpert => "reset_expert",
+            KeyAction::ChangeRole => "change_role",
+            KeyAction::ApplyRolePreset => "apply_role_preset",
+            KeyAction::Worktree => "worktree",
+            KeyAction::ViewDiff => "view_diff",
+            KeyAction::MergeWorktree => "merge_worktree",
+            KeyAction::RemoveWorktree => "remove_worktree",
+            KeyAction::FeatureExecution => "feature_execution",
+            KeyAction::PlanFeature => "plan_feature",
+            KeyAction::ViewReport => "view_report",
+            KeyAction::CycleEffort => "cycle_effort",
+            KeyAction::SwitchFocus => "switch_focus",
+            KeyAction::ToggleExpertPanel => "toggle_expert_panel",
+            KeyAction::ReplyToMessage => "reply_to_message",
+            KeyAction::EditNote => "edit_note",
+            KeyAction::MessageHistory => "message_history",
+            KeyAction::MessageTemplate => "message_template",
+            KeyAction::PingExpert => "ping_expert",
+            KeyAction::PinExpertPanel => "pin_expert_panel",
+            KeyAction::InterruptExpert => "interrupt_expert",
+            KeyAction::CycleExpertFilter => "cycle_expert_filter",
+            KeyAction::CycleModel => "cycle_model",
+            KeyAction::RetryFailedMessages => "retry_failed_messages",
+            KeyAction::DismissFailedMessages => "dismiss_failed_messages",
+            KeyAction::ZoomExpertPanel => "zoom_expert_panel",
+            KeyAction::MoveExpertUp => "move_expert_up",
+            KeyAction::MoveExpertDown => "move_expert_down",
+            KeyAction::NextRole => "next_role",
+            KeyAction::PrevRole => "prev_role",
+            KeyAction::ReplayLastMessage => "replay_last_message",
+            KeyAction::CommandPalette => "command_palette",
+            KeyAction::ToggleCompactStatus => "toggle_compact_status",
+            KeyAction::ToggleFollowMessages => "toggle_follow_messages",
+            KeyAction::AssignBatch => "assign_batch",
+            KeyAction::Scratchpad => "scratchpad",
+            KeyAction::AssignAndWatch => "assign_and_watch",
+            KeyAction::MessageQueue => "message_queue",
+        }
+    }
+
+    fn default_chord(&self) -> KeyChord {
+        match self {
+            KeyAction::AssignTask => KeyChord::ctrl('s'),
+            KeyAction::ResetExpert => KeyChord::ctrl('r'),
+            KeyAction::ChangeRole => KeyChord::ctrl('o'),
+            KeyAction::ApplyRolePreset => KeyChord::function(7),
+            KeyAction::Worktree => KeyChord::ctrl('w'),
+            KeyAction::ViewDiff => KeyChord::ctrl('v'),
+            KeyAction::MergeWorktree => KeyChord::ctrl('y'),
+            KeyAction::RemoveWorktree => KeyChord::ctrl('z'),
+            KeyAction::FeatureExecution => KeyChord::ctrl('g'),
+            KeyAction::PlanFeature => KeyChord::function(6),
+            KeyAction::ViewReport => KeyChord::ctrl('x'),
+            KeyAction::CycleEffort => KeyChord::ctrl('l'),
+            KeyAction::SwitchFocus => KeyChord::ctrl('t'),
+            KeyAction::ToggleExpertPanel => KeyChord::ctrl('j'),
+            KeyAction::ReplyToMessage => KeyChord::function(4),
+            KeyAction::EditNote => KeyChord::function(5),
+            KeyAction::MessageHistory => KeyChord::function(8),
+            KeyAction::MessageTemplate => KeyChord::function(9),
+            KeyAction::PingExpert => KeyChord::function(10),
+            KeyAction::PinExpertPanel => KeyChord::function(11),
+            KeyAction::InterruptExpert => KeyChord::function(12),
+            KeyAction::CycleExpertFilter => KeyChord::alt('f'),
+            KeyAction::CycleModel => KeyChord::alt('m'),
+            KeyAction::RetryFailedMessages => KeyChord::alt('t'),
+            KeyAction::DismissFailedMessages => KeyChord::alt('d'),
+            KeyAction::ZoomExpertPanel => KeyChord::alt('z'),
+            KeyAction::MoveExpertUp => KeyChord::alt('k'),
+            KeyAction::MoveExpertDown => KeyChord::alt('j'),
+            KeyAction::NextRole => KeyChord::alt('n'),
+            KeyAction::PrevRole => KeyChord::alt('p'),
+            KeyAction::ReplayLastMessage => KeyChord::alt('l'),
+            KeyAction::CommandPalette => KeyChord::alt('x'),
+            KeyAction::ToggleCompactStatus => KeyChord::alt('c'),
+            KeyAction::ToggleFollowMessages => KeyChord::alt('o'),
+            KeyAction::AssignBatch => KeyChord::alt('b'),
+            KeyAction::Scratchpad => KeyChord::alt('w'),
+            KeyAction::AssignAndWatch => KeyChord::alt('v'),
+            KeyAction::MessageQueue => KeyChord::alt('q'),
+        }
+    }
+
+    /// Whether the action changes expert or session state (refused in readonly mode)
+    pub fn is_mutating(&self) -> bool {
+        matches!(
+            self,
+            KeyAction::AssignTask
+                | KeyAction::AssignBatch
+                | KeyAction::AssignAndWatch
+                | KeyAction::ResetExpert
+                | KeyAction::ChangeRole
+                | KeyAction::NextRole
+                | KeyAction::PrevRole
+                | KeyAction::ApplyRolePreset
+                | KeyAction::Worktree
+                | KeyAction::MergeWorktree
+                | KeyAction::RemoveWorktree
+                | KeyAction::FeatureExecution
+                | KeyAction::CycleEffort
+                | KeyAction::CycleModel
+                | KeyAction::EditNote
+                | KeyAction::PingExpert
+                | KeyAction::InterruptExpert
+                | KeyAction::RetryFailedMessages
+                | KeyAction::DismissFailedMessages
+                | KeyAction::ReplayLastMessage
+                | KeyAction::MoveExpertUp
+                | KeyAction::MoveExpertDown
+                | KeyAction::PinExpertPanel
+        )
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|action| action.name() == name)
+    }
+}
+
+/// A key plus modifiers, written in the config as e.g. `ctrl+s`, `alt+a` or `f5`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+/// Ctrl chords used for quitting and text editing, which cannot be rebound
+const RESERVED_CTRL_CHARS: [char; 12] =
+    ['c', 'q', 'b', 'f', 'a', 'e', 'p', 'n', 'h', 'd', 'u', 'k'];
+/// Function keys bound to help, the activity timeline and the debug overlay
+const RESERVED_FUNCTION_KEYS: [u8; 3] = [1, 2, 3];
+
+impl KeyChord {
+    fn ctrl(c: char) -> Self {
+        Self {
+            code: KeyCode::Char(c),
+            modifiers: KeyModifiers::CONTROL,
+        }
+    }
+
+    fn alt(c: char) -> Self {
+        Self {
+            code: KeyCode::Char(c),
+            modifiers: KeyModifiers::ALT,
+        }
+    }
+
+    fn function(n: u8) -> Self {
+        Self {
+            code: KeyCode::F(n),
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    /// Chord of a key event; Shift is folded into the character for plain keys
+    pub fn from_event(key: &KeyEvent) -> Self {
+        let mut modifiers = key.modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT);
+        if !matches!(key.code, KeyCode::Char(_)) {
+            modifiers |= key.modifiers & KeyModifiers::SHIFT;
+        }
+        let code = match key.code {
+            KeyCode::Char(c) => KeyCode::Char(c.to_ascii_lowercase()),
+            code => code,
+        };
+        Self { code, modifiers }
+    }
+
+    pub fn parse(spec: &str) -> Result<Self> {
+        let spec = spec.trim().to_ascii_lowercase();
+        let mut parts: Vec<&str> = spec.split('+').map(str::trim).collect();
+        let key = parts.pop().filter(|key| !key.is_empty());
+        let Some(key) = key else {
+            bail!("empty key chord '{spec}'");
+        };
+
+        let mut modifiers = KeyModifiers::NONE;
+        for part in parts {
+            modifiers |= match part {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" | "meta" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                other => bail!("unknown modifier '{other}' in key chord '{spec}'"),
+            };
+        }
+
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) if c.is_ascii_alphanumeric() => KeyCode::Char(c),
+            _ => match key.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                Some(n @ 1..=12) => KeyCode::F(n),
+                _ => bail!("unknown key '{key}' in key chord '{spec}'"),
+            },
+        };
+
+        let chord = Self { code, modifiers };
+        chord.check_bindable(&spec)?;
+        Ok(chord)
+    }
+
+    fn check_bindable(&self, spec: &str) -> Result<()> {
+        match self.code {
+            KeyCode::Char(c) => {
+                if self.modifiers.contains(KeyModifiers::SHIFT) {
+                    bail!("key chord '{spec}' cannot use shift with a character key, terminals do not report it reliably");
+                }
+                if !self
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+                {
+                    bail!("key chord '{spec}' needs ctrl or alt, plain keys are typed into the task input");
+                }
+                if self.modifiers == KeyModifiers::CONTROL && RESERVED_CTRL_CHARS.contains(&c) {
+                    bail!("key chord '{spec}' is reserved for quitting or text editing");
+                }
+                if self.modifiers == KeyModifiers::ALT && c.is_ascii_digit() {
+                    bail!("key chord '{spec}' is reserved for jumping to an expert");
+                }
+            }
+            KeyCode::F(n) if RESERVED_FUNCTION_KEYS.contains(&n) => {
+                bail!("key chord '{spec}' is reserved for help, timeline and debug overlay");
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "Shift+")?;
+        }
+        match self.code {
+            KeyCode::Char(c) => write!(f, "{}", c.to_ascii_uppercase()),
+            KeyCode::F(n) => write!(f, "F{n}"),
+            code => write!(f, "{code:?}"),
+        }
+    }
+}
+
+/// Lookup from key chords to tower actions, built from defaults plus config overrides
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    chords: HashMap<KeyAction, KeyChord>,
+    actions: HashMap<KeyChord, KeyAction>,
+}
+
+impl Keymap {
+    /// Apply `overrides` (action name → chord) on top of the default bindings.
+    /// Fails on unknown actions, unparsable or reserved chords, and duplicates.
+    pub fn from_overrides(overrides: &BTreeMap<String, String>) -> Result<Self> {
+        let mut chords: HashMap<KeyAction, KeyChord> = KeyAction::ALL
+            .into_iter()
+            .map(|action| (action, action.default_chord()))
+            .collect();
+
+        for (name, spec) in overrides {
+            let action = KeyAction::from_name(name).with_context(|| {
+                let known: Vec<_> = KeyAction::ALL.iter().map(KeyAction::name).collect();
+                format!(
+                    "unknown keybinding action '{name}' (expected one of: {})",
+                    known.join(", ")
+                )
+            })?;
+            let chord = KeyChord::parse(spec)
+                .with_context(|| format!("invalid keybinding for '{name}'"))?;
+            chords.insert(action, chord);
+        }
+
+        let mut actions = HashMap::new();
+        for action in KeyAction::ALL {
+            let chord = chords[&action];
+            if let Some(other) = actions.insert(chord, action) {
+                bail!(
+                    "keybinding {chord} is bound to both '{}' and '{}'",
+                    other.name(),
+                    action.name()
+                );
+            }
+        }
+
+        Ok(Self { chords, actions })
+    }
+
+    pub fn action_for(&self, key: &KeyEvent) -> Option<KeyAction> {
+        self.actions.get(&KeyChord::from_event(key)).copied()
+    }
+
+    pub fn chord(&self, action: KeyAction) -> KeyChord {
+        self.chords[&action]
+    }
+
+    /// Human-readable chord for `action`, e.g. `Ctrl+S`
+    pub fn label(&self, action: KeyAction) -> String {
+        self.chord(action).to_string()
+    }
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::from_overrides(&BTreeMap::new()).expect("default keybindings are valid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overrides(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn default_keymap_matches_builtin_chords() {
+        let keymap = Keymap::default();
+        let ctrl_s = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL);
+        assert_eq!(keymap.action_for(&ctrl_s), Some(KeyAction::AssignTask));
+        assert_eq!(keymap.label(KeyAction::MergeWorktree), "Ctrl+Y");
+        assert_eq!(
+            keymap.action_for(&KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE)),
+            None,
+            "action_for: plain characters should never trigger actions"
+        );
+    }
+
+    #[test]
+    fn override_replaces_default_chord() {
+        let keymap = Keymap::from_overrides(&overrides(&[("assign_task", "alt+Enter")]));
+        assert!(
+            keymap.is_err(),
+            "from_overrides: named keys are not supported"
+        );
+
+        let keymap = Keymap::from_overrides(&overrides(&[("assign_task", "alt+s")])).unwrap();
+        let alt_s = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::ALT);
+        let ctrl_s = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL);
+        assert_eq!(keymap.action_for(&alt_s), Some(KeyAction::AssignTask));
+        assert_eq!(
+            keymap.action_for(&ctrl_s),
+            None,
+            "from_overrides: the old chord should be freed"
+        );
+        assert_eq!(keymap.label(KeyAction::AssignTask), "Alt+S");
+    }
+
+    #[test]
+    fn from_overrides_rejects_duplicate_bindings() {
+        let err = Keymap::from_overrides(&overrides(&[("assign_task", "ctrl+r")]))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("Ctrl+R") && err.contains("assign_task") && err.contains("reset_expert"),
+            "from_overrides: duplicate should name the chord and both actions, got: {err}"
+        );
+
+        assert!(
+            Keymap::from_overrides(&overrides(&[
+                ("assign_task", "ctrl+r"),
+                ("reset_expert", "alt+r")
+            ]))
+            .is_ok(),
+            "from_overrides: swapping chords away from a default should be allowed"
+        );
+    }
+
+    #[test]
+    fn from_overrides_rejects_unknown_action_and_reserved_chords() {
+        let err = Keymap::from_overrides(&overrides(&[("launch_rockets", "ctrl+m")]))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("unknown keybinding action 'launch_rockets'"));
+
+        for spec in [
+            "ctrl+c",
+            "ctrl+k",
+            "s",
+            "alt+3",
+            "f1",
+            "hyper+s",
+            "ctrl+shift+s",
+        ] {
+            assert!(
+                Keymap::from_overrides(&overrides(&[("assign_task", spec)])).is_err(),
+                "from_overrides: '{spec}' should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_rejects_shift_with_character_key() {
+        let err = KeyChord::parse("alt+shift+m").unwrap_err().to_string();
+        assert!(
+            err.contains("cannot use shift with a character key"),
+            "parse: shift on a character key should be rejected, got {err}"
+        );
+        assert!(
+            KeyChord::parse("shift+f5").is_ok(),
+            "parse: shift on a function key should still be allowed"
+        );
+    }
+
+    #[test]
+    fn chord_from_event_ignores_shift_on_characters() {
+        let event = KeyEvent::new(
+            KeyCode::Char('S'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        );
+        assert_eq!(KeyChord::from_event(&event), KeyChord::ctrl('s'));
+    }
+}
@@ -1,14 +1,35 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Component, Path, PathBuf};
 
+use super::keymap::Keymap;
+
+use crate::context::AvailableRoles;
 use crate::feature::scheduler::SchedulerMode;
+use crate::models::{EffortLevel, MessageContent};
+use crate::queue::{MessageFormat, RecipientStrategy};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExpertConfig {
     pub name: String, // Display name only
     #[serde(default)]
     pub role: String, // Instruction file name (required for instruction loading)
+    /// Expert ID that outbox messages without a `to` field are sent to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_recipient: Option<u32>,
+    /// Groups the expert belongs to, addressable with `to: { tag: ... }`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Environment for this expert's agent, layered over the session-wide `env`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    /// Model the agent is launched with, e.g. `opus`; the agent's own default when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Effort level the expert starts at; medium when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effort: Option<EffortLevel>,
 }
 
 impl Default for ExpertConfig {
@@ -16,10 +37,214 @@ impl Default for ExpertConfig {
         Self {
             name: "expert".to_string(),
             role: "general".to_string(),
+            default_recipient: None,
+            tags: Vec::new(),
+            env: BTreeMap::new(),
+            model: None,
+            effort: None,
         }
     }
 }
 
+/// What happens to messages waiting for an expert when it is reset or its role changes
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResetMessagePolicy {
+    /// Deliver them to the relaunched agent as usual
+    #[default]
+    Deliver,
+    /// Move them to `messages/held/` until released with retry_failed_messages
+    Hold,
+    /// Move them to the dead-letter directory `messages/dead/`
+    DeadLetter,
+}
+
+/// When `macot start` launches each expert's agent
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LaunchMode {
+    /// Launch every expert when the session starts
+    #[default]
+    Eager,
+    /// Create the windows only; the tower launches an expert the first time
+    /// it is selected or assigned a task
+    Lazy,
+}
+
+/// How paths are spelled for the shell tmux runs commands in
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ShellPathStyle {
+    /// The shell sees paths exactly as macot does
+    #[default]
+    Native,
+    /// macot sees Windows paths (`C:\repo`) while tmux runs under WSL (`/mnt/c/repo`)
+    Wsl,
+}
+
+impl ShellPathStyle {
+    /// Spell a host path for the tmux shell; non-UTF-8 paths are an error.
+    pub fn to_shell(self, path: &Path) -> Result<String> {
+        Ok(self.convert(crate::utils::path_to_str(path)?))
+    }
+
+    /// Best-effort variant of [`Self::to_shell`] for text handed to agents,
+    /// replacing non-UTF-8 bytes instead of failing.
+    pub fn to_shell_lossy(self, path: &Path) -> String {
+        self.convert(&path.to_string_lossy())
+    }
+
+    /// Map a path reported by the tmux shell back to its host form.
+    pub fn to_host(self, shell_path: &str) -> PathBuf {
+        match self {
+            ShellPathStyle::Native => PathBuf::from(shell_path),
+            ShellPathStyle::Wsl => {
+                let Some(rest) = shell_path.strip_prefix("/mnt/") else {
+                    return PathBuf::from(shell_path);
+                };
+                let mut parts = rest.splitn(2, '/');
+                match parts.next().map(str::as_bytes) {
+                    Some(&[drive]) if drive.is_ascii_alphabetic() => {
+                        let tail = parts.next().unwrap_or("").replace('/', "\\");
+                        PathBuf::from(format!("{}:\\{tail}", drive.to_ascii_uppercase() as char))
+                    }
+                    _ => PathBuf::from(shell_path),
+                }
+            }
+        }
+    }
+
+    fn convert(self, host: &str) -> String {
+        match self {
+            ShellPathStyle::Native => host.to_string(),
+            ShellPathStyle::Wsl => {
+                let unified = host.replace('\\', "/");
+                let bytes = unified.as_bytes();
+                let (prefix, rest) =
+                    if bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' {
+                        (
+                            format!("/mnt/{}", (bytes[0] as char).to_ascii_lowercase()),
+                            &unified[2..],
+                        )
+                    } else {
+                        (String::new(), unified.as_str())
+                    };
+                let mut out = prefix;
+                for part in rest.split('/').filter(|p| !p.is_empty()) {
+                    out.push('/');
+                    out.push_str(part);
+                }
+                if out.is_empty() && rest.starts_with('/') {
+                    out.push('/');
+                }
+                out
+            }
+        }
+    }
+}
+
+/// A named team layout that assigns roles to several experts in one step
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RolePreset {
+    /// Role per expert ID
+    pub roles: BTreeMap<u32, String>,
+    /// Relaunch experts whose role changed so the new instructions apply immediately
+    #[serde(default)]
+    pub restart: bool,
+}
+
+/// A canned operator message; `{name}` placeholders are filled in when it is used
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageTemplate {
+    pub subject: String,
+    pub body: String,
+}
+
+impl MessageTemplate {
+    /// Placeholder names in order of first appearance, subject before body
+    pub fn placeholders(&self) -> Vec<String> {
+        let mut names = Vec::new();
+        for text in [&self.subject, &self.body] {
+            for (_, name) in placeholder_spans(text) {
+                if !names.iter().any(|n: &String| n == name) {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        names
+    }
+
+    /// Fill placeholders from `values`; names without a value are left as written
+    pub fn render(&self, values: &HashMap<String, String>) -> MessageContent {
+        MessageContent {
+            subject: fill_placeholders(&self.subject, values),
+            body: fill_placeholders(&self.body, values),
+            attachments: Vec::new(),
+        }
+    }
+}
+
+/// `{name}` placeholders in `text` as (byte range, name) pairs
+fn placeholder_spans(text: &str) -> Vec<(std::ops::Range<usize>, &str)> {
+    let mut spans = Vec::new();
+    let mut rest = 0;
+    while let Some(open) = text[rest..].find('{').map(|i| rest + i) {
+        let Some(close) = text[open..].find('}').map(|i| open + i) else {
+            break;
+        };
+        let name = &text[open + 1..close];
+        if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            spans.push((open..close + 1, name));
+            rest = close + 1;
+        } else {
+            rest = open + 1;
+        }
+    }
+    spans
+}
+
+fn fill_placeholders(text: &str, values: &HashMap<String, String>) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+    for (range, name) in placeholder_spans(text) {
+        if let Some(value) = values.get(name) {
+            out.push_str(&text[last..range.start]);
+            out.push_str(value);
+            last = range.end;
+        }
+    }
+    out.push_str(&text[last..]);
+    out
+}
+
+/// A coding-agent CLI to launch in expert panes instead of Claude
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentConfig {
+    /// Command line started in the expert's working directory, e.g. `codex --full-auto`
+    pub command: String,
+    /// Pane text that shows the agent has started and accepts input
+    pub ready_marker: String,
+    /// Input that makes the agent quit
+    #[serde(default = "AgentConfig::default_exit_command")]
+    pub exit_command: String,
+    /// Flag given the role instructions as its argument; instructions are not passed when unset
+    #[serde(default)]
+    pub instruction_flag: Option<String>,
+    /// Flag given an expert's `model` as its argument; models are not passed when unset
+    #[serde(default)]
+    pub model_flag: Option<String>,
+    /// Flag given an expert's effort level (`low`, `medium` or `high`) as its argument;
+    /// effort only reaches the agent through its instructions when unset
+    #[serde(default)]
+    pub effort_flag: Option<String>,
+}
+
+impl AgentConfig {
+    fn default_exit_command() -> String {
+        "/exit".to_string()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TimeoutConfig {
     pub agent_ready: u64,
@@ -27,6 +252,36 @@ pub struct TimeoutConfig {
     pub graceful_shutdown: u64,
 }
 
+/// Bounds on how many experts one session runs; each is a tmux window with its own agent
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ExpertLimitsConfig {
+    /// `macot start` warns above this many experts
+    #[serde(default = "ExpertLimitsConfig::default_soft_max")]
+    pub soft_max: u32,
+    /// `Config::validate` rejects more experts than this
+    #[serde(default = "ExpertLimitsConfig::default_hard_max")]
+    pub hard_max: u32,
+}
+
+impl Default for ExpertLimitsConfig {
+    fn default() -> Self {
+        Self {
+            soft_max: Self::default_soft_max(),
+            hard_max: Self::default_hard_max(),
+        }
+    }
+}
+
+impl ExpertLimitsConfig {
+    fn default_soft_max() -> u32 {
+        8
+    }
+
+    fn default_hard_max() -> u32 {
+        32
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FeatureExecutionConfig {
     #[serde(default = "FeatureExecutionConfig::default_batch_size")]
@@ -41,6 +296,9 @@ pub struct FeatureExecutionConfig {
     pub ready_grace_secs: u64,
     #[serde(default)]
     pub scheduler_mode: SchedulerMode,
+    /// Log each batch prompt and polled pane content to `.macot/logs/feature-<name>.log`
+    #[serde(default)]
+    pub verbose: bool,
 }
 
 impl Default for FeatureExecutionConfig {
@@ -52,6 +310,7 @@ impl Default for FeatureExecutionConfig {
             ready_timeout_secs: 60,
             ready_grace_secs: 5,
             scheduler_mode: SchedulerMode::Dag,
+            verbose: false,
         }
     }
 }
@@ -74,6 +333,241 @@ impl FeatureExecutionConfig {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessagingConfig {
+    /// Raise replies to a High-priority query to High so urgent conversations
+    /// are not queued behind unrelated Normal traffic.
+    #[serde(default = "MessagingConfig::default_reply_priority_boost")]
+    pub reply_priority_boost: bool,
+    /// Message bodies larger than this are truncated when picked up from the
+    /// outbox, so a runaway report cannot flood an expert's pane.
+    #[serde(default = "MessagingConfig::default_max_message_body_bytes")]
+    pub max_message_body_bytes: usize,
+    /// Outbox messages identical in sender, recipient, subject and body to one
+    /// already queued within this many seconds are dropped. 0 disables.
+    #[serde(default = "MessagingConfig::default_duplicate_window_secs")]
+    pub duplicate_window_secs: u64,
+    /// How delivered messages are rendered: `pretty` (default) or `json`.
+    #[serde(default)]
+    pub format: MessageFormat,
+    /// Custom `pretty` template with `{from}`, `{from_id}`, `{to}`, `{type}`,
+    /// `{priority}`, `{subject}`, `{body}`, `{attachments}`, `{message_id}`,
+    /// `{timestamp}` and `{reply_to}` placeholders.
+    #[serde(default)]
+    pub template: Option<String>,
+    /// Pending messages an expert may have before role-targeted messages go to
+    /// an idle peer instead and further direct messages are deferred. 0 disables.
+    #[serde(default)]
+    pub max_queue_depth: usize,
+    /// Messages delivered to one expert per routing pass, counted separately for
+    /// `notify` messages and for task-bearing ones (`query`, `response`,
+    /// `delegate`), so a burst of notifications never holds back a query. 0 disables.
+    #[serde(default)]
+    pub max_deliveries_per_cycle: usize,
+    /// Which idle expert a role- or tag-targeted message goes to: `first`
+    /// (default, lowest id), `round_robin`, `least_recently_delivered` or `least_loaded`.
+    #[serde(default)]
+    pub recipient_strategy: RecipientStrategy,
+    /// Let High priority messages marked `preempt: true` reach a Busy expert
+    /// instead of waiting until it is idle.
+    #[serde(default)]
+    pub allow_preemption: bool,
+    /// Also write each delivered message to `.macot/messages/inbox/expert<N>/`
+    /// and send the pane a pointer to the file instead of the full text.
+    #[serde(default)]
+    pub inbox_files: bool,
+    /// Pending messages addressed to an expert when it is reset or its role
+    /// changes: `deliver` (default), `hold` or `dead_letter`. The relaunched
+    /// agent has none of the context those messages assume.
+    #[serde(default)]
+    pub on_expert_reset: ResetMessagePolicy,
+}
+
+impl Default for MessagingConfig {
+    fn default() -> Self {
+        Self {
+            reply_priority_boost: true,
+            max_message_body_bytes: Self::default_max_message_body_bytes(),
+            duplicate_window_secs: Self::default_duplicate_window_secs(),
+            format: MessageFormat::default(),
+            template: None,
+            max_queue_depth: 0,
+            max_deliveries_per_cycle: 0,
+            recipient_strategy: RecipientStrategy::default(),
+            allow_preemption: false,
+            inbox_files: false,
+            on_expert_reset: ResetMessagePolicy::default(),
+        }
+    }
+}
+
+impl MessagingConfig {
+    fn default_reply_priority_boost() -> bool {
+        true
+    }
+
+    fn default_max_message_body_bytes() -> usize {
+        32 * 1024
+    }
+
+    fn default_duplicate_window_secs() -> u64 {
+        30
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UiConfig {
+    /// Maximum number of scrollback lines captured when entering scroll mode
+    /// (0 captures the entire history).
+    #[serde(default = "UiConfig::default_scroll_history_lines")]
+    pub scroll_history_lines: usize,
+    /// Expert panel lines matching a rule's pattern are shown in its color.
+    /// The first matching rule wins.
+    #[serde(default = "UiConfig::default_highlight_rules")]
+    pub highlight_rules: Vec<HighlightRule>,
+    /// Show a sparkline of each expert's busy time over the last minute in the
+    /// expert list. Turn off to save width on narrow terminals.
+    #[serde(default = "UiConfig::default_show_activity_sparkline")]
+    pub show_activity_sparkline: bool,
+    /// Flash the status line when an expert that was given a task from the
+    /// tower finishes it (goes from Busy to Idle)
+    #[serde(default)]
+    pub notify_task_done: bool,
+    /// Also ring the terminal bell for those notifications
+    #[serde(default)]
+    pub task_done_bell: bool,
+    /// Mark a report read, clearing the expert's report dot, once it has been
+    /// open in the detail view this long (0 turns this off)
+    #[serde(default = "UiConfig::default_report_read_delay_ms")]
+    pub report_read_delay_ms: u64,
+    /// Lines that open a region of the agent's output. A region runs until the
+    /// next line matching any marker; the first matching marker wins. The
+    /// expert panel dims thinking and emphasizes final answers.
+    #[serde(default = "UiConfig::default_output_regions")]
+    pub output_regions: Vec<RegionMarker>,
+    /// Show the disk used by each expert's worktree, and their total in the
+    /// header. Sizes are measured in the background about once a minute.
+    #[serde(default = "UiConfig::default_show_worktree_size")]
+    pub show_worktree_size: bool,
+    /// Start with "follow messages" on: each delivery selects its recipient
+    /// and shows its pane. Toggle at runtime with `toggle_follow_messages`.
+    #[serde(default)]
+    pub follow_messages: bool,
+    /// After `assign_and_watch`, also move focus into the expert panel so
+    /// keys go straight to the agent
+    #[serde(default)]
+    pub watch_focuses_panel: bool,
+}
+
+/// Kind of agent output a region of the pane holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputRegion {
+    Thinking,
+    ToolOutput,
+    Answer,
+    /// Anything else, e.g. the prompt or the CLI's own chrome
+    Other,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RegionMarker {
+    /// Regular expression matched against each line's plain text
+    pub pattern: String,
+    pub region: OutputRegion,
+}
+
+impl RegionMarker {
+    pub fn new(pattern: impl Into<String>, region: OutputRegion) -> Self {
+        Self {
+            pattern: pattern.into(),
+            region,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HighlightRule {
+    /// Regular expression matched against each line's plain text
+    pub pattern: String,
+    /// Color name (e.g. "red", "light_yellow") or "#rrggbb"
+    pub color: String,
+}
+
+impl HighlightRule {
+    pub fn new(pattern: impl Into<String>, color: impl Into<String>) -> Self {
+        Self {
+            pattern: pattern.into(),
+            color: color.into(),
+        }
+    }
+
+    /// Compile the pattern and parse the color
+    pub fn compile(&self) -> Result<(regex::Regex, ratatui::style::Color)> {
+        let regex = regex::Regex::new(&self.pattern)
+            .with_context(|| format!("invalid pattern '{}'", self.pattern))?;
+        let color = self
+            .color
+            .parse()
+            .with_context(|| format!("invalid color '{}'", self.color))?;
+        Ok((regex, color))
+    }
+}
+
+impl Default for UiConfig {
+    fn default() -> Self {
+        Self {
+            scroll_history_lines: Self::default_scroll_history_lines(),
+            highlight_rules: Self::default_highlight_rules(),
+            show_activity_sparkline: Self::default_show_activity_sparkline(),
+            notify_task_done: false,
+            task_done_bell: false,
+            report_read_delay_ms: Self::default_report_read_delay_ms(),
+            output_regions: Self::default_output_regions(),
+            show_worktree_size: Self::default_show_worktree_size(),
+            follow_messages: false,
+            watch_focuses_panel: false,
+        }
+    }
+}
+
+impl UiConfig {
+    fn default_show_activity_sparkline() -> bool {
+        true
+    }
+
+    fn default_show_worktree_size() -> bool {
+        true
+    }
+
+    fn default_report_read_delay_ms() -> u64 {
+        1500
+    }
+
+    fn default_scroll_history_lines() -> usize {
+        5000
+    }
+
+    /// Claude Code's pane layout: `✻ Thinking…`, `⏺ Tool(...)` calls with
+    /// `⎿` results, `⏺` answers, and the `>` prompt inside a `─` rule
+    fn default_output_regions() -> Vec<RegionMarker> {
+        vec![
+            RegionMarker::new(r"^\s*[✻∴]\s*Thinking", OutputRegion::Thinking),
+            RegionMarker::new(r"^⏺ [\w:-]+\(", OutputRegion::ToolOutput),
+            RegionMarker::new(r"^\s*⎿", OutputRegion::ToolOutput),
+            RegionMarker::new(r"^⏺", OutputRegion::Answer),
+            RegionMarker::new(r"^(>|─{3,}|╭|│ >)", OutputRegion::Other),
+        ]
+    }
+
+    fn default_highlight_rules() -> Vec<HighlightRule> {
+        vec![
+            HighlightRule::new("error|panic|FAILED", "red"),
+            HighlightRule::new("warning", "yellow"),
+        ]
+    }
+}
+
 impl Default for TimeoutConfig {
     fn default() -> Self {
         Self {
@@ -92,6 +586,46 @@ pub struct Config {
     pub timeouts: TimeoutConfig,
     #[serde(default)]
     pub feature_execution: FeatureExecutionConfig,
+    #[serde(default)]
+    pub messaging: MessagingConfig,
+    #[serde(default)]
+    pub ui: UiConfig,
+    /// Overrides of tower key chords by action name, e.g. `assign_task: alt+s`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub keybindings: BTreeMap<String, String>,
+    /// Environment variables set for every expert's agent
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    #[serde(default)]
+    pub shell_path_style: ShellPathStyle,
+    /// Role layouts by name, applied from the tower by typing the name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub role_presets: BTreeMap<String, RolePreset>,
+    /// Canned operator messages by name, picked from the tower
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub message_templates: BTreeMap<String, MessageTemplate>,
+    /// Task framings by trigger; typing `/<trigger>` and a space at the start
+    /// of the task input expands it
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub task_templates: BTreeMap<String, String>,
+    /// After a role change relaunches a busy expert, send it a note naming
+    /// the task it was last assigned so the work can be picked up again
+    #[serde(default)]
+    pub role_change_handoff: bool,
+    /// Shell command the tower runs when an expert finishes a task assigned
+    /// from the tower
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_task_hook: Option<String>,
+    #[serde(default)]
+    pub launch: LaunchMode,
+    #[serde(default)]
+    pub expert_limits: ExpertLimitsConfig,
+    /// Alternative agent CLI; Claude is launched when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent: Option<AgentConfig>,
+    /// Models the tower cycles an expert through when overriding its `model`
+    #[serde(default = "Config::default_model_choices")]
+    pub model_choices: Vec<String>,
     #[serde(default = "Config::default_role_instructions_path")]
     pub role_instructions_path: PathBuf,
     #[serde(skip)]
@@ -100,6 +634,9 @@ pub struct Config {
     pub queue_path: PathBuf,
     #[serde(skip)]
     pub core_instructions_path: PathBuf,
+    /// Monorepo subdirectory the session is scoped to, relative to the repository root
+    #[serde(skip)]
+    pub subproject: Option<PathBuf>,
 }
 
 impl Default for Config {
@@ -110,31 +647,78 @@ impl Default for Config {
                 ExpertConfig {
                     name: "Alyosha".to_string(),
                     role: "architect".to_string(),
+                    default_recipient: None,
+                    tags: Vec::new(),
+                    env: BTreeMap::new(),
+                    model: None,
+                    effort: None,
                 },
                 ExpertConfig {
                     name: "Ilyusha".to_string(),
                     role: "planner".to_string(),
+                    default_recipient: None,
+                    tags: Vec::new(),
+                    env: BTreeMap::new(),
+                    model: None,
+                    effort: None,
                 },
                 ExpertConfig {
                     name: "Grigory".to_string(),
                     role: "general".to_string(),
+                    default_recipient: None,
+                    tags: Vec::new(),
+                    env: BTreeMap::new(),
+                    model: None,
+                    effort: None,
                 },
                 ExpertConfig {
                     name: "Katya".to_string(),
                     role: "debugger".to_string(),
+                    default_recipient: None,
+                    tags: Vec::new(),
+                    env: BTreeMap::new(),
+                    model: None,
+                    effort: None,
                 },
             ],
             timeouts: TimeoutConfig::default(),
             feature_execution: FeatureExecutionConfig::default(),
+            messaging: MessagingConfig::default(),
+            ui: UiConfig::default(),
+            keybindings: BTreeMap::new(),
+            env: BTreeMap::new(),
+            shell_path_style: ShellPathStyle::default(),
+            role_presets: BTreeMap::new(),
+            message_templates: BTreeMap::new(),
+            task_templates: BTreeMap::new(),
+            role_change_handoff: false,
+            post_task_hook: None,
+            launch: LaunchMode::default(),
+            expert_limits: ExpertLimitsConfig::default(),
+            agent: None,
+            model_choices: Self::default_model_choices(),
             role_instructions_path: Self::default_role_instructions_path(),
             project_path: PathBuf::new(),
             queue_path: PathBuf::new(),
             core_instructions_path: PathBuf::new(),
+            subproject: None,
         }
     }
 }
 
 impl Config {
+    /// Expand task input that is exactly `/<trigger>` into its template.
+    /// Returns the text and the char offset of its first `{placeholder}`
+    /// (the end of the text when it has none).
+    pub fn expand_task_template(&self, input: &str) -> Option<(String, usize)> {
+        let template = self.task_templates.get(input.strip_prefix('/')?)?;
+        let cursor = match placeholder_spans(template).first() {
+            Some((range, _)) => template[..range.start].chars().count(),
+            None => template.chars().count(),
+        };
+        Some((template.clone(), cursor))
+    }
+
     pub fn load(config_path: Option<PathBuf>) -> Result<Self> {
         let path = config_path.unwrap_or_else(Self::default_config_path);
 
@@ -143,6 +727,9 @@ impl Config {
                 .with_context(|| format!("Failed to read config file: {}", path.display()))?;
             let config: Config = serde_yaml::from_str(&content)
                 .with_context(|| format!("Failed to parse config file: {}", path.display()))?;
+            config
+                .keymap()
+                .with_context(|| format!("Invalid keybindings in {}", path.display()))?;
             Ok(config)
         } else {
             Ok(Config::default())
@@ -160,6 +747,10 @@ impl Config {
         }
     }
 
+    pub fn default_model_choices() -> Vec<String> {
+        ["opus", "sonnet", "haiku"].map(String::from).to_vec()
+    }
+
     /// Default path for role instructions: ~/.config/macot/instructions/
     pub fn default_role_instructions_path() -> PathBuf {
         dirs::config_dir()
@@ -175,6 +766,187 @@ impl Config {
         self
     }
 
+    /// Scope experts, the `.macot` queue, status markers and reports to
+    /// `subproject`, a directory relative to the current `project_path`.
+    /// Core instructions stay at the repository root.
+    pub fn with_subproject(self, subproject: &Path) -> Result<Self> {
+        use anyhow::bail;
+
+        if subproject.as_os_str().is_empty()
+            || !subproject
+                .components()
+                .all(|c| matches!(c, Component::Normal(_)))
+        {
+            bail!(
+                "subproject must be a relative path inside the project: {}",
+                subproject.display()
+            );
+        }
+        let path = self.project_path.join(subproject);
+        if !path.is_dir() {
+            bail!("subproject directory not found: {}", path.display());
+        }
+
+        let core_instructions_path = self.core_instructions_path.clone();
+        let mut config = self.with_project_path(path);
+        config.core_instructions_path = core_instructions_path;
+        config.subproject = Some(subproject.to_path_buf());
+        Ok(config)
+    }
+
+    /// Point the config at a running session, whose recorded project path is
+    /// already scoped to `subproject` when it has one.
+    pub fn with_session_project(
+        self,
+        project_path: PathBuf,
+        subproject: Option<&str>,
+    ) -> Result<Self> {
+        let Some(subproject) = subproject.map(Path::new) else {
+            return Ok(self.with_project_path(project_path));
+        };
+        let root = project_path
+            .ancestors()
+            .nth(subproject.components().count())
+            .map(Path::to_path_buf)
+            .context("session project path is shorter than its subproject")?;
+        self.with_project_path(root).with_subproject(subproject)
+    }
+
+    /// Check the preconditions a session needs before any tmux windows are created.
+    ///
+    /// Requires at least one expert, core instructions under `core_instructions_path`
+    /// (`templates/core.md.tmpl` or legacy `core.md`), and a readable
+    /// `role_instructions_path` when it exists; the embedded roles are always available.
+    pub fn validate(&self) -> Result<()> {
+        use anyhow::bail;
+
+        if self.experts.is_empty() {
+            bail!(
+                "no experts configured: add at least one entry under `experts` in the config file"
+            );
+        }
+
+        let hard_max = self.expert_limits.hard_max;
+        if self.num_experts() > hard_max {
+            bail!(
+                "{} experts configured, but `expert_limits.hard_max` is {hard_max}: every expert runs its own tmux window and agent. \
+                 Use fewer experts or raise `expert_limits.hard_max` in the config file",
+                self.num_experts()
+            );
+        }
+
+        for (id, expert) in self.experts.iter().enumerate() {
+            if let Some(recipient) = expert.default_recipient {
+                if recipient as usize >= self.experts.len() || recipient as usize == id {
+                    bail!(
+                        "expert '{}' has default_recipient {} which is not another configured expert",
+                        expert.name,
+                        recipient
+                    );
+                }
+            }
+            if expert.model.as_deref().is_some_and(|m| m.trim().is_empty()) {
+                bail!("expert '{}' has an empty `model`", expert.name);
+            }
+            // Messages address experts by name, matching case-insensitively
+            if let Some(first) = self.experts[..id]
+                .iter()
+                .position(|other| other.name.eq_ignore_ascii_case(&expert.name))
+            {
+                bail!(
+                    "experts {first} and {id} are both named '{}': expert names must be unique \
+                     (ignoring case) so messages addressed by name reach a single expert",
+                    expert.name
+                );
+            }
+        }
+
+        for (name, preset) in &self.role_presets {
+            if let Some(id) = preset.roles.keys().find(|&&id| id >= self.num_experts()) {
+                bail!(
+                    "role preset '{name}' assigns expert {id}, but only {} experts are configured",
+                    self.num_experts()
+                );
+            }
+        }
+
+        for (name, template) in &self.message_templates {
+            if template.subject.trim().is_empty() || template.body.trim().is_empty() {
+                bail!("message template '{name}' needs both `subject` and `body`");
+            }
+        }
+
+        for (trigger, template) in &self.task_templates {
+            if trigger.is_empty()
+                || !trigger
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            {
+                bail!(
+                    "task template trigger '{trigger}' may only use letters, digits, '-' and '_'"
+                );
+            }
+            if template.trim().is_empty() {
+                bail!("task template '{trigger}' is empty");
+            }
+        }
+
+        let is_env_name = |key: &str| {
+            key.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        };
+        let env_keys = self
+            .env
+            .keys()
+            .chain(self.experts.iter().flat_map(|e| e.env.keys()));
+        for key in env_keys {
+            if !is_env_name(key) {
+                bail!("env variable name '{key}' is not a valid shell identifier");
+            }
+        }
+
+        for (index, rule) in self.ui.highlight_rules.iter().enumerate() {
+            rule.compile()
+                .with_context(|| format!("ui.highlight_rules[{index}] is invalid"))?;
+        }
+
+        if let Some(ref agent) = self.agent {
+            if agent.command.trim().is_empty() || agent.ready_marker.is_empty() {
+                bail!("agent needs both `command` and `ready_marker` set");
+            }
+        }
+
+        let core_template = self
+            .core_instructions_path
+            .join("templates")
+            .join("core.md.tmpl");
+        let core_legacy = self.core_instructions_path.join("core.md");
+        if !core_template.exists() && !core_legacy.exists() {
+            bail!(
+                "core instructions not found at {} (expected {} or {})",
+                self.core_instructions_path.display(),
+                core_template.display(),
+                core_legacy.display()
+            );
+        }
+
+        AvailableRoles::from_instructions_path(&self.role_instructions_path).with_context(
+            || {
+                format!(
+                    "failed to read role instructions at {}",
+                    self.role_instructions_path.display()
+                )
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /// Tower keymap: the default chords with `keybindings` applied on top
+    pub fn keymap(&self) -> Result<Keymap> {
+        Keymap::from_overrides(&self.keybindings)
+    }
+
     /// Returns the number of experts (derived from experts array length)
     pub fn num_experts(&self) -> u32 {
         self.experts.len() as u32
@@ -186,12 +958,66 @@ impl Config {
             self.experts.push(ExpertConfig {
                 name: format!("expert{idx}"),
                 role: "general".to_string(),
+                default_recipient: None,
+                tags: Vec::new(),
+                env: BTreeMap::new(),
+                model: None,
+                effort: None,
             });
         }
         self.experts.truncate(num_experts as usize);
         self
     }
 
+    /// Map of expert ID to its configured `default_recipient`
+    pub fn default_recipients(&self) -> HashMap<u32, u32> {
+        self.experts
+            .iter()
+            .enumerate()
+            .filter_map(|(id, expert)| expert.default_recipient.map(|to| (id as u32, to)))
+            .collect()
+    }
+
+    /// Environment for an expert's agent: session-wide `env` with the expert's own entries on top
+    pub fn expert_env(&self, id: u32) -> BTreeMap<String, String> {
+        let mut env = self.env.clone();
+        if let Some(expert) = self.get_expert(id) {
+            env.extend(expert.env.clone());
+        }
+        env
+    }
+
+    /// Map of expert ID to its merged agent environment, for experts that have one
+    pub fn expert_envs(&self) -> HashMap<u32, BTreeMap<String, String>> {
+        (0..self.num_experts())
+            .map(|id| (id, self.expert_env(id)))
+            .filter(|(_, env)| !env.is_empty())
+            .collect()
+    }
+
+    /// Map of expert ID to its configured `model`, for experts that set one
+    pub fn expert_models(&self) -> HashMap<u32, String> {
+        self.experts
+            .iter()
+            .enumerate()
+            .filter_map(|(id, expert)| expert.model.clone().map(|m| (id as u32, m)))
+            .collect()
+    }
+
+    /// Map of expert ID to its configured `effort`, for experts that set one
+    pub fn expert_efforts(&self) -> HashMap<u32, EffortLevel> {
+        self.experts
+            .iter()
+            .enumerate()
+            .filter_map(|(id, expert)| expert.effort.map(|e| (id as u32, e)))
+            .collect()
+    }
+
+    /// Effort level expert `id` is configured to start at, if any
+    pub fn get_expert_effort(&self, id: u32) -> Option<EffortLevel> {
+        self.get_expert(id).and_then(|expert| expert.effort)
+    }
+
     pub fn get_expert(&self, id: u32) -> Option<&ExpertConfig> {
         self.experts.get(id as usize)
     }
@@ -244,11 +1070,17 @@ impl Config {
     /// Returns the absolute path to the status marker file for a given expert.
     /// Path format: {queue_path}/status/expert{expert_id}
     pub fn status_file_path(&self, expert_id: u32) -> String {
-        self.queue_path
-            .join("status")
-            .join(format!("expert{expert_id}"))
-            .to_string_lossy()
-            .into_owned()
+        self.shell_path_style.to_shell_lossy(
+            &self
+                .queue_path
+                .join("status")
+                .join(format!("expert{expert_id}")),
+        )
+    }
+
+    /// `path` as the tmux shell must see it, e.g. for `cd` before launching an agent
+    pub fn shell_path(&self, path: &Path) -> Result<String> {
+        self.shell_path_style.to_shell(path)
     }
 
     /// Get default role for expert from config
@@ -373,6 +1205,26 @@ timeouts:
         assert_eq!(config.timeouts.agent_ready, 60);
     }
 
+    #[test]
+    fn config_load_rejects_duplicate_keybindings() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.yaml");
+        let yaml = r#"
+session_prefix: "test"
+experts:
+  - name: "lead"
+keybindings:
+  assign_task: ctrl+w
+"#;
+        std::fs::write(&config_path, yaml).unwrap();
+
+        let err = format!("{:#}", Config::load(Some(config_path)).unwrap_err());
+        assert!(
+            err.contains("Invalid keybindings") && err.contains("worktree"),
+            "load: duplicate chord should fail with the clashing action, got: {err}"
+        );
+    }
+
     #[test]
     fn config_load_returns_default_when_file_missing() {
         let config = Config::load(Some(PathBuf::from("/nonexistent/config.yaml"))).unwrap();
@@ -392,6 +1244,57 @@ timeouts:
         );
     }
 
+    #[test]
+    fn config_with_subproject_roots_queue_under_subproject() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        std::fs::create_dir_all(root.join("services/api")).unwrap();
+
+        let config = Config::default()
+            .with_project_path(root.clone())
+            .with_subproject(Path::new("services/api"))
+            .unwrap();
+
+        let api = root.join("services/api");
+        assert_eq!(config.project_path, api);
+        assert_eq!(config.queue_path, api.join(".macot"));
+        assert_eq!(
+            PathBuf::from(config.status_file_path(1)),
+            api.join(".macot/status/expert1"),
+            "with_subproject: status markers should live under the subproject"
+        );
+        assert_eq!(
+            config.core_instructions_path,
+            root.join("instructions"),
+            "with_subproject: core instructions should stay at the repository root"
+        );
+
+        let restored = Config::default()
+            .with_session_project(api, Some("services/api"))
+            .unwrap();
+        assert_eq!(restored.queue_path, config.queue_path);
+        assert_eq!(
+            restored.core_instructions_path,
+            config.core_instructions_path
+        );
+    }
+
+    #[test]
+    fn config_with_subproject_rejects_paths_outside_project() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = Config::default().with_project_path(temp_dir.path().to_path_buf());
+
+        for subproject in ["../other", "/abs", "missing"] {
+            assert!(
+                config
+                    .clone()
+                    .with_subproject(Path::new(subproject))
+                    .is_err(),
+                "with_subproject: {subproject} should be rejected"
+            );
+        }
+    }
+
     #[test]
     fn config_role_instructions_path_defaults_to_config_dir() {
         let config = Config::default();
@@ -624,6 +1527,510 @@ feature_execution:
         );
     }
 
+    fn validated_config(project: &std::path::Path) -> Config {
+        let core = project.join("instructions").join("templates");
+        std::fs::create_dir_all(&core).unwrap();
+        std::fs::write(core.join("core.md.tmpl"), "# Core").unwrap();
+        let mut config = Config::default().with_project_path(project.to_path_buf());
+        config.role_instructions_path = project.join("roles");
+        config
+    }
+
+    #[test]
+    fn config_validate_accepts_complete_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = validated_config(temp_dir.path());
+        assert!(
+            config.validate().is_ok(),
+            "config_validate: complete config should pass"
+        );
+    }
+
+    #[test]
+    fn config_validate_accepts_legacy_core_md() {
+        let temp_dir = TempDir::new().unwrap();
+        let instructions = temp_dir.path().join("instructions");
+        std::fs::create_dir_all(&instructions).unwrap();
+        std::fs::write(instructions.join("core.md"), "# Core").unwrap();
+        let config = Config::default().with_project_path(temp_dir.path().to_path_buf());
+        assert!(
+            config.validate().is_ok(),
+            "config_validate: legacy core.md should satisfy core instructions check"
+        );
+    }
+
+    #[test]
+    fn config_validate_rejects_empty_experts() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = validated_config(temp_dir.path());
+        config.experts.clear();
+        let err = config.validate().unwrap_err().to_string();
+        assert!(
+            err.contains("no experts configured"),
+            "config_validate: empty experts should be reported, got: {err}"
+        );
+    }
+
+    #[test]
+    fn config_validate_enforces_expert_hard_cap() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = validated_config(temp_dir.path()).with_num_experts(32);
+        assert!(
+            config.validate().is_ok(),
+            "config_validate: the default hard cap itself should be allowed"
+        );
+
+        let err = config
+            .with_num_experts(50)
+            .validate()
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("50 experts configured") && err.contains("`expert_limits.hard_max` is 32"),
+            "config_validate: exceeding the hard cap should name the count and the limit, got: {err}"
+        );
+
+        let mut raised = validated_config(temp_dir.path()).with_num_experts(50);
+        raised.expert_limits.hard_max = 64;
+        assert!(raised.validate().is_ok());
+    }
+
+    #[test]
+    fn config_role_presets_parse_and_validate_expert_ids() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.yaml");
+        std::fs::write(
+            &config_path,
+            "session_prefix: test\nexperts: []\nrole_presets:\n  team:\n    roles: { 0: architect, 3: debugger }\n    restart: true\n",
+        )
+        .unwrap();
+
+        let loaded = Config::load(Some(config_path)).unwrap();
+        let preset = &loaded.role_presets["team"];
+        assert_eq!(preset.roles[&3], "debugger");
+        assert!(preset.restart, "role_presets: restart should parse");
+
+        let mut config = validated_config(temp_dir.path());
+        config.role_presets = loaded.role_presets;
+        assert!(config.validate().is_ok());
+
+        config
+            .role_presets
+            .get_mut("team")
+            .unwrap()
+            .roles
+            .insert(7, "general".to_string());
+        let err = config.validate().unwrap_err().to_string();
+        assert!(
+            err.contains("role preset 'team' assigns expert 7"),
+            "config_validate: out-of-range preset expert should be reported, got: {err}"
+        );
+    }
+
+    #[test]
+    fn config_message_templates_parse_and_fill_placeholders() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.yaml");
+        std::fs::write(
+            &config_path,
+            "session_prefix: test\nexperts: []\nmessage_templates:\n  rebase:\n    subject: Please rebase {branch}\n    body: \"{branch} is behind {base}; rebase onto {base}. Keep {braces } as is.\"\n",
+        )
+        .unwrap();
+
+        let loaded = Config::load(Some(config_path)).unwrap();
+        let template = &loaded.message_templates["rebase"];
+        assert_eq!(
+            template.placeholders(),
+            vec!["branch", "base"],
+            "placeholders: should list each name once in order"
+        );
+
+        let values = HashMap::from([
+            ("branch".to_string(), "feat/auth".to_string()),
+            ("base".to_string(), "main".to_string()),
+        ]);
+        let content = template.render(&values);
+        assert_eq!(content.subject, "Please rebase feat/auth");
+        assert_eq!(
+            content.body,
+            "feat/auth is behind main; rebase onto main. Keep {braces } as is."
+        );
+
+        let mut config = validated_config(temp_dir.path());
+        config.message_templates = loaded.message_templates;
+        assert!(config.validate().is_ok());
+        config.message_templates.get_mut("rebase").unwrap().body = " ".to_string();
+        let err = config.validate().unwrap_err().to_string();
+        assert!(
+            err.contains("message template 'rebase'"),
+            "config_validate: empty template body should be reported, got: {err}"
+        );
+    }
+
+    #[test]
+    fn config_expand_task_template_places_cursor_at_first_placeholder() {
+        let config = Config {
+            task_templates: BTreeMap::from([
+                (
+                    "impl".to_string(),
+                    "Implement {feature} with tests and update docs".to_string(),
+                ),
+                ("review".to_string(), "Review the last commit".to_string()),
+            ]),
+            ..Config::default()
+        };
+
+        assert_eq!(
+            config.expand_task_template("/impl"),
+            Some((
+                "Implement {feature} with tests and update docs".to_string(),
+                10
+            ))
+        );
+        assert_eq!(
+            config
+                .expand_task_template("/review")
+                .map(|(_, cursor)| cursor),
+            Some(22),
+            "expand_task_template: without placeholders the cursor goes to the end"
+        );
+        assert_eq!(config.expand_task_template("/nope"), None);
+        assert_eq!(config.expand_task_template("impl"), None);
+        assert_eq!(config.expand_task_template("/impl now"), None);
+
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = validated_config(temp_dir.path());
+        config
+            .task_templates
+            .insert("bad trigger".to_string(), "x".to_string());
+        let err = config.validate().unwrap_err().to_string();
+        assert!(
+            err.contains("task template trigger 'bad trigger'"),
+            "config_validate: triggers with spaces should be rejected, got: {err}"
+        );
+    }
+
+    #[test]
+    fn config_expert_env_layers_expert_over_session() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.yaml");
+        std::fs::write(
+            &config_path,
+            "session_prefix: test\nenv: { API_BASE: http://prod, DEBUG: '0' }\nexperts:\n  - name: a\n    env: { DEBUG: '1', EXTRA: x }\n  - name: b\n",
+        )
+        .unwrap();
+
+        let config = Config::load(Some(config_path)).unwrap();
+        let env = config.expert_env(0);
+        assert_eq!(env["DEBUG"], "1", "expert_env: expert value should win");
+        assert_eq!(env["API_BASE"], "http://prod");
+        assert_eq!(env["EXTRA"], "x");
+        assert_eq!(
+            config.expert_env(1),
+            config.env,
+            "expert_env: expert without overrides should get session env"
+        );
+        assert_eq!(config.expert_envs().len(), 2);
+    }
+
+    #[test]
+    fn config_expert_models_reads_per_expert_model() {
+        let yaml = r#"
+session_prefix: test
+experts:
+  - name: architect
+    model: opus
+    effort: high
+  - name: formatter
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(
+            config.expert_models(),
+            HashMap::from([(0, "opus".to_string())]),
+            "expert_models: only experts with a model should be listed"
+        );
+        assert_eq!(config.model_choices, Config::default_model_choices());
+        assert_eq!(
+            config.expert_efforts(),
+            HashMap::from([(0, EffortLevel::High)]),
+            "expert_efforts: only experts with an effort should be listed"
+        );
+    }
+
+    #[test]
+    fn config_validate_rejects_empty_model() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = validated_config(temp_dir.path());
+        config.experts[0].model = Some(" ".to_string());
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("empty `model`"), "validate: got {err}");
+    }
+
+    #[test]
+    fn config_validate_rejects_duplicate_expert_names() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = validated_config(temp_dir.path()).with_num_experts(3);
+        config.experts[0].name = "backend".to_string();
+        config.experts[2].name = "Backend".to_string();
+        let err = config.validate().unwrap_err().to_string();
+        assert!(
+            err.contains("experts 0 and 2 are both named 'Backend'") && err.contains("unique"),
+            "config_validate: duplicate names should say which experts clash, got: {err}"
+        );
+
+        config.experts[2].name = "backend-2".to_string();
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn config_validate_rejects_invalid_highlight_rules() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = validated_config(temp_dir.path());
+        config.ui.highlight_rules = vec![
+            HighlightRule::new("ok", "green"),
+            HighlightRule::new("(unclosed", "red"),
+        ];
+        let err = format!("{:#}", config.validate().unwrap_err());
+        assert!(
+            err.contains("ui.highlight_rules[1]") && err.contains("(unclosed"),
+            "config_validate: an invalid pattern should name the rule, got: {err}"
+        );
+
+        config.ui.highlight_rules[1] = HighlightRule::new("warning", "not-a-color");
+        let err = format!("{:#}", config.validate().unwrap_err());
+        assert!(
+            err.contains("ui.highlight_rules[1]") && err.contains("not-a-color"),
+            "config_validate: an invalid color should name the rule, got: {err}"
+        );
+
+        config.ui.highlight_rules[1] = HighlightRule::new("warning", "#ffaa00");
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn config_validate_rejects_invalid_env_names() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = validated_config(temp_dir.path());
+        config.experts[0]
+            .env
+            .insert("BAD-NAME".to_string(), "x".to_string());
+        let err = config.validate().unwrap_err().to_string();
+        assert!(
+            err.contains("'BAD-NAME'"),
+            "config_validate: invalid env name should be reported, got: {err}"
+        );
+    }
+
+    #[test]
+    fn config_validate_rejects_unknown_default_recipient() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = validated_config(temp_dir.path());
+        config.experts[0].default_recipient = Some(1);
+        assert!(config.validate().is_ok());
+
+        config.experts[0].default_recipient = Some(9);
+        let err = config.validate().unwrap_err().to_string();
+        assert!(
+            err.contains("default_recipient 9"),
+            "config_validate: unknown default_recipient should be reported, got: {err}"
+        );
+
+        config.experts[0].default_recipient = Some(0);
+        assert!(
+            config.validate().is_err(),
+            "config_validate: an expert cannot be its own default_recipient"
+        );
+    }
+
+    #[test]
+    fn config_validate_rejects_missing_core_instructions() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = Config::default().with_project_path(temp_dir.path().to_path_buf());
+        let err = config.validate().unwrap_err().to_string();
+        let expected = format!(
+            "core instructions not found at {}",
+            temp_dir.path().join("instructions").display()
+        );
+        assert!(
+            err.contains(&expected),
+            "config_validate: missing core instructions should name the path, got: {err}"
+        );
+    }
+
+    #[test]
+    fn config_validate_rejects_empty_task_template() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = validated_config(temp_dir.path());
+        config
+            .task_templates
+            .insert("review".to_string(), "  ".to_string());
+        let err = config.validate().unwrap_err().to_string();
+        assert!(
+            err.contains("task template 'review' is empty"),
+            "config_validate: an empty task template should be reported, got: {err}"
+        );
+    }
+
+    #[test]
+    fn config_validate_rejects_agent_without_command_or_ready_marker() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = validated_config(temp_dir.path());
+        let agent = AgentConfig {
+            command: "codex".to_string(),
+            ready_marker: "context left".to_string(),
+            exit_command: "/quit".to_string(),
+            instruction_flag: None,
+            model_flag: None,
+            effort_flag: None,
+        };
+        config.agent = Some(agent.clone());
+        assert!(config.validate().is_ok());
+
+        for broken in [
+            AgentConfig {
+                command: " ".to_string(),
+                ..agent.clone()
+            },
+            AgentConfig {
+                ready_marker: String::new(),
+                ..agent
+            },
+        ] {
+            config.agent = Some(broken);
+            let err = config.validate().unwrap_err().to_string();
+            assert!(
+                err.contains("agent needs both `command` and `ready_marker`"),
+                "config_validate: an incomplete agent should be reported, got: {err}"
+            );
+        }
+    }
+
+    #[test]
+    fn config_validate_accepts_missing_role_instructions_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = validated_config(temp_dir.path());
+        config.role_instructions_path = temp_dir.path().join("no-such-dir");
+        assert!(
+            config.validate().is_ok(),
+            "config_validate: the embedded roles cover a missing role directory"
+        );
+    }
+
+    #[test]
+    fn config_validate_rejects_unreadable_role_instructions() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = validated_config(temp_dir.path());
+        let not_a_dir = temp_dir.path().join("roles-file");
+        std::fs::write(&not_a_dir, "not a directory").unwrap();
+        config.role_instructions_path = not_a_dir;
+        let err = config.validate().unwrap_err().to_string();
+        assert!(
+            err.contains("failed to read role instructions at"),
+            "config_validate: unreadable role path should be reported, got: {err}"
+        );
+    }
+
+    #[test]
+    fn ui_config_highlight_rules_default_to_errors_and_warnings() {
+        let config = Config::default();
+        assert_eq!(
+            config.ui.highlight_rules,
+            vec![
+                HighlightRule::new("error|panic|FAILED", "red"),
+                HighlightRule::new("warning", "yellow"),
+            ],
+            "ui_config: default highlight rules should cover errors and warnings"
+        );
+    }
+
+    #[test]
+    fn ui_config_highlight_rules_load_from_yaml() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.yaml");
+
+        let yaml = r#"
+session_prefix: "test"
+experts:
+  - name: "dev"
+ui:
+  highlight_rules:
+    - pattern: "TODO"
+      color: "cyan"
+"#;
+        std::fs::write(&config_path, yaml).unwrap();
+
+        let config = Config::load(Some(config_path)).unwrap();
+        assert_eq!(
+            config.ui.highlight_rules,
+            vec![HighlightRule::new("TODO", "cyan")],
+            "ui_config: configured highlight rules should replace the defaults"
+        );
+        assert_eq!(config.ui.scroll_history_lines, 5000);
+    }
+
+    #[test]
+    fn messaging_config_reply_priority_boost_defaults_to_enabled() {
+        let config = Config::default();
+        assert!(
+            config.messaging.reply_priority_boost,
+            "messaging_config: reply_priority_boost should default to true"
+        );
+    }
+
+    #[test]
+    fn messaging_config_reply_priority_boost_can_be_disabled_from_yaml() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.yaml");
+
+        let yaml = r#"
+session_prefix: "test"
+experts:
+  - name: "dev"
+messaging:
+  reply_priority_boost: false
+"#;
+        std::fs::write(&config_path, yaml).unwrap();
+
+        let config = Config::load(Some(config_path)).unwrap();
+        assert!(
+            !config.messaging.reply_priority_boost,
+            "messaging_config: reply_priority_boost should be overridable"
+        );
+        assert_eq!(
+            config.messaging.max_message_body_bytes,
+            32 * 1024,
+            "messaging_config: unspecified max_message_body_bytes should use the default"
+        );
+    }
+
+    #[test]
+    fn messaging_config_format_defaults_to_pretty_and_parses_json() {
+        assert_eq!(Config::default().messaging.format, MessageFormat::Pretty);
+
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.yaml");
+        let yaml = r#"
+session_prefix: "test"
+experts:
+  - name: "dev"
+messaging:
+  format: json
+  template: "{from}: {subject}"
+"#;
+        std::fs::write(&config_path, yaml).unwrap();
+
+        let config = Config::load(Some(config_path)).unwrap();
+        assert_eq!(
+            config.messaging.format,
+            MessageFormat::Json,
+            "messaging_config: format should be configurable"
+        );
+        assert_eq!(
+            config.messaging.template.as_deref(),
+            Some("{from}: {subject}")
+        );
+    }
+
     #[test]
     fn config_expert_role_serde_without_role_defaults() {
         let temp_dir = TempDir::new().unwrap();
@@ -641,4 +2048,86 @@ experts:
         // role defaults to empty string via serde(default), get_expert_role falls back to name
         assert_eq!(config.get_expert_role(0), "lead");
     }
+
+    #[test]
+    fn shell_path_style_native_keeps_path_unchanged() {
+        let style = ShellPathStyle::Native;
+        assert_eq!(
+            style.to_shell(Path::new("/tmp/project")).unwrap(),
+            "/tmp/project",
+            "shell_path_style: native should not rewrite paths"
+        );
+        assert_eq!(style.to_host("/tmp/project"), PathBuf::from("/tmp/project"));
+    }
+
+    #[test]
+    fn shell_path_style_wsl_round_trips_mixed_separators() {
+        let style = ShellPathStyle::Wsl;
+        let shell = style
+            .to_shell(Path::new(r"C:\Users\me/proj\.macot\worktrees/feature"))
+            .unwrap();
+        assert_eq!(
+            shell, "/mnt/c/Users/me/proj/.macot/worktrees/feature",
+            "shell_path_style: wsl should map the drive and unify separators"
+        );
+        assert_eq!(
+            style.to_host(&shell),
+            PathBuf::from(r"C:\Users\me\proj\.macot\worktrees\feature"),
+            "shell_path_style: wsl should map the shell path back to the host form"
+        );
+        assert_eq!(
+            style.to_shell(Path::new(&shell)).unwrap(),
+            shell,
+            "shell_path_style: converting a shell path again should be a no-op"
+        );
+    }
+
+    #[test]
+    fn shell_path_style_wsl_leaves_non_drive_paths() {
+        let style = ShellPathStyle::Wsl;
+        assert_eq!(
+            style.to_shell(Path::new("/home/me//proj")).unwrap(),
+            "/home/me/proj"
+        );
+        assert_eq!(style.to_shell(Path::new("/")).unwrap(), "/");
+        assert_eq!(
+            style.to_host("/mnt/data/proj"),
+            PathBuf::from("/mnt/data/proj"),
+            "shell_path_style: /mnt mounts that are not drive letters stay as-is"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn shell_path_style_rejects_non_utf8_without_panicking() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+        let path = Path::new(OsStr::from_bytes(&[b'/', 0xff, b'x']));
+        assert!(
+            ShellPathStyle::Wsl.to_shell(path).is_err(),
+            "shell_path_style: non-UTF-8 path should be an error"
+        );
+        assert_eq!(ShellPathStyle::Native.to_shell_lossy(path), "/\u{fffd}x");
+    }
+
+    #[test]
+    fn config_shell_path_style_parses_from_yaml() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.yaml");
+        std::fs::write(
+            &config_path,
+            "session_prefix: test\nexperts: []\nshell_path_style: wsl\n",
+        )
+        .unwrap();
+
+        let config = Config::load(Some(config_path))
+            .unwrap()
+            .with_project_path(PathBuf::from(r"D:\repo"));
+        assert_eq!(config.shell_path_style, ShellPathStyle::Wsl);
+        assert_eq!(
+            config.status_file_path(1),
+            "/mnt/d/repo/.macot/status/expert1",
+            "status_file_path: should use the shell path form"
+        );
+    }
 }
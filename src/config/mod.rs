@@ -1,4 +1,11 @@
+mod keymap;
 mod loader;
 
 #[allow(unused_imports)]
-pub use loader::{Config, ExpertConfig, FeatureExecutionConfig};
+pub use keymap::{KeyAction, KeyChord, Keymap};
+#[allow(unused_imports)]
+pub use loader::{
+    AgentConfig, Config, ExpertConfig, ExpertLimitsConfig, FeatureExecutionConfig, HighlightRule,
+    LaunchMode, MessageTemplate, MessagingConfig, OutputRegion, RegionMarker, ResetMessagePolicy,
+    RolePreset, ShellPathStyle, UiConfig,
+};
@@ -1,6 +1,8 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::models::EffortLevel;
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ClaudeSession {
     pub session_id: Option<String>,
@@ -36,6 +38,17 @@ pub struct Knowledge {
     pub dependencies_mapped: Vec<Dependency>,
 }
 
+/// A task the tower assigned to the expert
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskRecord {
+    pub task_id: String,
+    pub status: String,
+    pub summary: String,
+}
+
+/// Entries kept in `task_history`; older ones are dropped first
+pub const MAX_TASK_HISTORY: usize = 20;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExpertContext {
     pub expert_id: u32,
@@ -51,6 +64,17 @@ pub struct ExpertContext {
     pub worktree_branch: Option<String>,
     #[serde(default)]
     pub worktree_path: Option<String>,
+    /// Effort level chosen in the tower, overriding the expert's configured `effort`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effort_level: Option<EffortLevel>,
+    /// Model chosen in the tower, overriding the expert's configured `model`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Operator's free-text note; shown in the tower only, never in instructions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub task_history: Vec<TaskRecord>,
 }
 
 impl ExpertContext {
@@ -66,6 +90,10 @@ impl ExpertContext {
             knowledge: Knowledge::default(),
             worktree_branch: None,
             worktree_path: None,
+            effort_level: None,
+            model: None,
+            note: None,
+            task_history: Vec::new(),
         }
     }
 
@@ -111,6 +139,45 @@ impl ExpertContext {
         self.worktree_path = None;
         self.touch();
     }
+
+    pub fn set_effort_level(&mut self, level: EffortLevel) {
+        self.effort_level = Some(level);
+        self.touch();
+    }
+
+    pub fn set_model(&mut self, model: Option<String>) {
+        self.model = model;
+        self.touch();
+    }
+
+    /// Set the operator note; blank text removes it
+    pub fn set_note(&mut self, note: &str) {
+        let note = note.trim();
+        self.note = (!note.is_empty()).then(|| note.to_string());
+        self.touch();
+    }
+
+    /// Append an assigned task, keeping at most `MAX_TASK_HISTORY` entries
+    pub fn record_task(&mut self, summary: &str) {
+        let next = self
+            .task_history
+            .last()
+            .and_then(|t| t.task_id.strip_prefix("task-"))
+            .and_then(|n| n.parse::<u32>().ok())
+            .map_or(1, |n| n + 1);
+        self.task_history.push(TaskRecord {
+            task_id: format!("task-{next:03}"),
+            status: "assigned".to_string(),
+            summary: summary.to_string(),
+        });
+        let excess = self.task_history.len().saturating_sub(MAX_TASK_HISTORY);
+        self.task_history.drain(..excess);
+        self.touch();
+    }
+
+    pub fn last_task(&self) -> Option<&TaskRecord> {
+        self.task_history.last()
+    }
 }
 
 #[cfg(test)]
@@ -353,5 +420,62 @@ task_history:
             Some("session-abc".to_string())
         );
         assert_eq!(ctx.knowledge.files_analyzed.len(), 1);
+        assert_eq!(ctx.last_task().unwrap().summary, "Created login form");
+    }
+
+    #[test]
+    fn expert_context_record_task_numbers_and_caps_history() {
+        let mut ctx = ExpertContext::new(0, "architect".to_string(), "abc123".to_string());
+        assert!(ctx.last_task().is_none());
+
+        for i in 0..MAX_TASK_HISTORY + 2 {
+            ctx.record_task(&format!("task {i}"));
+        }
+
+        assert_eq!(
+            ctx.task_history.len(),
+            MAX_TASK_HISTORY,
+            "record_task: history should be capped"
+        );
+        let last = ctx.last_task().unwrap();
+        assert_eq!(last.task_id, format!("task-{:03}", MAX_TASK_HISTORY + 2));
+        assert_eq!(last.summary, format!("task {}", MAX_TASK_HISTORY + 1));
+        assert_eq!(last.status, "assigned");
+    }
+
+    #[test]
+    fn expert_context_set_effort_level_persists_through_yaml() {
+        let mut ctx = ExpertContext::new(0, "architect".to_string(), "abc123".to_string());
+        ctx.set_effort_level(EffortLevel::High);
+
+        let yaml = serde_yaml::to_string(&ctx).unwrap();
+        assert!(
+            yaml.contains("effort_level: high"),
+            "set_effort_level: YAML should contain effort_level"
+        );
+        let parsed: ExpertContext = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(
+            parsed.effort_level,
+            Some(EffortLevel::High),
+            "set_effort_level: level should round-trip through YAML"
+        );
+    }
+
+    #[test]
+    fn expert_context_backward_compatible_deserialization_without_effort_level() {
+        let yaml = r#"
+expert_id: 1
+expert_name: frontend
+session_hash: def456
+created_at: "2024-01-15T10:00:00Z"
+updated_at: "2024-01-15T11:00:00Z"
+"#;
+
+        let ctx: ExpertContext = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(
+            ctx.effort_level.unwrap_or_default(),
+            EffortLevel::Medium,
+            "backward_compat: effort_level should default to medium"
+        );
     }
 }
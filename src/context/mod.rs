@@ -2,8 +2,10 @@ mod expert;
 mod role;
 mod shared;
 mod store;
+mod ui_state;
 
-pub use expert::ExpertContext;
+pub use expert::{ExpertContext, TaskRecord};
 pub use role::{AvailableRoles, RoleInfo, SessionExpertRoles};
-pub use shared::Decision;
+pub use shared::{Decision, ScratchpadEntry};
 pub use store::ContextStore;
+pub use ui_state::{ArchivedReport, TowerUiState};
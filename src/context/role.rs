@@ -67,9 +67,38 @@ pub struct RoleInfo {
 #[derive(Debug, Clone, Default)]
 pub struct AvailableRoles {
     pub roles: Vec<RoleInfo>,
+    /// Why custom roles could not be loaded, when only embedded defaults are listed
+    pub warning: Option<String>,
 }
 
 impl AvailableRoles {
+    /// Like [`Self::from_instructions_path`], but never fails: when the
+    /// directory is missing or unreadable, only the embedded defaults are
+    /// listed and `warning` explains why.
+    pub fn load_or_defaults(path: &Path) -> Self {
+        if !path.is_dir() {
+            let mut roles = Self::embedded_defaults();
+            roles.warning = Some(format!(
+                "Role instructions path not found: {} (using built-in roles)",
+                path.display()
+            ));
+            return roles;
+        }
+
+        Self::from_instructions_path(path).unwrap_or_else(|e| {
+            let mut roles = Self::embedded_defaults();
+            roles.warning = Some(format!(
+                "Failed to read role instructions at {}: {e} (using built-in roles)",
+                path.display()
+            ));
+            roles
+        })
+    }
+
+    fn embedded_defaults() -> Self {
+        Self::merge_defaults(Vec::new())
+    }
+
     /// Load available roles from user's config folder and merge with embedded defaults.
     /// User custom roles in the folder take precedence over embedded defaults.
     pub fn from_instructions_path(path: &Path) -> Result<Self> {
@@ -108,7 +137,11 @@ impl AvailableRoles {
             }
         }
 
-        // Merge with embedded defaults (always available)
+        Ok(Self::merge_defaults(roles))
+    }
+
+    /// Add embedded defaults (always available) that custom roles do not override
+    fn merge_defaults(mut roles: Vec<RoleInfo>) -> Self {
         for name in defaults::default_role_names() {
             if !roles.iter().any(|r| r.name == *name) {
                 let default_content = defaults::get_default(name).unwrap_or("");
@@ -128,7 +161,10 @@ impl AvailableRoles {
 
         roles.sort_by(|a, b| a.name.cmp(&b.name));
 
-        Ok(Self { roles })
+        Self {
+            roles,
+            warning: None,
+        }
     }
 
     fn capitalize_name(name: &str) -> String {
@@ -153,6 +189,23 @@ impl AvailableRoles {
     pub fn names(&self) -> Vec<&str> {
         self.roles.iter().map(|r| r.name.as_str()).collect()
     }
+
+    /// The role after `current` in list order (before it when `forward` is
+    /// false), wrapping around; a role not listed starts from the matching
+    /// end. `None` when no roles are available.
+    pub fn cycle_from(&self, current: &str, forward: bool) -> Option<&str> {
+        let len = self.roles.len();
+        if len == 0 {
+            return None;
+        }
+        let index = match self.roles.iter().position(|r| r.name == current) {
+            Some(i) if forward => (i + 1) % len,
+            Some(i) => (i + len - 1) % len,
+            None if forward => 0,
+            None => len - 1,
+        };
+        Some(&self.roles[index].name)
+    }
 }
 
 #[cfg(test)]
@@ -284,4 +337,100 @@ mod tests {
         let backend = roles.find_by_name("backend_engineer").unwrap();
         assert_eq!(backend.display_name, "Backend Engineer");
     }
+
+    #[test]
+    fn available_roles_cycle_from_wraps_both_ways() {
+        let role = |name: &str| RoleInfo {
+            name: name.to_string(),
+            display_name: name.to_string(),
+            description: String::new(),
+        };
+        let roles = AvailableRoles {
+            roles: vec![role("architect"), role("debugger"), role("general")],
+            warning: None,
+        };
+
+        assert_eq!(roles.cycle_from("architect", true), Some("debugger"));
+        assert_eq!(
+            roles.cycle_from("general", true),
+            Some("architect"),
+            "cycle_from: stepping past the last role wraps to the first"
+        );
+        assert_eq!(roles.cycle_from("architect", false), Some("general"));
+        assert_eq!(roles.cycle_from("custom", true), Some("architect"));
+        assert_eq!(roles.cycle_from("custom", false), Some("general"));
+        assert_eq!(
+            AvailableRoles::default().cycle_from("architect", true),
+            None,
+            "cycle_from: no roles means nothing to cycle to"
+        );
+    }
+
+    #[test]
+    fn available_roles_load_or_defaults_missing_directory_is_degraded() {
+        let temp_dir = TempDir::new().unwrap();
+        let missing = temp_dir.path().join("gone");
+
+        let roles = AvailableRoles::load_or_defaults(&missing);
+        assert!(
+            roles.find_by_name("general").is_some(),
+            "load_or_defaults: general should stay available without the directory"
+        );
+        let warning = roles
+            .warning
+            .expect("load_or_defaults: missing path should warn");
+        assert!(
+            warning.contains("Role instructions path not found"),
+            "load_or_defaults: warning should name the problem, got: {warning}"
+        );
+    }
+
+    #[test]
+    fn available_roles_load_or_defaults_drops_custom_roles_after_removal() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join("instructions");
+        std::fs::create_dir(&dir).unwrap();
+        std::fs::write(dir.join("reviewer.md"), "# Reviewer\n\nReviews code").unwrap();
+
+        let before = AvailableRoles::load_or_defaults(&dir);
+        assert!(before.find_by_name("reviewer").is_some());
+        assert!(
+            before.warning.is_none(),
+            "load_or_defaults: no warning when readable"
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        let after = AvailableRoles::load_or_defaults(&dir);
+        assert!(
+            after.find_by_name("reviewer").is_none(),
+            "load_or_defaults: removed custom roles should disappear on reload"
+        );
+        assert!(after.find_by_name("general").is_some());
+        assert!(after.warning.is_some());
+    }
+
+    #[test]
+    fn available_roles_load_or_defaults_empty_directory_has_no_warning() {
+        let temp_dir = TempDir::new().unwrap();
+        let roles = AvailableRoles::load_or_defaults(temp_dir.path());
+        assert!(roles.find_by_name("general").is_some());
+        assert!(
+            roles.warning.is_none(),
+            "load_or_defaults: an empty directory is valid and should not warn"
+        );
+    }
+
+    #[test]
+    fn available_roles_load_or_defaults_file_instead_of_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let file = temp_dir.path().join("instructions");
+        std::fs::write(&file, "not a directory").unwrap();
+
+        let roles = AvailableRoles::load_or_defaults(&file);
+        assert!(roles.find_by_name("general").is_some());
+        assert!(
+            roles.warning.is_some(),
+            "load_or_defaults: a file in place of the directory should warn"
+        );
+    }
 }
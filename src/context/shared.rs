@@ -48,6 +48,14 @@ pub struct FileDependency {
     pub depended_by: Vec<String>,
 }
 
+/// One note appended to the session scratchpad
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScratchpadEntry {
+    pub author: u32,
+    pub timestamp: DateTime<Utc>,
+    pub text: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct SharedContext {
     #[serde(default)]
@@ -56,6 +64,9 @@ pub struct SharedContext {
     pub conventions: Vec<Convention>,
     #[serde(default)]
     pub dependencies: Vec<FileDependency>,
+    /// Notes from any expert, oldest first
+    #[serde(default)]
+    pub scratchpad: Vec<ScratchpadEntry>,
 }
 
 impl SharedContext {
@@ -80,6 +91,28 @@ impl SharedContext {
             .collect()
     }
 
+    pub fn append_scratchpad(&mut self, author: u32, timestamp: DateTime<Utc>, text: String) {
+        self.scratchpad.push(ScratchpadEntry {
+            author,
+            timestamp,
+            text,
+        });
+    }
+
+    /// The scratchpad as the markdown document experts read
+    pub fn render_scratchpad(&self) -> String {
+        let mut doc = String::from("# Scratchpad\n");
+        for entry in &self.scratchpad {
+            doc.push_str(&format!(
+                "\n## expert{} - {}\n\n{}\n",
+                entry.author,
+                entry.timestamp.format("%Y-%m-%d %H:%M:%S UTC"),
+                entry.text.trim_end()
+            ));
+        }
+        doc
+    }
+
     #[allow(dead_code)]
     pub fn add_convention(&mut self, convention: Convention) {
         self.conventions.push(convention);
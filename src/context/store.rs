@@ -1,20 +1,24 @@
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use std::path::PathBuf;
 use tokio::fs;
 
 use super::expert::ExpertContext;
 use super::role::SessionExpertRoles;
 use super::shared::{Decision, SharedContext};
+use super::ui_state::TowerUiState;
 
 #[derive(Clone)]
 pub struct ContextStore {
     base_path: PathBuf,
+    scratchpad_path: PathBuf,
 }
 
 impl ContextStore {
     pub fn new(queue_path: PathBuf) -> Self {
         Self {
             base_path: queue_path.join("sessions"),
+            scratchpad_path: queue_path.join("scratchpad"),
         }
     }
 
@@ -40,8 +44,12 @@ impl ContextStore {
         for i in 0..num_experts {
             let expert_path = self.expert_path(session_hash, i);
             fs::create_dir_all(&expert_path).await?;
+            fs::create_dir_all(self.scratchpad_inbox(i)).await?;
         }
 
+        let ctx = self.load_shared_context(session_hash).await?;
+        fs::write(self.scratchpad_file(), ctx.render_scratchpad()).await?;
+
         Ok(())
     }
 
@@ -120,6 +128,76 @@ impl ContextStore {
         Ok(())
     }
 
+    /// Directory an expert drops note files into; each file becomes one
+    /// scratchpad entry authored by that expert
+    pub fn scratchpad_inbox(&self, expert_id: u32) -> PathBuf {
+        self.scratchpad_path.join(format!("expert{expert_id}"))
+    }
+
+    /// The rendered scratchpad every expert reads
+    pub fn scratchpad_file(&self) -> PathBuf {
+        self.scratchpad_path.join("scratchpad.md")
+    }
+
+    /// Move dropped notes into the shared context, oldest first, and rewrite
+    /// the scratchpad file. Returns how many notes were added.
+    pub async fn ingest_scratchpad(&self, session_hash: &str) -> Result<usize> {
+        if !self.scratchpad_path.exists() {
+            return Ok(0);
+        }
+
+        let mut notes = Vec::new();
+        let mut inboxes = fs::read_dir(&self.scratchpad_path).await?;
+        while let Some(inbox) = inboxes.next_entry().await? {
+            let Some(author) = inbox
+                .file_name()
+                .to_str()
+                .and_then(|name| name.strip_prefix("expert"))
+                .and_then(|id| id.parse::<u32>().ok())
+            else {
+                continue;
+            };
+            if !inbox.file_type().await?.is_dir() {
+                continue;
+            }
+            let mut files = fs::read_dir(inbox.path()).await?;
+            while let Some(file) = files.next_entry().await? {
+                // Skip dotfiles so a note written under a temporary name is
+                // not picked up half-finished
+                if file.file_name().to_string_lossy().starts_with('.')
+                    || !file.file_type().await?.is_file()
+                {
+                    continue;
+                }
+                let written: DateTime<Utc> = file.metadata().await?.modified()?.into();
+                notes.push((written, author, file.path()));
+            }
+        }
+        if notes.is_empty() {
+            return Ok(0);
+        }
+        notes.sort();
+
+        let mut ctx = self.load_shared_context(session_hash).await?;
+        let mut added = 0;
+        for (written, author, path) in &notes {
+            let text = fs::read_to_string(path).await?;
+            if !text.trim().is_empty() {
+                ctx.append_scratchpad(*author, *written, text);
+                added += 1;
+            }
+        }
+        // Rewrite the document before saving the context, so a failed write
+        // leaves the notes to be ingested again rather than recorded twice
+        fs::write(self.scratchpad_file(), ctx.render_scratchpad()).await?;
+        self.save_shared_context(session_hash, &ctx).await?;
+        // Only drop the notes once they are saved, so a failed write loses nothing
+        for (_, _, path) in notes {
+            fs::remove_file(&path).await?;
+        }
+        Ok(added)
+    }
+
     pub async fn cleanup_session(&self, session_hash: &str) -> Result<()> {
         let session_path = self.session_path(session_hash);
         if session_path.exists() {
@@ -150,6 +228,24 @@ impl ContextStore {
         Ok(())
     }
 
+    /// Tower view settings for the session; defaults when none were saved
+    pub async fn load_ui_state(&self, session_hash: &str) -> Result<TowerUiState> {
+        let path = self.session_path(session_hash).join("ui_state.yaml");
+        if !path.exists() {
+            return Ok(TowerUiState::default());
+        }
+        let content = fs::read_to_string(&path).await?;
+        Ok(serde_yaml::from_str(&content)?)
+    }
+
+    pub async fn save_ui_state(&self, session_hash: &str, state: &TowerUiState) -> Result<()> {
+        let session_path = self.session_path(session_hash);
+        fs::create_dir_all(&session_path).await?;
+        let content = serde_yaml::to_string(state)?;
+        fs::write(session_path.join("ui_state.yaml"), content).await?;
+        Ok(())
+    }
+
     #[allow(dead_code)]
     pub async fn list_sessions(&self) -> Result<Vec<String>> {
         let mut sessions = Vec::new();
@@ -174,6 +270,7 @@ impl ContextStore {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::context::ArchivedReport;
     use tempfile::TempDir;
 
     async fn create_test_store() -> (ContextStore, TempDir) {
@@ -228,6 +325,39 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn context_store_round_trips_expert_note() {
+        let (store, _temp) = create_test_store().await;
+        store.init_session("abc123", 2).await.unwrap();
+
+        let mut ctx = ExpertContext::new(1, "backend".to_string(), "abc123".to_string());
+        ctx.set_note("  this one handles auth  ");
+        store.save_expert_context(&ctx).await.unwrap();
+
+        let mut loaded = store
+            .load_expert_context("abc123", 1)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            loaded.note.as_deref(),
+            Some("this one handles auth"),
+            "save_expert_context: note should survive a reload"
+        );
+
+        loaded.set_note("");
+        store.save_expert_context(&loaded).await.unwrap();
+        let cleared = store
+            .load_expert_context("abc123", 1)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            cleared.note, None,
+            "set_note: blank text should clear the note"
+        );
+    }
+
     #[tokio::test]
     async fn context_store_load_expert_context_returns_none_when_missing() {
         let (store, _temp) = create_test_store().await;
@@ -279,6 +409,85 @@ mod tests {
         assert_eq!(ctx.decisions[0].topic, "Architecture");
     }
 
+    #[tokio::test]
+    async fn context_store_scratchpad_keeps_notes_when_rewrite_fails() {
+        let (store, _temp) = create_test_store().await;
+        store.init_session("abc123", 1).await.unwrap();
+        let note = store.scratchpad_inbox(0).join("a.md");
+        std::fs::write(&note, "API uses v2 paths").unwrap();
+        // A directory in place of scratchpad.md makes the rewrite fail
+        let _ = std::fs::remove_file(store.scratchpad_file());
+        std::fs::create_dir_all(store.scratchpad_file()).unwrap();
+
+        assert!(store.ingest_scratchpad("abc123").await.is_err());
+
+        assert!(
+            note.exists(),
+            "ingest_scratchpad: a note should survive a failed rewrite"
+        );
+        std::fs::remove_dir(store.scratchpad_file()).unwrap();
+        assert_eq!(store.ingest_scratchpad("abc123").await.unwrap(), 1);
+        assert_eq!(
+            store
+                .load_shared_context("abc123")
+                .await
+                .unwrap()
+                .scratchpad
+                .len(),
+            1,
+            "ingest_scratchpad: a retried note should be recorded once"
+        );
+    }
+
+    #[tokio::test]
+    async fn context_store_scratchpad_accumulates_notes_from_experts_in_order() {
+        let (store, _temp) = create_test_store().await;
+        store.init_session("abc123", 2).await.unwrap();
+
+        std::fs::write(store.scratchpad_inbox(1).join("a.md"), "API uses v2 paths").unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        std::fs::write(
+            store.scratchpad_inbox(0).join("b.md"),
+            "Auth lives in src/auth",
+        )
+        .unwrap();
+        std::fs::write(store.scratchpad_inbox(0).join(".c.md"), "not done yet").unwrap();
+        assert_eq!(store.ingest_scratchpad("abc123").await.unwrap(), 2);
+
+        std::fs::write(
+            store.scratchpad_inbox(1).join("a.md"),
+            "Tests need DATABASE_URL",
+        )
+        .unwrap();
+        assert_eq!(store.ingest_scratchpad("abc123").await.unwrap(), 1);
+        assert_eq!(store.ingest_scratchpad("abc123").await.unwrap(), 0);
+
+        let ctx = store.load_shared_context("abc123").await.unwrap();
+        let notes: Vec<(u32, &str)> = ctx
+            .scratchpad
+            .iter()
+            .map(|e| (e.author, e.text.as_str()))
+            .collect();
+        assert_eq!(
+            notes,
+            vec![
+                (1, "API uses v2 paths"),
+                (0, "Auth lives in src/auth"),
+                (1, "Tests need DATABASE_URL"),
+            ],
+            "ingest_scratchpad: notes should accumulate across ingests, oldest first"
+        );
+        assert!(!store.scratchpad_inbox(1).join("a.md").exists());
+        assert!(store.scratchpad_inbox(0).join(".c.md").exists());
+
+        let doc = std::fs::read_to_string(store.scratchpad_file()).unwrap();
+        let first = doc.find("API uses v2 paths").unwrap();
+        let second = doc.find("Auth lives in src/auth").unwrap();
+        let third = doc.find("Tests need DATABASE_URL").unwrap();
+        assert!(first < second && second < third);
+        assert!(doc.contains("## expert0"));
+    }
+
     #[tokio::test]
     async fn context_store_cleanup_session_removes_all() {
         let (store, _temp) = create_test_store().await;
@@ -334,4 +543,25 @@ mod tests {
         let loaded = store.load_session_roles("abc123").await.unwrap();
         assert!(loaded.is_none());
     }
+
+    #[tokio::test]
+    async fn context_store_round_trips_ui_state() {
+        let (store, _temp) = create_test_store().await;
+        assert_eq!(
+            store.load_ui_state("abc123").await.unwrap(),
+            TowerUiState::default(),
+            "load_ui_state: missing file should give defaults"
+        );
+
+        let state = TowerUiState {
+            pinned_expert: Some(2),
+            archived_reports: vec![ArchivedReport {
+                expert_id: 1,
+                task_id: "task-004".to_string(),
+            }],
+            expert_order: vec![2, 0, 1],
+        };
+        store.save_ui_state("abc123", &state).await.unwrap();
+        assert_eq!(store.load_ui_state("abc123").await.unwrap(), state);
+    }
 }
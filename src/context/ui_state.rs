@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// Tower view settings kept per session so they survive a tower restart
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TowerUiState {
+    /// Expert the expert panel stays on regardless of the list selection
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pinned_expert: Option<u32>,
+    /// Reports the operator has reviewed; they no longer light the report dot
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub archived_reports: Vec<ArchivedReport>,
+    /// Expert list order chosen by the operator, as expert ids
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub expert_order: Vec<u32>,
+}
+
+/// Identifies one report; a new task from the same expert is a new report
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ArchivedReport {
+    pub expert_id: u32,
+    pub task_id: String,
+}
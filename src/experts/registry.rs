@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use thiserror::Error;
 
 use crate::models::{ExpertId, ExpertInfo, ExpertState, Role};
@@ -38,6 +38,10 @@ pub struct ExpertRegistry {
 
     /// Next available expert ID for registration
     next_id: ExpertId,
+
+    /// Experts whose window exists but whose agent has not been launched yet
+    /// (`launch: lazy`)
+    not_launched: HashSet<ExpertId>,
 }
 
 impl ExpertRegistry {
@@ -48,6 +52,7 @@ impl ExpertRegistry {
             name_to_id: HashMap::new(),
             role_to_ids: HashMap::new(),
             next_id: 0,
+            not_launched: HashSet::new(),
         }
     }
 
@@ -59,8 +64,13 @@ impl ExpertRegistry {
         &mut self,
         mut expert_info: ExpertInfo,
     ) -> Result<ExpertId, RegistryError> {
-        // Check for duplicate names
-        if self.name_to_id.contains_key(&expert_info.name) {
+        // Check for duplicate names; lookups fall back to ignoring case, so
+        // names differing only in case would be ambiguous
+        if self
+            .name_to_id
+            .keys()
+            .any(|name| name.eq_ignore_ascii_case(&expert_info.name))
+        {
             return Err(RegistryError::DuplicateName(expert_info.name.clone()));
         }
 
@@ -281,6 +291,17 @@ impl ExpertRegistry {
         Ok(())
     }
 
+    /// Find another expert already attached to the given worktree path
+    pub fn worktree_owner(&self, worktree_path: &str, excluding: ExpertId) -> Option<ExpertId> {
+        self.experts
+            .iter()
+            .filter(|(&id, expert)| {
+                id != excluding && expert.worktree_path.as_deref() == Some(worktree_path)
+            })
+            .map(|(&id, _)| id)
+            .min()
+    }
+
     /// Get idle experts by role string, filtered to only those sharing the given worktree
     pub fn get_idle_experts_by_role_str_in_worktree(
         &self,
@@ -297,6 +318,40 @@ impl ExpertRegistry {
             .collect()
     }
 
+    /// Get every expert carrying `tag` in the given worktree, idle or not, in id order
+    pub fn get_experts_by_tag_in_worktree(
+        &self,
+        tag: &str,
+        worktree_path: &Option<String>,
+    ) -> Vec<ExpertId> {
+        let mut ids: Vec<ExpertId> = self
+            .experts
+            .iter()
+            .filter(|(_, expert)| expert.has_tag(tag) && expert.worktree_path == *worktree_path)
+            .map(|(&id, _)| id)
+            .collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Get idle experts carrying `tag`, filtered to only those sharing the given worktree
+    pub fn get_idle_experts_by_tag_in_worktree(
+        &self,
+        tag: &str,
+        worktree_path: &Option<String>,
+    ) -> Vec<ExpertId> {
+        let mut ids: Vec<ExpertId> = self
+            .experts
+            .iter()
+            .filter(|(_, expert)| {
+                expert.has_tag(tag) && expert.is_idle() && expert.worktree_path == *worktree_path
+            })
+            .map(|(&id, _)| id)
+            .collect();
+        ids.sort_unstable();
+        ids
+    }
+
     /// Update the role of an expert, maintaining lookup table consistency
     pub fn update_expert_role(
         &mut self,
@@ -334,6 +389,36 @@ impl ExpertRegistry {
         Ok(())
     }
 
+    /// Record whether the expert's agent has been launched; experts are
+    /// registered as launched
+    pub fn set_expert_launched(
+        &mut self,
+        expert_id: ExpertId,
+        launched: bool,
+    ) -> Result<(), RegistryError> {
+        if !self.experts.contains_key(&expert_id) {
+            return Err(RegistryError::ExpertNotFound(expert_id));
+        }
+        if launched {
+            self.not_launched.remove(&expert_id);
+        } else {
+            self.not_launched.insert(expert_id);
+        }
+        Ok(())
+    }
+
+    /// Whether the expert's agent has been launched; `None` for unknown experts
+    pub fn is_expert_launched(&self, expert_id: ExpertId) -> Option<bool> {
+        self.experts
+            .contains_key(&expert_id)
+            .then(|| !self.not_launched.contains(&expert_id))
+    }
+
+    /// Experts waiting for their first launch
+    pub fn not_launched_experts(&self) -> HashSet<ExpertId> {
+        self.not_launched.clone()
+    }
+
     /// Validate if a state transition is allowed
     ///
     /// Currently allows all transitions, but can be extended with business logic
@@ -388,6 +473,28 @@ mod tests {
         assert_eq!(registry.find_by_role(&Role::Developer), vec![0]);
     }
 
+    #[test]
+    fn set_expert_launched_tracks_lazy_experts() {
+        let mut registry = ExpertRegistry::new();
+        let id = registry
+            .register_expert(create_test_expert("backend-dev", Role::Developer))
+            .unwrap();
+        assert_eq!(
+            registry.is_expert_launched(id),
+            Some(true),
+            "is_expert_launched: experts are registered as launched"
+        );
+
+        registry.set_expert_launched(id, false).unwrap();
+        assert_eq!(registry.is_expert_launched(id), Some(false));
+        assert_eq!(registry.not_launched_experts(), HashSet::from([id]));
+
+        registry.set_expert_launched(id, true).unwrap();
+        assert!(registry.not_launched_experts().is_empty());
+        assert!(registry.set_expert_launched(9, false).is_err());
+        assert_eq!(registry.is_expert_launched(9), None);
+    }
+
     #[test]
     fn register_expert_with_existing_id() {
         let mut registry = ExpertRegistry::new();
@@ -412,6 +519,21 @@ mod tests {
         assert!(matches!(result, Err(RegistryError::DuplicateName(_))));
     }
 
+    #[test]
+    fn register_expert_rejects_name_differing_only_in_case() {
+        let mut registry = ExpertRegistry::new();
+        registry
+            .register_expert(create_test_expert("Backend", Role::Developer))
+            .unwrap();
+        let result = registry.register_expert(create_test_expert("backend", Role::Analyst));
+
+        assert!(
+            matches!(result, Err(RegistryError::DuplicateName(ref name)) if name == "backend"),
+            "register_expert: find_by_name could not tell these apart"
+        );
+        assert_eq!(registry.len(), 1);
+    }
+
     #[test]
     fn update_expert_state_changes_state() {
         let mut registry = ExpertRegistry::new();
@@ -764,6 +886,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn worktree_owner_finds_other_expert_in_worktree() {
+        let mut registry = ExpertRegistry::new();
+        let id1 = registry
+            .register_expert(create_test_expert("dev1", Role::Developer))
+            .unwrap();
+        let id2 = registry
+            .register_expert(create_test_expert("dev2", Role::Developer))
+            .unwrap();
+        registry
+            .update_expert_worktree(id1, Some("/worktrees/feature".to_string()))
+            .unwrap();
+
+        assert_eq!(
+            registry.worktree_owner("/worktrees/feature", id2),
+            Some(id1),
+            "worktree_owner: should report the expert attached to the worktree"
+        );
+        assert_eq!(
+            registry.worktree_owner("/worktrees/feature", id1),
+            None,
+            "worktree_owner: the requesting expert should not count as an owner"
+        );
+        assert_eq!(registry.worktree_owner("/worktrees/other", id2), None);
+    }
+
     #[test]
     fn get_idle_experts_by_role_str_in_worktree_filters_by_worktree() {
         let mut registry = ExpertRegistry::new();
@@ -907,7 +1055,11 @@ mod property_tests {
 
     // Generators for property-based testing
     fn arbitrary_expert_state() -> impl Strategy<Value = ExpertState> {
-        prop_oneof![Just(ExpertState::Idle), Just(ExpertState::Busy),]
+        prop_oneof![
+            Just(ExpertState::Idle),
+            Just(ExpertState::Busy),
+            Just(ExpertState::Offline),
+        ]
     }
 
     fn arbitrary_role() -> impl Strategy<Value = Role> {
@@ -971,7 +1123,7 @@ mod property_tests {
                             assert!(registry.is_expert_idle(expert_id).unwrap());
                             assert!(registry.get_idle_experts().contains(&expert_id));
                         },
-                        ExpertState::Busy => {
+                        ExpertState::Busy | ExpertState::Offline => {
                             assert!(!registry.is_expert_idle(expert_id).unwrap());
                             assert!(!registry.get_idle_experts().contains(&expert_id));
                         }
@@ -1,10 +1,12 @@
 use std::fmt::Write;
+use std::io::Write as _;
 use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 
 use anyhow::{bail, Result};
 
 use crate::config::FeatureExecutionConfig;
+use crate::feature::progress::FeatureProgress;
 use crate::feature::scheduler::{self, BlockedDiagnostic, ScheduleResult, SchedulerMode};
 use crate::feature::task_parser::{self, TaskEntry};
 
@@ -27,6 +29,17 @@ pub enum ExecutionPhase {
     Failed(String),
 }
 
+/// Batch sequence a feature run would send, computed without touching the expert
+#[derive(Debug, Default)]
+pub struct FeaturePlan {
+    /// Task numbers per batch, in send order
+    pub waves: Vec<Vec<String>>,
+    /// Tasks already marked done in the task file
+    pub completed: usize,
+    /// Why planning stopped early, if remaining tasks can never run
+    pub blocked: Option<String>,
+}
+
 pub struct FeatureExecutor {
     feature_name: String,
     expert_id: u32,
@@ -43,6 +56,9 @@ pub struct FeatureExecutor {
 
     tasks_file: PathBuf,
     design_file: Option<PathBuf>,
+    log_file: Option<PathBuf>,
+    progress_file: PathBuf,
+    progress: FeatureProgress,
 
     total_tasks: usize,
     completed_tasks: usize,
@@ -65,7 +81,16 @@ impl FeatureExecutor {
         settings_file: Option<PathBuf>,
         working_dir: String,
     ) -> Self {
-        let specs_dir = project_path.join(".macot").join("specs");
+        let macot_dir = project_path.join(".macot");
+        let specs_dir = macot_dir.join("specs");
+        let log_file = config.verbose.then(|| {
+            macot_dir
+                .join("logs")
+                .join(format!("feature-{feature_name}.log"))
+        });
+        let tasks_file = specs_dir.join(format!("{feature_name}-tasks.md"));
+        let progress_file = FeatureProgress::path_for(&specs_dir, &feature_name);
+        let progress = resume_progress(&progress_file, &tasks_file, &feature_name);
         Self {
             feature_name: feature_name.clone(),
             expert_id,
@@ -78,8 +103,11 @@ impl FeatureExecutor {
             phase: ExecutionPhase::Idle,
             current_batch: Vec::new(),
             batch_completion_wait_start: None,
-            tasks_file: specs_dir.join(format!("{feature_name}-tasks.md")),
+            tasks_file,
             design_file: None,
+            log_file,
+            progress_file,
+            progress,
             total_tasks: 0,
             completed_tasks: 0,
             instruction_file,
@@ -108,9 +136,11 @@ impl FeatureExecutor {
 
     pub fn parse_tasks(&mut self) -> Result<Vec<TaskEntry>> {
         let content = std::fs::read_to_string(&self.tasks_file)?;
-        let tasks = task_parser::parse_tasks(&content);
+        let tasks = task_parser::parse_tasks_checked(&content)
+            .map_err(|e| anyhow::anyhow!("{}: {e}", self.tasks_file.display()))?;
         self.total_tasks = tasks.len();
         self.completed_tasks = tasks.iter().filter(|t| t.completed).count();
+        self.progress.set_completed(&tasks);
         Ok(tasks)
     }
 
@@ -124,6 +154,36 @@ impl FeatureExecutor {
         }
     }
 
+    /// Dry-run the scheduler over the task file: each wave is the batch that
+    /// would be sent once every earlier wave is marked done.
+    pub fn plan(&mut self) -> Result<FeaturePlan> {
+        self.validate()?;
+        let mut tasks = self.parse_tasks()?;
+        let mut plan = FeaturePlan {
+            completed: self.completed_tasks,
+            ..FeaturePlan::default()
+        };
+
+        loop {
+            let wave: Vec<String> = match self.next_batch(&tasks) {
+                Ok(batch) => batch.iter().map(|t| t.number.clone()).collect(),
+                Err(msg) => {
+                    plan.blocked = Some(msg);
+                    break;
+                }
+            };
+            if wave.is_empty() {
+                break;
+            }
+            for task in tasks.iter_mut().filter(|t| wave.contains(&t.number)) {
+                task.completed = true;
+            }
+            plan.waves.push(wave);
+        }
+
+        Ok(plan)
+    }
+
     pub fn build_prompt(&self, batch: &[&TaskEntry]) -> String {
         let task_numbers: Vec<&str> = batch.iter().map(|t| t.number.as_str()).collect();
         let numbers_str = task_numbers.join(", ");
@@ -169,6 +229,49 @@ impl FeatureExecutor {
         prompt
     }
 
+    /// Whether batch prompts and pane snapshots are written to the feature log
+    pub fn is_verbose(&self) -> bool {
+        self.log_file.is_some()
+    }
+
+    #[cfg(test)]
+    pub fn log_file(&self) -> Option<&PathBuf> {
+        self.log_file.as_ref()
+    }
+
+    /// Record the prompt sent for `batch` in the feature log (verbose mode only)
+    pub fn log_batch_prompt(&self, batch: &[&TaskEntry], prompt: &str) {
+        let numbers: Vec<&str> = batch.iter().map(|t| t.number.as_str()).collect();
+        self.append_log(&format!("batch sent: tasks {}", numbers.join(", ")), prompt);
+    }
+
+    /// Record the expert pane as seen at a status poll (verbose mode only)
+    pub fn log_pane_snapshot(&self, content: &str) {
+        self.append_log("poll: pane snapshot", content);
+    }
+
+    fn append_log(&self, heading: &str, body: &str) {
+        let Some(ref path) = self.log_file else {
+            return;
+        };
+        let result = (|| -> std::io::Result<()> {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            let mut file = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)?;
+            let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
+            writeln!(file, "=== {timestamp} {heading}")?;
+            writeln!(file, "{}", body.trim_end())?;
+            writeln!(file)
+        })();
+        if let Err(e) = result {
+            tracing::warn!("Failed to write feature log {}: {}", path.display(), e);
+        }
+    }
+
     pub fn phase(&self) -> &ExecutionPhase {
         &self.phase
     }
@@ -248,6 +351,25 @@ impl FeatureExecutor {
 
     pub fn record_batch_sent(&mut self, batch: &[&TaskEntry]) {
         self.current_batch = batch.iter().map(|t| t.number.clone()).collect();
+        self.progress.record_batch(self.current_batch.clone());
+        self.save_progress();
+    }
+
+    /// Mark the run finished in the progress sidecar
+    pub fn record_completion(&mut self) {
+        self.progress.finished = true;
+        self.save_progress();
+    }
+
+    #[cfg(test)]
+    pub fn progress_file(&self) -> &Path {
+        &self.progress_file
+    }
+
+    fn save_progress(&self) {
+        if let Err(e) = self.progress.save(&self.progress_file) {
+            tracing::warn!("Failed to update feature progress: {}", e);
+        }
     }
 
     pub fn cancel(&mut self) {
@@ -280,6 +402,37 @@ impl FeatureExecutor {
     }
 }
 
+/// Pick up the sidecar an earlier run left behind, reconciled against the
+/// current task file, so a restarted tower keeps its record of sent batches
+/// instead of overwriting it.
+fn resume_progress(progress_file: &Path, tasks_file: &Path, feature: &str) -> FeatureProgress {
+    let mut progress = match FeatureProgress::load(progress_file) {
+        Ok(Some(progress)) => progress,
+        Ok(None) => return FeatureProgress::new(feature),
+        Err(e) => {
+            tracing::warn!("Ignoring feature progress: {}", e);
+            return FeatureProgress::new(feature);
+        }
+    };
+    let Ok(content) = std::fs::read_to_string(tasks_file) else {
+        return progress;
+    };
+    match task_parser::parse_tasks_checked(&content) {
+        Ok(tasks) => {
+            let unconfirmed = progress.reconcile(&tasks);
+            if !unconfirmed.is_empty() {
+                tracing::warn!(
+                    "Feature {}: tasks sent by an earlier run are still unchecked: {}",
+                    feature,
+                    unconfirmed.join(", ")
+                );
+            }
+        }
+        Err(e) => tracing::warn!("Failed to reconcile feature progress: {}", e),
+    }
+    progress
+}
+
 fn format_blocked_message(diag: &BlockedDiagnostic) -> String {
     let count = diag.blocked_tasks.len();
     let mut msg = format!("Execution blocked: {count} tasks cannot proceed.\n");
@@ -309,7 +462,7 @@ mod tests {
             "test-feature".to_string(),
             0,
             &config,
-            &temp.path().to_path_buf(),
+            temp.path(),
             None,
             None,
             None,
@@ -617,6 +770,82 @@ mod tests {
         assert_eq!(executor.current_batch(), &["1", "2"]);
     }
 
+    #[test]
+    fn record_batch_sent_updates_progress_sidecar() {
+        let temp = TempDir::new().unwrap();
+        write_tasks_file(&temp, "- [x] 1. Done\n- [ ] 2. Next\n- [ ] 3. Later\n");
+        let config = FeatureExecutionConfig {
+            batch_size: 1,
+            ..FeatureExecutionConfig::default()
+        };
+        let mut executor = FeatureExecutor::new(
+            "test-feature".to_string(),
+            0,
+            &config,
+            temp.path(),
+            None,
+            None,
+            None,
+            "/tmp/project".to_string(),
+        );
+        executor.validate().unwrap();
+        let tasks = executor.parse_tasks().unwrap();
+        let batch = executor.next_batch(&tasks).unwrap();
+        executor.record_batch_sent(&batch);
+
+        let path = executor.progress_file().to_path_buf();
+        assert!(path.ends_with(".macot/specs/test-feature-progress.json"));
+        let progress = FeatureProgress::load(&path).unwrap().unwrap();
+        assert_eq!(progress.sent_batches.len(), 1);
+        assert_eq!(
+            progress.sent_batches[0].tasks,
+            vec!["2"],
+            "record_batch_sent: sidecar should list the sent batch"
+        );
+        assert_eq!(progress.completed, vec!["1"]);
+        assert!(!progress.finished);
+
+        executor.record_completion();
+        let progress = FeatureProgress::load(&path).unwrap().unwrap();
+        assert!(
+            progress.finished,
+            "record_completion: sidecar should be marked finished"
+        );
+    }
+
+    #[test]
+    fn new_resumes_and_reconciles_existing_progress_sidecar() {
+        let temp = TempDir::new().unwrap();
+        write_tasks_file(&temp, "- [x] 1. Done\n- [ ] 2. Next\n- [ ] 3. Later\n");
+        let specs = temp.path().join(".macot").join("specs");
+        let path = FeatureProgress::path_for(&specs, "test-feature");
+        let mut earlier = FeatureProgress::new("test-feature");
+        earlier.record_batch(vec!["1".to_string(), "2".to_string()]);
+        earlier.record_batch(vec!["7".to_string()]);
+        earlier.save(&path).unwrap();
+
+        let mut executor = make_executor(&temp);
+        assert_eq!(
+            executor.progress.sent_batches.len(),
+            1,
+            "new: batches of tasks gone from the task file should be dropped"
+        );
+        assert_eq!(executor.progress.completed, vec!["1"]);
+
+        executor.validate().unwrap();
+        let tasks = executor.parse_tasks().unwrap();
+        let batch = executor.next_batch(&tasks).unwrap();
+        executor.record_batch_sent(&batch);
+
+        let progress = FeatureProgress::load(&path).unwrap().unwrap();
+        assert_eq!(
+            progress.sent_batches.len(),
+            2,
+            "new: earlier batches should survive the next save"
+        );
+        assert_eq!(progress.sent_batches[0].tasks, vec!["1", "2"]);
+    }
+
     #[test]
     fn cancel_resets_to_idle() {
         let temp = TempDir::new().unwrap();
@@ -1058,13 +1287,15 @@ mod tests {
 - [ ] 3. Task C
 ",
         );
-        let mut config = FeatureExecutionConfig::default();
-        config.scheduler_mode = SchedulerMode::Sequential;
+        let config = FeatureExecutionConfig {
+            scheduler_mode: SchedulerMode::Sequential,
+            ..Default::default()
+        };
         let mut executor = FeatureExecutor::new(
             "test-feature".to_string(),
             0,
             &config,
-            &temp.path().to_path_buf(),
+            temp.path(),
             None,
             None,
             None,
@@ -1079,4 +1310,169 @@ mod tests {
             "next_batch_sequential_mode: should return all uncompleted tasks regardless of deps"
         );
     }
+
+    // --- Dry-run plan tests ---
+
+    #[test]
+    fn plan_partitions_dag_into_waves() {
+        let temp = TempDir::new().unwrap();
+        write_tasks_file(
+            &temp,
+            "\
+- [ ] 1. Schema
+- [ ] 2. Config
+- [ ] 3. API [deps: 1]
+- [ ] 4. CLI [deps: 2]
+- [ ] 5. Docs [deps: 3, 4]
+",
+        );
+        let mut executor = make_executor(&temp);
+        let plan = executor.plan().unwrap();
+        assert_eq!(
+            plan.waves,
+            vec![vec!["1", "2"], vec!["3", "4"], vec!["5"]],
+            "plan: waves should follow dependency depth"
+        );
+        assert!(plan.blocked.is_none(), "plan: acyclic DAG should not block");
+    }
+
+    #[test]
+    fn plan_skips_completed_tasks_and_respects_batch_size() {
+        let temp = TempDir::new().unwrap();
+        write_tasks_file(
+            &temp,
+            "\
+- [x] 1. Done
+- [ ] 2. B [deps: 1]
+- [ ] 3. C [deps: 1]
+- [ ] 4. D [deps: 1]
+- [ ] 5. E [deps: 2]
+",
+        );
+        let config = FeatureExecutionConfig {
+            batch_size: 2,
+            ..Default::default()
+        };
+        let mut executor = FeatureExecutor::new(
+            "test-feature".to_string(),
+            0,
+            &config,
+            temp.path(),
+            None,
+            None,
+            None,
+            "/tmp/project".to_string(),
+        );
+        let plan = executor.plan().unwrap();
+        assert_eq!(plan.completed, 1, "plan: should count completed tasks");
+        assert_eq!(
+            plan.waves,
+            vec![vec!["2", "3"], vec!["4", "5"]],
+            "plan: waves should be capped at batch_size"
+        );
+    }
+
+    #[test]
+    fn plan_reports_cycle_after_runnable_waves() {
+        let temp = TempDir::new().unwrap();
+        write_tasks_file(
+            &temp,
+            "\
+- [ ] 1. Base
+- [ ] 2. A [deps: 1, 3]
+- [ ] 3. B [deps: 2]
+",
+        );
+        let mut executor = make_executor(&temp);
+        let plan = executor.plan().unwrap();
+        assert_eq!(
+            plan.waves,
+            vec![vec!["1"]],
+            "plan: runnable waves should be kept before the block"
+        );
+        let blocked = plan.blocked.expect("plan: cycle should block planning");
+        assert!(
+            blocked.contains("Circular dependency"),
+            "plan: blocked message should mention the cycle, got: {blocked}"
+        );
+    }
+
+    #[test]
+    fn plan_fails_without_task_file() {
+        let temp = TempDir::new().unwrap();
+        let mut executor = make_executor(&temp);
+        assert!(
+            executor.plan().is_err(),
+            "plan: missing task file should be an error"
+        );
+    }
+
+    // --- Verbose log tests ---
+
+    fn make_verbose_executor(temp: &TempDir) -> FeatureExecutor {
+        let config = FeatureExecutionConfig {
+            verbose: true,
+            ..Default::default()
+        };
+        FeatureExecutor::new(
+            "test-feature".to_string(),
+            0,
+            &config,
+            temp.path(),
+            None,
+            None,
+            None,
+            "/tmp/project".to_string(),
+        )
+    }
+
+    #[test]
+    fn verbose_mode_logs_sent_batch_prompt() {
+        let temp = TempDir::new().unwrap();
+        write_tasks_file(&temp, "- [ ] 1. Task one\n- [ ] 2. Task two\n");
+        let mut executor = make_verbose_executor(&temp);
+        executor.validate().unwrap();
+        let tasks = executor.parse_tasks().unwrap();
+        let batch = executor.next_batch(&tasks).unwrap();
+        let prompt = executor.build_prompt(&batch);
+
+        executor.log_batch_prompt(&batch, &prompt);
+        executor.log_pane_snapshot("> working on task 1");
+
+        let log_path = temp
+            .path()
+            .join(".macot")
+            .join("logs")
+            .join("feature-test-feature.log");
+        assert_eq!(executor.log_file(), Some(&log_path));
+        let log = std::fs::read_to_string(&log_path).unwrap();
+        assert!(
+            log.contains("batch sent: tasks 1, 2"),
+            "log_batch_prompt: should record the task ids, got: {log}"
+        );
+        assert!(
+            log.contains("Execute Tasks {1, 2}"),
+            "log_batch_prompt: should record the exact prompt, got: {log}"
+        );
+        assert!(
+            log.contains("poll: pane snapshot\n> working on task 1"),
+            "log_pane_snapshot: should append the pane content, got: {log}"
+        );
+    }
+
+    #[test]
+    fn non_verbose_mode_writes_no_log() {
+        let temp = TempDir::new().unwrap();
+        write_tasks_file(&temp, "- [ ] 1. Task one\n");
+        let mut executor = make_executor(&temp);
+        let tasks = executor.parse_tasks().unwrap();
+        let batch = executor.next_batch(&tasks).unwrap();
+        executor.log_batch_prompt(&batch, "prompt");
+
+        assert!(!executor.is_verbose());
+        assert!(
+            !temp.path().join(".macot").join("logs").exists(),
+            "log_batch_prompt: normal runs should stay quiet"
+        );
+    }
 }
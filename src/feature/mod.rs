@@ -1,3 +1,4 @@
 pub mod executor;
+pub mod progress;
 pub mod scheduler;
 pub mod task_parser;
@@ -0,0 +1,169 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::feature::task_parser::TaskEntry;
+
+/// A batch the tower sent to the expert
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SentBatch {
+    pub tasks: Vec<String>,
+    pub sent_at: DateTime<Utc>,
+}
+
+/// Tower-side record of a feature run, kept next to the task file as
+/// `<feature>-progress.json` so it survives a tower crash.
+///
+/// The task file records what the agent finished; this records what the
+/// tower sent, so the two can be compared when a run is picked up again.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeatureProgress {
+    pub feature: String,
+    #[serde(default)]
+    pub sent_batches: Vec<SentBatch>,
+    /// Tasks checked off in the task file when the record was last written
+    #[serde(default)]
+    pub completed: Vec<String>,
+    /// Set once the run finished with no tasks left
+    #[serde(default)]
+    pub finished: bool,
+}
+
+impl FeatureProgress {
+    pub fn new(feature: &str) -> Self {
+        Self {
+            feature: feature.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Sidecar path for a feature in the specs directory
+    pub fn path_for(specs_dir: &Path, feature: &str) -> PathBuf {
+        specs_dir.join(format!("{feature}-progress.json"))
+    }
+
+    /// Read a sidecar; `None` when no run has written one yet
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read progress file: {}", path.display()))?;
+        let progress = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse progress file: {}", path.display()))?;
+        Ok(Some(progress))
+    }
+
+    /// Write the sidecar through a temp file renamed into place, so a crash
+    /// mid-write never leaves a truncated record behind
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        let temp_path = path.with_extension("json.tmp");
+        std::fs::write(&temp_path, json).with_context(|| {
+            format!(
+                "Failed to write progress temp file: {}",
+                temp_path.display()
+            )
+        })?;
+        std::fs::rename(&temp_path, path)
+            .with_context(|| format!("Failed to move progress file: {}", path.display()))
+    }
+
+    pub fn record_batch(&mut self, tasks: Vec<String>) {
+        self.sent_batches.push(SentBatch {
+            tasks,
+            sent_at: Utc::now(),
+        });
+    }
+
+    pub fn set_completed(&mut self, tasks: &[TaskEntry]) {
+        self.completed = tasks
+            .iter()
+            .filter(|t| t.completed)
+            .map(|t| t.number.clone())
+            .collect();
+    }
+
+    /// Bring the record in line with the current task file and return the
+    /// tasks the tower sent that the agent has not checked off.
+    ///
+    /// Sent tasks that no longer exist in the task file are dropped, and
+    /// batches left empty by that are removed.
+    pub fn reconcile(&mut self, tasks: &[TaskEntry]) -> Vec<String> {
+        self.set_completed(tasks);
+        for batch in &mut self.sent_batches {
+            batch
+                .tasks
+                .retain(|num| tasks.iter().any(|t| t.number == *num));
+        }
+        self.sent_batches.retain(|b| !b.tasks.is_empty());
+        self.finished = self.finished && tasks.iter().all(|t| t.completed);
+
+        let mut unconfirmed = Vec::new();
+        for num in self.sent_batches.iter().flat_map(|b| &b.tasks) {
+            if !self.completed.contains(num) && !unconfirmed.contains(num) {
+                unconfirmed.push(num.clone());
+            }
+        }
+        unconfirmed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feature::task_parser::parse_tasks_checked;
+    use tempfile::TempDir;
+
+    #[test]
+    fn feature_progress_round_trips_through_file() {
+        let temp = TempDir::new().unwrap();
+        let path = FeatureProgress::path_for(temp.path(), "auth");
+        assert!(
+            FeatureProgress::load(&path).unwrap().is_none(),
+            "load: missing sidecar should be None"
+        );
+
+        let mut progress = FeatureProgress::new("auth");
+        progress.record_batch(vec!["1".to_string(), "2".to_string()]);
+        progress.save(&path).unwrap();
+
+        let loaded = FeatureProgress::load(&path).unwrap().unwrap();
+        assert_eq!(loaded, progress);
+        assert!(path.ends_with("auth-progress.json"));
+        assert!(
+            !path.with_extension("json.tmp").exists(),
+            "save: the temp file should be renamed into place"
+        );
+    }
+
+    #[test]
+    fn feature_progress_reconcile_reports_sent_but_unchecked_tasks() {
+        let mut progress = FeatureProgress::new("auth");
+        progress.record_batch(vec!["1".to_string(), "2".to_string()]);
+        progress.record_batch(vec!["3".to_string(), "9".to_string()]);
+        progress.finished = true;
+
+        let tasks =
+            parse_tasks_checked("- [x] 1. A\n- [ ] 2. B\n- [ ] 3. C\n- [ ] 4. D\n").unwrap();
+        let unconfirmed = progress.reconcile(&tasks);
+
+        assert_eq!(
+            unconfirmed,
+            vec!["2", "3"],
+            "reconcile: sent tasks still unchecked should be reported"
+        );
+        assert_eq!(progress.completed, vec!["1"]);
+        assert_eq!(
+            progress.sent_batches[1].tasks,
+            vec!["3"],
+            "reconcile: tasks missing from the task file should be dropped"
+        );
+        assert!(
+            !progress.finished,
+            "reconcile: a run with open tasks is not finished"
+        );
+    }
+}
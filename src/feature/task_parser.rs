@@ -1,4 +1,5 @@
 use regex::Regex;
+use thiserror::Error;
 
 #[derive(Debug, Clone)]
 pub struct TaskEntry {
@@ -11,45 +12,95 @@ pub struct TaskEntry {
     pub dependencies: Vec<String>,
 }
 
+/// A task file problem, located by 1-based line number
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TaskParseError {
+    #[error(
+        "line {line}: malformed task line, expected `- [ ] N. Title` or `- [x] N. Title`: {text}"
+    )]
+    MalformedCheckbox { line: usize, text: String },
+
+    #[error("line {line}: unclosed deps list, expected `[deps: 1, 2]`")]
+    UnclosedDeps { line: usize },
+
+    #[error("line {line}: unknown task id in deps: [{}]", .unknown.join(", "))]
+    UnknownDependency { line: usize, unknown: Vec<String> },
+}
+
+fn task_line_regex() -> Regex {
+    Regex::new(r"^(\s*)- \[([ x])\] (\d+(?:\.\d+)*)\.\s+(.+?)(?:\s+\[deps:\s*([^\]]*)\])?\s*$")
+        .unwrap()
+}
+
+fn parse_line(re: &Regex, line: &str) -> Option<TaskEntry> {
+    let caps = re.captures(line)?;
+    let leading_ws = caps.get(1).unwrap().as_str();
+    let indent_level = if leading_ws.is_empty() { 0 } else { 1 };
+    let dependencies = caps
+        .get(5)
+        .map(|m| {
+            m.as_str()
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(TaskEntry {
+        number: caps[3].to_string(),
+        title: caps[4].to_string(),
+        completed: &caps[2] == "x",
+        indent_level,
+        dependencies,
+    })
+}
+
 /// Parse a task file and extract task entries.
 ///
 /// Matches lines of the form `- [ ] N. Title` or `- [x] N. Title`
 /// where N is an integer or dot-notation number (e.g. 1, 1.1, 2.3).
-pub fn parse_tasks(content: &str) -> Vec<TaskEntry> {
-    let re =
-        Regex::new(r"^(\s*)- \[([ x])\] (\d+(?:\.\d+)*)\.\s+(.+?)(?:\s+\[deps:\s*([^\]]*)\])?\s*$")
-            .unwrap();
+/// Rejects task-like lines that do not parse, unclosed `[deps: ...]` lists,
+/// and dependencies on task ids missing from the file.
+pub fn parse_tasks_checked(content: &str) -> Result<Vec<TaskEntry>, TaskParseError> {
+    let re = task_line_regex();
+    // Something shaped like a checkbox followed by a task number
+    let task_like = Regex::new(r"^\s*-\s*\[[^\]]{0,3}\]\s*\d").unwrap();
     let mut tasks = Vec::new();
 
-    for line in content.lines() {
-        if let Some(caps) = re.captures(line) {
-            let leading_ws = caps.get(1).unwrap().as_str();
-            let indent_level = if leading_ws.is_empty() { 0 } else { 1 };
-            let completed = &caps[2] == "x";
-            let number = caps[3].to_string();
-            let title = caps[4].to_string();
-            let dependencies = caps
-                .get(5)
-                .map(|m| {
-                    m.as_str()
-                        .split(',')
-                        .map(|s| s.trim().to_string())
-                        .filter(|s| !s.is_empty())
-                        .collect()
-                })
-                .unwrap_or_default();
-
-            tasks.push(TaskEntry {
-                number,
-                title,
-                completed,
-                indent_level,
-                dependencies,
+    for (index, line) in content.lines().enumerate() {
+        let line_no = index + 1;
+        match parse_line(&re, line) {
+            Some(task) if task.title.contains("[deps:") => {
+                return Err(TaskParseError::UnclosedDeps { line: line_no });
+            }
+            Some(task) => tasks.push((line_no, task)),
+            None if task_like.is_match(line) => {
+                return Err(TaskParseError::MalformedCheckbox {
+                    line: line_no,
+                    text: line.trim().to_string(),
+                });
+            }
+            None => {}
+        }
+    }
+
+    for (line_no, task) in &tasks {
+        let unknown: Vec<String> = task
+            .dependencies
+            .iter()
+            .filter(|dep| !tasks.iter().any(|(_, t)| &t.number == *dep))
+            .cloned()
+            .collect();
+        if !unknown.is_empty() {
+            return Err(TaskParseError::UnknownDependency {
+                line: *line_no,
+                unknown,
             });
         }
     }
 
-    tasks
+    Ok(tasks.into_iter().map(|(_, task)| task).collect())
 }
 
 #[cfg(test)]
@@ -64,23 +115,23 @@ mod tests {
 - [ ] 2. Implement parser
   - Another description
 ";
-        let tasks = parse_tasks(content);
+        let tasks = parse_tasks_checked(content).unwrap();
         assert_eq!(
             tasks.len(),
             2,
-            "parse_tasks: should find 2 incomplete tasks"
+            "parse_tasks_checked: should find 2 incomplete tasks"
         );
         assert_eq!(tasks[0].number, "1");
         assert_eq!(tasks[0].title, "Create module structure");
         assert!(
             !tasks[0].completed,
-            "parse_tasks: task 1 should be incomplete"
+            "parse_tasks_checked: task 1 should be incomplete"
         );
         assert_eq!(tasks[1].number, "2");
         assert_eq!(tasks[1].title, "Implement parser");
         assert!(
             !tasks[1].completed,
-            "parse_tasks: task 2 should be incomplete"
+            "parse_tasks_checked: task 2 should be incomplete"
         );
     }
 
@@ -92,15 +143,19 @@ mod tests {
 - [x] 2. Implement parser
   - Done
 ";
-        let tasks = parse_tasks(content);
-        assert_eq!(tasks.len(), 2, "parse_tasks: should find 2 completed tasks");
+        let tasks = parse_tasks_checked(content).unwrap();
+        assert_eq!(
+            tasks.len(),
+            2,
+            "parse_tasks_checked: should find 2 completed tasks"
+        );
         assert!(
             tasks[0].completed,
-            "parse_tasks: task 1 should be completed"
+            "parse_tasks_checked: task 1 should be completed"
         );
         assert!(
             tasks[1].completed,
-            "parse_tasks: task 2 should be completed"
+            "parse_tasks_checked: task 2 should be completed"
         );
     }
 
@@ -115,27 +170,27 @@ mod tests {
   - [ ] 1.2. Sub-task two
     - Sub description
 ";
-        let tasks = parse_tasks(content);
+        let tasks = parse_tasks_checked(content).unwrap();
         assert_eq!(
             tasks.len(),
             3,
-            "parse_tasks: should find main task and 2 sub-tasks"
+            "parse_tasks_checked: should find main task and 2 sub-tasks"
         );
         assert_eq!(tasks[0].number, "1");
         assert_eq!(
             tasks[0].indent_level, 0,
-            "parse_tasks: main task indent_level should be 0"
+            "parse_tasks_checked: main task indent_level should be 0"
         );
         assert_eq!(tasks[1].number, "1.1");
         assert_eq!(tasks[1].title, "Sub-task one");
         assert_eq!(
             tasks[1].indent_level, 1,
-            "parse_tasks: sub-task indent_level should be 1"
+            "parse_tasks_checked: sub-task indent_level should be 1"
         );
         assert_eq!(tasks[2].number, "1.2");
         assert_eq!(
             tasks[2].indent_level, 1,
-            "parse_tasks: sub-task indent_level should be 1"
+            "parse_tasks_checked: sub-task indent_level should be 1"
         );
     }
 
@@ -154,11 +209,11 @@ Some description text.
 
 Random paragraph here.
 ";
-        let tasks = parse_tasks(content);
+        let tasks = parse_tasks_checked(content).unwrap();
         assert_eq!(
             tasks.len(),
             1,
-            "parse_tasks: should only find the actual task line"
+            "parse_tasks_checked: should only find the actual task line"
         );
         assert_eq!(tasks[0].number, "1");
         assert_eq!(tasks[0].title, "Actual task");
@@ -176,32 +231,36 @@ Random paragraph here.
 - [ ] 4. Another incomplete
   - Not done
 ";
-        let tasks = parse_tasks(content);
-        assert_eq!(tasks.len(), 4, "parse_tasks: should find all 4 tasks");
+        let tasks = parse_tasks_checked(content).unwrap();
+        assert_eq!(
+            tasks.len(),
+            4,
+            "parse_tasks_checked: should find all 4 tasks"
+        );
         assert!(
             tasks[0].completed,
-            "parse_tasks: task 1 should be completed"
+            "parse_tasks_checked: task 1 should be completed"
         );
         assert!(
             !tasks[1].completed,
-            "parse_tasks: task 2 should be incomplete"
+            "parse_tasks_checked: task 2 should be incomplete"
         );
         assert!(
             tasks[2].completed,
-            "parse_tasks: task 3 should be completed"
+            "parse_tasks_checked: task 3 should be completed"
         );
         assert!(
             !tasks[3].completed,
-            "parse_tasks: task 4 should be incomplete"
+            "parse_tasks_checked: task 4 should be incomplete"
         );
     }
 
     #[test]
     fn parse_tasks_empty_content() {
-        let tasks = parse_tasks("");
+        let tasks = parse_tasks_checked("").unwrap();
         assert!(
             tasks.is_empty(),
-            "parse_tasks: empty content should return empty vec"
+            "parse_tasks_checked: empty content should return empty vec"
         );
     }
 
@@ -214,10 +273,10 @@ Some text without any task lines.
 - A regular list item
 - Another list item
 ";
-        let tasks = parse_tasks(content);
+        let tasks = parse_tasks_checked(content).unwrap();
         assert!(
             tasks.is_empty(),
-            "parse_tasks: content without task lines should return empty vec"
+            "parse_tasks_checked: content without task lines should return empty vec"
         );
     }
 
@@ -228,7 +287,7 @@ Some text without any task lines.
 - [ ] 2. Create API [deps: 1]
 - [ ] 3. Build frontend [deps: 1, 2]
 ";
-        let tasks = parse_tasks(content);
+        let tasks = parse_tasks_checked(content).unwrap();
         assert_eq!(tasks.len(), 3, "parse_tasks_with_deps: should find 3 tasks");
         assert!(
             tasks[0].dependencies.is_empty(),
@@ -252,7 +311,7 @@ Some text without any task lines.
 - [ ] 1. Task one
 - [ ] 2. Task two
 ";
-        let tasks = parse_tasks(content);
+        let tasks = parse_tasks_checked(content).unwrap();
         assert_eq!(
             tasks.len(),
             2,
@@ -275,7 +334,7 @@ Some text without any task lines.
 - [ ] 2. Dependent task [deps: 1]
 - [ ] 3. Another independent
 ";
-        let tasks = parse_tasks(content);
+        let tasks = parse_tasks_checked(content).unwrap();
         assert_eq!(
             tasks.len(),
             3,
@@ -292,8 +351,9 @@ Some text without any task lines.
 - [ ] 1. Main task
   - [ ] 1.1. Sub task
 - [ ] 2. Depends on sub [deps: 1.1, 2.3]
+  - [ ] 2.3. Sibling sub task
 ";
-        let tasks = parse_tasks(content);
+        let tasks = parse_tasks_checked(content).unwrap();
         let task2 = tasks.iter().find(|t| t.number == "2").unwrap();
         assert_eq!(
             task2.dependencies,
@@ -305,7 +365,7 @@ Some text without any task lines.
     #[test]
     fn parse_tasks_deps_empty_bracket() {
         let content = "- [ ] 1. Task with empty deps [deps: ]\n";
-        let tasks = parse_tasks(content);
+        let tasks = parse_tasks_checked(content).unwrap();
         assert_eq!(tasks.len(), 1);
         assert!(
             tasks[0].dependencies.is_empty(),
@@ -320,7 +380,7 @@ Some text without any task lines.
 - [ ] 2. Spaced [deps: 1 , 2 ]
 - [ ] 3. Extra spaces [deps:  1  ,  2  ]
 ";
-        let tasks = parse_tasks(content);
+        let tasks = parse_tasks_checked(content).unwrap();
         assert_eq!(
             tasks[0].dependencies,
             vec!["1", "2"],
@@ -346,7 +406,7 @@ Some text without any task lines.
     - [ ] 1.2.3. Leaf
 - [ ] 2. Depends on leaf [deps: 1.2.3]
 ";
-        let tasks = parse_tasks(content);
+        let tasks = parse_tasks_checked(content).unwrap();
         let leaf = tasks.iter().find(|t| t.number == "1.2.3").unwrap();
         assert_eq!(
             leaf.number, "1.2.3",
@@ -362,8 +422,9 @@ Some text without any task lines.
 
     #[test]
     fn parse_tasks_title_preserved_with_deps() {
-        let content = "- [ ] 1. Build the API server [deps: 2, 3]\n";
-        let tasks = parse_tasks(content);
+        let content =
+            "- [ ] 1. Build the API server [deps: 2, 3]\n- [ ] 2. Schema\n- [ ] 3. Auth\n";
+        let tasks = parse_tasks_checked(content).unwrap();
         assert_eq!(
             tasks[0].title, "Build the API server",
             "parse_tasks_title_preserved_with_deps: title should not include [deps: ...]"
@@ -394,22 +455,77 @@ Some text without any task lines.
 - [ ] 3. Checkpoint - Parser correctness
   - Run `make test` to ensure all parser tests pass.
 ";
-        let tasks = parse_tasks(content);
+        let tasks = parse_tasks_checked(content).unwrap();
         assert_eq!(
             tasks.len(),
             4,
-            "parse_tasks: should find 4 tasks in real-world format"
+            "parse_tasks_checked: should find 4 tasks in real-world format"
         );
         assert!(
             tasks[0].completed,
-            "parse_tasks: task 1 should be completed"
+            "parse_tasks_checked: task 1 should be completed"
         );
         assert!(
             !tasks[1].completed,
-            "parse_tasks: task 2 should be incomplete"
+            "parse_tasks_checked: task 2 should be incomplete"
         );
         assert_eq!(tasks[1].number, "2");
         assert_eq!(tasks[2].number, "2.1");
         assert_eq!(tasks[3].number, "3");
     }
+
+    #[test]
+    fn parse_tasks_checked_reports_unknown_dependency_with_line() {
+        let content = "\
+# Tasks
+
+- [x] 1. Schema
+- [ ] 2. API [deps: 1]
+  - details
+- [ ] 3. UI [deps: 2, 99, 7]
+";
+        assert_eq!(
+            parse_tasks_checked(content).unwrap_err().to_string(),
+            "line 6: unknown task id in deps: [99, 7]",
+            "parse_tasks_checked: should locate the bad deps reference"
+        );
+        assert_eq!(
+            parse_tasks_checked("- [ ] 1. A\n- [ ] 2. B [deps: 1]\n")
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn parse_tasks_checked_reports_malformed_checkbox_with_line() {
+        let err = parse_tasks_checked("- [ ] 1. A\n- [X] 2. B\n").unwrap_err();
+        assert_eq!(
+            err,
+            TaskParseError::MalformedCheckbox {
+                line: 2,
+                text: "- [X] 2. B".to_string()
+            },
+            "parse_tasks_checked: uppercase X should be reported"
+        );
+        assert!(err.to_string().starts_with("line 2: malformed task line"));
+
+        for (content, line) in [("- [ ]3. Missing space\n", 1), ("\n- [] 1. Empty box\n", 2)] {
+            assert!(
+                matches!(
+                    parse_tasks_checked(content),
+                    Err(TaskParseError::MalformedCheckbox { line: l, .. }) if l == line
+                ),
+                "parse_tasks_checked: {content:?} should be malformed at line {line}"
+            );
+        }
+        assert_eq!(
+            parse_tasks_checked("- [ ] 1. Open [deps: 2\n- [ ] 2. B\n").unwrap_err(),
+            TaskParseError::UnclosedDeps { line: 1 }
+        );
+        assert!(
+            parse_tasks_checked("- [ ] 1. A\n- [link](http://x)\n- [ ] free-form note\n").is_ok(),
+            "parse_tasks_checked: links and unnumbered checkboxes are not tasks"
+        );
+    }
 }
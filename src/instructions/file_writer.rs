@@ -2,6 +2,8 @@ use anyhow::{Context, Result};
 use serde_json::json;
 use std::path::{Path, PathBuf};
 
+use crate::session::{BUSY_MARKER, IDLE_MARKER};
+
 /// Write content to an expert-specific file, creating parent directories as needed.
 fn write_expert_file(path: &Path, content: &str) -> Result<PathBuf> {
     if let Some(parent) = path.parent() {
@@ -46,8 +48,14 @@ pub fn write_settings_file(queue_path: &Path, expert_id: u32, json: &str) -> Res
 
 pub fn generate_hooks_settings(status_file_path: &str) -> String {
     let dq_path = shell_double_quote(status_file_path);
-    let processing_cmd = bash_c_wrap(&format!("printf \"%s\" \"processing\" >| \"{}\"", dq_path));
-    let pending_cmd = bash_c_wrap(&format!("printf \"%s\" \"pending\" >| \"{}\"", dq_path));
+    let processing_cmd = bash_c_wrap(&format!(
+        "printf \"%s\" \"{BUSY_MARKER}\" >| \"{}\"",
+        dq_path
+    ));
+    let pending_cmd = bash_c_wrap(&format!(
+        "printf \"%s\" \"{IDLE_MARKER}\" >| \"{}\"",
+        dq_path
+    ));
     let pre_tool_use_inner = concat!(
         "INPUT=$(cat); ",
         "TARGET=$(echo \"$INPUT\" | jq -r '(.tool_input.file_path // .tool_input.command // \"\")'); ",
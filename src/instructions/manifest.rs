@@ -1,4 +1,4 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
@@ -15,6 +15,8 @@ pub struct ExpertManifestEntry {
     pub name: String,
     pub role: String,
     pub worktree_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
 }
 
 /// Generate manifest JSON from config, session expert roles, and registry.
@@ -41,11 +43,17 @@ pub fn generate_expert_manifest(
                 .get_expert(id)
                 .and_then(|info| info.worktree_path.clone());
 
+            let tags = config
+                .get_expert(id)
+                .map(|e| e.tags.clone())
+                .unwrap_or_default();
+
             ExpertManifestEntry {
                 expert_id: id,
                 name,
                 role,
                 worktree_path,
+                tags,
             }
         })
         .collect();
@@ -56,13 +64,33 @@ pub fn generate_expert_manifest(
 
 /// Write manifest to `.macot/experts_manifest.json`.
 ///
-/// Overwrites existing file if present.
+/// Overwrites existing file if present. The content goes to a temp file that
+/// is renamed into place, so readers never see a half-written manifest.
 pub fn write_expert_manifest(queue_path: &Path, content: &str) -> Result<PathBuf> {
     let manifest_path = queue_path.join("experts_manifest.json");
-    std::fs::write(&manifest_path, content)?;
+    let temp_path = manifest_path.with_extension("json.tmp");
+    std::fs::write(&temp_path, content).context("Failed to write manifest temp file")?;
+    std::fs::rename(&temp_path, &manifest_path)
+        .context("Failed to atomically move manifest file")?;
     Ok(manifest_path)
 }
 
+/// Read and validate `.macot/experts_manifest.json`.
+///
+/// Returns `Ok(None)` when no manifest has been written yet and an error when
+/// the file does not parse, e.g. after a crash truncated it.
+pub fn read_expert_manifest(queue_path: &Path) -> Result<Option<Vec<ExpertManifestEntry>>> {
+    let manifest_path = queue_path.join("experts_manifest.json");
+    let content = match std::fs::read_to_string(&manifest_path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e).context("Failed to read expert manifest"),
+    };
+    let entries = serde_json::from_str(&content)
+        .with_context(|| format!("Corrupted expert manifest: {}", manifest_path.display()))?;
+    Ok(Some(entries))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -71,15 +99,21 @@ mod tests {
 
     fn make_config(experts: Vec<(&str, &str)>) -> Config {
         use crate::config::ExpertConfig;
-        let mut config = Config::default();
-        config.experts = experts
-            .into_iter()
-            .map(|(name, role)| ExpertConfig {
-                name: name.to_string(),
-                role: role.to_string(),
-            })
-            .collect();
-        config
+        Config {
+            experts: experts
+                .into_iter()
+                .map(|(name, role)| ExpertConfig {
+                    name: name.to_string(),
+                    role: role.to_string(),
+                    default_recipient: None,
+                    tags: Vec::new(),
+                    env: Default::default(),
+                    model: None,
+                    effort: None,
+                })
+                .collect(),
+            ..Config::default()
+        }
     }
 
     fn make_session_roles() -> SessionExpertRoles {
@@ -269,6 +303,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn write_manifest_is_atomic_when_interrupted() {
+        let tmp = TempDir::new().unwrap();
+        let content =
+            r#"[{"expert_id":0,"name":"Alyosha","role":"architect","worktree_path":null}]"#;
+        write_expert_manifest(tmp.path(), content).unwrap();
+
+        // A crash mid-write leaves only a truncated temp file behind
+        let temp_path = tmp.path().join("experts_manifest.json.tmp");
+        std::fs::write(&temp_path, &content[..20]).unwrap();
+        let entries = read_expert_manifest(tmp.path()).unwrap().unwrap();
+        assert_eq!(
+            entries[0].name, "Alyosha",
+            "write_manifest_atomic: interrupted write should leave the old manifest intact"
+        );
+
+        write_expert_manifest(tmp.path(), "[]").unwrap();
+        assert!(
+            !temp_path.exists(),
+            "write_manifest_atomic: next write should replace the leftover temp file"
+        );
+        assert_eq!(read_expert_manifest(tmp.path()).unwrap(), Some(vec![]));
+    }
+
+    #[test]
+    fn read_manifest_rejects_truncated_file() {
+        let tmp = TempDir::new().unwrap();
+        assert_eq!(read_expert_manifest(tmp.path()).unwrap(), None);
+
+        std::fs::write(
+            tmp.path().join("experts_manifest.json"),
+            r#"[{"expert_id":0,"na"#,
+        )
+        .unwrap();
+        let err = read_expert_manifest(tmp.path()).unwrap_err();
+        assert!(
+            format!("{err:#}").contains("Corrupted expert manifest"),
+            "read_manifest: truncated JSON should be reported as corrupted, got: {err:#}"
+        );
+    }
+
     #[test]
     fn write_manifest_overwrites_existing() {
         let tmp = TempDir::new().unwrap();
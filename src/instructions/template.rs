@@ -4,6 +4,7 @@ use std::path::Path;
 
 use super::defaults;
 use super::schema::generate_yaml_schema;
+use crate::models::EffortLevel;
 
 /// Result of loading instructions, including fallback information.
 #[derive(Debug, Clone)]
@@ -14,12 +15,15 @@ pub struct InstructionResult {
     pub agents_json: Option<String>,
 }
 
-/// Render a template file with the yaml_schema, expert_id, expert_name, and status_file_path variables.
+/// Render a template file with the yaml_schema, expert_id, expert_name, status_file_path,
+/// effort_level, and effort_directive variables.
 pub fn render_template(
     template_content: &str,
     expert_id: u32,
     expert_name: &str,
     status_file_path: &str,
+    scratchpad_dir: &str,
+    effort_level: EffortLevel,
 ) -> Result<String> {
     let mut env = Environment::new();
     env.add_template("core", template_content)
@@ -34,6 +38,10 @@ pub fn render_template(
             expert_id => expert_id,
             expert_name => expert_name,
             status_file_path => status_file_path,
+            scratchpad_path => format!("{scratchpad_dir}/scratchpad.md"),
+            scratchpad_inbox => format!("{scratchpad_dir}/expert{expert_id}"),
+            effort_level => effort_level.as_str(),
+            effort_directive => effort_level.directive(),
         })
         .context("Failed to render template")?;
 
@@ -45,6 +53,8 @@ pub fn render_template(
 /// - `core_path`: Project's instructions folder (for core.md and templates)
 /// - `role_instructions_path`: User's config folder (~/.config/macot/instructions/)
 /// - `role_name`: The role to load instructions for
+/// - `scratchpad_dir`: Directory holding the shared scratchpad and each expert's note inbox
+/// - `effort_level`: Thoroughness directive exposed to the core template
 ///
 /// Fallback chain for role instructions:
 /// 1. User custom: role_instructions_path/{role}.md
@@ -61,6 +71,8 @@ pub fn load_instruction_with_template(
     worktree_path: Option<&str>,
     manifest_path: &str,
     status_dir: &str,
+    scratchpad_dir: &str,
+    effort_level: EffortLevel,
 ) -> Result<InstructionResult> {
     let mut content = String::new();
 
@@ -77,6 +89,8 @@ pub fn load_instruction_with_template(
             expert_id,
             expert_name,
             status_file_path,
+            scratchpad_dir,
+            effort_level,
         )?);
         content.push_str("\n\n");
     } else if core_legacy_path.exists() {
@@ -145,7 +159,15 @@ mod tests {
     #[test]
     fn render_template_replaces_yaml_schema() {
         let template = "## Report Format\n\n```yaml\n{{ yaml_schema }}```\n";
-        let rendered = render_template(template, 0, "test", "/tmp/status/expert0").unwrap();
+        let rendered = render_template(
+            template,
+            0,
+            "test",
+            "/tmp/status/expert0",
+            "/tmp/scratchpad",
+            EffortLevel::Medium,
+        )
+        .unwrap();
 
         assert!(rendered.contains("task_id:"));
         assert!(rendered.contains("expert_id:"));
@@ -156,7 +178,15 @@ mod tests {
     #[test]
     fn render_template_preserves_surrounding_text() {
         let template = "# Header\n\nSome text before.\n\n{{ yaml_schema }}\n\nSome text after.";
-        let rendered = render_template(template, 0, "test", "/tmp/status/expert0").unwrap();
+        let rendered = render_template(
+            template,
+            0,
+            "test",
+            "/tmp/status/expert0",
+            "/tmp/scratchpad",
+            EffortLevel::Medium,
+        )
+        .unwrap();
 
         assert!(rendered.contains("# Header"));
         assert!(rendered.contains("Some text before."));
@@ -177,7 +207,15 @@ mod tests {
 **Critical Notes**:
 - `status` must be exactly `done`
 "#;
-        let rendered = render_template(template, 0, "test", "/tmp/status/expert0").unwrap();
+        let rendered = render_template(
+            template,
+            0,
+            "test",
+            "/tmp/status/expert0",
+            "/tmp/scratchpad",
+            EffortLevel::Medium,
+        )
+        .unwrap();
 
         assert!(rendered.contains("# Multi-Agent Control Tower"));
         assert!(rendered.contains("task_id:"));
@@ -199,6 +237,8 @@ mod tests {
             None,
             "/tmp/manifest.json",
             "/tmp/status",
+            "/tmp/scratchpad",
+            EffortLevel::Medium,
         )
         .unwrap();
 
@@ -228,6 +268,8 @@ mod tests {
             None,
             "/tmp/manifest.json",
             "/tmp/status",
+            "/tmp/scratchpad",
+            EffortLevel::Medium,
         )
         .unwrap();
 
@@ -251,6 +293,8 @@ mod tests {
             None,
             "/tmp/manifest.json",
             "/tmp/status",
+            "/tmp/scratchpad",
+            EffortLevel::Medium,
         )
         .unwrap();
 
@@ -276,6 +320,8 @@ mod tests {
             None,
             "/tmp/manifest.json",
             "/tmp/status",
+            "/tmp/scratchpad",
+            EffortLevel::Medium,
         )
         .unwrap();
 
@@ -285,7 +331,15 @@ mod tests {
     #[test]
     fn render_template_replaces_expert_identity() {
         let template = "You are **{{ expert_name }}** (Expert ID: {{ expert_id }}).";
-        let rendered = render_template(template, 3, "Alyosha", "/tmp/status/expert3").unwrap();
+        let rendered = render_template(
+            template,
+            3,
+            "Alyosha",
+            "/tmp/status/expert3",
+            "/tmp/scratchpad",
+            EffortLevel::Medium,
+        )
+        .unwrap();
 
         assert!(rendered.contains("You are **Alyosha** (Expert ID: 3)."));
         assert!(!rendered.contains("{{ expert_name }}"));
@@ -295,13 +349,41 @@ mod tests {
     #[test]
     fn render_template_replaces_status_file_path() {
         let template = "Write status to: {{ status_file_path }}";
-        let rendered =
-            render_template(template, 0, "test", "/tmp/project/.macot/status/expert0").unwrap();
+        let rendered = render_template(
+            template,
+            0,
+            "test",
+            "/tmp/project/.macot/status/expert0",
+            "/tmp/scratchpad",
+            EffortLevel::Medium,
+        )
+        .unwrap();
 
         assert!(rendered.contains("/tmp/project/.macot/status/expert0"));
         assert!(!rendered.contains("{{ status_file_path }}"));
     }
 
+    #[test]
+    fn render_template_replaces_scratchpad_paths() {
+        let template = "Read {{ scratchpad_path }}, write to {{ scratchpad_inbox }}";
+        let rendered = render_template(
+            template,
+            2,
+            "test",
+            "/tmp/status/expert2",
+            "/tmp/project/.macot/scratchpad",
+            EffortLevel::Medium,
+        )
+        .unwrap();
+
+        assert_eq!(
+            rendered,
+            "Read /tmp/project/.macot/scratchpad/scratchpad.md, \
+             write to /tmp/project/.macot/scratchpad/expert2",
+            "render_template: the inbox should be the expert's own directory"
+        );
+    }
+
     #[test]
     fn instruction_result_includes_agents_json() {
         let core_dir = TempDir::new().unwrap();
@@ -325,6 +407,8 @@ mod tests {
             None,
             "/tmp/manifest.json",
             "/tmp/status",
+            "/tmp/scratchpad",
+            EffortLevel::Medium,
         )
         .unwrap();
 
@@ -358,6 +442,8 @@ mod tests {
             None,
             "/tmp/manifest.json",
             "/tmp/status",
+            "/tmp/scratchpad",
+            EffortLevel::Medium,
         )
         .unwrap();
 
@@ -390,6 +476,8 @@ mod tests {
             None,
             "/custom/manifest.json",
             "/tmp/status",
+            "/tmp/scratchpad",
+            EffortLevel::Medium,
         )
         .unwrap();
 
@@ -426,6 +514,8 @@ mod tests {
             None,
             "/tmp/manifest.json",
             "/custom/status/dir",
+            "/tmp/scratchpad",
+            EffortLevel::Medium,
         )
         .unwrap();
 
@@ -462,6 +552,8 @@ mod tests {
             Some("/wt/my-feature"),
             "/tmp/manifest.json",
             "/tmp/status",
+            "/tmp/scratchpad",
+            EffortLevel::Medium,
         )
         .unwrap();
 
@@ -474,4 +566,75 @@ mod tests {
             prompt
         );
     }
+
+    #[test]
+    fn render_template_replaces_effort_placeholders() {
+        let template = "Effort: {{ effort_level }}\n{{ effort_directive }}";
+        let rendered = render_template(
+            template,
+            0,
+            "test",
+            "/tmp/status/expert0",
+            "/tmp/scratchpad",
+            EffortLevel::High,
+        )
+        .unwrap();
+
+        assert!(
+            rendered.contains("Effort: high"),
+            "render_template: should substitute effort_level"
+        );
+        assert!(
+            rendered.contains(EffortLevel::High.directive()),
+            "render_template: should substitute effort_directive"
+        );
+    }
+
+    #[test]
+    fn load_instruction_effort_level_changes_substituted_text() {
+        let core_dir = TempDir::new().unwrap();
+        let role_dir = TempDir::new().unwrap();
+
+        let templates_dir = core_dir.path().join("templates");
+        std::fs::create_dir_all(&templates_dir).unwrap();
+        std::fs::write(
+            templates_dir.join("core.md.tmpl"),
+            "## Effort Level\n\n{{ effort_directive }}",
+        )
+        .unwrap();
+
+        let load = |level| {
+            load_instruction_with_template(
+                core_dir.path(),
+                role_dir.path(),
+                "architect",
+                0,
+                "test",
+                "/tmp/status/expert0",
+                None,
+                "/tmp/manifest.json",
+                "/tmp/status",
+                "/tmp/scratchpad",
+                level,
+            )
+            .unwrap()
+            .content
+        };
+
+        let low = load(EffortLevel::Low);
+        let high = load(EffortLevel::High);
+
+        assert_ne!(
+            low, high,
+            "load_instruction: different effort levels should produce different instructions"
+        );
+        assert!(
+            low.contains(EffortLevel::Low.directive()),
+            "load_instruction: low effort directive should be injected"
+        );
+        assert!(
+            high.contains(EffortLevel::High.directive()),
+            "load_instruction: high effort directive should be injected"
+        );
+    }
 }
@@ -5,6 +5,7 @@ pub mod context;
 pub mod experts;
 pub mod feature;
 pub mod instructions;
+pub mod logging;
 pub mod models;
 pub mod queue;
 pub mod session;
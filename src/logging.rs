@@ -0,0 +1,138 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use tracing::Level;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+/// Log file used by debug builds when no path is given
+pub const DEFAULT_LOG_FILE: &str = "/tmp/macot-debug.log";
+/// Environment variable holding the log level (e.g. `debug`)
+pub const LOG_LEVEL_ENV: &str = "MACOT_LOG";
+/// Environment variable holding the log file path
+pub const LOG_FILE_ENV: &str = "MACOT_LOG_FILE";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogSettings {
+    pub file: PathBuf,
+    pub level: Level,
+}
+
+impl LogSettings {
+    /// Decide where and how verbosely to log.
+    ///
+    /// Flags take precedence over `MACOT_LOG_FILE` / `MACOT_LOG`, which take
+    /// precedence over the defaults. Debug builds always log (to
+    /// [`DEFAULT_LOG_FILE`] at DEBUG unless overridden); release builds only log
+    /// when a file or level is requested. Returns `None` when logging is off.
+    pub fn resolve(
+        flag_file: Option<PathBuf>,
+        flag_level: Option<Level>,
+        env_file: Option<String>,
+        env_level: Option<String>,
+        debug_build: bool,
+    ) -> Result<Option<Self>> {
+        let env_level = env_level
+            .filter(|level| !level.is_empty())
+            .map(|level| {
+                level
+                    .parse::<Level>()
+                    .with_context(|| format!("Invalid {LOG_LEVEL_ENV} level '{level}'"))
+            })
+            .transpose()?;
+        let env_file = env_file.filter(|file| !file.is_empty()).map(PathBuf::from);
+
+        let file = flag_file.or(env_file);
+        let level = flag_level.or(env_level);
+        if !debug_build && file.is_none() && level.is_none() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            file: file.unwrap_or_else(|| PathBuf::from(DEFAULT_LOG_FILE)),
+            level: level.unwrap_or(Level::DEBUG),
+        }))
+    }
+}
+
+/// Install the global tracing subscriber writing to `settings.file`.
+/// Output never goes to stdout/stderr, which belong to the TUI.
+pub fn init(settings: &LogSettings) -> Result<()> {
+    let file = &settings.file;
+    let directory = file
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let file_name = file
+        .file_name()
+        .with_context(|| format!("Log file path '{}' has no file name", file.display()))?;
+
+    let file_appender = tracing_appender::rolling::never(directory, file_name);
+    tracing_subscriber::fmt()
+        .with_writer(file_appender)
+        .with_ansi(false)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_max_level(settings.level)
+        .init();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_release_defaults_to_no_logging() {
+        assert_eq!(
+            LogSettings::resolve(None, None, None, None, false).unwrap(),
+            None,
+            "resolve: release builds should not log unless asked"
+        );
+    }
+
+    #[test]
+    fn resolve_debug_defaults_to_debug_log_file() {
+        let settings = LogSettings::resolve(None, None, None, None, true)
+            .unwrap()
+            .unwrap();
+        assert_eq!(settings.file, PathBuf::from(DEFAULT_LOG_FILE));
+        assert_eq!(settings.level, Level::DEBUG);
+    }
+
+    #[test]
+    fn resolve_env_enables_release_logging() {
+        let settings = LogSettings::resolve(None, None, None, Some("info".to_string()), false)
+            .unwrap()
+            .unwrap();
+        assert_eq!(settings.file, PathBuf::from(DEFAULT_LOG_FILE));
+        assert_eq!(settings.level, Level::INFO);
+    }
+
+    #[test]
+    fn resolve_flags_take_precedence_over_env() {
+        let settings = LogSettings::resolve(
+            Some(PathBuf::from("/var/log/macot.log")),
+            Some(Level::TRACE),
+            Some("/tmp/env.log".to_string()),
+            Some("warn".to_string()),
+            false,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(settings.file, PathBuf::from("/var/log/macot.log"));
+        assert_eq!(
+            settings.level,
+            Level::TRACE,
+            "resolve: --log-level should override MACOT_LOG"
+        );
+    }
+
+    #[test]
+    fn resolve_rejects_invalid_env_level() {
+        let err = LogSettings::resolve(None, None, None, Some("loud".to_string()), false)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains(LOG_LEVEL_ENV),
+            "resolve: invalid level should name the variable, got: {err}"
+        );
+    }
+}
@@ -1,6 +1,5 @@
 use anyhow::Result;
 use clap::Parser;
-use tracing_subscriber::fmt::format::FmtSpan;
 
 mod cli;
 mod commands;
@@ -9,6 +8,7 @@ mod context;
 mod experts;
 mod feature;
 mod instructions;
+mod logging;
 mod models;
 mod queue;
 mod session;
@@ -19,16 +19,19 @@ use cli::{Cli, Commands};
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    // Log to file for debugging TUI
-    let file_appender = tracing_appender::rolling::never("/tmp", "macot-debug.log");
-    tracing_subscriber::fmt()
-        .with_writer(file_appender)
-        .with_span_events(FmtSpan::CLOSE)
-        .with_max_level(tracing::Level::DEBUG)
-        .init();
-
     let cli = Cli::parse();
 
+    // Log to a file only; stdout belongs to the TUI
+    if let Some(settings) = logging::LogSettings::resolve(
+        cli.log_file.clone(),
+        cli.log_level,
+        std::env::var(logging::LOG_FILE_ENV).ok(),
+        std::env::var(logging::LOG_LEVEL_ENV).ok(),
+        cfg!(debug_assertions),
+    )? {
+        logging::init(&settings)?;
+    }
+
     match cli.command {
         Commands::Start(args) => commands::start::execute(args).await,
         Commands::Down(args) => commands::down::execute(args).await,
@@ -37,5 +40,8 @@ async fn main() -> Result<()> {
         Commands::Status(args) => commands::status::execute(args).await,
         Commands::Sessions => commands::sessions::execute().await,
         Commands::Reset(args) => commands::reset::execute(args).await,
+        Commands::Stop(args) => commands::stop::execute(args).await,
+        Commands::Prune(args) => commands::prune::execute(args).await,
+        Commands::Doctor(args) => commands::doctor::execute(args).await,
     }
 }
@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+
+/// How thorough an expert should be when working on a task.
+///
+/// Rendered into the instruction file via the `effort_level` / `effort_directive`
+/// template variables, so a change only takes effect on the next launch.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum EffortLevel {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+impl EffortLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EffortLevel::Low => "low",
+            EffortLevel::Medium => "medium",
+            EffortLevel::High => "high",
+        }
+    }
+
+    /// Cycle low -> medium -> high -> low.
+    pub fn next(&self) -> Self {
+        match self {
+            EffortLevel::Low => EffortLevel::Medium,
+            EffortLevel::Medium => EffortLevel::High,
+            EffortLevel::High => EffortLevel::Low,
+        }
+    }
+
+    /// Instruction text injected into the generated instructions.
+    pub fn directive(&self) -> &'static str {
+        match self {
+            EffortLevel::Low => {
+                "Work quickly. Prefer the simplest change that satisfies the task, \
+                 skip optional refactoring, and keep investigation and reports brief."
+            }
+            EffortLevel::Medium => {
+                "Balance speed and thoroughness. Verify your changes with the relevant \
+                 tests and explain notable decisions in your report."
+            }
+            EffortLevel::High => {
+                "Be thorough. Investigate edge cases, add or extend tests for every \
+                 behavior you touch, double-check your work before reporting, and \
+                 document trade-offs in detail."
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for EffortLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn effort_level_defaults_to_medium() {
+        assert_eq!(
+            EffortLevel::default(),
+            EffortLevel::Medium,
+            "effort_level: default should be medium"
+        );
+    }
+
+    #[test]
+    fn effort_level_next_cycles_through_all_levels() {
+        assert_eq!(EffortLevel::Low.next(), EffortLevel::Medium);
+        assert_eq!(EffortLevel::Medium.next(), EffortLevel::High);
+        assert_eq!(
+            EffortLevel::High.next(),
+            EffortLevel::Low,
+            "effort_level: next should wrap from high to low"
+        );
+    }
+
+    #[test]
+    fn effort_level_directives_are_distinct() {
+        assert_ne!(
+            EffortLevel::Low.directive(),
+            EffortLevel::Medium.directive()
+        );
+        assert_ne!(
+            EffortLevel::Medium.directive(),
+            EffortLevel::High.directive()
+        );
+        assert_ne!(EffortLevel::Low.directive(), EffortLevel::High.directive());
+    }
+
+    #[test]
+    fn effort_level_serializes_as_snake_case() {
+        let yaml = serde_yaml::to_string(&EffortLevel::High).unwrap();
+        assert_eq!(
+            yaml.trim(),
+            "high",
+            "effort_level: should serialize as 'high'"
+        );
+        let parsed: EffortLevel = serde_yaml::from_str("low").unwrap();
+        assert_eq!(parsed, EffortLevel::Low);
+    }
+}
@@ -10,6 +10,8 @@ pub enum ExpertState {
     #[default]
     Idle,
     Busy,
+    /// No live agent: the tmux window is gone or Claude exited to the shell
+    Offline,
 }
 
 impl ExpertState {
@@ -17,6 +19,7 @@ impl ExpertState {
         match self {
             ExpertState::Idle => "○",
             ExpertState::Busy => "●",
+            ExpertState::Offline => "✗",
         }
     }
 
@@ -24,6 +27,7 @@ impl ExpertState {
         match self {
             ExpertState::Idle => Color::Gray,
             ExpertState::Busy => Color::Green,
+            ExpertState::Offline => Color::DarkGray,
         }
     }
 
@@ -31,6 +35,7 @@ impl ExpertState {
         match self {
             ExpertState::Idle => "Waiting for input",
             ExpertState::Busy => "Working",
+            ExpertState::Offline => "Agent not running",
         }
     }
 }
@@ -89,6 +94,8 @@ pub struct ExpertInfo {
     pub last_activity: DateTime<Utc>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub worktree_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
 }
 
 impl ExpertInfo {
@@ -108,9 +115,15 @@ impl ExpertInfo {
             state: ExpertState::default(),
             last_activity: Utc::now(),
             worktree_path: None,
+            tags: Vec::new(),
         }
     }
 
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
     pub fn set_state(&mut self, state: ExpertState) {
         self.state = state;
         self.last_activity = Utc::now();
@@ -125,6 +138,14 @@ impl ExpertInfo {
         matches!(self.state, ExpertState::Busy)
     }
 
+    pub fn is_offline(&self) -> bool {
+        matches!(self.state, ExpertState::Offline)
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
     #[allow(dead_code)]
     pub fn matches_name(&self, name: &str) -> bool {
         self.name.eq_ignore_ascii_case(name)
@@ -9,6 +9,21 @@ pub const MAX_DELIVERY_ATTEMPTS: u32 = 100;
 #[allow(dead_code)]
 pub const DEFAULT_MESSAGE_TTL_SECS: u64 = 86400;
 
+/// Appended to a message body that was cut to fit the configured size limit
+pub const TRUNCATION_MARKER: &str = "\n[truncated]";
+
+/// Metadata key recording the original body size of a truncated message
+pub const TRUNCATED_METADATA_KEY: &str = "truncated_from_bytes";
+
+/// Metadata key recording that `to` was filled from the sender's default recipient
+pub const DEFAULT_RECIPIENT_METADATA_KEY: &str = "default_recipient";
+
+/// Metadata key on a delivery receipt naming the message it acknowledges
+pub const RECEIPT_FOR_METADATA_KEY: &str = "receipt_for";
+
+/// Metadata key on a replayed message naming the delivery it repeats
+pub const REPLAY_OF_METADATA_KEY: &str = "replay_of";
+
 /// Unique identifier for messages
 pub type MessageId = String;
 
@@ -16,13 +31,17 @@ pub type MessageId = String;
 pub type ExpertId = u32;
 
 /// Target for message delivery
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(untagged)]
 pub enum MessageRecipient {
     /// Send to specific expert by ID
     ExpertId { expert_id: u32 },
     /// Send to any idle expert with this role
     Role { role: String },
+    /// Send to any idle expert carrying this tag
+    Tag { tag: String },
+    /// Send to every recipient in the list, once per resolved expert
+    Multi(Vec<MessageRecipient>),
 }
 
 #[allow(dead_code)]
@@ -34,6 +53,22 @@ impl MessageRecipient {
     pub fn role(role: impl Into<String>) -> Self {
         Self::Role { role: role.into() }
     }
+
+    pub fn tag(tag: impl Into<String>) -> Self {
+        Self::Tag { tag: tag.into() }
+    }
+
+    pub fn multi(recipients: Vec<MessageRecipient>) -> Self {
+        Self::Multi(recipients)
+    }
+
+    /// The single-target recipients, with nested lists flattened
+    pub fn leaves(&self) -> Vec<&MessageRecipient> {
+        match self {
+            Self::Multi(recipients) => recipients.iter().flat_map(Self::leaves).collect(),
+            single => vec![single],
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -46,6 +81,23 @@ pub enum MessageType {
     Delegate, // Task handoff
 }
 
+/// Delivery budget a message draws from: informational notifications are
+/// limited separately from task-bearing messages so neither can starve the other
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MessageLane {
+    Task,
+    Notify,
+}
+
+impl MessageType {
+    pub fn lane(&self) -> MessageLane {
+        match self {
+            MessageType::Notify => MessageLane::Notify,
+            MessageType::Query | MessageType::Response | MessageType::Delegate => MessageLane::Task,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, PartialOrd, Ord)]
 #[serde(rename_all = "snake_case")]
 pub enum MessagePriority {
@@ -59,6 +111,56 @@ pub enum MessagePriority {
 pub struct MessageContent {
     pub subject: String,
     pub body: String,
+    /// Repo-relative file references, e.g. `src/main.rs` or `src/main.rs:42`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<String>,
+}
+
+impl MessageContent {
+    pub fn new(subject: impl Into<String>, body: impl Into<String>) -> anyhow::Result<Self> {
+        let content = Self {
+            subject: subject.into(),
+            body: body.into(),
+            attachments: Vec::new(),
+        };
+        content.validate()?;
+        Ok(content)
+    }
+
+    /// Check that subject and body are present
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.subject.is_empty() {
+            anyhow::bail!("Message subject is required");
+        }
+        if self.body.is_empty() {
+            anyhow::bail!("Message body is required");
+        }
+        Ok(())
+    }
+
+    /// Cut the body to at most `max_bytes` (marker included) on a char boundary.
+    /// A limit too small for the marker cuts the body without one.
+    ///
+    /// Returns the original body size when the body was truncated.
+    pub fn truncate_body(&mut self, max_bytes: usize) -> Option<usize> {
+        let original_len = self.body.len();
+        if original_len <= max_bytes {
+            return None;
+        }
+
+        let marker = if max_bytes >= TRUNCATION_MARKER.len() {
+            TRUNCATION_MARKER
+        } else {
+            ""
+        };
+        let mut cut = max_bytes - marker.len();
+        while !self.body.is_char_boundary(cut) {
+            cut -= 1;
+        }
+        self.body.truncate(cut);
+        self.body.push_str(marker);
+        Some(original_len)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -78,6 +180,13 @@ pub struct Message {
     pub expires_at: Option<DateTime<Utc>>,
     #[serde(default)]
     pub metadata: HashMap<String, String>,
+    /// Ask the router to notify the sender once the message has been delivered
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub request_delivery_receipt: bool,
+    /// Deliver even while the recipient is Busy; honoured only for High
+    /// priority messages when preemption is allowed in the config
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub preempt: bool,
 }
 
 #[allow(dead_code)]
@@ -103,6 +212,8 @@ impl Message {
             delivery_attempts: 0,
             expires_at: Some(now + chrono::Duration::seconds(DEFAULT_MESSAGE_TTL_SECS as i64)),
             metadata: HashMap::new(),
+            request_delivery_receipt: false,
+            preempt: false,
         }
     }
 
@@ -121,6 +232,16 @@ impl Message {
         self
     }
 
+    pub fn with_delivery_receipt(mut self) -> Self {
+        self.request_delivery_receipt = true;
+        self
+    }
+
+    pub fn with_preempt(mut self) -> Self {
+        self.preempt = true;
+        self
+    }
+
     pub fn with_metadata(mut self, key: String, value: String) -> Self {
         self.metadata.insert(key, value);
         self
@@ -141,6 +262,100 @@ impl Message {
     pub fn has_exceeded_max_attempts(&self) -> bool {
         self.delivery_attempts >= MAX_DELIVERY_ATTEMPTS
     }
+
+    pub fn is_truncated(&self) -> bool {
+        self.metadata.contains_key(TRUNCATED_METADATA_KEY)
+    }
+
+    /// Build the `Notify` sent back to the sender once this message reached `recipient_name`.
+    /// The receipt is sent on behalf of the recipient so it follows the same worktree rules.
+    pub fn delivery_receipt(&self, recipient_id: ExpertId, recipient_name: &str) -> Message {
+        let content = MessageContent {
+            subject: format!("Delivered: {}", self.content.subject),
+            body: format!(
+                "Your message {} was delivered to {}",
+                self.message_id, recipient_name
+            ),
+            attachments: Vec::new(),
+        };
+        let mut receipt = Message::new(
+            recipient_id,
+            MessageRecipient::expert_id(self.from_expert_id),
+            MessageType::Notify,
+            content,
+        )
+        .with_metadata(
+            RECEIPT_FOR_METADATA_KEY.to_string(),
+            self.message_id.clone(),
+        );
+        receipt.message_id = format!("{}-receipt", self.message_id);
+        receipt
+    }
+}
+
+impl Message {
+    /// A fresh copy of this message for `expert_id`, with a new id, timestamp and TTL
+    /// window and no delivery attempts, so it goes through the queue like a new send
+    pub fn replay_to(&self, expert_id: ExpertId) -> Message {
+        let mut replay = Message::new(
+            self.from_expert_id,
+            MessageRecipient::expert_id(expert_id),
+            self.message_type,
+            self.content.clone(),
+        )
+        .with_priority(self.priority);
+        replay.expires_at = self
+            .expires_at
+            .map(|expires_at| replay.created_at + (expires_at - self.created_at));
+        replay.reply_to = self.reply_to.clone();
+        replay.metadata = self.metadata.clone();
+        replay
+            .metadata
+            .insert(REPLAY_OF_METADATA_KEY.to_string(), self.message_id.clone());
+        replay.request_delivery_receipt = self.request_delivery_receipt;
+        replay.preempt = self.preempt;
+        replay
+    }
+}
+
+/// Record of a message handed to an expert, kept after it leaves the queue
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeliveredMessage {
+    pub message_id: MessageId,
+    pub from_expert_id: ExpertId,
+    pub to_expert_id: ExpertId,
+    pub message_type: MessageType,
+    pub subject: String,
+    pub delivered_at: DateTime<Utc>,
+    /// When the message was queued; absent in records from older versions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub queued_at: Option<DateTime<Utc>>,
+    /// Time spent waiting in the queue, from `queued_at` to `delivered_at`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+}
+
+impl DeliveredMessage {
+    pub fn new(message: &Message, to_expert_id: ExpertId) -> Self {
+        let delivered_at = Utc::now();
+        let latency_ms = (delivered_at - message.created_at)
+            .num_milliseconds()
+            .max(0) as u64;
+        Self {
+            message_id: message.message_id.clone(),
+            from_expert_id: message.from_expert_id,
+            to_expert_id,
+            message_type: message.message_type,
+            subject: message.content.subject.clone(),
+            delivered_at,
+            queued_at: Some(message.created_at),
+            latency_ms: Some(latency_ms),
+        }
+    }
+
+    pub fn latency(&self) -> Option<std::time::Duration> {
+        self.latency_ms.map(std::time::Duration::from_millis)
+    }
 }
 
 #[cfg(test)]
@@ -152,6 +367,7 @@ mod tests {
         let content = MessageContent {
             subject: "Test Subject".to_string(),
             body: "Test Body".to_string(),
+            attachments: Vec::new(),
         };
         let recipient = MessageRecipient::expert_id(1);
 
@@ -187,6 +403,7 @@ mod tests {
         let content = MessageContent {
             subject: "Test".to_string(),
             body: "Body".to_string(),
+            attachments: Vec::new(),
         };
         let recipient = MessageRecipient::expert_id(1);
 
@@ -206,6 +423,7 @@ mod tests {
         let content = MessageContent {
             subject: "Test".to_string(),
             body: "Body".to_string(),
+            attachments: Vec::new(),
         };
         let recipient = MessageRecipient::expert_id(1);
 
@@ -227,6 +445,7 @@ mod tests {
         let content = MessageContent {
             subject: "Test".to_string(),
             body: "Body".to_string(),
+            attachments: Vec::new(),
         };
         let recipient = MessageRecipient::expert_id(1);
 
@@ -248,6 +467,7 @@ mod tests {
         let content = MessageContent {
             subject: "API Question".to_string(),
             body: "What format should we use for dates?".to_string(),
+            attachments: Vec::new(),
         };
         let recipient = MessageRecipient::expert_id(2);
 
@@ -286,6 +506,10 @@ metadata: {}
         assert_eq!(message.message_type, MessageType::Delegate);
         assert_eq!(message.priority, MessagePriority::High);
         assert_eq!(message.content.subject, "Implement User API");
+        assert!(
+            message.content.attachments.is_empty(),
+            "message_deserializes_from_yaml: missing attachments should default to empty"
+        );
     }
 
     #[test]
@@ -333,6 +557,92 @@ metadata: {}
             }
         );
     }
+
+    #[test]
+    fn message_recipient_list_yaml_deserializes_as_multi() {
+        let yaml = "- expert_id: 2\n- role: backend\n";
+        let recipient: MessageRecipient = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(
+            recipient,
+            MessageRecipient::multi(vec![
+                MessageRecipient::expert_id(2),
+                MessageRecipient::role("backend"),
+            ])
+        );
+
+        let nested = MessageRecipient::multi(vec![
+            MessageRecipient::expert_id(0),
+            MessageRecipient::multi(vec![MessageRecipient::tag("qa")]),
+        ]);
+        assert_eq!(
+            nested.leaves(),
+            vec![
+                &MessageRecipient::expert_id(0),
+                &MessageRecipient::tag("qa")
+            ],
+            "leaves: nested lists should be flattened"
+        );
+    }
+
+    #[test]
+    fn message_recipient_tag_yaml_deserializes() {
+        let yaml = r#"tag: "frontend-team""#;
+        let recipient: MessageRecipient = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(recipient, MessageRecipient::tag("frontend-team"));
+    }
+
+    #[test]
+    fn message_content_new_rejects_empty_fields() {
+        assert!(
+            MessageContent::new("", "body").is_err(),
+            "message_content_new: empty subject should be rejected"
+        );
+        assert!(
+            MessageContent::new("subject", "").is_err(),
+            "message_content_new: empty body should be rejected"
+        );
+        assert!(MessageContent::new("subject", "body").is_ok());
+    }
+
+    #[test]
+    fn message_content_truncate_body_leaves_small_body_unchanged() {
+        let mut content = MessageContent::new("s", "short body").unwrap();
+        assert_eq!(content.truncate_body(64), None);
+        assert_eq!(
+            content.body, "short body",
+            "truncate_body: under-limit body should pass through unchanged"
+        );
+    }
+
+    #[test]
+    fn message_content_truncate_body_cuts_on_char_boundary() {
+        let mut content = MessageContent::new("s", "あ".repeat(100)).unwrap();
+        let original_len = content.body.len();
+
+        assert_eq!(content.truncate_body(50), Some(original_len));
+        assert!(
+            content.body.len() <= 50,
+            "truncate_body: result should fit the limit, got {} bytes",
+            content.body.len()
+        );
+        assert!(
+            content.body.ends_with(TRUNCATION_MARKER),
+            "truncate_body: truncated body should end with the marker"
+        );
+    }
+
+    #[test]
+    fn message_content_truncate_body_never_exceeds_limit_smaller_than_marker() {
+        for max_bytes in 0..TRUNCATION_MARKER.len() {
+            let mut content = MessageContent::new("s", "あいうえお").unwrap();
+            assert_eq!(content.truncate_body(max_bytes), Some(15));
+            assert!(
+                content.body.len() <= max_bytes,
+                "truncate_body: {max_bytes}-byte limit produced {} bytes",
+                content.body.len()
+            );
+        }
+    }
 }
 
 #[cfg(test)]
@@ -342,8 +652,13 @@ mod property_tests {
 
     // Generators for property-based testing
     fn arbitrary_message_content() -> impl Strategy<Value = MessageContent> {
-        ("[a-zA-Z0-9 ]{1,100}", "[a-zA-Z0-9 \n]{1,1000}")
-            .prop_map(|(subject, body)| MessageContent { subject, body })
+        ("[a-zA-Z0-9 ]{1,100}", "[a-zA-Z0-9 \n]{1,1000}").prop_map(|(subject, body)| {
+            MessageContent {
+                subject,
+                body,
+                attachments: Vec::new(),
+            }
+        })
     }
 
     fn arbitrary_message_recipient() -> impl Strategy<Value = MessageRecipient> {
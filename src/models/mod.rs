@@ -1,13 +1,16 @@
+mod effort;
 mod expert;
 mod message;
 mod queued_message;
 mod report;
 
+pub use effort::EffortLevel;
 pub use expert::{ExpertInfo, ExpertState, Role};
 #[allow(unused_imports)]
 pub use message::{
-    ExpertId, Message, MessageContent, MessageId, MessagePriority, MessageRecipient, MessageType,
-    DEFAULT_MESSAGE_TTL_SECS, MAX_DELIVERY_ATTEMPTS,
+    DeliveredMessage, ExpertId, Message, MessageContent, MessageId, MessageLane, MessagePriority,
+    MessageRecipient, MessageType, DEFAULT_MESSAGE_TTL_SECS, DEFAULT_RECIPIENT_METADATA_KEY,
+    MAX_DELIVERY_ATTEMPTS, REPLAY_OF_METADATA_KEY, TRUNCATED_METADATA_KEY, TRUNCATION_MARKER,
 };
 #[allow(unused_imports)]
 pub use queued_message::{MessageStatus, QueuedMessage};
@@ -70,6 +70,11 @@ impl QueuedMessage {
         matches!(self.status, MessageStatus::Expired)
     }
 
+    /// Whether a delivery of this message has been tried and did not succeed
+    pub fn has_failed_delivery(&self) -> bool {
+        self.is_failed() || (self.attempts > 0 && !self.is_expired())
+    }
+
     pub fn should_retry(&self) -> bool {
         self.is_pending() && !self.message.is_expired() && !self.message.has_exceeded_max_attempts()
     }
@@ -92,6 +97,7 @@ mod tests {
         let content = MessageContent {
             subject: "Test Subject".to_string(),
             body: "Test Body".to_string(),
+            attachments: Vec::new(),
         };
         let recipient = MessageRecipient::expert_id(1);
         Message::new(0, recipient, MessageType::Query, content)
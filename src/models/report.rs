@@ -106,6 +106,11 @@ impl Report {
         self.details.files_created.push(file);
     }
 
+    /// When the report last changed: its completion, or its start while in progress
+    pub fn last_activity(&self) -> DateTime<Utc> {
+        self.completed_at.unwrap_or(self.started_at)
+    }
+
     #[allow(dead_code)]
     pub fn duration(&self) -> Option<chrono::Duration> {
         self.completed_at.map(|end| end - self.started_at)
@@ -0,0 +1,262 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{Message, MessagePriority, MessageType};
+
+/// Template reproducing the original human-readable delivery block
+pub const DEFAULT_MESSAGE_TEMPLATE: &str = "📨 INCOMING MESSAGE [{priority}] 📨\n\
+From: {from} (Expert {from_id})\n\
+To: {to}\n\
+Type: {type}\n\
+Priority: {priority}\n\
+Subject: {subject}\n\
+\n\
+{body}{attachments}\n\
+\n\
+Message ID: {message_id}\n\
+Timestamp: {timestamp}\n\
+{reply_to}";
+
+/// How messages are rendered when typed into a recipient's pane
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageFormat {
+    /// Human-readable block rendered from a template
+    #[default]
+    Pretty,
+    /// Fenced JSON envelope for agents that parse messages programmatically
+    Json,
+}
+
+/// Renders a message for delivery according to the configured format
+#[derive(Debug, Clone)]
+pub struct MessageFormatter {
+    format: MessageFormat,
+    template: String,
+}
+
+impl Default for MessageFormatter {
+    fn default() -> Self {
+        Self::new(MessageFormat::Pretty, None)
+    }
+}
+
+fn message_type_label(message_type: MessageType) -> &'static str {
+    match message_type {
+        MessageType::Query => "QUERY",
+        MessageType::Response => "RESPONSE",
+        MessageType::Notify => "NOTIFICATION",
+        MessageType::Delegate => "TASK_DELEGATION",
+    }
+}
+
+fn priority_label(priority: MessagePriority) -> &'static str {
+    match priority {
+        MessagePriority::High => "HIGH",
+        MessagePriority::Normal => "NORMAL",
+        MessagePriority::Low => "LOW",
+    }
+}
+
+/// "Attachments:" list preceded by a blank line, or empty when there are none
+fn attachments_section(message: &Message) -> String {
+    if message.content.attachments.is_empty() {
+        return String::new();
+    }
+    let mut section = String::from("\n\nAttachments:");
+    for attachment in &message.content.attachments {
+        section.push_str("\n- ");
+        section.push_str(attachment);
+    }
+    section
+}
+
+impl MessageFormatter {
+    /// `template` overrides [`DEFAULT_MESSAGE_TEMPLATE`] in pretty mode
+    pub fn new(format: MessageFormat, template: Option<String>) -> Self {
+        Self {
+            format,
+            template: template.unwrap_or_else(|| DEFAULT_MESSAGE_TEMPLATE.to_string()),
+        }
+    }
+
+    pub fn format(&self, message: &Message, sender_name: &str, recipient_name: &str) -> String {
+        match self.format {
+            MessageFormat::Pretty => self.render_template(message, sender_name, recipient_name),
+            MessageFormat::Json => Self::render_json(message, sender_name, recipient_name),
+        }
+    }
+
+    /// Substitute `{from}`, `{from_id}`, `{to}`, `{type}`, `{priority}`, `{subject}`,
+    /// `{body}`, `{attachments}`, `{message_id}`, `{timestamp}` and `{reply_to}` in the template
+    fn render_template(
+        &self,
+        message: &Message,
+        sender_name: &str,
+        recipient_name: &str,
+    ) -> String {
+        let reply_to = message
+            .reply_to
+            .as_ref()
+            .map(|id| format!("Reply to: {id}"))
+            .unwrap_or_default();
+        let attachments = attachments_section(message);
+        let lookup = |key: &str| -> Option<String> {
+            Some(match key {
+                "from" => sender_name.to_string(),
+                "from_id" => message.from_expert_id.to_string(),
+                "to" => recipient_name.to_string(),
+                "type" => message_type_label(message.message_type).to_string(),
+                "priority" => priority_label(message.priority).to_string(),
+                "subject" => message.content.subject.clone(),
+                "body" => message.content.body.clone(),
+                "attachments" => attachments.clone(),
+                "message_id" => message.message_id.clone(),
+                "timestamp" => message
+                    .created_at
+                    .format("%Y-%m-%d %H:%M:%S UTC")
+                    .to_string(),
+                "reply_to" => reply_to.clone(),
+                _ => return None,
+            })
+        };
+
+        // Single pass, so placeholder-like text inside field values is left alone
+        let mut rendered = String::with_capacity(self.template.len());
+        let mut rest = self.template.as_str();
+        while let Some(open) = rest.find('{') {
+            rendered.push_str(&rest[..open]);
+            let after = &rest[open + 1..];
+            match after
+                .find('}')
+                .and_then(|close| lookup(&after[..close]).map(|value| (value, close)))
+            {
+                Some((value, close)) => {
+                    rendered.push_str(&value);
+                    rest = &after[close + 1..];
+                }
+                None => {
+                    rendered.push('{');
+                    rest = after;
+                }
+            }
+        }
+        rendered.push_str(rest);
+        rendered
+    }
+
+    fn render_json(message: &Message, sender_name: &str, recipient_name: &str) -> String {
+        let envelope = serde_json::json!({
+            "message_id": message.message_id,
+            "from": sender_name,
+            "from_id": message.from_expert_id,
+            "to": recipient_name,
+            "type": message_type_label(message.message_type),
+            "priority": priority_label(message.priority),
+            "subject": message.content.subject,
+            "body": message.content.body,
+            "attachments": message.content.attachments,
+            "timestamp": message.created_at.to_rfc3339(),
+            "reply_to": message.reply_to,
+        });
+        format!("```json\n{envelope}\n```")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{MessageContent, MessageRecipient};
+
+    fn create_test_message() -> Message {
+        Message::new(
+            1,
+            MessageRecipient::expert_id(2),
+            MessageType::Query,
+            MessageContent {
+                subject: "Schema".to_string(),
+                body: "Line one\n\"quoted\" {subject}".to_string(),
+                attachments: Vec::new(),
+            },
+        )
+        .with_priority(MessagePriority::High)
+    }
+
+    fn parse_json_envelope(formatted: &str) -> serde_json::Value {
+        let inner = formatted
+            .strip_prefix("```json\n")
+            .and_then(|s| s.strip_suffix("\n```"))
+            .expect("json envelope should be fenced");
+        serde_json::from_str(inner).expect("json envelope should parse")
+    }
+
+    #[test]
+    fn default_formatter_matches_pretty_block() {
+        let message = create_test_message();
+        let formatted = MessageFormatter::default().format(&message, "backend", "frontend");
+
+        assert!(formatted.starts_with("📨 INCOMING MESSAGE [HIGH] 📨\nFrom: backend (Expert 1)\n"));
+        assert!(formatted.contains("To: frontend\nType: QUERY\nPriority: HIGH\nSubject: Schema\n"));
+        assert!(formatted.contains(&format!("Message ID: {}", message.message_id)));
+        assert!(
+            formatted.contains("\"quoted\" {subject}"),
+            "format: placeholders inside field values should not be expanded"
+        );
+    }
+
+    #[test]
+    fn pretty_format_lists_attachments_after_body() {
+        let mut message = create_test_message();
+        message.content.attachments = vec!["src/main.rs:10".to_string(), "README.md".to_string()];
+        let formatted = MessageFormatter::default().format(&message, "backend", "frontend");
+
+        assert!(
+            formatted.contains(
+                "{subject}\n\nAttachments:\n- src/main.rs:10\n- README.md\n\nMessage ID:"
+            ),
+            "format: attachments should be listed in their own section, got: {formatted}"
+        );
+    }
+
+    #[test]
+    fn pretty_format_omits_attachments_section_when_empty() {
+        let formatted =
+            MessageFormatter::default().format(&create_test_message(), "backend", "frontend");
+        assert!(
+            !formatted.contains("Attachments:"),
+            "format: no attachments section should be rendered without attachments"
+        );
+        assert!(formatted.contains("{subject}\n\nMessage ID:"));
+    }
+
+    #[test]
+    fn json_format_produces_parseable_envelope() {
+        let message = create_test_message().with_reply_to("msg-1".to_string());
+        let formatted = MessageFormatter::new(MessageFormat::Json, None)
+            .format(&message, "backend", "frontend");
+
+        let value = parse_json_envelope(&formatted);
+        assert_eq!(value["subject"], "Schema");
+        assert_eq!(value["body"], "Line one\n\"quoted\" {subject}");
+        assert_eq!(value["from"], "backend");
+        assert_eq!(value["from_id"], 1);
+        assert_eq!(value["to"], "frontend");
+        assert_eq!(value["type"], "QUERY");
+        assert_eq!(value["priority"], "HIGH");
+        assert_eq!(value["reply_to"], "msg-1");
+        assert_eq!(value["attachments"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn custom_template_substitutes_placeholders() {
+        let message = create_test_message();
+        let formatter = MessageFormatter::new(
+            MessageFormat::Pretty,
+            Some("[{type}/{priority}] {from} -> {to}: {subject}".to_string()),
+        );
+
+        assert_eq!(
+            formatter.format(&message, "backend", "frontend"),
+            "[QUERY/HIGH] backend -> frontend: Schema"
+        );
+    }
+}
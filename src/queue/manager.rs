@@ -1,9 +1,15 @@
 use anyhow::{Context, Result};
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, SystemTime};
 use thiserror::Error;
 use tokio::fs;
 
-use crate::models::{Message, MessageId, QueuedMessage, Report};
+use crate::models::{
+    DeliveredMessage, ExpertId, Message, MessageId, MessageRecipient, QueuedMessage, Report,
+    DEFAULT_RECIPIENT_METADATA_KEY, TRUNCATED_METADATA_KEY,
+};
 
 /// Comprehensive error types for message queue operations
 ///
@@ -90,17 +96,74 @@ impl QueueError {
 #[allow(dead_code)]
 pub type QueueResult<T> = std::result::Result<T, QueueError>;
 
+/// Files younger than this are never treated as orphans, so compaction cannot
+/// race a writer that is between its temp-file write and rename.
+const ORPHAN_GRACE: Duration = Duration::from_secs(60);
+
+/// Outbox files modified more recently than this that fail to parse are
+/// assumed to be mid-write and retried on the next cycle without an error.
+const OUTBOX_SETTLE: Duration = Duration::from_secs(2);
+
+/// What [`QueueManager::compact`] cleaned up
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompactionReport {
+    /// Leftover `.tmp` files from interrupted atomic writes
+    pub temp_files: usize,
+    /// Queue files that no longer parse, or duplicate another file's message
+    pub unreadable: usize,
+    /// Messages moved back under their canonical `<message_id>.yaml` name
+    pub renamed: usize,
+}
+
+impl CompactionReport {
+    /// Files deleted from the queue and outbox directories
+    pub fn reclaimed(&self) -> usize {
+        self.temp_files + self.unreadable
+    }
+}
+
 pub struct QueueManager {
     base_path: PathBuf,
+    max_message_body_bytes: Option<usize>,
+    duplicate_window_secs: u64,
+    collapsed_count: AtomicUsize,
+    default_recipients: HashMap<ExpertId, ExpertId>,
 }
 
 impl QueueManager {
     pub fn new(queue_path: PathBuf) -> Self {
         Self {
             base_path: queue_path,
+            max_message_body_bytes: None,
+            duplicate_window_secs: 0,
+            collapsed_count: AtomicUsize::new(0),
+            default_recipients: HashMap::new(),
         }
     }
 
+    /// Truncate outbox message bodies larger than `max_bytes`
+    pub fn with_max_message_body_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_message_body_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Drop outbox messages duplicating one queued within `window_secs` (0 disables)
+    pub fn with_duplicate_window_secs(mut self, window_secs: u64) -> Self {
+        self.duplicate_window_secs = window_secs;
+        self
+    }
+
+    /// Route outbox messages that omit `to` from a sender to its default recipient
+    pub fn with_default_recipients(mut self, recipients: HashMap<ExpertId, ExpertId>) -> Self {
+        self.default_recipients = recipients;
+        self
+    }
+
+    /// Number of duplicate outbox messages dropped since the last call
+    pub fn take_collapsed_count(&self) -> usize {
+        self.collapsed_count.swap(0, Ordering::Relaxed)
+    }
+
     fn reports_path(&self) -> PathBuf {
         self.base_path.join("reports")
     }
@@ -117,6 +180,33 @@ impl QueueManager {
         self.messages_path().join("outbox")
     }
 
+    /// Directory delivered messages are copied to for an expert to read back
+    pub fn inbox_path(&self, expert_id: ExpertId) -> PathBuf {
+        self.messages_path()
+            .join("inbox")
+            .join(format!("expert{expert_id}"))
+    }
+
+    /// Where dismissed messages are kept instead of being deleted
+    pub fn dead_letter_path(&self) -> PathBuf {
+        self.messages_path().join("dead")
+    }
+
+    /// Messages set aside when their recipient was reset, until released
+    pub fn held_path(&self) -> PathBuf {
+        self.messages_path().join("held")
+    }
+
+    fn delivered_log_file(&self) -> PathBuf {
+        self.messages_path().join("delivered.jsonl")
+    }
+
+    fn last_delivered_file(&self, expert_id: ExpertId) -> PathBuf {
+        self.messages_path()
+            .join("last_delivered")
+            .join(format!("expert{expert_id}.yaml"))
+    }
+
     fn status_path(&self) -> PathBuf {
         self.base_path.join("status")
     }
@@ -218,7 +308,7 @@ impl QueueManager {
             }
         }
 
-        reports.sort_by(|a, b| a.started_at.cmp(&b.started_at));
+        reports.sort_by_key(|a| a.started_at);
         Ok(reports)
     }
 
@@ -242,6 +332,25 @@ impl QueueManager {
         Ok(())
     }
 
+    /// Drop a message into the outbox, as an agent would, so it goes through
+    /// the router's outbox checks before it is queued
+    pub async fn post_to_outbox(&self, message: &Message) -> Result<()> {
+        fs::create_dir_all(self.outbox_path()).await?;
+        let path = self
+            .outbox_path()
+            .join(format!("{}.yaml", message.message_id));
+        let yaml = serde_yaml::to_string(message).context("Failed to serialize message to YAML")?;
+
+        let temp_path = path.with_extension("yaml.tmp");
+        fs::write(&temp_path, yaml)
+            .await
+            .context("Failed to write message to temp file")?;
+        fs::rename(&temp_path, &path)
+            .await
+            .context("Failed to atomically move message file")?;
+        Ok(())
+    }
+
     /// Read all queued messages (sorted by created_at, then by priority)
     pub async fn read_queue(&self) -> Result<Vec<QueuedMessage>> {
         let mut messages = Vec::new();
@@ -302,6 +411,21 @@ impl QueueManager {
         Ok(messages)
     }
 
+    /// Read a single queued message by ID
+    pub async fn read_message(&self, message_id: &str) -> Result<Option<QueuedMessage>> {
+        let path = self.message_file(message_id);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(&path)
+            .await
+            .context("Failed to read message file")?;
+        let queued_message: QueuedMessage =
+            serde_yaml::from_str(&content).context("Failed to parse message file")?;
+        Ok(Some(queued_message))
+    }
+
     /// Remove message from queue
     pub async fn dequeue(&self, message_id: &str) -> Result<()> {
         let path = self.message_file(message_id);
@@ -314,6 +438,229 @@ impl QueueManager {
         Ok(())
     }
 
+    /// Append a delivery record to the delivered-message log
+    pub async fn record_delivery(&self, delivered: &DeliveredMessage) -> Result<()> {
+        use tokio::io::AsyncWriteExt;
+
+        let mut line =
+            serde_json::to_string(delivered).context("Failed to serialize delivery record")?;
+        line.push('\n');
+
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.delivered_log_file())
+            .await
+            .context("Failed to open delivered-message log")?;
+        file.write_all(line.as_bytes())
+            .await
+            .context("Failed to append to delivered-message log")?;
+        file.flush()
+            .await
+            .context("Failed to flush delivered-message log")?;
+        Ok(())
+    }
+
+    /// Keep a full copy of the latest message delivered to `expert_id` for replay
+    pub async fn record_last_delivered(
+        &self,
+        expert_id: ExpertId,
+        message: &Message,
+    ) -> Result<()> {
+        let path = self.last_delivered_file(expert_id);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .context("Failed to create last-delivered directory")?;
+        }
+        let yaml = serde_yaml::to_string(message).context("Failed to serialize message to YAML")?;
+        let temp_path = path.with_extension("yaml.tmp");
+        fs::write(&temp_path, yaml)
+            .await
+            .context("Failed to write last-delivered message")?;
+        fs::rename(&temp_path, &path)
+            .await
+            .context("Failed to atomically move last-delivered message")?;
+        Ok(())
+    }
+
+    /// The latest message delivered to `expert_id`, if any has been recorded
+    pub async fn last_delivered(&self, expert_id: ExpertId) -> Result<Option<Message>> {
+        let path = self.last_delivered_file(expert_id);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(&path)
+            .await
+            .context("Failed to read last-delivered message")?;
+        let message =
+            serde_yaml::from_str(&content).context("Failed to parse last-delivered message")?;
+        Ok(Some(message))
+    }
+
+    /// Enqueue a fresh copy of the latest message delivered to `expert_id`;
+    /// it waits for the expert to be idle like any other queued message
+    pub async fn replay_last_delivered(&self, expert_id: ExpertId) -> Result<Option<Message>> {
+        let Some(last) = self.last_delivered(expert_id).await? else {
+            return Ok(None);
+        };
+        let replay = last.replay_to(expert_id);
+        self.enqueue(&replay).await?;
+        Ok(Some(replay))
+    }
+
+    /// Read the delivered-message log, oldest first; unparsable lines are skipped
+    pub async fn list_delivered(&self) -> Result<Vec<DeliveredMessage>> {
+        let path = self.delivered_log_file();
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(&path)
+            .await
+            .context("Failed to read delivered-message log")?;
+        let mut delivered = Vec::new();
+        for line in content.lines().filter(|l| !l.trim().is_empty()) {
+            match serde_json::from_str::<DeliveredMessage>(line) {
+                Ok(record) => delivered.push(record),
+                Err(e) => tracing::warn!("Skipping malformed delivery record: {}", e),
+            }
+        }
+        Ok(delivered)
+    }
+
+    /// Give every message whose delivery failed a fresh set of attempts,
+    /// returning the IDs that were reset to pending
+    pub async fn retry_failed(&self) -> Result<Vec<MessageId>> {
+        let mut retried = Vec::new();
+        for mut queued_msg in self.read_queue().await? {
+            if !queued_msg.has_failed_delivery() {
+                continue;
+            }
+            queued_msg.reset_to_pending();
+            queued_msg.attempts = 0;
+            queued_msg.message.delivery_attempts = 0;
+            let message_id = queued_msg.message.message_id.clone();
+            self.update_message_status(&message_id, &queued_msg).await?;
+            retried.push(message_id);
+        }
+        Ok(retried)
+    }
+
+    /// Move every message whose delivery failed out of the queue into the
+    /// dead-letter directory, returning their IDs
+    pub async fn dismiss_failed(&self) -> Result<Vec<MessageId>> {
+        let failed: Vec<MessageId> = self
+            .read_queue()
+            .await?
+            .into_iter()
+            .filter(QueuedMessage::has_failed_delivery)
+            .map(|queued_msg| queued_msg.message.message_id)
+            .collect();
+        if failed.is_empty() {
+            return Ok(failed);
+        }
+
+        self.move_out_of_queue(&failed, &self.dead_letter_path())
+            .await?;
+        tracing::info!("Dismissed {} failed messages", failed.len());
+        Ok(failed)
+    }
+
+    /// Take the pending messages addressed to `expert_id` out of the queue
+    /// into `dir` (the held or dead-letter directory), returning their IDs.
+    ///
+    /// A multi-recipient message that lists the expert is split: the expert's
+    /// copy is set aside and the rest stays queued for the other recipients.
+    pub async fn set_aside_for_expert(
+        &self,
+        expert_id: ExpertId,
+        dir: &Path,
+    ) -> Result<Vec<MessageId>> {
+        let target = MessageRecipient::expert_id(expert_id);
+        let mut pending = Vec::new();
+        for mut queued_msg in self.get_pending_messages().await? {
+            if queued_msg.message.to == target {
+                pending.push(queued_msg.message.message_id);
+                continue;
+            }
+            if !matches!(queued_msg.message.to, MessageRecipient::Multi(_)) {
+                continue;
+            }
+            let leaves = queued_msg.message.to.leaves();
+            if !leaves.contains(&&target) {
+                continue;
+            }
+            let mut rest: Vec<MessageRecipient> = leaves
+                .into_iter()
+                .filter(|leaf| **leaf != target)
+                .cloned()
+                .collect();
+
+            // Numbered past the remaining recipients so the router's own
+            // split of the rest cannot reuse the ID
+            let mut copy = queued_msg.message.clone();
+            copy.message_id = format!("{}-{}", copy.message_id, rest.len() + 1);
+            copy.to = target.clone();
+            self.enqueue(&copy).await?;
+            pending.push(copy.message_id);
+
+            queued_msg.message.to = if rest.len() == 1 {
+                rest.remove(0)
+            } else {
+                MessageRecipient::multi(rest)
+            };
+            let message_id = queued_msg.message.message_id.clone();
+            self.update_message_status(&message_id, &queued_msg).await?;
+        }
+        self.move_out_of_queue(&pending, dir).await?;
+        Ok(pending)
+    }
+
+    /// Put every held message back in the queue, returning their IDs
+    pub async fn release_held(&self) -> Result<Vec<MessageId>> {
+        let held = self.held_path();
+        if !held.exists() {
+            return Ok(Vec::new());
+        }
+        let mut released = Vec::new();
+        let mut entries = fs::read_dir(&held).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if !path.extension().is_some_and(|e| e == "yaml") {
+                continue;
+            }
+            let Some(message_id) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            let message_id = message_id.to_string();
+            fs::rename(&path, self.message_file(&message_id))
+                .await
+                .with_context(|| format!("Failed to release held message {message_id}"))?;
+            released.push(message_id);
+        }
+        released.sort();
+        Ok(released)
+    }
+
+    async fn move_out_of_queue(&self, message_ids: &[MessageId], dir: &Path) -> Result<()> {
+        if message_ids.is_empty() {
+            return Ok(());
+        }
+        fs::create_dir_all(dir)
+            .await
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+        for message_id in message_ids {
+            fs::rename(
+                self.message_file(message_id),
+                dir.join(format!("{message_id}.yaml")),
+            )
+            .await
+            .with_context(|| format!("Failed to move message {message_id} to {}", dir.display()))?;
+        }
+        Ok(())
+    }
+
     /// Count messages in queue
     #[allow(dead_code)]
     pub async fn queue_len(&self) -> Result<usize> {
@@ -461,23 +808,47 @@ impl QueueManager {
         let mut entries = fs::read_dir(&outbox).await?;
         while let Some(entry) = entries.next_entry().await? {
             let path = entry.path();
-            if path.extension().is_some_and(|e| e == "yaml") {
-                match self.process_outbox_file(&path).await {
-                    Ok(message_id) => {
-                        processed_messages.push(message_id);
-                        // Remove the processed file from outbox
-                        if let Err(e) = fs::remove_file(&path).await {
-                            tracing::warn!(
-                                "Failed to remove processed outbox file {}: {}",
-                                path.display(),
-                                e
-                            );
-                        }
-                    }
-                    Err(e) => {
-                        tracing::error!("Failed to process outbox file {}: {}", path.display(), e);
+            // Agents write `<id>.yaml.tmp` and rename, so only finished files match
+            if !path.extension().is_some_and(|e| e == "yaml") {
+                continue;
+            }
+            let (content, modified) = match Self::read_settled(&path).await {
+                Ok(Some(read)) => read,
+                Ok(None) => {
+                    tracing::debug!(
+                        "Skipping outbox file {}: still being written",
+                        path.display()
+                    );
+                    continue;
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to read outbox file {}: {}", path.display(), e);
+                    continue;
+                }
+            };
+
+            match self.process_outbox_file(&content).await {
+                Ok(message_id) => {
+                    processed_messages.extend(message_id);
+                    // Remove the processed file from outbox
+                    if let Err(e) = fs::remove_file(&path).await {
+                        tracing::warn!(
+                            "Failed to remove processed outbox file {}: {}",
+                            path.display(),
+                            e
+                        );
                     }
                 }
+                Err(e) if Self::modified_within(modified, OUTBOX_SETTLE) => {
+                    tracing::debug!(
+                        "Skipping outbox file {} until it settles: {}",
+                        path.display(),
+                        e
+                    );
+                }
+                Err(e) => {
+                    tracing::error!("Failed to process outbox file {}: {}", path.display(), e);
+                }
             }
         }
 
@@ -491,23 +862,127 @@ impl QueueManager {
         Ok(processed_messages)
     }
 
-    /// Process a single outbox file
-    async fn process_outbox_file(&self, file_path: &std::path::Path) -> Result<MessageId> {
-        let content = fs::read_to_string(file_path)
-            .await
-            .context("Failed to read outbox file")?;
+    /// Read a file only if it did not change while being read.
+    ///
+    /// Returns `None` when a writer is still appending to it, along with the
+    /// modification time otherwise.
+    async fn read_settled(path: &Path) -> Result<Option<(String, Option<SystemTime>)>> {
+        let before = fs::metadata(path).await?;
+        let content = fs::read_to_string(path).await?;
+        let after = fs::metadata(path).await?;
+
+        let modified = after.modified().ok();
+        if before.len() != after.len()
+            || content.len() as u64 != after.len()
+            || before.modified().ok() != modified
+        {
+            return Ok(None);
+        }
+        Ok(Some((content, modified)))
+    }
+
+    fn modified_within(modified: Option<SystemTime>, window: Duration) -> bool {
+        modified
+            .and_then(|m| SystemTime::now().duration_since(m).ok())
+            .is_some_and(|age| age < window)
+    }
 
-        let message: Message =
-            serde_yaml::from_str(&content).context("Failed to parse message YAML from outbox")?;
+    /// Process the content of a single outbox file
+    ///
+    /// Returns `None` when the message was dropped as a duplicate.
+    async fn process_outbox_file(&self, content: &str) -> Result<Option<MessageId>> {
+        let mut value: serde_yaml::Value =
+            serde_yaml::from_str(content).context("Failed to parse message YAML from outbox")?;
+        let used_default = self.apply_default_recipient(&mut value)?;
+        let mut message: Message =
+            serde_yaml::from_value(value).context("Failed to parse message YAML from outbox")?;
+        if let Some(recipient) = used_default {
+            message.metadata.insert(
+                DEFAULT_RECIPIENT_METADATA_KEY.to_string(),
+                recipient.to_string(),
+            );
+        }
 
         // Validate required fields are present
         self.validate_message(&message)?;
 
+        if let Some(max_bytes) = self.max_message_body_bytes {
+            if let Some(original_len) = message.content.truncate_body(max_bytes) {
+                tracing::warn!(
+                    "Message {} body is {} bytes (limit {}), truncated",
+                    message.message_id,
+                    original_len,
+                    max_bytes
+                );
+                message
+                    .metadata
+                    .insert(TRUNCATED_METADATA_KEY.to_string(), original_len.to_string());
+            }
+        }
+
+        if let Some(existing_id) = self.find_duplicate(&message).await? {
+            tracing::warn!(
+                "Dropping message {}: duplicate of queued message {}",
+                message.message_id,
+                existing_id
+            );
+            self.collapsed_count.fetch_add(1, Ordering::Relaxed);
+            return Ok(None);
+        }
+
         // Enqueue the message
         self.enqueue(&message).await?;
 
         tracing::debug!("Processed outbox message: {}", message.message_id);
-        Ok(message.message_id)
+        Ok(Some(message.message_id))
+    }
+
+    /// Fill in a missing `to` with the sender's configured default recipient.
+    /// Returns the recipient ID when the default was applied.
+    fn apply_default_recipient(&self, value: &mut serde_yaml::Value) -> Result<Option<ExpertId>> {
+        let Some(mapping) = value.as_mapping_mut() else {
+            return Ok(None);
+        };
+        if mapping.get("to").is_some_and(|to| !to.is_null()) {
+            return Ok(None);
+        }
+
+        let sender = mapping
+            .get("from_expert_id")
+            .and_then(serde_yaml::Value::as_u64)
+            .ok_or_else(|| anyhow::anyhow!("Message has no recipient and no valid sender"))?
+            as ExpertId;
+        let Some(&recipient) = self.default_recipients.get(&sender) else {
+            return Err(anyhow::anyhow!(
+                "Message has no `to` field and expert {sender} has no default_recipient"
+            ));
+        };
+
+        let mut to = serde_yaml::Mapping::new();
+        to.insert("expert_id".into(), recipient.into());
+        mapping.insert("to".into(), to.into());
+        Ok(Some(recipient))
+    }
+
+    /// Find a pending message with the same sender, recipient and content
+    /// created within the duplicate window
+    async fn find_duplicate(&self, message: &Message) -> Result<Option<MessageId>> {
+        if self.duplicate_window_secs == 0 {
+            return Ok(None);
+        }
+        let window = chrono::Duration::seconds(self.duplicate_window_secs as i64);
+
+        let duplicate = self.read_queue().await?.into_iter().find(|queued| {
+            let existing = &queued.message;
+            queued.is_pending()
+                && existing.from_expert_id == message.from_expert_id
+                && existing.to == message.to
+                && existing.content.subject == message.content.subject
+                && existing.content.body == message.content.body
+                && (message.created_at - existing.created_at).abs() <= window
+        });
+
+        Ok(duplicate.map(|queued| queued.message.message_id))
     }
 
     /// Validate that a message has all required fields
@@ -515,17 +990,106 @@ impl QueueManager {
         if message.message_id.is_empty() {
             return Err(anyhow::anyhow!("Message ID is required"));
         }
+        if message.to.leaves().is_empty() {
+            return Err(anyhow::anyhow!("Message `to` list is empty"));
+        }
+
+        message.content.validate()
+    }
+
+    /// Remove files the queue can never process again and repair misnamed
+    /// message files, leaving every live pending message in place.
+    ///
+    /// Pending order is derived from priority and age on each read, so there is
+    /// no index to rewrite. Only files older than [`ORPHAN_GRACE`] are deleted
+    /// and renames are atomic, so this is safe alongside `process_queue` and
+    /// agents writing to the outbox.
+    pub async fn compact(&self) -> Result<CompactionReport> {
+        let mut report = CompactionReport::default();
+        let now = SystemTime::now();
+
+        for dir in [self.queue_path(), self.outbox_path()] {
+            if !dir.exists() {
+                continue;
+            }
+            let mut entries = fs::read_dir(&dir).await?;
+            while let Some(entry) = entries.next_entry().await? {
+                let path = entry.path();
+                if path.extension().is_some_and(|e| e == "tmp") && Self::is_stale(&path, now).await
+                {
+                    tracing::info!("Removing leftover temp file {}", path.display());
+                    fs::remove_file(&path).await?;
+                    report.temp_files += 1;
+                }
+            }
+        }
 
-        if message.content.subject.is_empty() {
-            return Err(anyhow::anyhow!("Message subject is required"));
+        let queue = self.queue_path();
+        if !queue.exists() {
+            return Ok(report);
         }
+        let mut entries = fs::read_dir(&queue).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if !path.extension().is_some_and(|e| e == "yaml") {
+                continue;
+            }
+            let Ok(content) = fs::read_to_string(&path).await else {
+                continue;
+            };
 
-        if message.content.body.is_empty() {
-            return Err(anyhow::anyhow!("Message body is required"));
+            match serde_yaml::from_str::<QueuedMessage>(&content) {
+                Ok(queued) => {
+                    let canonical = self.message_file(&queued.message.message_id);
+                    if canonical == path {
+                        continue;
+                    }
+                    if canonical.exists() {
+                        tracing::warn!(
+                            "Removing {}: duplicate of {}",
+                            path.display(),
+                            canonical.display()
+                        );
+                        fs::remove_file(&path).await?;
+                        report.unreadable += 1;
+                    } else {
+                        fs::rename(&path, &canonical)
+                            .await
+                            .context("Failed to move message file to its canonical name")?;
+                        report.renamed += 1;
+                    }
+                }
+                // Raw messages written to the wrong directory are left for the operator
+                Err(_) if Self::detect_misplaced_message(&content).is_some() => {}
+                Err(e) => {
+                    if Self::is_stale(&path, now).await {
+                        tracing::warn!("Removing unreadable queue file {}: {}", path.display(), e);
+                        fs::remove_file(&path).await?;
+                        report.unreadable += 1;
+                    }
+                }
+            }
         }
 
-        // Additional validation can be added here
-        Ok(())
+        if report != CompactionReport::default() {
+            tracing::info!(
+                "Compacted message queue: {} temp files, {} unreadable files removed, {} renamed",
+                report.temp_files,
+                report.unreadable,
+                report.renamed
+            );
+        }
+        Ok(report)
+    }
+
+    async fn is_stale(path: &Path, now: SystemTime) -> bool {
+        fs::metadata(path)
+            .await
+            .and_then(|meta| meta.modified())
+            .is_ok_and(|modified| {
+                now.duration_since(modified)
+                    .is_ok_and(|age| age >= ORPHAN_GRACE)
+            })
     }
 
     #[allow(dead_code)]
@@ -544,7 +1108,7 @@ impl QueueManager {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::TaskStatus;
+    use crate::models::{TaskStatus, REPLAY_OF_METADATA_KEY};
     use tempfile::TempDir;
 
     async fn create_test_manager() -> (QueueManager, TempDir) {
@@ -611,6 +1175,7 @@ mod tests {
         let content = MessageContent {
             subject: "Test Subject".to_string(),
             body: "Test Body".to_string(),
+            attachments: Vec::new(),
         };
         let recipient = MessageRecipient::expert_id(1);
         Message::new(0, recipient, MessageType::Query, content)
@@ -624,6 +1189,72 @@ mod tests {
         assert!(manager.outbox_path().exists());
     }
 
+    fn backdate(path: &Path) {
+        std::fs::File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(SystemTime::now() - ORPHAN_GRACE * 2)
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn compact_removes_stale_orphans_and_keeps_pending_messages() {
+        let (manager, _temp) = create_test_manager().await;
+        let message = create_test_message();
+        manager.enqueue(&message).await.unwrap();
+        backdate(&manager.message_file(&message.message_id));
+
+        let queue = manager.queue_path();
+        let stale_tmp = queue.join("old-msg.yaml.tmp");
+        let fresh_tmp = queue.join("in-flight.yaml.tmp");
+        let garbage = queue.join("garbage.yaml");
+        std::fs::write(&stale_tmp, "partial").unwrap();
+        std::fs::write(&fresh_tmp, "partial").unwrap();
+        std::fs::write(&garbage, "{ not: [valid").unwrap();
+        backdate(&stale_tmp);
+        backdate(&garbage);
+
+        let report = manager.compact().await.unwrap();
+
+        assert_eq!(report.temp_files, 1);
+        assert_eq!(report.unreadable, 1);
+        assert_eq!(report.reclaimed(), 2);
+        assert!(!stale_tmp.exists() && !garbage.exists());
+        assert!(
+            fresh_tmp.exists(),
+            "compact: temp files within the grace period may still be renamed by a writer"
+        );
+        let pending = manager.get_pending_messages().await.unwrap();
+        assert_eq!(
+            pending.len(),
+            1,
+            "compact: live pending messages should be untouched"
+        );
+        assert_eq!(pending[0].message.message_id, message.message_id);
+    }
+
+    #[tokio::test]
+    async fn compact_moves_misnamed_message_to_canonical_file() {
+        let (manager, _temp) = create_test_manager().await;
+        let message = create_test_message();
+        manager.enqueue(&message).await.unwrap();
+        let canonical = manager.message_file(&message.message_id);
+        let misnamed = manager.queue_path().join("copy-of-message.yaml");
+        std::fs::rename(&canonical, &misnamed).unwrap();
+
+        let report = manager.compact().await.unwrap();
+
+        assert_eq!(report.renamed, 1);
+        assert!(canonical.exists() && !misnamed.exists());
+        manager.dequeue(&message.message_id).await.unwrap();
+        assert_eq!(
+            manager.queue_len().await.unwrap(),
+            0,
+            "compact: renamed message should be dequeueable by id"
+        );
+    }
+
     #[tokio::test]
     async fn queue_manager_enqueue_and_read_message() {
         let (manager, _temp) = create_test_manager().await;
@@ -637,6 +1268,25 @@ mod tests {
         assert_eq!(messages[0].message.content.subject, "Test Subject");
     }
 
+    #[tokio::test]
+    async fn queue_manager_read_message_by_id() {
+        let (manager, _temp) = create_test_manager().await;
+
+        let message = create_test_message();
+        manager.enqueue(&message).await.unwrap();
+
+        let found = manager.read_message(&message.message_id).await.unwrap();
+        assert_eq!(
+            found.map(|q| q.message.message_id),
+            Some(message.message_id),
+            "read_message: should return the enqueued message"
+        );
+        assert!(
+            manager.read_message("missing").await.unwrap().is_none(),
+            "read_message: unknown ID should return None"
+        );
+    }
+
     #[tokio::test]
     async fn queue_manager_dequeue_removes_message() {
         let (manager, _temp) = create_test_manager().await;
@@ -649,6 +1299,155 @@ mod tests {
         assert_eq!(manager.queue_len().await.unwrap(), 0);
     }
 
+    async fn enqueue_failed(manager: &QueueManager) -> Message {
+        let message = create_test_message();
+        manager.enqueue(&message).await.unwrap();
+        let mut queued = manager
+            .read_message(&message.message_id)
+            .await
+            .unwrap()
+            .unwrap();
+        queued.mark_delivery_attempt();
+        queued.message.increment_delivery_attempts();
+        queued.mark_failed("window not found".to_string());
+        manager
+            .update_message_status(&message.message_id, &queued)
+            .await
+            .unwrap();
+        message
+    }
+
+    #[tokio::test]
+    async fn retry_failed_resets_failed_messages_to_pending() {
+        let (manager, _temp) = create_test_manager().await;
+        let failed = enqueue_failed(&manager).await;
+        tokio::time::sleep(tokio::time::Duration::from_millis(2)).await;
+        let fresh = create_test_message();
+        manager.enqueue(&fresh).await.unwrap();
+        assert_eq!(manager.get_pending_messages().await.unwrap().len(), 1);
+
+        let retried = manager.retry_failed().await.unwrap();
+        assert_eq!(
+            retried,
+            vec![failed.message_id.clone()],
+            "retry_failed: only the failed message should be reset"
+        );
+        let queued = manager
+            .read_message(&failed.message_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(queued.is_pending());
+        assert_eq!(queued.attempts, 0);
+        assert_eq!(queued.message.delivery_attempts, 0);
+        assert_eq!(manager.get_pending_messages().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn dismiss_failed_moves_failed_messages_to_dead_letters() {
+        let (manager, _temp) = create_test_manager().await;
+        let failed = enqueue_failed(&manager).await;
+        tokio::time::sleep(tokio::time::Duration::from_millis(2)).await;
+        let fresh = create_test_message();
+        manager.enqueue(&fresh).await.unwrap();
+
+        let dismissed = manager.dismiss_failed().await.unwrap();
+        assert_eq!(dismissed, vec![failed.message_id.clone()]);
+        let remaining: Vec<_> = manager
+            .read_queue()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|q| q.message.message_id)
+            .collect();
+        assert_eq!(
+            remaining,
+            vec![fresh.message_id],
+            "dismiss_failed: failed messages should leave the active queue"
+        );
+        assert!(manager
+            .dead_letter_path()
+            .join(format!("{}.yaml", failed.message_id))
+            .exists());
+        assert!(manager.dismiss_failed().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn set_aside_for_expert_holds_only_its_pending_messages() {
+        let (manager, _temp) = create_test_manager().await;
+        let mut to_reset = create_test_message();
+        to_reset.to = MessageRecipient::expert_id(3);
+        manager.enqueue(&to_reset).await.unwrap();
+        tokio::time::sleep(tokio::time::Duration::from_millis(2)).await;
+        let other = create_test_message();
+        manager.enqueue(&other).await.unwrap();
+
+        let held = manager
+            .set_aside_for_expert(3, &manager.held_path())
+            .await
+            .unwrap();
+        assert_eq!(held, vec![to_reset.message_id.clone()]);
+        let pending: Vec<_> = manager
+            .get_pending_messages()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|q| q.message.message_id)
+            .collect();
+        assert_eq!(
+            pending,
+            vec![other.message_id.clone()],
+            "set_aside_for_expert: other experts' messages should stay queued"
+        );
+
+        assert_eq!(
+            manager.release_held().await.unwrap(),
+            vec![to_reset.message_id]
+        );
+        assert_eq!(manager.get_pending_messages().await.unwrap().len(), 2);
+        assert!(manager.release_held().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn set_aside_for_expert_splits_multi_recipient_messages() {
+        let (manager, _temp) = create_test_manager().await;
+        let mut fan_out = create_test_message();
+        fan_out.to = MessageRecipient::multi(vec![
+            MessageRecipient::expert_id(3),
+            MessageRecipient::multi(vec![
+                MessageRecipient::expert_id(4),
+                MessageRecipient::role("backend"),
+            ]),
+        ]);
+        manager.enqueue(&fan_out).await.unwrap();
+
+        let held = manager
+            .set_aside_for_expert(3, &manager.held_path())
+            .await
+            .unwrap();
+        let copy_id = format!("{}-3", fan_out.message_id);
+        assert_eq!(held, vec![copy_id.clone()]);
+
+        let pending = manager.get_pending_messages().await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(
+            pending[0].message.to,
+            MessageRecipient::multi(vec![
+                MessageRecipient::expert_id(4),
+                MessageRecipient::role("backend"),
+            ]),
+            "set_aside_for_expert: other recipients should stay queued"
+        );
+
+        assert_eq!(manager.release_held().await.unwrap(), vec![copy_id.clone()]);
+        let released = manager.read_message(&copy_id).await.unwrap().unwrap();
+        assert_eq!(
+            released.message.to,
+            MessageRecipient::expert_id(3),
+            "set_aside_for_expert: the held copy should go to the expert alone"
+        );
+    }
+
     #[tokio::test]
     async fn queue_manager_priority_ordering() {
         let (manager, _temp) = create_test_manager().await;
@@ -657,6 +1456,7 @@ mod tests {
         let content1 = MessageContent {
             subject: "Low Priority".to_string(),
             body: "Low priority message".to_string(),
+            attachments: Vec::new(),
         };
         let low_msg = Message::new(
             0,
@@ -672,6 +1472,7 @@ mod tests {
         let content2 = MessageContent {
             subject: "High Priority".to_string(),
             body: "High priority message".to_string(),
+            attachments: Vec::new(),
         };
         let high_msg = Message::new(
             0,
@@ -686,6 +1487,7 @@ mod tests {
         let content3 = MessageContent {
             subject: "Normal Priority".to_string(),
             body: "Normal priority message".to_string(),
+            attachments: Vec::new(),
         };
         let normal_msg = Message::new(
             0,
@@ -726,6 +1528,89 @@ mod tests {
         assert_eq!(messages[0].message.delivery_attempts, 5);
     }
 
+    #[tokio::test]
+    async fn queue_manager_record_and_list_delivered() {
+        let (manager, _temp) = create_test_manager().await;
+        assert!(manager.list_delivered().await.unwrap().is_empty());
+
+        let message = create_test_message();
+        manager
+            .record_delivery(&DeliveredMessage::new(&message, 1))
+            .await
+            .unwrap();
+        manager
+            .record_delivery(&DeliveredMessage::new(&message, 2))
+            .await
+            .unwrap();
+
+        let delivered = manager.list_delivered().await.unwrap();
+        assert_eq!(
+            delivered.len(),
+            2,
+            "list_delivered: should return every record"
+        );
+        assert_eq!(delivered[0].to_expert_id, 1);
+        assert_eq!(delivered[1].to_expert_id, 2);
+        assert_eq!(delivered[0].subject, "Test Subject");
+    }
+
+    #[tokio::test]
+    async fn replay_last_delivered_enqueues_fresh_copy() {
+        let (manager, _temp) = create_test_manager().await;
+        assert!(
+            manager.replay_last_delivered(1).await.unwrap().is_none(),
+            "replay_last_delivered: nothing to replay before any delivery"
+        );
+
+        let original = create_test_message()
+            .with_priority(MessagePriority::High)
+            .with_metadata("origin".to_string(), "cli".to_string());
+        manager.record_last_delivered(1, &original).await.unwrap();
+        tokio::time::sleep(tokio::time::Duration::from_millis(2)).await;
+
+        let replay = manager.replay_last_delivered(1).await.unwrap().unwrap();
+        let queued = manager.get_pending_messages().await.unwrap();
+        assert_eq!(queued.len(), 1, "replay_last_delivered: one message queued");
+        let queued = &queued[0].message;
+        assert_eq!(queued.message_id, replay.message_id);
+        assert_ne!(
+            queued.message_id, original.message_id,
+            "replay_last_delivered: the copy needs its own id"
+        );
+        assert!(queued.created_at > original.created_at);
+        assert_eq!(queued.content.subject, original.content.subject);
+        assert_eq!(queued.content.body, original.content.body);
+        assert_eq!(queued.message_type, original.message_type);
+        assert_eq!(queued.priority, original.priority);
+        assert_eq!(queued.from_expert_id, original.from_expert_id);
+        assert_eq!(queued.to, MessageRecipient::expert_id(1));
+        assert_eq!(queued.delivery_attempts, 0);
+        assert_eq!(
+            queued.metadata.get(REPLAY_OF_METADATA_KEY),
+            Some(&original.message_id)
+        );
+        assert_eq!(queued.metadata.get("origin"), Some(&"cli".to_string()));
+    }
+
+    #[tokio::test]
+    async fn queue_manager_list_delivered_skips_malformed_lines() {
+        let (manager, _temp) = create_test_manager().await;
+        manager
+            .record_delivery(&DeliveredMessage::new(&create_test_message(), 1))
+            .await
+            .unwrap();
+        let mut log = std::fs::read_to_string(manager.delivered_log_file()).unwrap();
+        log.push_str("not json\n");
+        std::fs::write(manager.delivered_log_file(), log).unwrap();
+
+        let delivered = manager.list_delivered().await.unwrap();
+        assert_eq!(
+            delivered.len(),
+            1,
+            "list_delivered: malformed lines should be skipped"
+        );
+    }
+
     #[tokio::test]
     async fn queue_manager_cleanup_expired_messages() {
         let (manager, _temp) = create_test_manager().await;
@@ -734,6 +1619,7 @@ mod tests {
         let expired_content = MessageContent {
             subject: "Expired Message".to_string(),
             body: "This message will expire".to_string(),
+            attachments: Vec::new(),
         };
         let expired_msg = Message::new(
             0,
@@ -748,6 +1634,7 @@ mod tests {
         let normal_content = MessageContent {
             subject: "Normal Message".to_string(),
             body: "This message is normal".to_string(),
+            attachments: Vec::new(),
         };
         let normal_msg = Message::new(
             0,
@@ -778,6 +1665,7 @@ mod tests {
         let pending_content = MessageContent {
             subject: "Pending Message".to_string(),
             body: "This message is pending".to_string(),
+            attachments: Vec::new(),
         };
         let pending_msg = Message::new(
             0,
@@ -791,6 +1679,7 @@ mod tests {
         let expired_content = MessageContent {
             subject: "Expired Message".to_string(),
             body: "This message will expire".to_string(),
+            attachments: Vec::new(),
         };
         let expired_msg = Message::new(
             0,
@@ -837,11 +1726,255 @@ mod tests {
         assert!(!message_file.exists());
     }
 
+    #[tokio::test]
+    async fn queue_manager_process_outbox_skips_partially_written_file() {
+        let (manager, _temp) = create_test_manager().await;
+        let valid = create_test_message();
+        write_to_outbox(&manager, &valid).await;
+
+        let mut partial = create_test_message();
+        partial.message_id = "msg-partial".to_string();
+        let yaml = serde_yaml::to_string(&partial).unwrap();
+        let partial_file = manager.outbox_path().join("msg-partial.yaml");
+        fs::write(&partial_file, &yaml[..yaml.len() / 2])
+            .await
+            .unwrap();
+
+        let processed = manager.process_outbox().await.unwrap();
+        assert_eq!(
+            processed,
+            vec![valid.message_id.clone()],
+            "process_outbox: a partial file should be skipped without failing the cycle"
+        );
+        assert!(
+            partial_file.exists(),
+            "process_outbox: partial file should stay in the outbox for the next cycle"
+        );
+
+        fs::write(&partial_file, &yaml).await.unwrap();
+        let processed = manager.process_outbox().await.unwrap();
+        assert_eq!(processed, vec![partial.message_id]);
+    }
+
+    #[tokio::test]
+    async fn queue_manager_process_outbox_truncates_over_limit_body() {
+        let (manager, _temp) = create_test_manager().await;
+        let manager = manager.with_max_message_body_bytes(64);
+
+        let mut message = create_test_message();
+        message.content.body = "x".repeat(200);
+        let message_file = manager
+            .outbox_path()
+            .join(format!("{}.yaml", message.message_id));
+        fs::write(&message_file, serde_yaml::to_string(&message).unwrap())
+            .await
+            .unwrap();
+
+        manager.process_outbox().await.unwrap();
+
+        let messages = manager.read_queue().await.unwrap();
+        let queued = &messages[0].message;
+        assert!(
+            queued.content.body.len() <= 64,
+            "process_outbox: over-limit body should be truncated, got {} bytes",
+            queued.content.body.len()
+        );
+        assert!(
+            queued.content.body.ends_with("[truncated]"),
+            "process_outbox: truncated body should carry the marker"
+        );
+        assert_eq!(
+            queued
+                .metadata
+                .get(TRUNCATED_METADATA_KEY)
+                .map(String::as_str),
+            Some("200"),
+            "process_outbox: original size should be recorded in metadata"
+        );
+    }
+
+    #[tokio::test]
+    async fn queue_manager_process_outbox_keeps_under_limit_body() {
+        let (manager, _temp) = create_test_manager().await;
+        let manager = manager.with_max_message_body_bytes(1024);
+
+        let message = create_test_message();
+        let message_file = manager
+            .outbox_path()
+            .join(format!("{}.yaml", message.message_id));
+        fs::write(&message_file, serde_yaml::to_string(&message).unwrap())
+            .await
+            .unwrap();
+
+        manager.process_outbox().await.unwrap();
+
+        let messages = manager.read_queue().await.unwrap();
+        assert_eq!(
+            messages[0].message.content.body, message.content.body,
+            "process_outbox: under-limit body should pass through unchanged"
+        );
+        assert!(
+            !messages[0].message.is_truncated(),
+            "process_outbox: under-limit message should not be flagged as truncated"
+        );
+    }
+
+    async fn write_to_outbox(manager: &QueueManager, message: &Message) {
+        let message_file = manager
+            .outbox_path()
+            .join(format!("{}.yaml", message.message_id));
+        fs::write(&message_file, serde_yaml::to_string(message).unwrap())
+            .await
+            .unwrap();
+    }
+
+    const OUTBOX_YAML_WITHOUT_TO: &str = r#"
+message_id: "msg-no-to"
+from_expert_id: 0
+message_type: notify
+priority: normal
+created_at: "2024-01-15T10:30:00Z"
+content:
+  subject: "Done"
+  body: "Schema is ready"
+"#;
+
+    #[tokio::test]
+    async fn queue_manager_process_outbox_applies_default_recipient() {
+        let (manager, _temp) = create_test_manager().await;
+        let manager = manager.with_default_recipients(HashMap::from([(0, 2)]));
+        fs::write(
+            manager.outbox_path().join("msg-no-to.yaml"),
+            OUTBOX_YAML_WITHOUT_TO,
+        )
+        .await
+        .unwrap();
+
+        let processed = manager.process_outbox().await.unwrap();
+        assert_eq!(processed, vec!["msg-no-to".to_string()]);
+
+        let queued = manager.read_message("msg-no-to").await.unwrap().unwrap();
+        assert_eq!(
+            queued.message.to,
+            MessageRecipient::expert_id(2),
+            "process_outbox: missing `to` should use the sender's default recipient"
+        );
+        assert_eq!(
+            queued
+                .message
+                .metadata
+                .get(DEFAULT_RECIPIENT_METADATA_KEY)
+                .map(String::as_str),
+            Some("2")
+        );
+    }
+
+    #[tokio::test]
+    async fn queue_manager_process_outbox_rejects_missing_to_without_default() {
+        let (manager, _temp) = create_test_manager().await;
+        fs::write(
+            manager.outbox_path().join("msg-no-to.yaml"),
+            OUTBOX_YAML_WITHOUT_TO,
+        )
+        .await
+        .unwrap();
+
+        let processed = manager.process_outbox().await.unwrap();
+        assert!(
+            processed.is_empty(),
+            "process_outbox: a message without `to` or a default should not be queued"
+        );
+    }
+
+    #[tokio::test]
+    async fn queue_manager_process_outbox_collapses_duplicates_within_window() {
+        let (manager, _temp) = create_test_manager().await;
+        let manager = manager.with_duplicate_window_secs(30);
+
+        let original = create_test_message();
+        for i in 0..3 {
+            let mut message = original.clone();
+            message.message_id = format!("{}-{i}", original.message_id);
+            write_to_outbox(&manager, &message).await;
+        }
+
+        let processed = manager.process_outbox().await.unwrap();
+        assert_eq!(
+            processed.len(),
+            1,
+            "process_outbox: only the first of identical messages should be enqueued"
+        );
+
+        let pending = manager.get_pending_messages().await.unwrap();
+        assert_eq!(
+            pending.len(),
+            1,
+            "process_outbox: exactly one identical message should remain pending"
+        );
+        assert_eq!(
+            manager.take_collapsed_count(),
+            2,
+            "process_outbox: dropped duplicates should be counted"
+        );
+        assert_eq!(manager.take_collapsed_count(), 0);
+        assert!(
+            std::fs::read_dir(manager.outbox_path())
+                .unwrap()
+                .next()
+                .is_none(),
+            "process_outbox: duplicate outbox files should be removed"
+        );
+    }
+
+    #[tokio::test]
+    async fn queue_manager_process_outbox_keeps_distinct_messages() {
+        let (manager, _temp) = create_test_manager().await;
+        let manager = manager.with_duplicate_window_secs(30);
+
+        let first = create_test_message();
+        let mut second = first.clone();
+        second.message_id = format!("{}-b", first.message_id);
+        second.content.body = "Different body".to_string();
+        write_to_outbox(&manager, &first).await;
+        write_to_outbox(&manager, &second).await;
+
+        manager.process_outbox().await.unwrap();
+
+        assert_eq!(
+            manager.get_pending_messages().await.unwrap().len(),
+            2,
+            "process_outbox: messages with distinct content should both be enqueued"
+        );
+        assert_eq!(manager.take_collapsed_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn queue_manager_process_outbox_keeps_duplicates_outside_window() {
+        let (manager, _temp) = create_test_manager().await;
+        let manager = manager.with_duplicate_window_secs(30);
+
+        let first = create_test_message();
+        let mut second = first.clone();
+        second.message_id = format!("{}-b", first.message_id);
+        second.created_at = first.created_at + chrono::Duration::seconds(60);
+        write_to_outbox(&manager, &first).await;
+        write_to_outbox(&manager, &second).await;
+
+        manager.process_outbox().await.unwrap();
+
+        assert_eq!(
+            manager.get_pending_messages().await.unwrap().len(),
+            2,
+            "process_outbox: identical messages outside the window should both be enqueued"
+        );
+    }
+
     #[test]
     fn detect_misplaced_message_identifies_raw_message() {
         let content = MessageContent {
             subject: "Test".to_string(),
             body: "Body".to_string(),
+            attachments: Vec::new(),
         };
         let msg = Message::new(
             0,
@@ -868,6 +2001,7 @@ mod tests {
         let content = MessageContent {
             subject: "Test".to_string(),
             body: "Body".to_string(),
+            attachments: Vec::new(),
         };
         let msg = Message::new(
             0,
@@ -924,6 +2058,7 @@ content:
         let content = MessageContent {
             subject: "Misplaced".to_string(),
             body: "This was written directly to queue".to_string(),
+            attachments: Vec::new(),
         };
         let raw_msg = Message::new(
             3,
@@ -984,8 +2119,13 @@ mod property_tests {
 
     // Generators for property-based testing
     fn arbitrary_message_content() -> impl Strategy<Value = MessageContent> {
-        ("[a-zA-Z0-9 ]{1,100}", "[a-zA-Z0-9 \n]{1,1000}")
-            .prop_map(|(subject, body)| MessageContent { subject, body })
+        ("[a-zA-Z0-9 ]{1,100}", "[a-zA-Z0-9 \n]{1,1000}").prop_map(|(subject, body)| {
+            MessageContent {
+                subject,
+                body,
+                attachments: Vec::new(),
+            }
+        })
     }
 
     fn arbitrary_message_recipient() -> impl Strategy<Value = MessageRecipient> {
@@ -1576,6 +2716,7 @@ mod property_tests {
                 let invalid_content1 = MessageContent {
                     subject: "".to_string(),  // Empty subject
                     body: "Valid body content".to_string(),
+                    attachments: Vec::new(),
                 };
                 let message1 = Message::new(from_expert_id, recipient.clone(), MessageType::Query, invalid_content1);
 
@@ -1591,6 +2732,7 @@ mod property_tests {
                 let invalid_content2 = MessageContent {
                     subject: "Valid subject".to_string(),
                     body: "".to_string(),  // Empty body
+                    attachments: Vec::new(),
                 };
                 let message2 = Message::new(from_expert_id, recipient.clone(), MessageType::Query, invalid_content2);
 
@@ -1604,6 +2746,7 @@ mod property_tests {
                 let valid_content = MessageContent {
                     subject: "Valid subject".to_string(),
                     body: "Valid body".to_string(),
+                    attachments: Vec::new(),
                 };
                 let mut message3 = Message::new(from_expert_id, recipient, MessageType::Query, valid_content);
                 message3.message_id = "".to_string();  // Empty message ID
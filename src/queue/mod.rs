@@ -1,7 +1,12 @@
+mod format;
 mod manager;
 mod router;
 
 #[allow(unused_imports)]
-pub use manager::{QueueError, QueueManager, QueueResult};
+pub use format::{MessageFormat, MessageFormatter, DEFAULT_MESSAGE_TEMPLATE};
 #[allow(unused_imports)]
-pub use router::{DeliveryResult, MessageRouter, ProcessingStats, QueueStats, RouterError};
+pub use manager::{CompactionReport, QueueError, QueueManager, QueueResult};
+#[allow(unused_imports)]
+pub use router::{
+    DeliveryResult, MessageRouter, ProcessingStats, QueueStats, RecipientStrategy, RouterError,
+};
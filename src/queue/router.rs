@@ -1,14 +1,22 @@
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 use thiserror::Error;
 use tracing::{debug, error, info, warn};
 
 use crate::experts::ExpertRegistry;
 use crate::models::{
-    ExpertId, Message, MessageId, MessageRecipient, QueuedMessage, MAX_DELIVERY_ATTEMPTS,
+    DeliveredMessage, ExpertId, ExpertState, Message, MessageId, MessageLane, MessagePriority,
+    MessageRecipient, MessageType, QueuedMessage, DEFAULT_RECIPIENT_METADATA_KEY,
+    MAX_DELIVERY_ATTEMPTS,
 };
-use crate::session::TmuxSender;
+use crate::session::{is_window_not_found_error, TmuxSender};
 
-use super::QueueManager;
+use super::{MessageFormatter, QueueManager};
 
 #[derive(Debug, Error)]
 pub enum RouterError {
@@ -18,6 +26,9 @@ pub enum RouterError {
     #[error("Tmux error: {0}")]
     Tmux(String),
 
+    #[error("Tmux window not found: {0}")]
+    WindowNotFound(u32),
+
     #[error("Expert not found: {0}")]
     ExpertNotFound(String),
 
@@ -68,22 +79,131 @@ pub struct ProcessingStats {
     pub messages_failed: usize,
     pub messages_expired: usize,
     pub messages_skipped: usize,
+    /// Duplicate outbox messages dropped since the previous run
+    pub messages_collapsed: usize,
     pub delivered_expert_ids: Vec<u32>,
+    /// Subject of each delivered message, by recipient, in delivery order
+    pub delivered_subjects: Vec<(u32, String)>,
+    /// Priority of each delivered message, by recipient, in delivery order
+    pub delivered_priorities: Vec<(u32, MessagePriority)>,
+    /// Experts marked offline because their tmux window disappeared
+    pub offline_expert_ids: Vec<u32>,
 }
 
 /// MessageRouter handles message routing logic and delivery coordination
 ///
 /// The MessageRouter is responsible for:
-/// - Processing queued messages in priority order
+/// - Processing queued messages in priority order, spreading each tier across recipients
 /// - Finding appropriate recipients using targeting strategies (ID, name, role)
 /// - Checking expert availability (idle state) for non-blocking delivery
 /// - Delivering messages via tmux integration
 /// - Managing delivery attempts and retry logic
 /// - Cleaning up expired messages and failed deliveries
+/// - Boosting replies to High-priority queries (when enabled)
+/// - Marking experts offline when their tmux window has disappeared, and
+///   deferring direct messages to offline experts until they return
+/// - Preempting Busy experts with High priority messages (when enabled)
 pub struct MessageRouter<T: TmuxSender = crate::session::TmuxManager> {
     queue_manager: QueueManager,
     expert_registry: ExpertRegistry,
     tmux_sender: T,
+    reply_priority_boost: bool,
+    /// IDs of delivered High-priority queries with their expiry, kept so
+    /// replies can be boosted after the original has left the queue. An entry
+    /// goes once a reply to it is handled or the query expires.
+    high_priority_queries: HashMap<MessageId, Option<DateTime<Utc>>>,
+    /// Experts marked offline since the last `process_queue` report
+    newly_offline: Vec<ExpertId>,
+    formatter: MessageFormatter,
+    /// Working directory of experts not launched in a worktree
+    project_root: Option<PathBuf>,
+    /// Pending messages an expert may have before it counts as saturated (0 = no limit)
+    max_queue_depth: usize,
+    /// Pending messages addressed to each expert by ID, refreshed every `process_queue`
+    queue_depths: HashMap<ExpertId, usize>,
+    /// Messages delivered per expert and lane in one `process_queue` (0 = no limit)
+    max_deliveries_per_cycle: usize,
+    /// Deliver High priority messages flagged `preempt` to Busy experts
+    allow_preemption: bool,
+    /// Write delivered messages to the recipient's inbox and send only a pointer
+    inbox_files: bool,
+    recipient_strategy: RecipientStrategy,
+    /// Expert each role or tag group last delivered to, for round-robin
+    last_group_recipient: HashMap<MessageRecipient, ExpertId>,
+    /// Delivery sequence number of each expert's latest delivery
+    last_delivery_seq: HashMap<ExpertId, u64>,
+    delivery_seq: u64,
+}
+
+/// How a role- or tag-targeted message picks among the idle experts of its group
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecipientStrategy {
+    /// Lowest expert id (least loaded first when `max_queue_depth` is set)
+    #[default]
+    First,
+    /// The next expert id after the one the group's previous message went to
+    RoundRobin,
+    /// The expert that has gone longest without a delivery
+    LeastRecentlyDelivered,
+    /// The expert with the fewest pending messages
+    LeastLoaded,
+}
+
+/// Reorder messages (sorted by priority, then age) so that within each
+/// priority tier every recipient's oldest message comes before any recipient's
+/// second one. Messages to one recipient keep their order and tiers never mix,
+/// so a burst to one expert cannot hold back a lone message to another.
+fn spread_recipients(messages: Vec<QueuedMessage>) -> Vec<QueuedMessage> {
+    let mut keyed = Vec::with_capacity(messages.len());
+    let mut tier = 0usize;
+    let mut tier_priority = None;
+    let mut seen: Vec<(MessageRecipient, usize)> = Vec::new();
+    for queued in messages {
+        if tier_priority.is_some_and(|p| p != queued.message.priority) {
+            tier += 1;
+            seen.clear();
+        }
+        tier_priority = Some(queued.message.priority);
+        let round = match seen.iter_mut().find(|(to, _)| *to == queued.message.to) {
+            Some((_, count)) => {
+                *count += 1;
+                *count
+            }
+            None => {
+                seen.push((queued.message.to.clone(), 0));
+                0
+            }
+        };
+        keyed.push((tier, round, queued));
+    }
+    keyed.sort_by_key(|(tier, round, _)| (*tier, *round));
+    keyed.into_iter().map(|(_, _, queued)| queued).collect()
+}
+
+/// Appended to an attachment that does not exist in the recipient's working directory
+pub const MISSING_ATTACHMENT_NOTE: &str = " (missing in your working directory)";
+
+/// Whether `attachment` (a relative path, optionally suffixed with `:line` or
+/// `:start-end`) names an existing file inside `base`
+fn attachment_within(base: &Path, attachment: &str) -> bool {
+    let path = match attachment.rsplit_once(':') {
+        Some((path, range))
+            if !range.is_empty() && range.chars().all(|c| c.is_ascii_digit() || c == '-') =>
+        {
+            path
+        }
+        _ => attachment,
+    };
+    let path = Path::new(path);
+    if path.as_os_str().is_empty() || path.is_absolute() {
+        return false;
+    }
+
+    let (Ok(base), Ok(resolved)) = (base.canonicalize(), base.join(path).canonicalize()) else {
+        return false;
+    };
+    resolved.starts_with(&base) && resolved.is_file()
 }
 
 impl<T: TmuxSender> MessageRouter<T> {
@@ -97,9 +217,89 @@ impl<T: TmuxSender> MessageRouter<T> {
             queue_manager,
             expert_registry,
             tmux_sender,
+            reply_priority_boost: false,
+            high_priority_queries: HashMap::new(),
+            newly_offline: Vec::new(),
+            formatter: MessageFormatter::default(),
+            project_root: None,
+            max_queue_depth: 0,
+            queue_depths: HashMap::new(),
+            max_deliveries_per_cycle: 0,
+            allow_preemption: false,
+            inbox_files: false,
+            recipient_strategy: RecipientStrategy::default(),
+            last_group_recipient: HashMap::new(),
+            last_delivery_seq: HashMap::new(),
+            delivery_seq: 0,
         }
     }
 
+    /// Enable or disable raising replies to High-priority queries to High priority
+    pub fn with_reply_priority_boost(mut self, enabled: bool) -> Self {
+        self.reply_priority_boost = enabled;
+        self
+    }
+
+    /// Render delivered messages with `formatter` instead of the default pretty block
+    pub fn with_message_formatter(mut self, formatter: MessageFormatter) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Resolve attachments of experts outside a worktree against `project_root`
+    pub fn with_project_root(mut self, project_root: PathBuf) -> Self {
+        self.project_root = Some(project_root);
+        self
+    }
+
+    /// Cap how many pending messages an expert may have; 0 disables the limit
+    pub fn with_max_queue_depth(mut self, max_queue_depth: usize) -> Self {
+        self.max_queue_depth = max_queue_depth;
+        self
+    }
+
+    /// Cap deliveries to one expert per `process_queue`, with notifications and
+    /// task-bearing messages each getting the full budget; 0 disables the limit
+    pub fn with_max_deliveries_per_cycle(mut self, max_deliveries: usize) -> Self {
+        self.max_deliveries_per_cycle = max_deliveries;
+        self
+    }
+
+    /// Choose among idle group members with `strategy` instead of taking the first
+    pub fn with_recipient_strategy(mut self, strategy: RecipientStrategy) -> Self {
+        self.recipient_strategy = strategy;
+        self
+    }
+
+    /// Allow High priority messages flagged `preempt` to interrupt Busy experts
+    pub fn with_preemption(mut self, enabled: bool) -> Self {
+        self.allow_preemption = enabled;
+        self
+    }
+
+    /// Write each delivered message to `messages/inbox/expert<N>/` and send the
+    /// pane a one-line pointer instead of the full message
+    pub fn with_inbox_files(mut self, enabled: bool) -> Self {
+        self.inbox_files = enabled;
+        self
+    }
+
+    /// Whether `message` may be delivered to `expert_id` even though it is Busy
+    fn may_preempt(&self, expert_id: ExpertId, message: &Message) -> bool {
+        self.allow_preemption
+            && message.preempt
+            && message.priority == MessagePriority::High
+            && self
+                .expert_registry
+                .get_expert(expert_id)
+                .is_some_and(|expert| expert.state == ExpertState::Busy)
+    }
+
+    fn is_saturated(&self, expert_id: ExpertId) -> bool {
+        self.max_queue_depth > 0
+            && self.queue_depths.get(&expert_id).copied().unwrap_or(0) >= self.max_queue_depth
+    }
+
     /// Process the message queue, attempting delivery for all pending messages
     ///
     /// This method:
@@ -114,11 +314,31 @@ impl<T: TmuxSender> MessageRouter<T> {
         // First, clean up expired messages
         let expired_messages = self.queue_manager.cleanup_expired_messages().await?;
         stats.messages_expired = expired_messages.len();
+        let now = Utc::now();
+        self.high_priority_queries
+            .retain(|_, expires_at| !expires_at.is_some_and(|at| at <= now));
+        stats.messages_collapsed = self.queue_manager.take_collapsed_count();
 
         // Get pending messages (already sorted by priority and timestamp)
-        let pending_messages = self.queue_manager.get_pending_messages().await?;
+        let pending_messages = self
+            .expand_multi_recipients(self.queue_manager.get_pending_messages().await?)
+            .await?;
+        let pending_messages = spread_recipients(pending_messages);
         stats.messages_processed = pending_messages.len();
 
+        // Only direct messages are bound to an expert while pending; role and
+        // tag messages count towards an expert's depth once they resolve below
+        self.queue_depths.clear();
+        for queued_message in &pending_messages {
+            if let MessageRecipient::ExpertId { expert_id } = queued_message.message.to {
+                *self.queue_depths.entry(expert_id).or_default() += 1;
+            }
+        }
+        // Position of each message among those resolved to the same expert
+        let mut depth_seen: HashMap<ExpertId, usize> = HashMap::new();
+        // Deliveries so far this pass, per recipient and lane
+        let mut lane_deliveries: HashMap<(ExpertId, MessageLane), usize> = HashMap::new();
+
         debug!(
             "Processing {} pending messages, cleaned up {} expired messages",
             pending_messages.len(),
@@ -127,12 +347,95 @@ impl<T: TmuxSender> MessageRouter<T> {
 
         // Process each message
         for queued_message in pending_messages {
-            match self.attempt_delivery(&queued_message).await {
+            let recipient = self
+                .find_recipient(
+                    &queued_message.message.to,
+                    queued_message.message.from_expert_id,
+                )
+                .await?;
+            if let Some(expert_id) = recipient {
+                if self
+                    .expert_registry
+                    .get_expert(expert_id)
+                    .is_some_and(|expert| expert.is_offline())
+                {
+                    debug!(
+                        "Expert {} is offline, deferring message {}",
+                        expert_id, queued_message.message.message_id
+                    );
+                    stats.messages_skipped += 1;
+                    continue;
+                }
+                let position = depth_seen.entry(expert_id).or_default();
+                *position += 1;
+                if self.max_queue_depth > 0 && *position > self.max_queue_depth {
+                    debug!(
+                        "Expert {} reached its max queue depth, deferring message {}",
+                        expert_id, queued_message.message.message_id
+                    );
+                    stats.messages_skipped += 1;
+                    continue;
+                }
+                let lane = queued_message.message.message_type.lane();
+                if self.max_deliveries_per_cycle > 0
+                    && lane_deliveries
+                        .get(&(expert_id, lane))
+                        .is_some_and(|&n| n >= self.max_deliveries_per_cycle)
+                {
+                    debug!(
+                        "Expert {} used its {:?} lane budget, deferring message {}",
+                        expert_id, lane, queued_message.message.message_id
+                    );
+                    stats.messages_skipped += 1;
+                    continue;
+                }
+            }
+
+            match self.deliver_to_recipient(&queued_message, recipient).await {
                 Ok(result) => {
                     if result.success {
                         stats.messages_delivered += 1;
+                        if self.reply_priority_boost
+                            && queued_message.message.message_type == MessageType::Query
+                            && queued_message.message.priority == MessagePriority::High
+                        {
+                            self.high_priority_queries.insert(
+                                queued_message.message.message_id.clone(),
+                                queued_message.message.expires_at,
+                            );
+                        }
                         if let Some(eid) = result.expert_id {
+                            *lane_deliveries
+                                .entry((eid, queued_message.message.message_type.lane()))
+                                .or_default() += 1;
                             stats.delivered_expert_ids.push(eid);
+                            stats
+                                .delivered_subjects
+                                .push((eid, queued_message.message.content.subject.clone()));
+                            stats
+                                .delivered_priorities
+                                .push((eid, queued_message.message.priority));
+                            let record = DeliveredMessage::new(&queued_message.message, eid);
+                            if let Err(e) = self.queue_manager.record_delivery(&record).await {
+                                warn!(
+                                    "Failed to record delivery of message {}: {}",
+                                    result.message_id, e
+                                );
+                            }
+                            if let Err(e) = self
+                                .queue_manager
+                                .record_last_delivered(eid, &queued_message.message)
+                                .await
+                            {
+                                warn!(
+                                    "Failed to keep message {} for replay: {}",
+                                    result.message_id, e
+                                );
+                            }
+                            if queued_message.message.request_delivery_receipt {
+                                self.enqueue_delivery_receipt(&queued_message.message, eid)
+                                    .await;
+                            }
                         }
                         // Remove successfully delivered message from queue
                         self.queue_manager
@@ -181,6 +484,8 @@ impl<T: TmuxSender> MessageRouter<T> {
             }
         }
 
+        stats.offline_expert_ids = std::mem::take(&mut self.newly_offline);
+
         debug!(
             "Queue processing complete. Delivered: {}, Failed: {}, Expired: {}, Skipped: {}",
             stats.messages_delivered,
@@ -192,19 +497,136 @@ impl<T: TmuxSender> MessageRouter<T> {
         Ok(stats)
     }
 
+    /// Split each message addressed to a `Multi` list or a tag into one queued
+    /// message per resolved recipient, so retries and receipts apply per
+    /// expert. A recipient that resolves to nobody is left in place and fails
+    /// like any other message without a recipient.
+    async fn expand_multi_recipients(
+        &self,
+        messages: Vec<QueuedMessage>,
+    ) -> Result<Vec<QueuedMessage>, RouterError> {
+        let mut expanded = Vec::with_capacity(messages.len());
+        for queued in messages {
+            if !matches!(
+                queued.message.to,
+                MessageRecipient::Multi(_) | MessageRecipient::Tag { .. }
+            ) {
+                expanded.push(queued);
+                continue;
+            }
+            let targets = self.resolve_multi(&queued.message.to, queued.message.from_expert_id);
+            if targets.is_empty() {
+                expanded.push(queued);
+                continue;
+            }
+            debug!(
+                "Splitting message {} into {} deliveries",
+                queued.message.message_id,
+                targets.len()
+            );
+            for (index, to) in targets.into_iter().enumerate() {
+                let mut message = queued.message.clone();
+                message.message_id = format!("{}-{}", queued.message.message_id, index + 1);
+                message.to = to;
+                self.queue_manager.enqueue(&message).await?;
+                expanded.push(QueuedMessage::new(message));
+            }
+            self.queue_manager
+                .dequeue(&queued.message.message_id)
+                .await?;
+        }
+        Ok(expanded)
+    }
+
+    /// Resolve the members of a `Multi` recipient (or a lone tag) to distinct
+    /// experts.
+    ///
+    /// Experts named by ID are kept if they exist and share the sender's
+    /// worktree. A tag expands to every other expert carrying it in the
+    /// sender's worktree, idle or not. A role resolves as it would on its own,
+    /// and counts as covered when that expert is already targeted; if no
+    /// expert is free (or nobody carries the tag) the role or tag is kept
+    /// as-is and routed once one is.
+    pub fn resolve_multi(
+        &self,
+        recipient: &MessageRecipient,
+        sender_id: ExpertId,
+    ) -> Vec<MessageRecipient> {
+        let mut chosen: Vec<ExpertId> = Vec::new();
+        let mut waiting: Vec<MessageRecipient> = Vec::new();
+        for leaf in recipient.leaves() {
+            if let MessageRecipient::Tag { tag } = leaf {
+                let members = self.tag_members(tag, sender_id);
+                if !members.is_empty() {
+                    for expert_id in members {
+                        if !chosen.contains(&expert_id) {
+                            chosen.push(expert_id);
+                        }
+                    }
+                    continue;
+                }
+            }
+            match self.resolve_recipient(leaf, sender_id) {
+                Some(expert_id) if chosen.contains(&expert_id) => {}
+                Some(expert_id) => chosen.push(expert_id),
+                None if matches!(leaf, MessageRecipient::ExpertId { .. }) => {}
+                None => {
+                    if !waiting.contains(leaf) {
+                        waiting.push(leaf.clone());
+                    }
+                }
+            }
+        }
+        chosen
+            .into_iter()
+            .map(MessageRecipient::expert_id)
+            .chain(waiting)
+            .collect()
+    }
+
+    /// Experts other than the sender carrying `tag` in the sender's worktree
+    fn tag_members(&self, tag: &str, sender_id: ExpertId) -> Vec<ExpertId> {
+        let Some(sender) = self.expert_registry.get_expert(sender_id) else {
+            return Vec::new();
+        };
+        self.expert_registry
+            .get_experts_by_tag_in_worktree(tag, &sender.worktree_path)
+            .into_iter()
+            .filter(|&id| id != sender_id)
+            .collect()
+    }
+
     /// Attempt delivery of a single message
     ///
     /// This method:
     /// 1. Finds the appropriate recipient using targeting logic
-    /// 2. Checks if the recipient is idle (non-blocking delivery)
+    /// 2. Checks if the recipient is idle (non-blocking delivery), unless the
+    ///    message may preempt a Busy recipient
     /// 3. Delivers the message via tmux if recipient is available
     /// 4. Returns delivery result with success/failure information
+    #[cfg(test)]
     pub async fn attempt_delivery(
         &mut self,
         queued_message: &QueuedMessage,
     ) -> Result<DeliveryResult, RouterError> {
         let message = &queued_message.message;
 
+        // Find recipient expert (worktree-aware)
+        let recipient = self
+            .find_recipient(&message.to, message.from_expert_id)
+            .await?;
+        self.deliver_to_recipient(queued_message, recipient).await
+    }
+
+    /// Deliver a message to the expert its recipient resolved to, if any.
+    /// `process_queue` resolves first so its per-expert limits see the target.
+    async fn deliver_to_recipient(
+        &mut self,
+        queued_message: &QueuedMessage,
+        recipient: Option<ExpertId>,
+    ) -> Result<DeliveryResult, RouterError> {
+        let message = &queued_message.message;
+
         debug!(
             "Attempting delivery of message {} (attempt {}/{})",
             message.message_id,
@@ -212,9 +634,7 @@ impl<T: TmuxSender> MessageRouter<T> {
             MAX_DELIVERY_ATTEMPTS
         );
 
-        // Find recipient expert (worktree-aware)
-        let sender_id = message.from_expert_id;
-        let expert_id = match self.find_recipient(&message.to, sender_id).await? {
+        let expert_id = match recipient {
             Some(id) => id,
             None => {
                 let error = format!("No recipient found for targeting: {:?}", message.to);
@@ -224,7 +644,12 @@ impl<T: TmuxSender> MessageRouter<T> {
         };
 
         // Check if expert is idle (non-blocking delivery requirement)
-        if !self.is_expert_idle(expert_id).await? {
+        if self.may_preempt(expert_id, message) {
+            info!(
+                "Preempting busy expert {} with message {}",
+                expert_id, message.message_id
+            );
+        } else if !self.is_expert_idle(expert_id).await? {
             debug!(
                 "Expert {} is not idle, skipping delivery of message {}",
                 expert_id, message.message_id
@@ -242,11 +667,25 @@ impl<T: TmuxSender> MessageRouter<T> {
                     "Successfully delivered message {} to expert {}",
                     message.message_id, expert_id
                 );
+                self.note_delivery(&message.to, expert_id);
                 Ok(DeliveryResult::success(
                     message.message_id.clone(),
                     expert_id,
                 ))
             }
+            Err(RouterError::WindowNotFound(window_id)) => {
+                warn!(
+                    "Window {} for expert {} not found, marking expert offline",
+                    window_id, expert_id
+                );
+                self.expert_registry
+                    .update_expert_state(expert_id, ExpertState::Offline)?;
+                self.newly_offline.push(expert_id);
+                Ok(DeliveryResult::failed(
+                    message.message_id.clone(),
+                    format!("Expert {expert_id} is offline (window {window_id} not found)"),
+                ))
+            }
             Err(e) => {
                 let error = format!("Tmux delivery failed: {e}");
                 warn!("{}", error);
@@ -255,6 +694,22 @@ impl<T: TmuxSender> MessageRouter<T> {
         }
     }
 
+    /// Queue a `Notify` telling the sender that `message` reached `recipient_id`
+    async fn enqueue_delivery_receipt(&self, message: &Message, recipient_id: ExpertId) {
+        let recipient_name = self
+            .expert_registry
+            .get_expert(recipient_id)
+            .map(|expert| expert.name.clone())
+            .unwrap_or_else(|| format!("expert {recipient_id}"));
+        let receipt = message.delivery_receipt(recipient_id, &recipient_name);
+        if let Err(e) = self.queue_manager.enqueue(&receipt).await {
+            warn!(
+                "Failed to queue delivery receipt for message {}: {}",
+                message.message_id, e
+            );
+        }
+    }
+
     /// Check if sender and recipient share the same worktree context
     fn worktree_matches(&self, sender_id: ExpertId, recipient_id: ExpertId) -> bool {
         let sender = match self.expert_registry.get_expert(sender_id) {
@@ -282,10 +737,14 @@ impl<T: TmuxSender> MessageRouter<T> {
 
     /// Find the appropriate recipient expert based on targeting strategy
     ///
-    /// Supports three targeting strategies:
+    /// Supports these targeting strategies:
     /// 1. ExpertId: Direct targeting by expert ID
-    /// 2. ExpertName: Targeting by expert name (case-insensitive)
-    /// 3. Role: Targeting by role (finds first idle expert with matching role)
+    /// 2. Role: Targeting by role (finds the least-loaded idle expert with matching
+    ///    role, skipping experts at the max queue depth)
+    /// 3. Tag: Targeting by tag, resolved the same way as role; `process_queue`
+    ///    first splits these into one message per expert carrying the tag
+    /// 4. Multi: The first member that resolves; `process_queue` splits these
+    ///    into one message per member before delivery
     ///
     /// All strategies enforce worktree affinity: the recipient must share the
     /// same worktree context as the sender.
@@ -294,55 +753,118 @@ impl<T: TmuxSender> MessageRouter<T> {
         recipient: &MessageRecipient,
         sender_id: ExpertId,
     ) -> Result<Option<ExpertId>, RouterError> {
+        Ok(self.resolve_recipient(recipient, sender_id))
+    }
+
+    fn resolve_recipient(
+        &self,
+        recipient: &MessageRecipient,
+        sender_id: ExpertId,
+    ) -> Option<ExpertId> {
         match recipient {
             MessageRecipient::ExpertId { expert_id } => {
                 // Direct targeting by ID
                 if self.expert_registry.get_expert(*expert_id).is_some() {
                     if self.worktree_matches(sender_id, *expert_id) {
-                        Ok(Some(*expert_id))
+                        Some(*expert_id)
                     } else {
                         warn!(
                             "Expert {} is in a different worktree than sender {}",
                             expert_id, sender_id
                         );
-                        Ok(None)
+                        None
                     }
                 } else {
                     warn!("Expert with ID {} not found in registry", expert_id);
-                    Ok(None)
+                    None
                 }
             }
-            MessageRecipient::Role { role } => {
-                // Role-based targeting - find first idle expert with matching role and worktree
+            MessageRecipient::Multi(recipients) => recipients
+                .iter()
+                .find_map(|member| self.resolve_recipient(member, sender_id)),
+            MessageRecipient::Role { .. } | MessageRecipient::Tag { .. } => {
+                // Group targeting - find first idle expert in the group and worktree
                 let sender_worktree = self
                     .expert_registry
                     .get_expert(sender_id)
                     .map(|e| e.worktree_path.clone());
-                let idle_experts = match sender_worktree {
-                    Some(ref wt) => self
-                        .expert_registry
-                        .get_idle_experts_by_role_str_in_worktree(role, wt),
-                    None => {
-                        // Sender not found in registry; fall back to empty
-                        vec![]
-                    }
+                let (mut idle_experts, group) = match (recipient, sender_worktree) {
+                    (MessageRecipient::Role { role }, Some(ref wt)) => (
+                        self.expert_registry
+                            .get_idle_experts_by_role_str_in_worktree(role, wt),
+                        format!("role '{role}'"),
+                    ),
+                    (MessageRecipient::Tag { tag }, Some(ref wt)) => (
+                        self.expert_registry
+                            .get_idle_experts_by_tag_in_worktree(tag, wt),
+                        format!("tag '{tag}'"),
+                    ),
+                    // Sender not found in registry; fall back to empty
+                    _ => (vec![], format!("{recipient:?}")),
                 };
 
-                if idle_experts.is_empty() {
-                    debug!(
-                        "No idle experts found for role '{}' in sender's worktree",
-                        role
-                    );
-                    Ok(None)
-                } else {
-                    let expert_id = idle_experts[0];
-                    debug!("Found idle expert {} for role '{}'", expert_id, role);
-                    Ok(Some(expert_id))
+                idle_experts.sort_unstable();
+                if self.max_queue_depth > 0 {
+                    idle_experts.retain(|id| !self.is_saturated(*id));
+                }
+
+                match self.pick_group_member(recipient, &idle_experts) {
+                    Some(expert_id) => {
+                        debug!("Found idle expert {} for {}", expert_id, group);
+                        Some(expert_id)
+                    }
+                    None => {
+                        debug!(
+                            "No idle, unsaturated experts found for {} in sender's worktree",
+                            group
+                        );
+                        None
+                    }
                 }
             }
         }
     }
 
+    /// Apply the recipient strategy to a group's idle experts, given in id order
+    fn pick_group_member(
+        &self,
+        group: &MessageRecipient,
+        candidates: &[ExpertId],
+    ) -> Option<ExpertId> {
+        let depth = |id: &ExpertId| self.queue_depths.get(id).copied().unwrap_or(0);
+        match self.recipient_strategy {
+            RecipientStrategy::First if self.max_queue_depth > 0 => {
+                candidates.iter().copied().min_by_key(depth)
+            }
+            RecipientStrategy::First => candidates.first().copied(),
+            RecipientStrategy::RoundRobin => {
+                let last = self.last_group_recipient.get(group);
+                candidates
+                    .iter()
+                    .copied()
+                    .find(|id| last.is_some_and(|last| id > last))
+                    .or_else(|| candidates.first().copied())
+            }
+            RecipientStrategy::LeastRecentlyDelivered => candidates
+                .iter()
+                .copied()
+                .min_by_key(|id| self.last_delivery_seq.get(id).copied().unwrap_or(0)),
+            RecipientStrategy::LeastLoaded => candidates.iter().copied().min_by_key(depth),
+        }
+    }
+
+    /// Remember a delivery for the round-robin and least-recently-delivered strategies
+    fn note_delivery(&mut self, to: &MessageRecipient, expert_id: ExpertId) {
+        self.delivery_seq += 1;
+        self.last_delivery_seq.insert(expert_id, self.delivery_seq);
+        if matches!(
+            to,
+            MessageRecipient::Role { .. } | MessageRecipient::Tag { .. }
+        ) {
+            self.last_group_recipient.insert(to.clone(), expert_id);
+        }
+    }
+
     /// Check if a specific expert is idle and available for message delivery
     pub async fn is_expert_idle(&self, expert_id: ExpertId) -> Result<bool, RouterError> {
         match self.expert_registry.is_expert_idle(expert_id) {
@@ -357,7 +879,9 @@ impl<T: TmuxSender> MessageRouter<T> {
     /// Deliver a message to an expert via tmux
     ///
     /// This method formats the message for delivery and sends it to the expert's
-    /// tmux pane using the standardized message format.
+    /// tmux pane using the standardized message format. With inbox files enabled
+    /// the message is written to the expert's inbox first and the pane only gets
+    /// a pointer to that file.
     pub async fn deliver_via_tmux(
         &self,
         expert_id: ExpertId,
@@ -377,16 +901,32 @@ impl<T: TmuxSender> MessageRouter<T> {
             ))
         })?;
 
+        let message = self.annotate_missing_attachments(expert_id, message);
+        let message = message.as_ref();
+
         // Format message for delivery
-        let formatted_message =
-            self.format_message_for_delivery(message, expert_info.name.as_str());
+        let formatted_message = if self.inbox_files {
+            let path = self.write_inbox_file(expert_id, message).await?;
+            format!(
+                "[macot] Message from {}: {} (full message: {})",
+                self.sender_name(message),
+                message.content.subject,
+                path.display()
+            )
+        } else {
+            self.format_message_for_delivery(message, expert_info.name.as_str())
+        };
 
         // Send message via tmux
         self.tmux_sender
             .send_keys_with_enter(window_id, &formatted_message)
             .await
             .map_err(|e| {
-                RouterError::Tmux(format!("Failed to send message to window {window_id}: {e}"))
+                if is_window_not_found_error(&e) {
+                    RouterError::WindowNotFound(window_id)
+                } else {
+                    RouterError::Tmux(format!("Failed to send message to window {window_id}: {e}"))
+                }
             })?;
 
         debug!(
@@ -397,60 +937,84 @@ impl<T: TmuxSender> MessageRouter<T> {
         Ok(())
     }
 
+    /// The expert's worktree, or the project root for experts outside one
+    fn working_dir(&self, expert_id: ExpertId) -> Option<PathBuf> {
+        self.expert_registry
+            .get_expert(expert_id)
+            .and_then(|info| info.worktree_path.clone())
+            .map(PathBuf::from)
+            .or_else(|| self.project_root.clone())
+    }
+
+    /// Mark attachments the recipient cannot open, e.g. a file that only
+    /// exists on the sender's branch, so it does not go looking for them
+    fn annotate_missing_attachments<'a>(
+        &self,
+        expert_id: ExpertId,
+        message: &'a Message,
+    ) -> Cow<'a, Message> {
+        let Some(recipient_dir) = self.working_dir(expert_id) else {
+            return Cow::Borrowed(message);
+        };
+        let missing: Vec<usize> = message
+            .content
+            .attachments
+            .iter()
+            .enumerate()
+            .filter(|(_, attachment)| !attachment_within(&recipient_dir, attachment))
+            .map(|(index, _)| index)
+            .collect();
+        if missing.is_empty() {
+            return Cow::Borrowed(message);
+        }
+
+        let mut annotated = message.clone();
+        for index in missing {
+            let attachment = &mut annotated.content.attachments[index];
+            warn!(
+                "Message {}: attachment {} is missing in {} for expert {}",
+                message.message_id,
+                attachment,
+                recipient_dir.display(),
+                expert_id
+            );
+            attachment.push_str(MISSING_ATTACHMENT_NOTE);
+        }
+        Cow::Owned(annotated)
+    }
+
     /// Format a message for standardized delivery to experts
     ///
     /// Creates a consistent message format that includes all required information
     /// for the receiving expert to understand and process the message.
     fn format_message_for_delivery(&self, message: &Message, recipient_name: &str) -> String {
-        let message_type = match message.message_type {
-            crate::models::MessageType::Query => "QUERY",
-            crate::models::MessageType::Response => "RESPONSE",
-            crate::models::MessageType::Notify => "NOTIFICATION",
-            crate::models::MessageType::Delegate => "TASK_DELEGATION",
-        };
-
-        let priority = match message.priority {
-            crate::models::MessagePriority::High => "HIGH",
-            crate::models::MessagePriority::Normal => "NORMAL",
-            crate::models::MessagePriority::Low => "LOW",
-        };
+        self.formatter
+            .format(message, self.sender_name(message), recipient_name)
+    }
 
-        let sender_info = self
-            .expert_registry
+    fn sender_name(&self, message: &Message) -> &str {
+        self.expert_registry
             .get_expert(message.from_expert_id)
             .map(|expert| expert.name.as_str())
-            .unwrap_or("Unknown");
-
-        // Create standardized message format
-        format!(
-            "📨 INCOMING MESSAGE [{}] 📨\n\
-            From: {} (Expert {})\n\
-            To: {}\n\
-            Type: {}\n\
-            Priority: {}\n\
-            Subject: {}\n\
-            \n\
-            {}\n\
-            \n\
-            Message ID: {}\n\
-            Timestamp: {}\n\
-            {}",
-            priority,
-            sender_info,
-            message.from_expert_id,
-            recipient_name,
-            message_type,
-            priority,
-            message.content.subject,
-            message.content.body,
-            message.message_id,
-            message.created_at.format("%Y-%m-%d %H:%M:%S UTC"),
-            if let Some(reply_to) = &message.reply_to {
-                format!("Reply to: {reply_to}")
-            } else {
-                String::new()
-            }
-        )
+            .unwrap_or("Unknown")
+    }
+
+    /// Write the full message as YAML to the recipient's inbox, returning its path
+    async fn write_inbox_file(
+        &self,
+        expert_id: ExpertId,
+        message: &Message,
+    ) -> Result<PathBuf, RouterError> {
+        let dir = self.queue_manager.inbox_path(expert_id);
+        let path = dir.join(format!("{}.yaml", message.message_id));
+        let yaml = serde_yaml::to_string(message).context("Failed to serialize message")?;
+        tokio::fs::create_dir_all(&dir)
+            .await
+            .with_context(|| format!("Failed to create inbox {}", dir.display()))?;
+        tokio::fs::write(&path, yaml)
+            .await
+            .with_context(|| format!("Failed to write inbox file {}", path.display()))?;
+        Ok(path)
     }
 
     /// Get access to the queue manager for external operations
@@ -480,15 +1044,138 @@ impl<T: TmuxSender> MessageRouter<T> {
     /// This method processes messages from the outbox directory and moves
     /// valid messages to the main queue for delivery processing.
     pub async fn process_outbox(&mut self) -> Result<Vec<MessageId>, RouterError> {
-        let processed = self.queue_manager.process_outbox().await?;
+        let mut processed = Vec::new();
+        for message_id in self.queue_manager.process_outbox().await? {
+            if self.default_recipient_allowed(&message_id).await? {
+                processed.push(message_id);
+            } else {
+                self.queue_manager.dequeue(&message_id).await?;
+            }
+        }
 
         if !processed.is_empty() {
             info!("Processed {} messages from outbox", processed.len());
         }
 
+        for message_id in &processed {
+            self.validate_attachments(message_id).await?;
+        }
+
+        if self.reply_priority_boost {
+            for message_id in &processed {
+                self.boost_reply_priority(message_id).await?;
+            }
+        }
+
         Ok(processed)
     }
 
+    /// Drop attachments that do not name an existing file inside the sender's
+    /// working directory (its worktree, or the project root)
+    async fn validate_attachments(&self, message_id: &str) -> Result<(), RouterError> {
+        let Some(mut queued) = self.queue_manager.read_message(message_id).await? else {
+            return Ok(());
+        };
+        if queued.message.content.attachments.is_empty() {
+            return Ok(());
+        }
+        let Some(sender_dir) = self.working_dir(queued.message.from_expert_id) else {
+            return Ok(());
+        };
+
+        let (valid, invalid): (Vec<String>, Vec<String>) = queued
+            .message
+            .content
+            .attachments
+            .drain(..)
+            .partition(|attachment| attachment_within(&sender_dir, attachment));
+        if invalid.is_empty() {
+            return Ok(());
+        }
+
+        warn!(
+            "Message {}: dropping attachments outside {} or missing: {}",
+            message_id,
+            sender_dir.display(),
+            invalid.join(", ")
+        );
+        queued.message.content.attachments = valid;
+        self.queue_manager
+            .update_message_status(message_id, &queued)
+            .await?;
+        Ok(())
+    }
+
+    /// Check that a message addressed via the sender's default recipient targets a
+    /// registered expert in the sender's worktree; other messages always pass
+    async fn default_recipient_allowed(&self, message_id: &str) -> Result<bool, RouterError> {
+        let Some(queued) = self.queue_manager.read_message(message_id).await? else {
+            return Ok(true);
+        };
+        let message = &queued.message;
+        let Some(recipient) = message
+            .metadata
+            .get(DEFAULT_RECIPIENT_METADATA_KEY)
+            .and_then(|id| id.parse::<ExpertId>().ok())
+        else {
+            return Ok(true);
+        };
+
+        if self.expert_registry.get_expert(recipient).is_none()
+            || !self.worktree_matches(message.from_expert_id, recipient)
+        {
+            warn!(
+                "Dropping message {}: default recipient {} of expert {} is not in the same worktree",
+                message_id, recipient, message.from_expert_id
+            );
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    /// Raise a queued reply to High priority when it answers a High-priority query
+    ///
+    /// The original is looked up among delivered High-priority queries first, then
+    /// in the queue itself (when the reply arrives before the original is dequeued).
+    async fn boost_reply_priority(&mut self, message_id: &str) -> Result<(), RouterError> {
+        let Some(mut queued) = self.queue_manager.read_message(message_id).await? else {
+            return Ok(());
+        };
+        let Some(reply_to) = queued.message.reply_to.clone() else {
+            return Ok(());
+        };
+        // The query is answered; it no longer needs tracking
+        let delivered_high = self.high_priority_queries.remove(&reply_to).is_some();
+        if queued.message.priority == MessagePriority::High {
+            return Ok(());
+        }
+
+        let original_is_high = if delivered_high {
+            true
+        } else {
+            self.queue_manager
+                .read_message(&reply_to)
+                .await?
+                .is_some_and(|original| {
+                    original.message.message_type == MessageType::Query
+                        && original.message.priority == MessagePriority::High
+                })
+        };
+
+        if original_is_high {
+            info!(
+                "Boosting reply {} to High priority (reply to High-priority query {})",
+                message_id, reply_to
+            );
+            queued.message.priority = MessagePriority::High;
+            self.queue_manager
+                .update_message_status(message_id, &queued)
+                .await?;
+        }
+
+        Ok(())
+    }
+
     /// Get current queue statistics
     #[allow(dead_code)]
     pub async fn get_queue_stats(&self) -> Result<QueueStats, RouterError> {
@@ -510,6 +1197,13 @@ impl<T: TmuxSender> MessageRouter<T> {
             }
         }
 
+        for message in &pending_messages {
+            match message.message.message_type.lane() {
+                MessageLane::Task => stats.task_lane += 1,
+                MessageLane::Notify => stats.notify_lane += 1,
+            }
+        }
+
         // Count by status
         for message in &all_messages {
             if message.is_expired() {
@@ -519,10 +1213,39 @@ impl<T: TmuxSender> MessageRouter<T> {
             }
         }
 
+        let delivered = self.queue_manager.list_delivered().await?;
+        let mut latencies = Vec::new();
+        let mut by_expert: BTreeMap<ExpertId, Vec<Duration>> = BTreeMap::new();
+        for record in &delivered {
+            if let Some(latency) = record.latency() {
+                latencies.push(latency);
+                by_expert
+                    .entry(record.to_expert_id)
+                    .or_default()
+                    .push(latency);
+            }
+        }
+        stats.latency_p50 = percentile(&mut latencies, 50);
+        stats.latency_p95 = percentile(&mut latencies, 95);
+        stats.latency_p95_by_expert = by_expert
+            .into_iter()
+            .filter_map(|(id, mut samples)| Some((id, percentile(&mut samples, 95)?)))
+            .collect();
+
         Ok(stats)
     }
 }
 
+/// Nearest-rank percentile (`pct` in 1..=100); sorts `samples` in place
+fn percentile(samples: &mut [Duration], pct: usize) -> Option<Duration> {
+    if samples.is_empty() {
+        return None;
+    }
+    samples.sort_unstable();
+    let rank = (samples.len() * pct).div_ceil(100).max(1);
+    Some(samples[rank - 1])
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, Default)]
 pub struct QueueStats {
@@ -531,8 +1254,16 @@ pub struct QueueStats {
     pub high_priority: usize,
     pub normal_priority: usize,
     pub low_priority: usize,
+    /// Pending messages by delivery lane
+    pub task_lane: usize,
+    pub notify_lane: usize,
     pub expired_messages: usize,
     pub failed_messages: usize,
+    /// Queue-to-delivery latency over the delivered-message log
+    pub latency_p50: Option<Duration>,
+    pub latency_p95: Option<Duration>,
+    /// Per recipient, to spot chronically busy experts holding up their queue
+    pub latency_p95_by_expert: BTreeMap<ExpertId, Duration>,
 }
 
 #[cfg(test)]
@@ -552,11 +1283,46 @@ mod mock_tmux {
             Ok(String::new())
         }
     }
+
+    /// Sender that keeps every key sequence it was asked to send
+    #[derive(Clone, Default)]
+    pub struct RecordingTmuxSender {
+        pub sent: std::sync::Arc<std::sync::Mutex<Vec<(u32, String)>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl TmuxSender for RecordingTmuxSender {
+        async fn send_keys(&self, pane_id: u32, keys: &str) -> anyhow::Result<()> {
+            self.sent.lock().unwrap().push((pane_id, keys.to_string()));
+            Ok(())
+        }
+
+        async fn capture_pane(&self, _pane_id: u32) -> anyhow::Result<String> {
+            Ok(String::new())
+        }
+    }
+
+    /// Sender whose target window has been closed, as after an agent crash
+    #[derive(Clone)]
+    pub struct MissingWindowTmuxSender;
+
+    #[async_trait::async_trait]
+    impl TmuxSender for MissingWindowTmuxSender {
+        async fn send_keys(&self, pane_id: u32, _keys: &str) -> anyhow::Result<()> {
+            anyhow::bail!(
+                "send-keys to window {pane_id}: tmux exited with exit status: 1: can't find window: {pane_id}"
+            )
+        }
+
+        async fn capture_pane(&self, _pane_id: u32) -> anyhow::Result<String> {
+            Ok(String::new())
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::mock_tmux::MockTmuxSender;
+    use super::mock_tmux::{MissingWindowTmuxSender, MockTmuxSender, RecordingTmuxSender};
     use super::*;
     use crate::models::{
         ExpertInfo, ExpertState, MessageContent, MessagePriority, MessageType, Role,
@@ -564,6 +1330,12 @@ mod tests {
     use tempfile::TempDir;
 
     async fn create_test_router() -> (MessageRouter<MockTmuxSender>, TempDir) {
+        create_test_router_with_sender(MockTmuxSender).await
+    }
+
+    async fn create_test_router_with_sender<S: TmuxSender>(
+        sender: S,
+    ) -> (MessageRouter<S>, TempDir) {
         let temp_dir = TempDir::new().unwrap();
         let queue_manager = QueueManager::new(temp_dir.path().to_path_buf());
         queue_manager.init().await.unwrap();
@@ -589,7 +1361,7 @@ mod tests {
         expert_registry.register_expert(expert1).unwrap();
         expert_registry.register_expert(expert2).unwrap();
 
-        let router = MessageRouter::new(queue_manager, expert_registry, MockTmuxSender);
+        let router = MessageRouter::new(queue_manager, expert_registry, sender);
         (router, temp_dir)
     }
 
@@ -597,6 +1369,7 @@ mod tests {
         let content = MessageContent {
             subject: "Test Subject".to_string(),
             body: "Test Body".to_string(),
+            attachments: Vec::new(),
         };
         let recipient = MessageRecipient::expert_id(1);
         Message::new(1, recipient, MessageType::Query, content)
@@ -638,22 +1411,509 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn find_recipient_by_role_returns_none_when_no_idle_experts() {
+    async fn resolve_multi_returns_union_of_valid_recipients_without_duplicates() {
         let (mut router, _temp) = create_test_router().await;
-
-        // Set all experts to busy
-        router
-            .expert_registry_mut()
-            .update_expert_state(1, ExpertState::Busy)
-            .unwrap();
         router
             .expert_registry_mut()
-            .update_expert_state(2, ExpertState::Busy)
+            .update_expert_state(1, ExpertState::Idle)
             .unwrap();
 
-        let recipient = MessageRecipient::role("developer".to_string());
-        let result = router.find_recipient(&recipient, 1).await.unwrap();
-        assert_eq!(result, None);
+        let to = MessageRecipient::multi(vec![
+            MessageRecipient::expert_id(1),
+            MessageRecipient::role("developer"),
+            MessageRecipient::expert_id(1),
+            MessageRecipient::expert_id(999),
+        ]);
+        assert_eq!(
+            router.resolve_multi(&to, 2),
+            vec![MessageRecipient::expert_id(1)],
+            "resolve_multi: the role resolves to expert 1, already targeted by ID, \
+             and unknown IDs are dropped"
+        );
+
+        let to = MessageRecipient::multi(vec![
+            MessageRecipient::expert_id(2),
+            MessageRecipient::role("developer"),
+            MessageRecipient::role("reviewer"),
+        ]);
+        assert_eq!(
+            router.resolve_multi(&to, 2),
+            vec![
+                MessageRecipient::expert_id(2),
+                MessageRecipient::expert_id(1),
+                MessageRecipient::role("reviewer"),
+            ],
+            "resolve_multi: a role with no idle expert should wait as-is"
+        );
+    }
+
+    #[tokio::test]
+    async fn process_queue_delivers_multi_once_per_recipient_with_receipts() {
+        let (mut router, _temp) = create_test_router().await;
+        for id in [1, 2] {
+            router
+                .expert_registry_mut()
+                .update_expert_state(id, ExpertState::Idle)
+                .unwrap();
+        }
+        let to = MessageRecipient::multi(vec![
+            MessageRecipient::expert_id(1),
+            MessageRecipient::expert_id(2),
+            MessageRecipient::role("developer"),
+        ]);
+        let mut message = notify(2, to, "Heads up");
+        message.request_delivery_receipt = true;
+        router.queue_manager_mut().enqueue(&message).await.unwrap();
+
+        let stats = router.process_queue().await.unwrap();
+        assert_eq!(
+            stats.delivered_expert_ids,
+            vec![1, 2],
+            "process_queue: each resolved recipient should get one delivery"
+        );
+
+        let pending = router.queue_manager().get_pending_messages().await.unwrap();
+        assert_eq!(
+            pending.len(),
+            2,
+            "process_queue: one receipt per resolved recipient, original removed"
+        );
+        let mut receipt_senders: Vec<ExpertId> = pending
+            .iter()
+            .map(|queued| queued.message.from_expert_id)
+            .collect();
+        receipt_senders.sort_unstable();
+        assert_eq!(receipt_senders, vec![1, 2]);
+        assert!(pending
+            .iter()
+            .all(|queued| queued.message.to == MessageRecipient::expert_id(2)));
+    }
+
+    fn register_idle_developer(router: &mut MessageRouter<MockTmuxSender>, id: ExpertId) {
+        let expert = ExpertInfo::new(
+            id,
+            format!("developer-{id}"),
+            Role::Developer,
+            "test-session".to_string(),
+            id.to_string(),
+        );
+        router
+            .expert_registry_mut()
+            .register_expert(expert)
+            .unwrap();
+        router
+            .expert_registry_mut()
+            .update_expert_state(id, ExpertState::Idle)
+            .unwrap();
+    }
+
+    fn notify(from: ExpertId, to: MessageRecipient, subject: &str) -> Message {
+        Message::new(
+            from,
+            to,
+            MessageType::Notify,
+            MessageContent {
+                subject: subject.to_string(),
+                body: "Body".to_string(),
+                attachments: Vec::new(),
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn process_queue_preempts_busy_expert_only_for_flagged_high_priority() {
+        let (router, _temp) = create_test_router().await;
+        let mut router = router.with_preemption(true);
+        router
+            .expert_registry_mut()
+            .update_expert_state(1, ExpertState::Busy)
+            .unwrap();
+
+        let normal = notify(2, MessageRecipient::expert_id(1), "Routine").with_preempt();
+        tokio::time::sleep(tokio::time::Duration::from_millis(2)).await;
+        let urgent = notify(
+            2,
+            MessageRecipient::expert_id(1),
+            "Stop, requirements changed",
+        )
+        .with_priority(MessagePriority::High)
+        .with_preempt();
+        router.queue_manager_mut().enqueue(&normal).await.unwrap();
+        router.queue_manager_mut().enqueue(&urgent).await.unwrap();
+
+        let stats = router.process_queue().await.unwrap();
+        assert_eq!(
+            stats.delivered_expert_ids,
+            vec![1],
+            "process_queue: a preemptive High message should reach a Busy expert"
+        );
+        assert_eq!(
+            stats.delivered_subjects,
+            vec![(1, "Stop, requirements changed".to_string())]
+        );
+        let pending = router.queue_manager().get_pending_messages().await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(
+            pending[0].message.message_id, normal.message_id,
+            "process_queue: a Normal message should wait for the Busy expert"
+        );
+    }
+
+    #[tokio::test]
+    async fn process_queue_ignores_preempt_flag_unless_allowed() {
+        let (mut router, _temp) = create_test_router().await;
+        router
+            .expert_registry_mut()
+            .update_expert_state(1, ExpertState::Busy)
+            .unwrap();
+
+        let urgent = notify(2, MessageRecipient::expert_id(1), "Urgent")
+            .with_priority(MessagePriority::High)
+            .with_preempt();
+        router.queue_manager_mut().enqueue(&urgent).await.unwrap();
+
+        let stats = router.process_queue().await.unwrap();
+        assert_eq!(
+            stats.messages_delivered, 0,
+            "process_queue: preemption should be off unless the config allows it"
+        );
+    }
+
+    #[tokio::test]
+    async fn find_recipient_by_role_skips_saturated_expert() {
+        let (router, _temp) = create_test_router().await;
+        let mut router = router.with_max_queue_depth(2);
+        router
+            .expert_registry_mut()
+            .update_expert_state(1, ExpertState::Idle)
+            .unwrap();
+        register_idle_developer(&mut router, 3);
+        // The sender shares the role; keep it out of the candidate set
+        router
+            .expert_registry_mut()
+            .update_expert_state(2, ExpertState::Busy)
+            .unwrap();
+        router.queue_depths = HashMap::from([(1, 2), (3, 1)]);
+
+        let recipient = MessageRecipient::role("developer".to_string());
+        assert_eq!(
+            router.find_recipient(&recipient, 2).await.unwrap(),
+            Some(3),
+            "find_recipient: role targeting should avoid an expert at max queue depth"
+        );
+
+        router.queue_depths.insert(3, 2);
+        assert_eq!(
+            router.find_recipient(&recipient, 2).await.unwrap(),
+            None,
+            "find_recipient: role targeting should wait when every peer is saturated"
+        );
+    }
+
+    #[tokio::test]
+    async fn process_queue_balances_role_messages_away_from_saturated_expert() {
+        let (router, _temp) = create_test_router().await;
+        let mut router = router.with_max_queue_depth(1);
+        router
+            .expert_registry_mut()
+            .update_expert_state(1, ExpertState::Idle)
+            .unwrap();
+        register_idle_developer(&mut router, 3);
+        // The sender shares the role; keep it out of the candidate set
+        router
+            .expert_registry_mut()
+            .update_expert_state(2, ExpertState::Busy)
+            .unwrap();
+
+        let direct =
+            notify(2, MessageRecipient::expert_id(1), "Direct").with_priority(MessagePriority::Low);
+        tokio::time::sleep(tokio::time::Duration::from_millis(2)).await;
+        let by_role = notify(
+            2,
+            MessageRecipient::role("developer".to_string()),
+            "By role",
+        )
+        .with_priority(MessagePriority::High);
+        router.queue_manager_mut().enqueue(&direct).await.unwrap();
+        router.queue_manager_mut().enqueue(&by_role).await.unwrap();
+
+        router.process_queue().await.unwrap();
+
+        let delivered = router.queue_manager().list_delivered().await.unwrap();
+        let role_delivery = delivered
+            .iter()
+            .find(|d| d.message_id == by_role.message_id)
+            .expect("role-targeted message should be delivered");
+        assert_eq!(
+            role_delivery.to_expert_id, 3,
+            "process_queue: role message should go to the idle peer, not the saturated expert"
+        );
+    }
+
+    #[tokio::test]
+    async fn process_queue_defers_direct_messages_beyond_max_depth() {
+        let (router, _temp) = create_test_router().await;
+        let mut router = router.with_max_queue_depth(1);
+        router
+            .expert_registry_mut()
+            .update_expert_state(1, ExpertState::Busy)
+            .unwrap();
+
+        for subject in ["first", "second", "third"] {
+            let message = notify(2, MessageRecipient::expert_id(1), subject);
+            router.queue_manager_mut().enqueue(&message).await.unwrap();
+            tokio::time::sleep(tokio::time::Duration::from_millis(2)).await;
+        }
+
+        let stats = router.process_queue().await.unwrap();
+
+        assert_eq!(
+            stats.messages_skipped, 2,
+            "process_queue: messages beyond the max depth should be deferred"
+        );
+        assert_eq!(stats.messages_failed, 1);
+        let pending = router.queue_manager().get_pending_messages().await.unwrap();
+        assert_eq!(pending.len(), 3, "deferred messages should stay queued");
+        assert_eq!(
+            pending.iter().map(|m| m.attempts).sum::<u32>(),
+            1,
+            "process_queue: deferred messages should not use up delivery attempts"
+        );
+    }
+
+    #[tokio::test]
+    async fn process_queue_counts_role_messages_towards_the_resolved_expert_depth() {
+        let (router, _temp) = create_test_router().await;
+        let mut router = router.with_max_queue_depth(2);
+        router
+            .expert_registry_mut()
+            .update_expert_state(1, ExpertState::Idle)
+            .unwrap();
+        // The sender shares the role; keep it out of the candidate set
+        router
+            .expert_registry_mut()
+            .update_expert_state(2, ExpertState::Busy)
+            .unwrap();
+
+        for subject in ["role one", "role two"] {
+            let message = notify(2, MessageRecipient::role("developer".to_string()), subject)
+                .with_priority(MessagePriority::High);
+            router.queue_manager_mut().enqueue(&message).await.unwrap();
+            tokio::time::sleep(tokio::time::Duration::from_millis(2)).await;
+        }
+        let direct = notify(2, MessageRecipient::expert_id(1), "direct");
+        router.queue_manager_mut().enqueue(&direct).await.unwrap();
+
+        let stats = router.process_queue().await.unwrap();
+
+        assert_eq!(
+            stats.delivered_subjects,
+            vec![(1, "role one".to_string()), (1, "role two".to_string())],
+            "process_queue: role messages resolved to an expert should count towards its depth"
+        );
+        assert_eq!(stats.messages_skipped, 1);
+        let pending = router.queue_manager().get_pending_messages().await.unwrap();
+        assert_eq!(pending.len(), 1, "the direct message should stay queued");
+        assert_eq!(pending[0].message.message_id, direct.message_id);
+    }
+
+    #[tokio::test]
+    async fn process_queue_saturated_notify_lane_still_delivers_query() {
+        let (router, _temp) = create_test_router().await;
+        let mut router = router.with_max_deliveries_per_cycle(1);
+        router
+            .expert_registry_mut()
+            .update_expert_state(1, ExpertState::Idle)
+            .unwrap();
+
+        for subject in ["build started", "lint passed", "tests passed"] {
+            let message = notify(2, MessageRecipient::expert_id(1), subject);
+            router.queue_manager_mut().enqueue(&message).await.unwrap();
+            tokio::time::sleep(tokio::time::Duration::from_millis(2)).await;
+        }
+        let query = Message::new(
+            2,
+            MessageRecipient::expert_id(1),
+            MessageType::Query,
+            MessageContent {
+                subject: "Which schema?".to_string(),
+                body: "Urgent".to_string(),
+                attachments: Vec::new(),
+            },
+        );
+        router.queue_manager_mut().enqueue(&query).await.unwrap();
+
+        let queue_stats = router.get_queue_stats().await.unwrap();
+        assert_eq!((queue_stats.notify_lane, queue_stats.task_lane), (3, 1));
+
+        let stats = router.process_queue().await.unwrap();
+
+        assert_eq!(
+            stats.delivered_subjects,
+            vec![
+                (1, "build started".to_string()),
+                (1, "Which schema?".to_string())
+            ],
+            "process_queue: the query should not wait behind the saturated notify lane"
+        );
+        assert_eq!(stats.messages_skipped, 2);
+        let pending = router.queue_manager().get_pending_messages().await.unwrap();
+        assert_eq!(pending.len(), 2, "over-budget notifications stay queued");
+        assert!(pending.iter().all(|m| m.attempts == 0));
+    }
+
+    #[tokio::test]
+    async fn process_queue_applies_lane_budget_to_role_addressed_notifies() {
+        let (router, _temp) = router_with_two_idle_developers(RecipientStrategy::First).await;
+        let mut router = router.with_max_deliveries_per_cycle(1);
+
+        for subject in ["build started", "lint passed", "tests passed"] {
+            let message = notify(2, MessageRecipient::role("developer".to_string()), subject);
+            router.queue_manager_mut().enqueue(&message).await.unwrap();
+            tokio::time::sleep(tokio::time::Duration::from_millis(2)).await;
+        }
+        let query = Message::new(
+            2,
+            MessageRecipient::role("developer".to_string()),
+            MessageType::Query,
+            MessageContent {
+                subject: "Which schema?".to_string(),
+                body: "Urgent".to_string(),
+                attachments: Vec::new(),
+            },
+        );
+        router.queue_manager_mut().enqueue(&query).await.unwrap();
+
+        let stats = router.process_queue().await.unwrap();
+
+        assert_eq!(
+            stats.delivered_subjects,
+            vec![
+                (1, "build started".to_string()),
+                (1, "Which schema?".to_string())
+            ],
+            "process_queue: role-addressed notifies should share the resolved expert's lane budget"
+        );
+        assert_eq!(stats.messages_skipped, 2);
+        let pending = router.queue_manager().get_pending_messages().await.unwrap();
+        assert!(
+            pending.iter().all(|m| m.attempts == 0),
+            "process_queue: over-budget notifies should not use up delivery attempts"
+        );
+    }
+
+    #[tokio::test]
+    async fn process_queue_delivers_tag_message_to_every_carrier() {
+        let (mut router, _temp) = create_test_router().await;
+        router
+            .expert_registry_mut()
+            .update_expert_state(1, ExpertState::Idle)
+            .unwrap();
+        register_idle_developer(&mut router, 3);
+        for id in [1, 2, 3] {
+            let expert = router.expert_registry_mut().get_expert_mut(id).unwrap();
+            expert.tags = vec!["core".to_string()];
+        }
+
+        let message = notify(2, MessageRecipient::tag("core"), "Schema changed");
+        router.queue_manager_mut().enqueue(&message).await.unwrap();
+
+        let stats = router.process_queue().await.unwrap();
+
+        let mut delivered = stats.delivered_expert_ids.clone();
+        delivered.sort_unstable();
+        assert_eq!(
+            delivered,
+            vec![1, 3],
+            "process_queue: a tag message should reach every carrier except the sender"
+        );
+    }
+
+    async fn deliver_to_developers(router: &mut MessageRouter<MockTmuxSender>) -> Vec<ExpertId> {
+        let mut recipients = Vec::new();
+        for i in 0..4 {
+            let message = notify(
+                2,
+                MessageRecipient::role("developer".to_string()),
+                &format!("task {i}"),
+            );
+            router.queue_manager_mut().enqueue(&message).await.unwrap();
+            let stats = router.process_queue().await.unwrap();
+            recipients.extend(stats.delivered_expert_ids);
+            tokio::time::sleep(tokio::time::Duration::from_millis(2)).await;
+        }
+        recipients
+    }
+
+    async fn router_with_two_idle_developers(
+        strategy: RecipientStrategy,
+    ) -> (MessageRouter<MockTmuxSender>, TempDir) {
+        let (router, temp) = create_test_router().await;
+        let mut router = router.with_recipient_strategy(strategy);
+        router
+            .expert_registry_mut()
+            .update_expert_state(1, ExpertState::Idle)
+            .unwrap();
+        register_idle_developer(&mut router, 3);
+        // The sender shares the role; keep it out of the candidate set
+        router
+            .expert_registry_mut()
+            .update_expert_state(2, ExpertState::Busy)
+            .unwrap();
+        (router, temp)
+    }
+
+    #[tokio::test]
+    async fn round_robin_alternates_between_idle_experts_of_a_role() {
+        let (mut router, _temp) =
+            router_with_two_idle_developers(RecipientStrategy::RoundRobin).await;
+        assert_eq!(
+            deliver_to_developers(&mut router).await,
+            vec![1, 3, 1, 3],
+            "round_robin: successive role messages should alternate between idle peers"
+        );
+
+        let (mut router, _temp) = router_with_two_idle_developers(RecipientStrategy::First).await;
+        assert_eq!(
+            deliver_to_developers(&mut router).await,
+            vec![1, 1, 1, 1],
+            "first: the default keeps picking the lowest id"
+        );
+    }
+
+    #[tokio::test]
+    async fn least_recently_delivered_prefers_expert_without_recent_work() {
+        let (mut router, _temp) =
+            router_with_two_idle_developers(RecipientStrategy::LeastRecentlyDelivered).await;
+        let direct = notify(2, MessageRecipient::expert_id(1), "direct");
+        router.queue_manager_mut().enqueue(&direct).await.unwrap();
+        router.process_queue().await.unwrap();
+        tokio::time::sleep(tokio::time::Duration::from_millis(2)).await;
+
+        assert_eq!(
+            deliver_to_developers(&mut router).await,
+            vec![3, 1, 3, 1],
+            "least_recently_delivered: a direct message counts as recent work"
+        );
+    }
+
+    #[tokio::test]
+    async fn find_recipient_by_role_returns_none_when_no_idle_experts() {
+        let (mut router, _temp) = create_test_router().await;
+
+        // Set all experts to busy
+        router
+            .expert_registry_mut()
+            .update_expert_state(1, ExpertState::Busy)
+            .unwrap();
+        router
+            .expert_registry_mut()
+            .update_expert_state(2, ExpertState::Busy)
+            .unwrap();
+
+        let recipient = MessageRecipient::role("developer".to_string());
+        let result = router.find_recipient(&recipient, 1).await.unwrap();
+        assert_eq!(result, None);
     }
 
     #[tokio::test]
@@ -681,24 +1941,218 @@ mod tests {
         let message = create_test_message();
         let formatted = router.format_message_for_delivery(&message, "backend-dev");
 
-        assert!(formatted.contains("📨 INCOMING MESSAGE"));
-        assert!(formatted.contains("From: backend-dev (Expert 1)"));
-        assert!(formatted.contains("To: backend-dev"));
-        assert!(formatted.contains("Type: QUERY"));
-        assert!(formatted.contains("Priority: NORMAL"));
-        assert!(formatted.contains("Subject: Test Subject"));
-        assert!(formatted.contains("Test Body"));
-        assert!(formatted.contains(&message.message_id));
+        assert!(formatted.contains("📨 INCOMING MESSAGE"));
+        assert!(formatted.contains("From: backend-dev (Expert 1)"));
+        assert!(formatted.contains("To: backend-dev"));
+        assert!(formatted.contains("Type: QUERY"));
+        assert!(formatted.contains("Priority: NORMAL"));
+        assert!(formatted.contains("Subject: Test Subject"));
+        assert!(formatted.contains("Test Body"));
+        assert!(formatted.contains(&message.message_id));
+    }
+
+    #[tokio::test]
+    async fn process_queue_handles_empty_queue() {
+        let (mut router, _temp) = create_test_router().await;
+
+        let stats = router.process_queue().await.unwrap();
+        assert_eq!(stats.messages_processed, 0);
+        assert_eq!(stats.messages_delivered, 0);
+        assert_eq!(stats.messages_failed, 0);
+    }
+
+    #[tokio::test]
+    async fn process_queue_delivers_to_each_idle_recipient_before_stacking() {
+        let (mut router, _temp) = create_test_router().await;
+        let low =
+            notify(2, MessageRecipient::expert_id(1), "low").with_priority(MessagePriority::Low);
+        router.queue_manager_mut().enqueue(&low).await.unwrap();
+        tokio::time::sleep(tokio::time::Duration::from_millis(2)).await;
+        for (from, to, subject) in [(2, 1, "a1"), (2, 1, "a2"), (1, 2, "b1")] {
+            let message = notify(from, MessageRecipient::expert_id(to), subject)
+                .with_priority(MessagePriority::High);
+            router.queue_manager_mut().enqueue(&message).await.unwrap();
+            tokio::time::sleep(tokio::time::Duration::from_millis(2)).await;
+        }
+
+        let stats = router.process_queue().await.unwrap();
+
+        assert_eq!(
+            stats.delivered_expert_ids,
+            vec![1, 2, 1, 1],
+            "process_queue: the lone High message to expert 2 should go before the second \
+             one to expert 1, and Low should still wait for every High"
+        );
+    }
+
+    #[test]
+    fn spread_recipients_keeps_per_recipient_order_and_tiers() {
+        let queued = |to: u32, subject: &str, priority: MessagePriority| {
+            QueuedMessage::new(
+                notify(0, MessageRecipient::expert_id(to), subject).with_priority(priority),
+            )
+        };
+        let order: Vec<String> = spread_recipients(vec![
+            queued(1, "h1", MessagePriority::High),
+            queued(1, "h2", MessagePriority::High),
+            queued(1, "h3", MessagePriority::High),
+            queued(2, "h4", MessagePriority::High),
+            queued(2, "n1", MessagePriority::Normal),
+            queued(2, "n2", MessagePriority::Normal),
+            queued(3, "n3", MessagePriority::Normal),
+        ])
+        .into_iter()
+        .map(|m| m.message.content.subject)
+        .collect();
+        assert_eq!(order, ["h1", "h4", "h2", "h3", "n1", "n3", "n2"]);
+    }
+
+    #[tokio::test]
+    async fn process_queue_records_delivered_messages() {
+        let (mut router, _temp) = create_test_router().await;
+        let message = create_test_message();
+        router.queue_manager_mut().enqueue(&message).await.unwrap();
+
+        let stats = router.process_queue().await.unwrap();
+        assert_eq!(stats.messages_delivered, 1);
+
+        let delivered = router.queue_manager().list_delivered().await.unwrap();
+        assert_eq!(
+            delivered.len(),
+            1,
+            "process_queue: delivered message should be logged"
+        );
+        assert_eq!(delivered[0].message_id, message.message_id);
+        assert_eq!(delivered[0].to_expert_id, 1);
+    }
+
+    #[tokio::test]
+    async fn process_queue_records_latency_after_busy_cycles() {
+        let (mut router, _temp) = create_test_router().await;
+        router
+            .expert_registry_mut()
+            .update_expert_state(1, ExpertState::Busy)
+            .unwrap();
+        let mut message = create_test_message();
+        message.created_at = chrono::Utc::now() - chrono::Duration::seconds(5);
+        router.queue_manager_mut().enqueue(&message).await.unwrap();
+
+        for _ in 0..2 {
+            let stats = router.process_queue().await.unwrap();
+            assert_eq!(stats.messages_delivered, 0, "expert 1 is still busy");
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        router
+            .expert_registry_mut()
+            .update_expert_state(1, ExpertState::Idle)
+            .unwrap();
+        assert_eq!(router.process_queue().await.unwrap().messages_delivered, 1);
+
+        let delivered = router.queue_manager().list_delivered().await.unwrap();
+        let record = &delivered[0];
+        assert_eq!(record.queued_at, Some(message.created_at));
+        let elapsed = (record.delivered_at - message.created_at).num_milliseconds() as u64;
+        assert_eq!(
+            record.latency_ms,
+            Some(elapsed),
+            "process_queue: latency should span enqueue to delivery"
+        );
+        assert!(
+            elapsed >= 5_040,
+            "process_queue: latency should include both busy cycles, got {elapsed}ms"
+        );
+
+        let stats = router.get_queue_stats().await.unwrap();
+        assert_eq!(stats.latency_p50, record.latency());
+        assert_eq!(stats.latency_p95, record.latency());
+        assert_eq!(
+            stats.latency_p95_by_expert.get(&1).copied(),
+            record.latency()
+        );
+    }
+
+    #[test]
+    fn percentile_uses_nearest_rank() {
+        let mut samples: Vec<Duration> = (1..=20).rev().map(Duration::from_secs).collect();
+        assert_eq!(percentile(&mut samples, 50), Some(Duration::from_secs(10)));
+        assert_eq!(percentile(&mut samples, 95), Some(Duration::from_secs(19)));
+        assert_eq!(
+            percentile(&mut samples[..1], 95),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(percentile(&mut [], 50), None);
+    }
+
+    #[tokio::test]
+    async fn process_queue_sends_delivery_receipt_when_requested() {
+        let (mut router, _temp) = create_test_router().await;
+        let message = Message::new(
+            2,
+            MessageRecipient::expert_id(1),
+            MessageType::Query,
+            MessageContent {
+                subject: "Schema".to_string(),
+                body: "Which format?".to_string(),
+                attachments: Vec::new(),
+            },
+        )
+        .with_delivery_receipt();
+        router.queue_manager_mut().enqueue(&message).await.unwrap();
+
+        let stats = router.process_queue().await.unwrap();
+        assert_eq!(stats.messages_delivered, 1);
+
+        let pending = router.queue_manager().get_pending_messages().await.unwrap();
+        assert_eq!(
+            pending.len(),
+            1,
+            "process_queue: a receipt should be queued after delivery"
+        );
+        let receipt = &pending[0].message;
+        assert_eq!(receipt.message_type, MessageType::Notify);
+        assert_eq!(receipt.from_expert_id, 1);
+        assert_eq!(receipt.to, MessageRecipient::expert_id(2));
+        assert!(
+            receipt.content.body.contains(&message.message_id)
+                && receipt.content.body.contains("backend-dev"),
+            "process_queue: receipt should name the message and recipient"
+        );
+        assert!(!receipt.request_delivery_receipt);
+
+        let stats = router.process_queue().await.unwrap();
+        assert_eq!(
+            stats.delivered_expert_ids,
+            vec![2],
+            "process_queue: the receipt should be delivered to the original sender"
+        );
+        assert!(router
+            .queue_manager()
+            .get_pending_messages()
+            .await
+            .unwrap()
+            .is_empty());
     }
 
     #[tokio::test]
-    async fn process_queue_handles_empty_queue() {
+    async fn process_queue_sends_no_receipt_by_default() {
         let (mut router, _temp) = create_test_router().await;
+        router
+            .queue_manager_mut()
+            .enqueue(&create_test_message())
+            .await
+            .unwrap();
 
-        let stats = router.process_queue().await.unwrap();
-        assert_eq!(stats.messages_processed, 0);
-        assert_eq!(stats.messages_delivered, 0);
-        assert_eq!(stats.messages_failed, 0);
+        router.process_queue().await.unwrap();
+
+        assert!(
+            router
+                .queue_manager()
+                .get_pending_messages()
+                .await
+                .unwrap()
+                .is_empty(),
+            "process_queue: receipts should be opt-in"
+        );
     }
 
     #[tokio::test]
@@ -709,6 +2163,7 @@ mod tests {
         let content1 = MessageContent {
             subject: "High Priority Message".to_string(),
             body: "This is a high priority message".to_string(),
+            attachments: Vec::new(),
         };
         let message1 = Message::new(
             0,
@@ -724,6 +2179,7 @@ mod tests {
         let content2 = MessageContent {
             subject: "Low Priority Message".to_string(),
             body: "This is a low priority message".to_string(),
+            attachments: Vec::new(),
         };
         let message2 = Message::new(
             0,
@@ -765,6 +2221,7 @@ mod tests {
         let content = MessageContent {
             subject: "Test".to_string(),
             body: "Body".to_string(),
+            attachments: Vec::new(),
         };
         let msg = Message::new(
             2,
@@ -805,6 +2262,7 @@ mod tests {
         let content = MessageContent {
             subject: "Test".to_string(),
             body: "Body".to_string(),
+            attachments: Vec::new(),
         };
         let mut msg = Message::new(
             2,
@@ -827,6 +2285,419 @@ mod tests {
             "process_queue_removes_message_after_max_delivery_attempts: message should be removed after exceeding max attempts"
         );
     }
+
+    async fn deliver_high_query_and_write_reply(
+        router: &mut MessageRouter<MockTmuxSender>,
+        temp: &TempDir,
+    ) -> MessageId {
+        let query = Message::new(
+            2,
+            MessageRecipient::expert_id(1),
+            MessageType::Query,
+            MessageContent {
+                subject: "Urgent".to_string(),
+                body: "Need an answer".to_string(),
+                attachments: Vec::new(),
+            },
+        )
+        .with_priority(MessagePriority::High);
+        router.queue_manager_mut().enqueue(&query).await.unwrap();
+        let stats = router.process_queue().await.unwrap();
+        assert_eq!(stats.messages_delivered, 1, "query should be delivered");
+
+        tokio::time::sleep(tokio::time::Duration::from_millis(2)).await;
+        let reply = Message::new(
+            1,
+            MessageRecipient::expert_id(2),
+            MessageType::Response,
+            MessageContent {
+                subject: "Re: Urgent".to_string(),
+                body: "Here is the answer".to_string(),
+                attachments: Vec::new(),
+            },
+        )
+        .with_reply_to(query.message_id.clone());
+        let outbox = temp.path().join("messages").join("outbox");
+        std::fs::write(
+            outbox.join(format!("{}.yaml", reply.message_id)),
+            serde_yaml::to_string(&reply).unwrap(),
+        )
+        .unwrap();
+        reply.message_id
+    }
+
+    #[tokio::test]
+    async fn process_outbox_drops_attachments_outside_sender_dir() {
+        let (router, temp) = create_test_router().await;
+        let project = TempDir::new().unwrap();
+        std::fs::create_dir_all(project.path().join("src")).unwrap();
+        std::fs::write(project.path().join("src").join("lib.rs"), "").unwrap();
+        let mut router = router.with_project_root(project.path().to_path_buf());
+
+        let mut message = Message::new(
+            1,
+            MessageRecipient::expert_id(2),
+            MessageType::Notify,
+            MessageContent {
+                subject: "Look here".to_string(),
+                body: "See the attached file".to_string(),
+                attachments: vec![
+                    "src/lib.rs:42".to_string(),
+                    "src/missing.rs".to_string(),
+                    "../outside.rs".to_string(),
+                    "/etc/hosts".to_string(),
+                ],
+            },
+        );
+        message.message_id = "msg-attach".to_string();
+        std::fs::write(
+            temp.path()
+                .join("messages")
+                .join("outbox")
+                .join("msg-attach.yaml"),
+            serde_yaml::to_string(&message).unwrap(),
+        )
+        .unwrap();
+
+        router.process_outbox().await.unwrap();
+
+        let queued = router
+            .queue_manager()
+            .read_message("msg-attach")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            queued.message.content.attachments,
+            vec!["src/lib.rs:42".to_string()],
+            "process_outbox: only existing files inside the sender's dir should be kept"
+        );
+    }
+
+    #[tokio::test]
+    async fn process_outbox_boosts_reply_to_high_priority_query() {
+        let (router, temp) = create_test_router().await;
+        let mut router = router.with_reply_priority_boost(true);
+
+        let reply_id = deliver_high_query_and_write_reply(&mut router, &temp).await;
+        router.process_outbox().await.unwrap();
+
+        let queued = router
+            .queue_manager()
+            .read_message(&reply_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            queued.message.priority,
+            MessagePriority::High,
+            "process_outbox: reply to a High query should be reprioritized to High"
+        );
+    }
+
+    #[tokio::test]
+    async fn high_priority_queries_are_forgotten_once_answered_or_expired() {
+        let (router, temp) = create_test_router().await;
+        let mut router = router.with_reply_priority_boost(true);
+
+        deliver_high_query_and_write_reply(&mut router, &temp).await;
+        assert_eq!(router.high_priority_queries.len(), 1);
+        router.process_outbox().await.unwrap();
+        assert!(
+            router.high_priority_queries.is_empty(),
+            "boost_reply_priority: an answered query should stop being tracked"
+        );
+
+        router.high_priority_queries.insert(
+            "stale".to_string(),
+            Some(Utc::now() - chrono::Duration::seconds(1)),
+        );
+        router.process_queue().await.unwrap();
+        assert!(
+            router.high_priority_queries.is_empty(),
+            "process_queue: expired queries should stop being tracked"
+        );
+    }
+
+    #[tokio::test]
+    async fn process_outbox_boosts_reply_when_original_still_queued() {
+        let (router, temp) = create_test_router().await;
+        let mut router = router.with_reply_priority_boost(true);
+
+        let query = Message::new(
+            2,
+            MessageRecipient::expert_id(1),
+            MessageType::Query,
+            MessageContent {
+                subject: "Urgent".to_string(),
+                body: "Need an answer".to_string(),
+                attachments: Vec::new(),
+            },
+        )
+        .with_priority(MessagePriority::High);
+        router.queue_manager_mut().enqueue(&query).await.unwrap();
+
+        tokio::time::sleep(tokio::time::Duration::from_millis(2)).await;
+        let reply = Message::new(
+            1,
+            MessageRecipient::expert_id(2),
+            MessageType::Response,
+            MessageContent {
+                subject: "Re: Urgent".to_string(),
+                body: "Answer".to_string(),
+                attachments: Vec::new(),
+            },
+        )
+        .with_reply_to(query.message_id.clone());
+        let outbox = temp.path().join("messages").join("outbox");
+        std::fs::write(
+            outbox.join(format!("{}.yaml", reply.message_id)),
+            serde_yaml::to_string(&reply).unwrap(),
+        )
+        .unwrap();
+        router.process_outbox().await.unwrap();
+
+        let queued = router
+            .queue_manager()
+            .read_message(&reply.message_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            queued.message.priority,
+            MessagePriority::High,
+            "process_outbox: reply should be boosted when the High original is still queued"
+        );
+    }
+
+    #[tokio::test]
+    async fn process_outbox_keeps_reply_priority_when_boost_disabled() {
+        let (mut router, temp) = create_test_router().await;
+
+        let reply_id = deliver_high_query_and_write_reply(&mut router, &temp).await;
+        router.process_outbox().await.unwrap();
+
+        let queued = router
+            .queue_manager()
+            .read_message(&reply_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            queued.message.priority,
+            MessagePriority::Normal,
+            "process_outbox: reply priority should be unchanged when boost is disabled"
+        );
+    }
+
+    #[tokio::test]
+    async fn process_outbox_does_not_boost_reply_to_normal_query() {
+        let (router, temp) = create_test_router().await;
+        let mut router = router.with_reply_priority_boost(true);
+
+        let query = Message::new(
+            2,
+            MessageRecipient::expert_id(1),
+            MessageType::Query,
+            MessageContent {
+                subject: "Question".to_string(),
+                body: "Whenever".to_string(),
+                attachments: Vec::new(),
+            },
+        );
+        router.queue_manager_mut().enqueue(&query).await.unwrap();
+        router.process_queue().await.unwrap();
+
+        tokio::time::sleep(tokio::time::Duration::from_millis(2)).await;
+        let reply = Message::new(
+            1,
+            MessageRecipient::expert_id(2),
+            MessageType::Response,
+            MessageContent {
+                subject: "Re: Question".to_string(),
+                body: "Answer".to_string(),
+                attachments: Vec::new(),
+            },
+        )
+        .with_reply_to(query.message_id.clone());
+        let outbox = temp.path().join("messages").join("outbox");
+        std::fs::write(
+            outbox.join(format!("{}.yaml", reply.message_id)),
+            serde_yaml::to_string(&reply).unwrap(),
+        )
+        .unwrap();
+        router.process_outbox().await.unwrap();
+
+        let queued = router
+            .queue_manager()
+            .read_message(&reply.message_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            queued.message.priority,
+            MessagePriority::Normal,
+            "process_outbox: reply to a Normal query should keep its priority"
+        );
+    }
+
+    #[tokio::test]
+    async fn process_queue_marks_expert_offline_when_window_missing() {
+        let (mut router, _temp) = create_test_router_with_sender(MissingWindowTmuxSender).await;
+
+        let message = create_test_message();
+        router.queue_manager().enqueue(&message).await.unwrap();
+
+        let stats = router.process_queue().await.unwrap();
+
+        assert_eq!(
+            stats.offline_expert_ids,
+            vec![1],
+            "process_queue: expert with a missing window should be reported offline"
+        );
+        assert!(
+            router.expert_registry().get_expert(1).unwrap().is_offline(),
+            "process_queue: expert with a missing window should be flagged offline"
+        );
+
+        let queued = router
+            .queue_manager()
+            .read_message(&message.message_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            queued.attempts, 1,
+            "process_queue: message should stay queued for when the expert comes back"
+        );
+
+        let stats = router.process_queue().await.unwrap();
+        assert!(
+            stats.offline_expert_ids.is_empty(),
+            "process_queue: an already-offline expert should not be reported again"
+        );
+        assert_eq!(stats.messages_skipped, 1);
+        let queued = router
+            .queue_manager()
+            .read_message(&message.message_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            queued.attempts, 1,
+            "process_queue: messages to an offline expert should be deferred, not retried"
+        );
+    }
+
+    #[tokio::test]
+    async fn deliver_via_tmux_reports_window_not_found() {
+        let (router, _temp) = create_test_router_with_sender(MissingWindowTmuxSender).await;
+
+        let result = router.deliver_via_tmux(1, &create_test_message()).await;
+
+        assert!(
+            matches!(result, Err(RouterError::WindowNotFound(0))),
+            "deliver_via_tmux: missing window should map to WindowNotFound, got {result:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn deliver_via_tmux_flags_attachments_missing_for_recipient() {
+        let sender = RecordingTmuxSender::default();
+        let (router, _temp) = create_test_router_with_sender(sender.clone()).await;
+        let project = TempDir::new().unwrap();
+        let worktree = TempDir::new().unwrap();
+        for dir in [project.path(), worktree.path()] {
+            std::fs::create_dir_all(dir.join("src")).unwrap();
+            std::fs::write(dir.join("src").join("lib.rs"), "").unwrap();
+        }
+        std::fs::write(project.path().join("src").join("new.rs"), "").unwrap();
+        let mut router = router.with_project_root(project.path().to_path_buf());
+        router
+            .expert_registry_mut()
+            .update_expert_worktree(2, Some(worktree.path().display().to_string()))
+            .unwrap();
+
+        let mut message = create_test_message();
+        message.content.attachments = vec!["src/lib.rs:3".to_string(), "src/new.rs".to_string()];
+
+        router.deliver_via_tmux(2, &message).await.unwrap();
+        router.deliver_via_tmux(1, &message).await.unwrap();
+
+        let sent = sender.sent.lock().unwrap().clone();
+        let to_worktree = &sent
+            .iter()
+            .find(|(window, keys)| *window == 1 && keys.contains("Test Subject"))
+            .unwrap()
+            .1;
+        assert!(
+            to_worktree.contains("- src/lib.rs:3\n")
+                && to_worktree.contains(&format!("- src/new.rs{MISSING_ATTACHMENT_NOTE}")),
+            "deliver_via_tmux: only the file absent from the recipient's worktree \
+             should be flagged, got {to_worktree}"
+        );
+        let to_root = &sent
+            .iter()
+            .find(|(window, keys)| *window == 0 && keys.contains("Test Subject"))
+            .unwrap()
+            .1;
+        assert!(
+            !to_root.contains(MISSING_ATTACHMENT_NOTE),
+            "deliver_via_tmux: files present for the recipient should not be flagged, \
+             got {to_root}"
+        );
+    }
+
+    #[tokio::test]
+    async fn deliver_via_tmux_writes_inbox_file_and_sends_pointer() {
+        let sender = RecordingTmuxSender::default();
+        let (router, temp) = create_test_router_with_sender(sender.clone()).await;
+        let router = router.with_inbox_files(true);
+        let mut message = create_test_message();
+        message.content.attachments = vec!["src/main.rs:42".to_string()];
+
+        router.deliver_via_tmux(2, &message).await.unwrap();
+
+        let path = temp
+            .path()
+            .join("messages")
+            .join("inbox")
+            .join("expert2")
+            .join(format!("{}.yaml", message.message_id));
+        let written: Message =
+            serde_yaml::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(written.message_id, message.message_id);
+        assert_eq!(written.content.body, "Test Body");
+        assert_eq!(
+            written.content.attachments,
+            vec!["src/main.rs:42"],
+            "deliver_via_tmux: inbox file should hold the full message"
+        );
+
+        let sent = sender.sent.lock().unwrap().clone();
+        assert!(
+            sent.iter().any(|(window, keys)| *window == 1
+                && keys.contains("Test Subject")
+                && keys.contains(&path.display().to_string())
+                && !keys.contains("Test Body")),
+            "deliver_via_tmux: pane should get a pointer to the inbox file, got {sent:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn deliver_via_tmux_without_inbox_writes_no_file() {
+        let (router, temp) = create_test_router().await;
+
+        router
+            .deliver_via_tmux(2, &create_test_message())
+            .await
+            .unwrap();
+
+        assert!(
+            !temp.path().join("messages").join("inbox").exists(),
+            "deliver_via_tmux: inbox files should be opt-in"
+        );
+    }
 }
 
 #[cfg(test)]
@@ -836,6 +2707,7 @@ mod worktree_tests {
     use crate::models::{
         ExpertInfo, ExpertState, MessageContent, MessageType, QueuedMessage, Role,
     };
+    use std::collections::HashMap;
     use tempfile::TempDir;
 
     async fn create_worktree_router() -> (MessageRouter<MockTmuxSender>, TempDir) {
@@ -909,6 +2781,49 @@ mod worktree_tests {
         (router, temp_dir)
     }
 
+    fn write_outbox_without_to(temp: &TempDir, message_id: &str, from_expert_id: u32) {
+        let yaml = format!(
+            "message_id: \"{message_id}\"\nfrom_expert_id: {from_expert_id}\nmessage_type: notify\npriority: normal\n\
+             created_at: \"2024-01-15T10:30:00Z\"\ncontent:\n  subject: \"Hi\"\n  body: \"Hello\"\n"
+        );
+        std::fs::write(
+            temp.path()
+                .join("messages")
+                .join("outbox")
+                .join(format!("{message_id}.yaml")),
+            yaml,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn process_outbox_default_recipient_must_share_worktree() {
+        let (router, temp) = create_worktree_router().await;
+        let queue_manager = QueueManager::new(temp.path().to_path_buf())
+            .with_default_recipients(HashMap::from([(1, 2), (2, 3)]));
+        let mut router = MessageRouter::new(
+            queue_manager,
+            router.expert_registry().clone(),
+            MockTmuxSender,
+        );
+        write_outbox_without_to(&temp, "msg-same-wt", 1);
+        write_outbox_without_to(&temp, "msg-cross-wt", 2);
+
+        let processed = router.process_outbox().await.unwrap();
+
+        assert_eq!(
+            processed,
+            vec!["msg-same-wt".to_string()],
+            "process_outbox: default recipient in another worktree should be rejected"
+        );
+        assert!(router
+            .queue_manager()
+            .read_message("msg-cross-wt")
+            .await
+            .unwrap()
+            .is_none());
+    }
+
     // Property 1: worktree_matches returns correct results for all affinity matrix combinations
     #[tokio::test]
     async fn worktree_matches_none_none() {
@@ -1030,6 +2945,79 @@ mod worktree_tests {
         );
     }
 
+    fn tag_experts(router: &mut MessageRouter<MockTmuxSender>, tag: &str, ids: &[ExpertId]) {
+        for &id in ids {
+            let expert = router.expert_registry_mut().get_expert_mut(id).unwrap();
+            expert.tags = vec![tag.to_string()];
+        }
+    }
+
+    #[tokio::test]
+    async fn find_recipient_by_tag_returns_idle_tagged_expert_in_same_worktree() {
+        let (mut router, _temp) = create_worktree_router().await;
+        tag_experts(&mut router, "auth-team", &[2, 3]);
+
+        // Expert 1 (feature-auth) targets tag "Auth-Team" -> Expert 2, not Expert 3 (feature-payments)
+        let recipient = MessageRecipient::tag("Auth-Team");
+        assert_eq!(
+            router.find_recipient(&recipient, 1).await.unwrap(),
+            Some(2),
+            "find_recipient: tag targeting should match case-insensitively within the worktree"
+        );
+
+        router
+            .expert_registry_mut()
+            .update_expert_state(2, ExpertState::Busy)
+            .unwrap();
+        assert_eq!(
+            router.find_recipient(&recipient, 1).await.unwrap(),
+            None,
+            "find_recipient: tag targeting should not fall through to another worktree"
+        );
+    }
+
+    #[tokio::test]
+    async fn find_recipient_by_tag_main_repo_only_finds_main_repo() {
+        let (mut router, _temp) = create_worktree_router().await;
+        tag_experts(&mut router, "core", &[1, 4]);
+
+        let recipient = MessageRecipient::tag("core");
+        assert_eq!(
+            router.find_recipient(&recipient, 0).await.unwrap(),
+            Some(4),
+            "find_recipient: main repo tag targeting should only find main repo experts"
+        );
+        assert_eq!(
+            router
+                .find_recipient(&MessageRecipient::tag("missing"), 0)
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn resolve_multi_expands_tag_to_every_other_carrier_in_the_worktree() {
+        let (mut router, _temp) = create_worktree_router().await;
+        tag_experts(&mut router, "auth-team", &[1, 2, 3]);
+        router
+            .expert_registry_mut()
+            .update_expert_state(2, ExpertState::Busy)
+            .unwrap();
+
+        assert_eq!(
+            router.resolve_multi(&MessageRecipient::tag("auth-team"), 1),
+            vec![MessageRecipient::expert_id(2)],
+            "resolve_multi: a tag should reach busy carriers in the sender's worktree, \
+             but not the sender or other worktrees"
+        );
+        assert_eq!(
+            router.resolve_multi(&MessageRecipient::tag("missing"), 1),
+            vec![MessageRecipient::tag("missing")],
+            "resolve_multi: a tag nobody carries should wait as-is"
+        );
+    }
+
     // Property 2: Main Repo Affinity
     #[tokio::test]
     async fn main_repo_experts_can_find_each_other_by_id() {
@@ -1052,6 +3040,7 @@ mod worktree_tests {
         let content = MessageContent {
             subject: "Cross-worktree".to_string(),
             body: "Should fail".to_string(),
+            attachments: Vec::new(),
         };
         let message = Message::new(
             0,
@@ -1081,6 +3070,7 @@ mod worktree_tests {
         let content = MessageContent {
             subject: "Same worktree".to_string(),
             body: "Should find recipient".to_string(),
+            attachments: Vec::new(),
         };
         let message = Message::new(
             1,
@@ -1111,6 +3101,7 @@ mod worktree_tests {
         let content = MessageContent {
             subject: "Cross-worktree via queue".to_string(),
             body: "Should not deliver".to_string(),
+            attachments: Vec::new(),
         };
         let msg = Message::new(
             0,
@@ -1154,7 +3145,11 @@ mod property_tests {
     }
 
     fn arbitrary_expert_state() -> impl Strategy<Value = ExpertState> {
-        prop_oneof![Just(ExpertState::Idle), Just(ExpertState::Busy),]
+        prop_oneof![
+            Just(ExpertState::Idle),
+            Just(ExpertState::Busy),
+            Just(ExpertState::Offline),
+        ]
     }
 
     fn arbitrary_expert_info() -> impl Strategy<Value = ExpertInfo> {
@@ -1177,8 +3172,13 @@ mod property_tests {
     }
 
     fn arbitrary_message_content() -> impl Strategy<Value = MessageContent> {
-        ("[a-zA-Z0-9 ]{1,100}", "[a-zA-Z0-9 \n]{1,1000}")
-            .prop_map(|(subject, body)| MessageContent { subject, body })
+        ("[a-zA-Z0-9 ]{1,100}", "[a-zA-Z0-9 \n]{1,1000}").prop_map(|(subject, body)| {
+            MessageContent {
+                subject,
+                body,
+                attachments: Vec::new(),
+            }
+        })
     }
 
     fn arbitrary_message() -> impl Strategy<Value = Message> {
@@ -1273,6 +3273,15 @@ mod property_tests {
                                 assert!(idle_experts_for_role.is_empty());
                             }
                         }
+                        MessageRecipient::Tag { tag } => {
+                            if let Some(found_id) = result {
+                                let expert_info = router.expert_registry().get_expert(found_id).unwrap();
+                                assert!(expert_info.has_tag(tag));
+                                assert!(expert_info.is_idle());
+                            }
+                        }
+                        // Not generated by arbitrary_message_recipient
+                        MessageRecipient::Multi(_) => {}
                     }
                 }
             });
@@ -1366,6 +3375,11 @@ mod property_tests {
                             assert!(expert_info.matches_role(role));
                             assert!(expert_info.is_idle()); // Role-based targeting requires idle state
                         }
+                        MessageRecipient::Tag { tag } => {
+                            assert!(expert_info.has_tag(tag));
+                            assert!(expert_info.is_idle());
+                        }
+                        MessageRecipient::Multi(_) => {}
                     }
                 }
             });
@@ -1479,7 +3493,7 @@ mod property_tests {
                                 }
                             }
                         },
-                        ExpertState::Busy => {
+                        ExpertState::Busy | ExpertState::Offline => {
                             // When not idle, delivery should be blocked
                             assert!(!delivery_result.success);
                             if let Some(error) = &delivery_result.error {
@@ -1801,6 +3815,7 @@ mod property_tests {
                 let content = MessageContent {
                     subject: subject.clone(),
                     body: body.clone(),
+                    attachments: Vec::new(),
                 };
 
                 // Test all message types
@@ -1846,6 +3861,7 @@ mod property_tests {
                 let special_content = MessageContent {
                     subject: "Test: Special Characters [Test]".to_string(),
                     body: "Body with special chars: <>&\"'\\n\ttab".to_string(),
+                    attachments: Vec::new(),
                 };
 
                 let message = Message::new(
@@ -1927,6 +3943,7 @@ mod integration_tests {
         let content = MessageContent {
             subject: "Integration Test".to_string(),
             body: "Testing end-to-end message flow".to_string(),
+            attachments: Vec::new(),
         };
         let message = Message::new(
             expert_ids[0],
@@ -1989,6 +4006,7 @@ mod integration_tests {
             let content = MessageContent {
                 subject: "Persistence Test".to_string(),
                 body: "This message should persist".to_string(),
+                attachments: Vec::new(),
             };
             let message = Message::new(
                 1,
@@ -2064,6 +4082,7 @@ mod integration_tests {
             let content = MessageContent {
                 subject: format!("Message from expert {}", i),
                 body: format!("Concurrent test message #{}", i),
+                attachments: Vec::new(),
             };
             let message = Message::new(
                 from_expert,
@@ -2109,6 +4128,7 @@ mod integration_tests {
         let content = MessageContent {
             subject: "State Transition Test".to_string(),
             body: "Testing delivery with state changes".to_string(),
+            attachments: Vec::new(),
         };
         let message = Message::new(
             sender_id,
@@ -2178,6 +4198,7 @@ mod integration_tests {
             let content = MessageContent {
                 subject: subject.to_string(),
                 body: format!("Message with {} priority", subject),
+                attachments: Vec::new(),
             };
             let message = Message::new(
                 sender_id,
@@ -2214,6 +4235,7 @@ mod integration_tests {
         let expired_content = MessageContent {
             subject: "Expired Message".to_string(),
             body: "This should be cleaned up".to_string(),
+            attachments: Vec::new(),
         };
         let expired_message = Message::new(
             sender_id,
@@ -2230,6 +4252,7 @@ mod integration_tests {
         let valid_content = MessageContent {
             subject: "Valid Message".to_string(),
             body: "This should remain".to_string(),
+            attachments: Vec::new(),
         };
         let valid_message = Message::new(
             sender_id,
@@ -2320,6 +4343,7 @@ mod integration_tests {
         let content = MessageContent {
             subject: "Backend Task".to_string(),
             body: "Need help with backend work".to_string(),
+            attachments: Vec::new(),
         };
         let message = Message::new(
             frontend_id,
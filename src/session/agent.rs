@@ -0,0 +1,249 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::config::AgentConfig;
+use crate::models::EffortLevel;
+
+/// How a coding-agent CLI is started, recognised as ready, and asked to quit
+/// inside an expert pane.
+pub trait AgentBackend: Send + Sync {
+    /// Command line that starts the agent; it runs from the expert's working directory.
+    fn launch_command(
+        &self,
+        instruction_file: Option<&Path>,
+        agents_file: Option<&Path>,
+        settings_file: Option<&Path>,
+        model: Option<&str>,
+        effort: Option<EffortLevel>,
+    ) -> String;
+
+    /// Whether `launch_command` passes a model on to the agent.
+    fn supports_model(&self) -> bool;
+
+    /// Whether captured pane content shows the agent is ready for input.
+    fn is_ready(&self, pane_content: &str) -> bool;
+
+    /// Input that makes the agent exit back to the shell.
+    fn exit_command(&self) -> &str;
+}
+
+/// The Claude CLI, run with permissions bypassed so experts work unattended.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ClaudeBackend;
+
+impl AgentBackend for ClaudeBackend {
+    fn launch_command(
+        &self,
+        instruction_file: Option<&Path>,
+        agents_file: Option<&Path>,
+        settings_file: Option<&Path>,
+        model: Option<&str>,
+        // Claude has no effort flag; the level reaches it through the instructions
+        _effort: Option<EffortLevel>,
+    ) -> String {
+        let mut args = vec!["--dangerously-skip-permissions".to_string()];
+        if let Some(model) = model {
+            args.push("--model".to_string());
+            args.push(shell_single_quote(model));
+        }
+
+        for (flag, file) in [
+            ("--append-system-prompt", instruction_file),
+            ("--agents", agents_file),
+            ("--settings", settings_file),
+        ] {
+            if let Some(file) = file {
+                args.push(flag.to_string());
+                args.push(cat_file_arg(file));
+            }
+        }
+
+        format!("claude {}", args.join(" "))
+    }
+
+    fn supports_model(&self) -> bool {
+        true
+    }
+
+    fn is_ready(&self, pane_content: &str) -> bool {
+        pane_content.contains("bypass permissions")
+    }
+
+    fn exit_command(&self) -> &str {
+        "/exit"
+    }
+}
+
+/// A user-configured agent CLI. Only the role instructions, model and effort
+/// level are passed on, and only when `instruction_flag` / `model_flag` /
+/// `effort_flag` are set; the Claude-specific agents and settings files are
+/// ignored.
+#[derive(Debug, Clone)]
+pub struct CommandBackend {
+    config: AgentConfig,
+}
+
+impl CommandBackend {
+    pub fn new(config: AgentConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl AgentBackend for CommandBackend {
+    fn launch_command(
+        &self,
+        instruction_file: Option<&Path>,
+        _agents_file: Option<&Path>,
+        _settings_file: Option<&Path>,
+        model: Option<&str>,
+        effort: Option<EffortLevel>,
+    ) -> String {
+        let mut cmd = self.config.command.clone();
+        if let (Some(flag), Some(model)) = (&self.config.model_flag, model) {
+            cmd = format!("{cmd} {flag} {}", shell_single_quote(model));
+        }
+        if let (Some(flag), Some(effort)) = (&self.config.effort_flag, effort) {
+            cmd = format!("{cmd} {flag} {effort}");
+        }
+        match (&self.config.instruction_flag, instruction_file) {
+            (Some(flag), Some(file)) => format!("{cmd} {flag} {}", cat_file_arg(file)),
+            _ => cmd,
+        }
+    }
+
+    fn supports_model(&self) -> bool {
+        self.config.model_flag.is_some()
+    }
+
+    fn is_ready(&self, pane_content: &str) -> bool {
+        pane_content.contains(&self.config.ready_marker)
+    }
+
+    fn exit_command(&self) -> &str {
+        &self.config.exit_command
+    }
+}
+
+/// Backend for the configured agent, falling back to Claude when none is set.
+pub fn agent_backend(config: Option<&AgentConfig>) -> Arc<dyn AgentBackend> {
+    match config {
+        Some(config) => Arc::new(CommandBackend::new(config.clone())),
+        None => Arc::new(ClaudeBackend),
+    }
+}
+
+/// Shell argument expanding to the contents of `file`.
+fn cat_file_arg(file: &Path) -> String {
+    format!(
+        "\"$(cat {})\"",
+        shell_single_quote(&file.display().to_string())
+    )
+}
+
+pub(super) fn shell_single_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom_config(instruction_flag: Option<&str>) -> AgentConfig {
+        AgentConfig {
+            command: "codex --full-auto".to_string(),
+            ready_marker: "context left".to_string(),
+            exit_command: "/quit".to_string(),
+            instruction_flag: instruction_flag.map(str::to_string),
+            model_flag: None,
+            effort_flag: None,
+        }
+    }
+
+    #[test]
+    fn claude_backend_passes_all_files() {
+        let cmd = ClaudeBackend.launch_command(
+            Some(Path::new("/tmp/i.md")),
+            Some(Path::new("/tmp/a.json")),
+            Some(Path::new("/tmp/s.json")),
+            None,
+            None,
+        );
+        assert!(cmd.starts_with("claude --dangerously-skip-permissions"));
+        assert!(
+            cmd.contains("--append-system-prompt \"$(cat '/tmp/i.md')\"")
+                && cmd.contains("--agents \"$(cat '/tmp/a.json')\"")
+                && cmd.contains("--settings \"$(cat '/tmp/s.json')\""),
+            "launch_command: every file should be passed, got: {cmd}"
+        );
+    }
+
+    #[test]
+    fn command_backend_passes_instructions_only_with_flag() {
+        let file = Path::new("/tmp/i.md");
+        let with_flag = CommandBackend::new(custom_config(Some("--instructions")));
+        assert_eq!(
+            with_flag.launch_command(Some(file), Some(file), None, Some("o3"), None),
+            "codex --full-auto --instructions \"$(cat '/tmp/i.md')\""
+        );
+
+        let without_flag = CommandBackend::new(custom_config(None));
+        assert_eq!(
+            without_flag.launch_command(Some(file), None, None, None, None),
+            "codex --full-auto",
+            "launch_command: instructions need a configured flag"
+        );
+    }
+
+    #[test]
+    fn launch_command_passes_model_when_backend_supports_it() {
+        let cmd = ClaudeBackend.launch_command(None, None, None, Some("opus"), None);
+        assert_eq!(
+            cmd, "claude --dangerously-skip-permissions --model 'opus'",
+            "launch_command: Claude should get --model"
+        );
+
+        let custom = CommandBackend::new(AgentConfig {
+            model_flag: Some("-m".to_string()),
+            ..custom_config(None)
+        });
+        assert_eq!(
+            custom.launch_command(None, None, None, Some("o3"), None),
+            "codex --full-auto -m 'o3'"
+        );
+    }
+
+    #[test]
+    fn launch_command_passes_effort_only_with_flag() {
+        let custom = CommandBackend::new(AgentConfig {
+            effort_flag: Some("--reasoning-effort".to_string()),
+            ..custom_config(None)
+        });
+        assert_eq!(
+            custom.launch_command(None, None, None, None, Some(EffortLevel::High)),
+            "codex --full-auto --reasoning-effort high",
+            "launch_command: the effort level should follow effort_flag"
+        );
+
+        let without_flag = CommandBackend::new(custom_config(None));
+        assert_eq!(
+            without_flag.launch_command(None, None, None, None, Some(EffortLevel::High)),
+            "codex --full-auto"
+        );
+        assert!(!without_flag.supports_model());
+    }
+
+    #[test]
+    fn agent_backend_uses_configured_ready_marker_and_exit() {
+        let backend = agent_backend(Some(&custom_config(None)));
+        assert!(backend.is_ready("42% context left"));
+        assert!(
+            !backend.is_ready("bypass permissions"),
+            "is_ready: custom backend should not use Claude's marker"
+        );
+        assert_eq!(backend.exit_command(), "/quit");
+
+        let default = agent_backend(None);
+        assert!(default.is_ready("bypass permissions on"));
+        assert_eq!(default.exit_command(), "/exit");
+    }
+}
@@ -1,26 +1,89 @@
 use anyhow::Result;
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
+use std::sync::Arc;
 use tokio::time::{sleep, Duration};
 
+use super::agent::{shell_single_quote, AgentBackend, ClaudeBackend};
 use super::{TmuxManager, TmuxSender};
+use crate::models::EffortLevel;
+use crate::utils::strip_ansi;
 
+/// Drives the agent CLI in each expert pane; Claude unless another backend is set.
 #[derive(Clone)]
 pub struct ClaudeManager<T: TmuxSender = TmuxManager> {
     tmux: T,
+    backend: Arc<dyn AgentBackend>,
+    expert_env: HashMap<u32, BTreeMap<String, String>>,
+    expert_model: HashMap<u32, String>,
+    expert_effort: HashMap<u32, EffortLevel>,
 }
 
 impl ClaudeManager {
     pub fn new(session_name: String) -> Self {
-        Self {
-            tmux: TmuxManager::new(session_name),
-        }
+        Self::with_sender(TmuxManager::new(session_name))
     }
 }
 
 impl<T: TmuxSender> ClaudeManager<T> {
-    #[allow(dead_code)]
     pub fn with_sender(sender: T) -> Self {
-        Self { tmux: sender }
+        Self {
+            tmux: sender,
+            backend: Arc::new(ClaudeBackend),
+            expert_env: HashMap::new(),
+            expert_model: HashMap::new(),
+            expert_effort: HashMap::new(),
+        }
+    }
+
+    pub fn with_backend(mut self, backend: Arc<dyn AgentBackend>) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    /// Environment variables applied to each expert's agent at launch, by expert ID
+    pub fn with_expert_env(mut self, expert_env: HashMap<u32, BTreeMap<String, String>>) -> Self {
+        self.expert_env = expert_env;
+        self
+    }
+
+    /// Model each expert's agent is launched with, by expert ID
+    pub fn with_expert_models(mut self, expert_model: HashMap<u32, String>) -> Self {
+        self.expert_model = expert_model;
+        self
+    }
+
+    /// Change the model used on the expert's next launch; `None` uses the agent's default
+    pub fn set_expert_model(&mut self, expert_id: u32, model: Option<String>) {
+        match model {
+            Some(model) => self.expert_model.insert(expert_id, model),
+            None => self.expert_model.remove(&expert_id),
+        };
+    }
+
+    pub fn expert_model(&self, expert_id: u32) -> Option<&str> {
+        self.expert_model.get(&expert_id).map(String::as_str)
+    }
+
+    /// Whether the agent is launched with the expert's model at all
+    pub fn supports_model(&self) -> bool {
+        self.backend.supports_model()
+    }
+
+    /// Effort level each expert's agent is launched with, by expert ID
+    pub fn with_expert_efforts(mut self, expert_effort: HashMap<u32, EffortLevel>) -> Self {
+        self.expert_effort = expert_effort;
+        self
+    }
+
+    /// Change the effort level passed on the expert's next launch
+    pub fn set_expert_effort(&mut self, expert_id: u32, effort: EffortLevel) {
+        self.expert_effort.insert(expert_id, effort);
+    }
+
+    /// Whether captured pane content shows the agent is ready for input
+    pub fn is_agent_ready(&self, pane_content: &str) -> bool {
+        self.backend.is_ready(pane_content)
     }
 
     pub async fn launch_claude(
@@ -31,40 +94,26 @@ impl<T: TmuxSender> ClaudeManager<T> {
         agents_file: Option<&Path>,
         settings_file: Option<&Path>,
     ) -> Result<()> {
-        let mut args = vec!["--dangerously-skip-permissions".to_string()];
-
-        if let Some(file) = instruction_file {
-            args.push("--append-system-prompt".to_string());
-            args.push(format!(
-                "\"$(cat {})\"",
-                shell_single_quote(&file.display().to_string())
-            ));
-        }
-
-        if let Some(file) = agents_file {
-            args.push("--agents".to_string());
-            args.push(format!(
-                "\"$(cat {})\"",
-                shell_single_quote(&file.display().to_string())
-            ));
-        }
-
-        if let Some(file) = settings_file {
-            args.push("--settings".to_string());
-            args.push(format!(
-                "\"$(cat {})\"",
-                shell_single_quote(&file.display().to_string())
-            ));
-        }
-
-        let claude_cmd = format!(
-            "cd {} && claude {}",
-            shell_single_quote(working_dir),
-            args.join(" ")
+        let env = self.expert_env.get(&expert_id);
+        let agent_cmd = self.backend.launch_command(
+            instruction_file,
+            agents_file,
+            settings_file,
+            self.expert_model(expert_id),
+            self.expert_effort.get(&expert_id).copied(),
+        );
+        let cd = format!("cd {}", shell_single_quote(working_dir));
+        tracing::debug!(
+            "Launching agent for expert {}: {} && {}{}",
+            expert_id,
+            cd,
+            env_prefix(env, true),
+            agent_cmd
         );
+        let launch_cmd = format!("{} && {}{}", cd, env_prefix(env, false), agent_cmd);
 
         self.tmux
-            .send_keys_with_enter(expert_id, &claude_cmd)
+            .send_keys_with_enter(expert_id, &launch_cmd)
             .await?;
 
         Ok(())
@@ -78,8 +127,14 @@ impl<T: TmuxSender> ClaudeManager<T> {
         self.tmux.capture_pane_with_escapes(expert_id).await
     }
 
-    pub async fn capture_full_history(&self, expert_id: u32) -> Result<String> {
-        self.tmux.capture_full_history(expert_id).await
+    /// Pane text with terminal escapes removed, for logging, search and copying
+    pub async fn capture_pane_plain(&self, expert_id: u32) -> Result<String> {
+        let content = self.tmux.capture_pane(expert_id).await?;
+        Ok(strip_ansi(&content))
+    }
+
+    pub async fn capture_full_history(&self, expert_id: u32, max_lines: usize) -> Result<String> {
+        self.tmux.capture_full_history(expert_id, max_lines).await
     }
 
     pub async fn send_keys_with_enter(&self, expert_id: u32, keys: &str) -> Result<()> {
@@ -91,7 +146,14 @@ impl<T: TmuxSender> ClaudeManager<T> {
     }
 
     pub async fn send_exit(&self, expert_id: u32) -> Result<()> {
-        self.send_keys_with_enter(expert_id, "/exit").await
+        self.send_keys_with_enter(expert_id, self.backend.exit_command())
+            .await
+    }
+
+    /// Interrupt the agent's current action (Ctrl+C in its pane); unlike
+    /// `send_exit` the agent keeps running
+    pub async fn send_interrupt(&self, expert_id: u32) -> Result<()> {
+        self.send_keys(expert_id, "C-c").await
     }
 
     /// Check whether the foreground process in the pane is a shell (not claude).
@@ -123,7 +185,7 @@ impl<T: TmuxSender> ClaudeManager<T> {
         while start.elapsed() < timeout {
             let content = self.tmux.capture_pane(expert_id).await?;
 
-            if content.contains("bypass permissions") {
+            if self.backend.is_ready(&content) {
                 return Ok(true);
             }
 
@@ -133,6 +195,21 @@ impl<T: TmuxSender> ClaudeManager<T> {
         Ok(false)
     }
 
+    /// Wait for the agent to become ready, then type `keys` and press Enter.
+    /// Returns `false` without sending when the agent is not ready in time.
+    pub async fn send_when_ready(
+        &self,
+        expert_id: u32,
+        keys: &str,
+        timeout_secs: u64,
+    ) -> Result<bool> {
+        if !self.wait_for_ready(expert_id, timeout_secs).await? {
+            return Ok(false);
+        }
+        self.send_keys_with_enter(expert_id, keys).await?;
+        Ok(true)
+    }
+
     #[allow(dead_code)]
     pub async fn send_instruction(&self, expert_id: u32, instruction: &str) -> Result<()> {
         for chunk in instruction.as_bytes().chunks(200) {
@@ -145,13 +222,26 @@ impl<T: TmuxSender> ClaudeManager<T> {
     }
 }
 
-fn shell_single_quote(value: &str) -> String {
-    format!("'{}'", value.replace('\'', "'\\''"))
+/// `KEY='value' ` assignments prefixed to the agent command; values are masked when `redact` is set
+fn env_prefix(env: Option<&BTreeMap<String, String>>, redact: bool) -> String {
+    env.into_iter()
+        .flatten()
+        .map(|(key, value)| {
+            let value = if redact {
+                "***".to_string()
+            } else {
+                shell_single_quote(value)
+            };
+            format!("{key}={value} ")
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
+    use super::super::agent::CommandBackend;
     use super::*;
+    use crate::config::AgentConfig;
     use std::sync::{Arc, Mutex};
 
     #[derive(Clone)]
@@ -348,6 +438,20 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn send_interrupt_sends_ctrl_c_only() {
+        let mock = MockTmuxSender::new();
+        let manager = create_mock_manager(mock.clone());
+
+        manager.send_interrupt(3).await.unwrap();
+
+        assert_eq!(
+            mock.sent_keys(),
+            vec![(3, "C-c".to_string())],
+            "send_interrupt: should send a bare C-c without Enter or an exit command"
+        );
+    }
+
     #[tokio::test]
     async fn send_clear_sends_clear_command() {
         let mock = MockTmuxSender::new();
@@ -397,6 +501,19 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn capture_pane_plain_strips_sgr_sequences() {
+        let mock = MockTmuxSender::new()
+            .with_capture_response("\x1b[32m✔ tests passed\x1b[0m\n\x1b[1mDone\x1b[22m (3s)");
+        let manager = create_mock_manager(mock);
+
+        assert_eq!(
+            manager.capture_pane_plain(0).await.unwrap(),
+            "✔ tests passed\nDone (3s)",
+            "capture_pane_plain: should drop escapes and keep visible text"
+        );
+    }
+
     #[tokio::test]
     async fn wait_for_ready_returns_true_when_bypass_found() {
         let mock = MockTmuxSender::new().with_capture_response("bypass permissions");
@@ -421,12 +538,195 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn launch_claude_passes_configured_model_per_expert() {
+        let mock = MockTmuxSender::new();
+        let mut manager = create_mock_manager(mock.clone())
+            .with_expert_models(HashMap::from([(0, "opus".to_string())]));
+        manager.set_expert_model(1, Some("haiku".to_string()));
+
+        for id in 0..3 {
+            manager
+                .launch_claude(id, "/work", None, None, None)
+                .await
+                .unwrap();
+        }
+
+        let keys = mock.sent_keys();
+        let launched = |expert: u32| {
+            keys.iter()
+                .find(|(id, k)| *id == expert && k.starts_with("cd "))
+                .map(|(_, k)| k.clone())
+                .unwrap()
+        };
+        assert!(
+            launched(0).contains("--model 'opus'"),
+            "launch_claude: configured model should be passed, got: {}",
+            launched(0)
+        );
+        assert!(launched(1).contains("--model 'haiku'"));
+        assert!(
+            !launched(2).contains("--model"),
+            "launch_claude: experts without a model use the agent default"
+        );
+
+        manager.set_expert_model(0, None);
+        assert_eq!(manager.expert_model(0), None);
+    }
+
+    #[tokio::test]
+    async fn launch_claude_passes_configured_effort_flag_per_expert() {
+        let mock = MockTmuxSender::new();
+        let backend = CommandBackend::new(AgentConfig {
+            command: "codex".to_string(),
+            ready_marker: "context left".to_string(),
+            exit_command: "/quit".to_string(),
+            instruction_flag: None,
+            model_flag: None,
+            effort_flag: Some("--effort".to_string()),
+        });
+        let mut manager = create_mock_manager(mock.clone())
+            .with_backend(Arc::new(backend))
+            .with_expert_efforts(HashMap::from([(0, EffortLevel::High)]));
+        manager.set_expert_effort(1, EffortLevel::Low);
+
+        for id in 0..3 {
+            manager
+                .launch_claude(id, "/work", None, None, None)
+                .await
+                .unwrap();
+        }
+
+        let keys = mock.sent_keys();
+        let launched = |expert: u32| {
+            keys.iter()
+                .find(|(id, k)| *id == expert && k.starts_with("cd "))
+                .map(|(_, k)| k.clone())
+                .unwrap()
+        };
+        assert!(
+            launched(0).ends_with("codex --effort high"),
+            "launch_claude: configured effort should be passed, got: {}",
+            launched(0)
+        );
+        assert!(launched(1).ends_with("codex --effort low"));
+        assert!(
+            !launched(2).contains("--effort"),
+            "launch_claude: experts without an effort use the agent default"
+        );
+    }
+
+    #[tokio::test]
+    async fn launch_claude_prefixes_expert_env() {
+        let mock = MockTmuxSender::new();
+        let env = BTreeMap::from([
+            ("API_BASE".to_string(), "http://localhost:8080".to_string()),
+            ("FLAG".to_string(), "it's on".to_string()),
+        ]);
+        let manager = create_mock_manager(mock.clone()).with_expert_env(HashMap::from([(2, env)]));
+
+        manager
+            .launch_claude(2, "/work", None, None, None)
+            .await
+            .unwrap();
+        manager
+            .launch_claude(0, "/work", None, None, None)
+            .await
+            .unwrap();
+
+        let keys = mock.sent_keys();
+        let launched = |expert: u32| {
+            keys.iter()
+                .find(|(id, k)| *id == expert && k.starts_with("cd "))
+                .map(|(_, k)| k.as_str())
+        };
+        assert_eq!(
+            launched(2),
+            Some(
+            "cd '/work' && API_BASE='http://localhost:8080' FLAG='it'\\''s on' claude --dangerously-skip-permissions"),
+            "launch_claude: env should be quoted and prefixed to the agent command"
+        );
+        assert_eq!(
+            launched(0),
+            Some("cd '/work' && claude --dangerously-skip-permissions"),
+            "launch_claude: experts without env should launch unchanged"
+        );
+    }
+
+    #[test]
+    fn env_prefix_redacts_values() {
+        let env = BTreeMap::from([("TOKEN".to_string(), "secret".to_string())]);
+        assert_eq!(env_prefix(Some(&env), true), "TOKEN=*** ");
+        assert_eq!(env_prefix(Some(&env), false), "TOKEN='secret' ");
+        assert_eq!(env_prefix(None, false), "");
+    }
+
+    struct MockBackend;
+
+    impl AgentBackend for MockBackend {
+        fn launch_command(
+            &self,
+            instruction_file: Option<&Path>,
+            _agents_file: Option<&Path>,
+            _settings_file: Option<&Path>,
+            _model: Option<&str>,
+            _effort: Option<EffortLevel>,
+        ) -> String {
+            match instruction_file {
+                Some(file) => format!("mock-agent --prompt {}", file.display()),
+                None => "mock-agent".to_string(),
+            }
+        }
+
+        fn supports_model(&self) -> bool {
+            false
+        }
+
+        fn is_ready(&self, pane_content: &str) -> bool {
+            pane_content.contains("mock ready")
+        }
+
+        fn exit_command(&self) -> &str {
+            ":quit"
+        }
+    }
+
+    #[tokio::test]
+    async fn custom_backend_drives_launch_ready_and_exit() {
+        let mock = MockTmuxSender::new().with_capture_response("mock ready >");
+        let manager = create_mock_manager(mock.clone()).with_backend(Arc::new(MockBackend));
+
+        manager
+            .launch_claude(1, "/work", Some(Path::new("/tmp/i.md")), None, None)
+            .await
+            .unwrap();
+        assert!(
+            mock.sent_keys()
+                .iter()
+                .any(|(id, k)| *id == 1 && k == "cd '/work' && mock-agent --prompt /tmp/i.md"),
+            "launch_claude: should send the backend's launch command, got: {:?}",
+            mock.sent_keys()
+        );
+
+        assert!(
+            manager.wait_for_ready(1, 1).await.unwrap(),
+            "wait_for_ready: should use the backend's ready marker"
+        );
+        assert!(!manager.is_agent_ready("bypass permissions"));
+
+        manager.send_exit(1).await.unwrap();
+        assert!(
+            mock.sent_keys().iter().any(|(_, k)| k == ":quit"),
+            "send_exit: should send the backend's exit command"
+        );
+    }
+
     #[tokio::test]
     async fn capture_full_history_delegates_to_sender() {
         let mock = MockTmuxSender::new().with_capture_response("full history content");
         let manager = create_mock_manager(mock);
 
-        let result = manager.capture_full_history(0).await.unwrap();
+        let result = manager.capture_full_history(0, 5000).await.unwrap();
         assert_eq!(
             result, "full history content",
             "capture_full_history: should delegate to tmux sender"
@@ -3,6 +3,18 @@ use std::path::PathBuf;
 
 use crate::models::ExpertState;
 
+/// Marker the Stop hook writes: the agent is waiting for a prompt
+pub const IDLE_MARKER: &str = "pending";
+/// Marker the UserPromptSubmit hook writes: the agent is working
+pub const BUSY_MARKER: &str = "processing";
+/// Every marker the detector recognizes, with the state it maps to
+pub const MARKERS: [(&str, ExpertState); 2] = [
+    (IDLE_MARKER, ExpertState::Idle),
+    (BUSY_MARKER, ExpertState::Busy),
+];
+/// File in the status directory describing the marker protocol
+pub const PROTOCOL_FILE: &str = "PROTOCOL.md";
+
 pub struct ExpertStateDetector {
     status_dir: PathBuf,
 }
@@ -22,11 +34,11 @@ impl ExpertStateDetector {
 
         let trimmed = content.trim();
 
-        match trimmed {
-            "pending" => ExpertState::Idle,
-            "processing" => ExpertState::Busy,
-            _ => ExpertState::Busy, // unknown content → safe default
-        }
+        MARKERS
+            .iter()
+            .find(|(marker, _)| *marker == trimmed)
+            .map(|(_, state)| state.clone())
+            .unwrap_or(ExpertState::Busy) // unknown content → safe default
     }
 
     pub fn detect_all(&self, expert_ids: &[u32]) -> Vec<(u32, ExpertState)> {
@@ -42,11 +54,70 @@ impl ExpertStateDetector {
         Ok(())
     }
 
+    /// Flag an expert whose window was created without launching its agent
+    /// (`launch: lazy`), or clear the flag once it is launched
+    pub fn set_not_started(&self, expert_id: u32, not_started: bool) -> Result<()> {
+        let path = self.not_started_path(expert_id);
+        if not_started {
+            std::fs::write(&path, "")?;
+        } else if path.exists() {
+            std::fs::remove_file(&path)?;
+        }
+        Ok(())
+    }
+
+    pub fn is_not_started(&self, expert_id: u32) -> bool {
+        self.not_started_path(expert_id).exists()
+    }
+
+    fn not_started_path(&self, expert_id: u32) -> PathBuf {
+        self.status_dir
+            .join(format!("expert{expert_id}.not_started"))
+    }
+
     #[allow(dead_code)]
     pub fn ensure_status_dir(&self) -> Result<()> {
         std::fs::create_dir_all(&self.status_dir)?;
         Ok(())
     }
+
+    /// Write `PROTOCOL.md` next to the markers so instruction authors and
+    /// agents can see the exact values this detector accepts
+    pub fn write_protocol_doc(&self) -> Result<PathBuf> {
+        let path = self.status_dir.join(PROTOCOL_FILE);
+        std::fs::write(&path, protocol_doc())?;
+        Ok(path)
+    }
+}
+
+/// The status marker protocol, generated from `MARKERS`
+pub fn protocol_doc() -> String {
+    let mut doc = String::from(
+        "# Status marker protocol\n\n\
+         Generated by macot; edits are overwritten when the session starts.\n\n\
+         Each expert has one marker file in this directory named `expert<ID>` \
+         (e.g. `expert0`). The file holds exactly one of the values below; \
+         surrounding whitespace is ignored.\n\n\
+         | Marker | State | Written by |\n\
+         |---|---|---|\n",
+    );
+    for (marker, state) in MARKERS {
+        let (name, writer) = state_row(&state);
+        doc.push_str(&format!("| `{marker}` | {name} | {writer} |\n"));
+    }
+    doc.push_str(
+        "\nA missing or unreadable file, or any other value, is read as busy. \
+         Offline is never written to a marker; the tower derives it from the tmux pane.\n",
+    );
+    doc
+}
+
+fn state_row(state: &ExpertState) -> (&'static str, &'static str) {
+    match state {
+        ExpertState::Idle => ("idle", "Stop hook, when the agent finishes a run"),
+        ExpertState::Busy => ("busy", "UserPromptSubmit hook, when the agent starts a run"),
+        ExpertState::Offline => ("offline", "-"),
+    }
 }
 
 #[cfg(test)]
@@ -60,6 +131,32 @@ mod tests {
         (detector, tmp)
     }
 
+    #[test]
+    fn protocol_doc_lists_every_detected_marker() {
+        let (detector, tmp) = setup();
+        let path = detector.write_protocol_doc().unwrap();
+        assert_eq!(path, tmp.path().join(PROTOCOL_FILE));
+        let doc = std::fs::read_to_string(&path).unwrap();
+
+        for (marker, state) in MARKERS {
+            assert!(
+                doc.contains(&format!("| `{marker}` | {} |", state_row(&state).0)),
+                "protocol_doc: should list marker {marker:?}"
+            );
+            std::fs::write(tmp.path().join("expert0"), marker).unwrap();
+            assert_eq!(
+                detector.detect_state(0),
+                state,
+                "protocol_doc: documented marker {marker:?} should map to its listed state"
+            );
+        }
+        assert_eq!(
+            detector.detect_state(9),
+            ExpertState::Busy,
+            "write_protocol_doc: the doc must not be mistaken for a marker"
+        );
+    }
+
     #[test]
     fn pending_content_returns_idle() {
         let (detector, _tmp) = setup();
@@ -84,6 +181,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn not_started_flag_is_separate_from_marker() {
+        let (detector, _tmp) = setup();
+        std::fs::write(_tmp.path().join("expert0"), "pending").unwrap();
+        assert!(!detector.is_not_started(0));
+
+        detector.set_not_started(0, true).unwrap();
+        assert!(detector.is_not_started(0));
+        assert_eq!(
+            detector.detect_state(0),
+            ExpertState::Idle,
+            "set_not_started: the status marker should be left alone"
+        );
+
+        detector.set_not_started(0, false).unwrap();
+        detector.set_not_started(0, false).unwrap();
+        assert!(!detector.is_not_started(0));
+    }
+
     #[test]
     fn missing_file_returns_busy() {
         let (detector, _tmp) = setup();
@@ -1,9 +1,16 @@
+mod agent;
 mod claude;
 mod detector;
+mod ping;
 mod tmux;
 mod worktree;
 
+#[allow(unused_imports)]
+pub use agent::{agent_backend, AgentBackend, ClaudeBackend, CommandBackend};
 pub use claude::ClaudeManager;
-pub use detector::ExpertStateDetector;
-pub use tmux::{SessionMetadata, TmuxManager, TmuxSender};
-pub use worktree::{WorktreeLaunchResult, WorktreeLaunchState, WorktreeManager};
+pub use detector::{ExpertStateDetector, BUSY_MARKER, IDLE_MARKER};
+pub use ping::{ExpertPing, PingOutcome, PING_PROMPT};
+pub use tmux::{is_window_not_found_error, SessionMetadata, TmuxManager, TmuxSender};
+pub use worktree::{
+    WorktreeDiff, WorktreeLaunchResult, WorktreeLaunchState, WorktreeManager, MAX_DIFF_LINES,
+};
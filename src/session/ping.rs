@@ -0,0 +1,133 @@
+use std::time::{Duration, Instant};
+
+use crate::models::ExpertState;
+
+/// Prompt sent to check that an agent answers; short so the round trip stays cheap
+pub const PING_PROMPT: &str = "[macot] Ping from the control tower: reply with just \"pong\".";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PingPhase {
+    /// Prompt sent; the status marker has not shown Processing yet
+    AwaitingBusy,
+    /// Marker showed Processing; waiting for the agent to finish
+    AwaitingIdle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PingOutcome {
+    Pending,
+    /// Marker cycled Processing→Idle; holds the round trip
+    Responded(Duration),
+    TimedOut,
+    /// The expert went Offline while the ping was outstanding
+    Unreachable,
+}
+
+/// An outstanding ping to one expert, advanced with the states reported by
+/// `ExpertStateDetector`
+#[derive(Debug, Clone)]
+pub struct ExpertPing {
+    expert_id: u32,
+    sent_at: Instant,
+    timeout: Duration,
+    phase: PingPhase,
+}
+
+impl ExpertPing {
+    pub fn new(expert_id: u32, sent_at: Instant, timeout: Duration) -> Self {
+        Self {
+            expert_id,
+            sent_at,
+            timeout,
+            phase: PingPhase::AwaitingBusy,
+        }
+    }
+
+    pub fn expert_id(&self) -> u32 {
+        self.expert_id
+    }
+
+    /// Advance with the expert's state as seen at `now`
+    pub fn observe(&mut self, state: &ExpertState, now: Instant) -> PingOutcome {
+        match (self.phase, state) {
+            (_, ExpertState::Offline) => return PingOutcome::Unreachable,
+            (PingPhase::AwaitingBusy, ExpertState::Busy) => self.phase = PingPhase::AwaitingIdle,
+            (PingPhase::AwaitingIdle, ExpertState::Idle) => {
+                return PingOutcome::Responded(now.saturating_duration_since(self.sent_at));
+            }
+            _ => {}
+        }
+        if now.saturating_duration_since(self.sent_at) >= self.timeout {
+            return PingOutcome::TimedOut;
+        }
+        PingOutcome::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIMEOUT: Duration = Duration::from_secs(30);
+
+    #[test]
+    fn expert_ping_reports_round_trip_after_busy_then_idle() {
+        let start = Instant::now();
+        let mut ping = ExpertPing::new(2, start, TIMEOUT);
+        assert_eq!(ping.expert_id(), 2);
+
+        assert_eq!(
+            ping.observe(&ExpertState::Idle, start + Duration::from_millis(100)),
+            PingOutcome::Pending,
+            "observe: Idle before Processing is the pre-ping state, not a reply"
+        );
+        assert_eq!(
+            ping.observe(&ExpertState::Busy, start + Duration::from_secs(1)),
+            PingOutcome::Pending
+        );
+        assert_eq!(
+            ping.observe(&ExpertState::Busy, start + Duration::from_secs(2)),
+            PingOutcome::Pending
+        );
+        assert_eq!(
+            ping.observe(&ExpertState::Idle, start + Duration::from_secs(3)),
+            PingOutcome::Responded(Duration::from_secs(3)),
+            "observe: Processing→Idle should complete with the round trip"
+        );
+    }
+
+    #[test]
+    fn expert_ping_times_out_when_marker_never_cycles() {
+        let start = Instant::now();
+        let mut ping = ExpertPing::new(0, start, TIMEOUT);
+
+        assert_eq!(
+            ping.observe(&ExpertState::Busy, start + Duration::from_secs(5)),
+            PingOutcome::Pending
+        );
+        assert_eq!(
+            ping.observe(&ExpertState::Busy, start + TIMEOUT),
+            PingOutcome::TimedOut,
+            "observe: stuck in Processing past the timeout is a hang"
+        );
+
+        let mut silent = ExpertPing::new(0, start, TIMEOUT);
+        assert_eq!(
+            silent.observe(&ExpertState::Idle, start + TIMEOUT),
+            PingOutcome::TimedOut,
+            "observe: never reaching Processing should also time out"
+        );
+    }
+
+    #[test]
+    fn expert_ping_fails_fast_when_expert_goes_offline() {
+        let start = Instant::now();
+        let mut ping = ExpertPing::new(1, start, TIMEOUT);
+        ping.observe(&ExpertState::Busy, start + Duration::from_secs(1));
+
+        assert_eq!(
+            ping.observe(&ExpertState::Offline, start + Duration::from_secs(2)),
+            PingOutcome::Unreachable
+        );
+    }
+}
@@ -20,6 +20,18 @@ fn check_tmux_output(output: Output, context: &str) -> Result<String> {
     Ok(String::from_utf8_lossy(&output.stdout).to_string())
 }
 
+/// Like `check_tmux_output`, for `capture-pane` output that may contain arbitrary
+/// program bytes. Invalid UTF-8 becomes U+FFFD and control characters other than
+/// newline, tab and the escape that starts colour sequences are dropped, so a
+/// stray byte cannot break the expert panel.
+fn check_capture_output(output: Output, context: &str) -> Result<String> {
+    let text = check_tmux_output(output, context)?;
+    Ok(text
+        .chars()
+        .filter(|&c| !c.is_control() || matches!(c, '\n' | '\t' | '\x1b'))
+        .collect())
+}
+
 fn check_tmux_status(output: Output, context: &str) -> Result<()> {
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -33,6 +45,41 @@ fn check_tmux_status(output: Output, context: &str) -> Result<()> {
     Ok(())
 }
 
+/// Build `capture-pane` arguments for scrollback capture.
+///
+/// `-S -N` starts N lines above the visible area, bounding the capture;
+/// `max_lines == 0` uses `-S -` to capture the entire history.
+fn full_history_args(target: &str, max_lines: usize) -> Vec<String> {
+    let start = if max_lines == 0 {
+        "-".to_string()
+    } else {
+        format!("-{max_lines}")
+    };
+    [
+        "capture-pane",
+        "-e",
+        "-J",
+        "-p",
+        "-S",
+        &start,
+        "-E",
+        "-",
+        "-t",
+        target,
+    ]
+    .iter()
+    .map(ToString::to_string)
+    .collect()
+}
+
+/// Whether a tmux error means the target window or pane no longer exists
+pub fn is_window_not_found_error(err: &anyhow::Error) -> bool {
+    let msg = format!("{err:#}").to_lowercase();
+    msg.contains("can't find window")
+        || msg.contains("can't find pane")
+        || msg.contains("no such window")
+}
+
 fn parse_pane_paths(stdout: &str) -> HashMap<u32, String> {
     let mut paths = HashMap::new();
     for line in stdout.lines() {
@@ -94,7 +141,8 @@ pub trait TmuxSender: Send + Sync {
         self.capture_pane(window_id).await
     }
 
-    async fn capture_full_history(&self, window_id: u32) -> Result<String> {
+    /// Capture at most `max_lines` lines of scrollback (0 = entire history)
+    async fn capture_full_history(&self, window_id: u32, _max_lines: usize) -> Result<String> {
         self.capture_pane_with_escapes(window_id).await
     }
 
@@ -108,6 +156,12 @@ pub trait TmuxSender: Send + Sync {
     async fn get_pane_current_command(&self, _window_id: u32) -> Result<Option<String>> {
         Ok(None)
     }
+
+    /// Whether the target tmux session is still running.
+    /// Default assumes it is (suitable for mocks).
+    async fn has_session(&self) -> bool {
+        true
+    }
 }
 
 #[async_trait::async_trait]
@@ -130,6 +184,10 @@ impl TmuxSender for TmuxManager {
         std::time::Duration::from_millis(300)
     }
 
+    async fn has_session(&self) -> bool {
+        self.session_exists().await
+    }
+
     async fn send_text(&self, window_id: u32, text: &str) -> Result<()> {
         if !text.contains('\n') {
             return self.send_keys(window_id, text).await;
@@ -170,7 +228,7 @@ impl TmuxSender for TmuxManager {
             .output()
             .await
             .context(format!("Failed to capture window {window_id}"))?;
-        check_tmux_output(output, &format!("capture-pane {window_id}"))
+        check_capture_output(output, &format!("capture-pane {window_id}"))
     }
 
     async fn capture_pane_with_escapes(&self, window_id: u32) -> Result<String> {
@@ -185,29 +243,21 @@ impl TmuxSender for TmuxManager {
             .output()
             .await
             .context(format!("Failed to capture window {window_id} with escapes"))?;
-        check_tmux_output(output, &format!("capture-pane-with-escapes {window_id}"))
+        check_capture_output(output, &format!("capture-pane-with-escapes {window_id}"))
     }
 
-    async fn capture_full_history(&self, window_id: u32) -> Result<String> {
+    async fn capture_full_history(&self, window_id: u32, max_lines: usize) -> Result<String> {
         let output = Command::new("tmux")
-            .args([
-                "capture-pane",
-                "-e",
-                "-J",
-                "-p",
-                "-S",
-                "-",
-                "-E",
-                "-",
-                "-t",
+            .args(full_history_args(
                 &format!("{}:{}", self.session_name, window_id),
-            ])
+                max_lines,
+            ))
             .output()
             .await
             .context(format!(
                 "Failed to capture full history of window {window_id}"
             ))?;
-        check_tmux_output(output, &format!("capture-full-history {window_id}"))
+        check_capture_output(output, &format!("capture-full-history {window_id}"))
     }
 
     async fn resize_pane(&self, window_id: u32, width: u16, height: u16) -> Result<()> {
@@ -505,6 +555,8 @@ impl TmuxManager {
 
         let created_at = self.get_env("MACOT_CREATED_AT").await?;
 
+        let subproject = self.get_env("MACOT_SUBPROJECT").await?;
+
         let queue_path = self
             .get_env("MACOT_QUEUE_PATH")
             .await?
@@ -515,6 +567,7 @@ impl TmuxManager {
             num_experts,
             created_at,
             queue_path,
+            subproject,
         })
     }
 }
@@ -529,6 +582,8 @@ pub struct SessionMetadata {
     pub num_experts: Option<u32>,
     pub created_at: Option<String>,
     pub queue_path: String,
+    /// Monorepo subdirectory the session is scoped to, if any
+    pub subproject: Option<String>,
 }
 
 #[cfg(test)]
@@ -556,6 +611,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn check_capture_output_sanitizes_invalid_bytes() {
+        let mut output = make_output(0, "", "");
+        output.stdout = b"ok \xff\xfe\x1b[31mred\x1b[0m\x00\x07\tdone\n".to_vec();
+        let text = check_capture_output(output, "capture-pane 0").unwrap();
+        assert_eq!(
+            text, "ok \u{fffd}\u{fffd}\x1b[31mred\x1b[0m\tdone\n",
+            "check_capture_output: invalid bytes should be replaced and stray controls dropped"
+        );
+    }
+
     #[test]
     fn check_tmux_output_failure_returns_error_with_stderr() {
         let output = make_output(1, "", "no such pane");
@@ -592,6 +658,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn full_history_args_bounds_start_line() {
+        let args = full_history_args("macot-abc:2", 5000);
+        let start = args.iter().position(|a| a == "-S").unwrap();
+        assert_eq!(
+            args[start + 1],
+            "-5000",
+            "full_history_args: capture should start max_lines above the visible area"
+        );
+        assert_eq!(args.last().unwrap(), "macot-abc:2");
+    }
+
+    #[test]
+    fn full_history_args_zero_captures_entire_history() {
+        let args = full_history_args("macot-abc:2", 0);
+        let start = args.iter().position(|a| a == "-S").unwrap();
+        assert_eq!(
+            args[start + 1],
+            "-",
+            "full_history_args: max_lines of 0 should capture the entire history"
+        );
+    }
+
+    #[test]
+    fn is_window_not_found_error_matches_missing_window() {
+        let output = make_output(1, "", "can't find window: 3");
+        let err = check_tmux_status(output, "send-keys to window 3").unwrap_err();
+        assert!(
+            is_window_not_found_error(&err),
+            "is_window_not_found_error: missing window should be detected"
+        );
+
+        let output = make_output(1, "", "no server running on /tmp/tmux-0/default");
+        let err = check_tmux_status(output, "send-keys to window 3").unwrap_err();
+        assert!(
+            !is_window_not_found_error(&err),
+            "is_window_not_found_error: unrelated tmux failures should not match"
+        );
+    }
+
     #[test]
     fn tmux_manager_new_sets_session_name() {
         let manager = TmuxManager::new("test-session".to_string());
@@ -653,7 +759,7 @@ mod tests {
             capture_output: "mock full history".to_string(),
         };
 
-        let result = mock.capture_full_history(0).await.unwrap();
+        let result = mock.capture_full_history(0, 5000).await.unwrap();
         assert_eq!(
             result, "mock full history",
             "capture_full_history: default impl should fall back to capture_pane_with_escapes → capture_pane"
@@ -3,8 +3,19 @@ use std::path::{Path, PathBuf};
 use tokio::process::Command;
 use tokio::task::JoinHandle;
 
+use crate::config::ShellPathStyle;
 use crate::utils::path_to_str;
 
+/// Upper bound on diff lines loaded into the tower
+pub const MAX_DIFF_LINES: usize = 5000;
+
+/// Changes in a worktree relative to the branch it was created from
+pub struct WorktreeDiff {
+    pub base_branch: String,
+    pub diff: String,
+    pub truncated: bool,
+}
+
 pub struct WorktreeLaunchResult {
     pub expert_id: u32,
     pub expert_name: String,
@@ -24,6 +35,75 @@ pub enum WorktreeLaunchState {
     },
 }
 
+fn merge_base_args(base_branch: &str) -> Vec<String> {
+    vec![
+        "merge-base".to_string(),
+        base_branch.to_string(),
+        "HEAD".to_string(),
+    ]
+}
+
+/// Compare the working tree against `merge_base`, covering commits plus staged and
+/// unstaged changes to tracked files
+fn diff_args(merge_base: &str) -> Vec<String> {
+    vec![
+        "diff".to_string(),
+        "--no-color".to_string(),
+        "--no-ext-diff".to_string(),
+        merge_base.to_string(),
+    ]
+}
+
+fn merge_args(branch_name: &str) -> Vec<String> {
+    vec![
+        "merge".to_string(),
+        "--no-ff".to_string(),
+        "--no-edit".to_string(),
+        branch_name.to_string(),
+    ]
+}
+
+fn remove_worktree_args(worktree_path: &str) -> Vec<String> {
+    vec![
+        "worktree".to_string(),
+        "remove".to_string(),
+        worktree_path.to_string(),
+    ]
+}
+
+/// Keep the first `max_lines` lines; returns whether anything was dropped
+fn truncate_lines(text: &str, max_lines: usize) -> (String, bool) {
+    let mut lines = text.lines();
+    let kept: Vec<&str> = lines.by_ref().take(max_lines).collect();
+    let truncated = lines.next().is_some();
+    (kept.join("\n"), truncated)
+}
+
+/// Whether `git status --porcelain` output lists changes other than the
+/// `.macot` symlink macot adds to every worktree
+fn porcelain_has_changes(status: &str) -> bool {
+    status
+        .lines()
+        .filter_map(|line| line.get(3..))
+        .any(|path| path.trim_end_matches('/') != ".macot")
+}
+
+async fn run_git(args: &[String], current_dir: &Path) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(current_dir)
+        .output()
+        .await
+        .with_context(|| format!("Failed to run git {}", args.join(" ")))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("git {} failed: {}", args[0], stderr.trim());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
 async fn resolve_git_root(project_path: &Path) -> Result<PathBuf> {
     let output = Command::new("git")
         .args(["rev-parse", "--path-format=absolute", "--git-common-dir"])
@@ -49,10 +129,31 @@ async fn resolve_git_root(project_path: &Path) -> Result<PathBuf> {
         .unwrap_or_else(|| project_path.to_path_buf()))
 }
 
+/// Total size in bytes of the files under `path`. Symlinks are counted as
+/// links, not followed, so a worktree's `.macot` link adds nothing.
+pub fn dir_size(path: &Path) -> std::io::Result<u64> {
+    let metadata = std::fs::symlink_metadata(path)?;
+    if !metadata.is_dir() {
+        return Ok(metadata.len());
+    }
+    let mut total = 0;
+    for entry in std::fs::read_dir(path)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        total += if file_type.is_dir() {
+            dir_size(&entry.path())?
+        } else {
+            entry.metadata()?.len()
+        };
+    }
+    Ok(total)
+}
+
 #[derive(Clone)]
 pub struct WorktreeManager {
     git_root: PathBuf,
     macot_path: PathBuf,
+    shell_path_style: ShellPathStyle,
 }
 
 impl WorktreeManager {
@@ -61,6 +162,7 @@ impl WorktreeManager {
         Self {
             git_root,
             macot_path,
+            shell_path_style: ShellPathStyle::default(),
         }
     }
 
@@ -69,6 +171,24 @@ impl WorktreeManager {
         Ok(Self::new(git_root))
     }
 
+    /// Keep worktrees and their `.macot` symlink under `macot_path` instead of
+    /// the repository root, e.g. a subproject's queue directory
+    pub fn with_macot_path(mut self, macot_path: PathBuf) -> Self {
+        self.macot_path = macot_path;
+        self
+    }
+
+    /// Spell worktree paths for a tmux shell that sees the filesystem differently
+    pub fn with_shell_path_style(mut self, style: ShellPathStyle) -> Self {
+        self.shell_path_style = style;
+        self
+    }
+
+    /// `worktree_path` as the tmux shell must see it to `cd` into the worktree
+    pub fn shell_path(&self, worktree_path: &Path) -> Result<String> {
+        self.shell_path_style.to_shell(worktree_path)
+    }
+
     pub fn worktree_dir(&self) -> PathBuf {
         self.macot_path.join("worktrees")
     }
@@ -140,23 +260,125 @@ impl WorktreeManager {
         Ok(())
     }
 
-    #[allow(dead_code)]
-    pub async fn remove_worktree(&self, branch_name: &str) -> Result<()> {
-        let wt_path = self.worktree_path(branch_name);
-        let wt_path_str = path_to_str(&wt_path)?;
+    /// Branch checked out in the main repository, which worktrees are created from
+    pub async fn base_branch(&self) -> Result<String> {
+        let args = ["rev-parse", "--abbrev-ref", "HEAD"].map(String::from);
+        Ok(run_git(&args, &self.git_root).await?.trim().to_string())
+    }
 
-        let output = Command::new("git")
-            .args(["worktree", "remove", wt_path_str])
+    /// Diff a worktree against its merge base with the base branch, keeping at
+    /// most `max_lines` lines
+    pub async fn diff_against_base(
+        &self,
+        worktree_path: &Path,
+        max_lines: usize,
+    ) -> Result<WorktreeDiff> {
+        let base_branch = self.base_branch().await?;
+        let merge_base = run_git(&merge_base_args(&base_branch), worktree_path).await?;
+        let diff = run_git(&diff_args(merge_base.trim()), worktree_path).await?;
+        let (diff, truncated) = truncate_lines(&diff, max_lines);
+
+        Ok(WorktreeDiff {
+            base_branch,
+            diff,
+            truncated,
+        })
+    }
+
+    /// Whether a worktree has staged, unstaged, or untracked changes
+    pub async fn has_uncommitted_changes(&self, worktree_path: &Path) -> Result<bool> {
+        let args = ["status", "--porcelain"].map(String::from);
+        Ok(porcelain_has_changes(&run_git(&args, worktree_path).await?))
+    }
+
+    /// Worktree directories under `worktree_dir`, sorted; empty when there are none
+    pub fn list_worktrees(&self) -> Result<Vec<PathBuf>> {
+        let dir = self.worktree_dir();
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+        let mut worktrees = Vec::new();
+        for entry in std::fs::read_dir(&dir)
+            .with_context(|| format!("Failed to list worktrees in {}", dir.display()))?
+        {
+            let path = entry?.path();
+            if path.is_dir() {
+                worktrees.push(path);
+            }
+        }
+        worktrees.sort();
+        Ok(worktrees)
+    }
+
+    /// Whether the main repository is in the middle of a merge (MERGE_HEAD exists)
+    async fn merge_in_progress(&self) -> Result<bool> {
+        let status = Command::new("git")
+            .args(["rev-parse", "-q", "--verify", "MERGE_HEAD"])
             .current_dir(&self.git_root)
             .output()
             .await
-            .context("Failed to remove git worktree")?;
+            .context("Failed to run git rev-parse")?
+            .status;
+        Ok(status.success())
+    }
 
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            anyhow::bail!("git worktree remove failed: {stderr}");
+    /// Merge `branch_name` into the branch checked out in the main repository.
+    ///
+    /// Refuses when the main repository already has a merge in progress or
+    /// uncommitted changes to tracked files. A merge this call starts that
+    /// fails (e.g. conflicts) is aborted so the main repository is never left
+    /// half-merged.
+    pub async fn merge_branch(&self, branch_name: &str) -> Result<()> {
+        if self.merge_in_progress().await? {
+            anyhow::bail!(
+                "the main repository already has a merge in progress; conclude or abort it first"
+            );
+        }
+        let status = ["status", "--porcelain", "--untracked-files=no"].map(String::from);
+        if !run_git(&status, &self.git_root).await?.trim().is_empty() {
+            anyhow::bail!(
+                "the main repository has uncommitted changes; commit or stash them first"
+            );
+        }
+
+        if let Err(e) = run_git(&merge_args(branch_name), &self.git_root).await {
+            if !self.merge_in_progress().await? {
+                anyhow::bail!("merge of '{branch_name}' failed: {e}");
+            }
+            let abort = ["merge", "--abort"].map(String::from);
+            if let Err(abort_err) = run_git(&abort, &self.git_root).await {
+                tracing::debug!("git merge --abort after failed merge: {}", abort_err);
+            }
+            anyhow::bail!("merge of '{branch_name}' failed and was aborted: {e}");
+        }
+        Ok(())
+    }
+
+    /// Disk used by a worktree, walked on a blocking thread
+    pub async fn disk_usage(&self, worktree_path: &Path) -> Result<u64> {
+        let path = worktree_path.to_path_buf();
+        tokio::task::spawn_blocking(move || dir_size(&path))
+            .await
+            .context("Worktree size task panicked")?
+            .with_context(|| format!("Failed to measure {}", worktree_path.display()))
+    }
+
+    /// Remove a worktree created by `create_worktree`.
+    ///
+    /// The untracked `.macot` symlink is deleted first so git does not refuse
+    /// the removal; any other untracked or modified file still makes it fail.
+    pub async fn remove_worktree(&self, worktree_path: &Path) -> Result<()> {
+        let symlink_path = worktree_path.join(".macot");
+        if symlink_path.is_symlink() {
+            tokio::fs::remove_file(&symlink_path)
+                .await
+                .context("Failed to remove .macot symlink")?;
         }
 
+        let wt_path_str = path_to_str(worktree_path)?;
+        run_git(&remove_worktree_args(wt_path_str), &self.git_root)
+            .await
+            .context("Failed to remove git worktree")?;
         Ok(())
     }
 }
@@ -185,6 +407,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn shell_path_uses_configured_style() {
+        let mgr = WorktreeManager::new(PathBuf::from(r"C:\work\project"))
+            .with_shell_path_style(ShellPathStyle::Wsl);
+        let wt = mgr.worktree_path("feature-auth");
+        assert_eq!(
+            mgr.shell_path(&wt).unwrap(),
+            "/mnt/c/work/project/.macot/worktrees/feature-auth",
+            "shell_path: worktree path should be spelled for the WSL shell"
+        );
+    }
+
     #[test]
     fn worktree_exists_returns_false_for_nonexistent() {
         let mgr = WorktreeManager::new(PathBuf::from("/tmp/nonexistent-project-abc123"));
@@ -203,6 +437,101 @@ mod tests {
         );
     }
 
+    #[test]
+    fn merge_base_args_compare_base_with_head() {
+        assert_eq!(
+            merge_base_args("main"),
+            vec!["merge-base", "main", "HEAD"],
+            "merge_base_args: should find the fork point of HEAD from the base branch"
+        );
+    }
+
+    #[test]
+    fn diff_args_compare_working_tree_with_merge_base() {
+        assert_eq!(
+            diff_args("abc123"),
+            vec!["diff", "--no-color", "--no-ext-diff", "abc123"],
+            "diff_args: should diff the working tree against the merge base without colors"
+        );
+    }
+
+    #[test]
+    fn merge_args_create_merge_commit_without_editor() {
+        assert_eq!(
+            merge_args("feature-auth"),
+            vec!["merge", "--no-ff", "--no-edit", "feature-auth"],
+            "merge_args: should merge the branch non-interactively with a merge commit"
+        );
+    }
+
+    #[test]
+    fn remove_worktree_args_target_worktree_path() {
+        assert_eq!(
+            remove_worktree_args("/tmp/project/.macot/worktrees/feature-auth"),
+            vec![
+                "worktree",
+                "remove",
+                "/tmp/project/.macot/worktrees/feature-auth"
+            ],
+            "remove_worktree_args: should remove the worktree at the given path"
+        );
+    }
+
+    #[test]
+    fn porcelain_has_changes_ignores_macot_symlink() {
+        assert!(!porcelain_has_changes(""));
+        assert!(
+            !porcelain_has_changes("?? .macot\n"),
+            "porcelain_has_changes: the .macot symlink is not a change"
+        );
+        assert!(porcelain_has_changes("?? .macot\n M src/lib.rs\n"));
+        assert!(porcelain_has_changes("A  new.txt\n"));
+    }
+
+    #[test]
+    fn truncate_lines_keeps_short_text() {
+        let (text, truncated) = truncate_lines("a\nb", 5);
+        assert_eq!(text, "a\nb");
+        assert!(
+            !truncated,
+            "truncate_lines: short text should not be truncated"
+        );
+    }
+
+    #[test]
+    fn truncate_lines_bounds_long_text() {
+        let (text, truncated) = truncate_lines("a\nb\nc\nd", 2);
+        assert_eq!(text, "a\nb");
+        assert!(
+            truncated,
+            "truncate_lines: long text should be flagged as truncated"
+        );
+    }
+
+    #[test]
+    fn dir_size_sums_nested_files_without_following_symlinks() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path().join("wt");
+        std::fs::create_dir_all(root.join("src/nested")).unwrap();
+        std::fs::write(root.join("README.md"), vec![b'a'; 100]).unwrap();
+        std::fs::write(root.join("src/lib.rs"), vec![b'b'; 250]).unwrap();
+        std::fs::write(root.join("src/nested/mod.rs"), vec![b'c'; 4096]).unwrap();
+
+        let outside = tmp.path().join("outside");
+        std::fs::create_dir_all(&outside).unwrap();
+        std::fs::write(outside.join("big.bin"), vec![0u8; 1 << 20]).unwrap();
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(&outside, root.join(".macot")).unwrap();
+
+        let size = dir_size(&root).unwrap();
+        assert!(
+            (100 + 250 + 4096..1 << 20).contains(&size),
+            "dir_size: nested files count, the symlinked directory does not (got {size})"
+        );
+        assert_eq!(dir_size(&root.join("README.md")).unwrap(), 100);
+        assert!(dir_size(&tmp.path().join("missing")).is_err());
+    }
+
     #[test]
     fn worktree_path_different_branches_produce_different_paths() {
         let mgr = WorktreeManager::new(PathBuf::from("/tmp/project"));
@@ -248,6 +577,36 @@ mod resolve_tests {
         );
     }
 
+    #[tokio::test]
+    async fn resolve_from_subproject_targets_repo_root() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo_path = tmp.path().canonicalize().unwrap();
+        for args in [vec!["init"], vec!["commit", "--allow-empty", "-m", "init"]] {
+            std::process::Command::new("git")
+                .args(args)
+                .current_dir(&repo_path)
+                .output()
+                .unwrap();
+        }
+        let subproject = repo_path.join("services").join("api");
+        std::fs::create_dir_all(&subproject).unwrap();
+
+        let mgr = WorktreeManager::resolve(subproject.clone())
+            .await
+            .unwrap()
+            .with_macot_path(subproject.join(".macot"));
+
+        assert_eq!(
+            mgr.git_root, repo_path,
+            "resolve: a subproject should keep git operations at the repo root"
+        );
+        assert_eq!(
+            mgr.worktree_dir(),
+            subproject.join(".macot").join("worktrees"),
+            "with_macot_path: worktrees should live beside the subproject queue"
+        );
+    }
+
     #[tokio::test]
     async fn resolve_from_worktree_returns_main_repo_root() {
         let tmp = tempfile::tempdir().unwrap();
@@ -341,6 +700,204 @@ mod resolve_tests {
         );
     }
 
+    #[tokio::test]
+    async fn diff_against_base_includes_worktree_changes() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo_path = tmp.path().canonicalize().unwrap();
+
+        for args in [vec!["init"], vec!["commit", "--allow-empty", "-m", "init"]] {
+            std::process::Command::new("git")
+                .args(args)
+                .current_dir(&repo_path)
+                .output()
+                .unwrap();
+        }
+
+        let mgr = WorktreeManager::new(repo_path.clone());
+        let wt_path = mgr.create_worktree("diff-branch").await.unwrap();
+        std::fs::write(wt_path.join("notes.txt"), "hello\n").unwrap();
+        std::process::Command::new("git")
+            .args(["add", "notes.txt"])
+            .current_dir(&wt_path)
+            .output()
+            .unwrap();
+
+        let result = mgr
+            .diff_against_base(&wt_path, MAX_DIFF_LINES)
+            .await
+            .unwrap();
+        assert!(
+            result.diff.contains("+hello"),
+            "diff_against_base: staged worktree changes should appear, got: {}",
+            result.diff
+        );
+        assert!(!result.truncated);
+    }
+
+    fn git(args: &[&str], dir: &Path) {
+        std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+    }
+
+    fn init_repo_with_file(repo_path: &Path) {
+        git(&["init"], repo_path);
+        std::fs::write(repo_path.join("shared.txt"), "base\n").unwrap();
+        git(&["add", "shared.txt"], repo_path);
+        git(&["commit", "-m", "init"], repo_path);
+    }
+
+    fn commit_file(dir: &Path, content: &str, message: &str) {
+        std::fs::write(dir.join("shared.txt"), content).unwrap();
+        git(&["commit", "-am", message], dir);
+    }
+
+    #[tokio::test]
+    async fn merge_branch_merges_worktree_commits() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo_path = tmp.path().canonicalize().unwrap();
+        init_repo_with_file(&repo_path);
+
+        let mgr = WorktreeManager::new(repo_path.clone());
+        let wt_path = mgr.create_worktree("merge-ok").await.unwrap();
+        commit_file(&wt_path, "from worktree\n", "worktree change");
+
+        assert!(!mgr.has_uncommitted_changes(&wt_path).await.unwrap());
+        mgr.merge_branch("merge-ok").await.unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(repo_path.join("shared.txt")).unwrap(),
+            "from worktree\n",
+            "merge_branch: worktree commits should land on the base branch"
+        );
+    }
+
+    #[tokio::test]
+    async fn merge_branch_aborts_on_conflict() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo_path = tmp.path().canonicalize().unwrap();
+        init_repo_with_file(&repo_path);
+
+        let mgr = WorktreeManager::new(repo_path.clone());
+        let wt_path = mgr.create_worktree("merge-conflict").await.unwrap();
+        commit_file(&wt_path, "from worktree\n", "worktree change");
+        commit_file(&repo_path, "from base\n", "base change");
+
+        let err = mgr.merge_branch("merge-conflict").await.unwrap_err();
+        assert!(
+            err.to_string().contains("aborted"),
+            "merge_branch: conflict should be reported as an aborted merge, got: {err}"
+        );
+        assert!(
+            !repo_path.join(".git/MERGE_HEAD").exists(),
+            "merge_branch: main repository should not be left mid-merge"
+        );
+        assert_eq!(
+            std::fs::read_to_string(repo_path.join("shared.txt")).unwrap(),
+            "from base\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn merge_branch_leaves_existing_merge_in_progress_untouched() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo_path = tmp.path().canonicalize().unwrap();
+        init_repo_with_file(&repo_path);
+
+        let mgr = WorktreeManager::new(repo_path.clone());
+        let wt_path = mgr.create_worktree("to-merge").await.unwrap();
+        commit_file(&wt_path, "from worktree\n", "worktree change");
+
+        // The user's own conflicting merge, resolved but not yet committed
+        git(&["checkout", "-b", "side"], &repo_path);
+        commit_file(&repo_path, "from side\n", "side change");
+        git(&["checkout", "-"], &repo_path);
+        commit_file(&repo_path, "from base\n", "base change");
+        git(&["merge", "side"], &repo_path);
+        assert!(repo_path.join(".git/MERGE_HEAD").exists());
+        std::fs::write(repo_path.join("shared.txt"), "resolved\n").unwrap();
+
+        let err = mgr.merge_branch("to-merge").await.unwrap_err();
+        assert!(
+            err.to_string().contains("merge in progress"),
+            "merge_branch: should refuse during an existing merge, got: {err}"
+        );
+        assert!(
+            repo_path.join(".git/MERGE_HEAD").exists(),
+            "merge_branch: the user's merge should not be aborted"
+        );
+        assert_eq!(
+            std::fs::read_to_string(repo_path.join("shared.txt")).unwrap(),
+            "resolved\n",
+            "merge_branch: the user's conflict resolution should be kept"
+        );
+    }
+
+    #[tokio::test]
+    async fn merge_branch_refuses_dirty_main_repository() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo_path = tmp.path().canonicalize().unwrap();
+        init_repo_with_file(&repo_path);
+
+        let mgr = WorktreeManager::new(repo_path.clone());
+        let wt_path = mgr.create_worktree("to-merge").await.unwrap();
+        commit_file(&wt_path, "from worktree\n", "worktree change");
+        std::fs::write(repo_path.join("shared.txt"), "local edit\n").unwrap();
+
+        let err = mgr.merge_branch("to-merge").await.unwrap_err();
+        assert!(
+            err.to_string().contains("uncommitted changes"),
+            "merge_branch: should refuse with a dirty main repository, got: {err}"
+        );
+        assert_eq!(
+            std::fs::read_to_string(repo_path.join("shared.txt")).unwrap(),
+            "local edit\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn remove_worktree_deletes_worktree_directory() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo_path = tmp.path().canonicalize().unwrap();
+        init_repo_with_file(&repo_path);
+
+        let mgr = WorktreeManager::new(repo_path.clone());
+        let wt_path = mgr.create_worktree("remove-me").await.unwrap();
+        std::fs::write(wt_path.join("scratch.txt"), "x").unwrap();
+        assert!(mgr.has_uncommitted_changes(&wt_path).await.unwrap());
+        std::fs::remove_file(wt_path.join("scratch.txt")).unwrap();
+
+        mgr.remove_worktree(&wt_path).await.unwrap();
+        assert!(
+            !wt_path.exists(),
+            "remove_worktree: worktree directory should be removed"
+        );
+    }
+
+    #[tokio::test]
+    async fn remove_worktree_removes_worktree_with_macot_symlink() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo_path = tmp.path().canonicalize().unwrap();
+        init_repo_with_file(&repo_path);
+
+        let mgr = WorktreeManager::new(repo_path.clone());
+        let wt_path = mgr.create_worktree("with-symlink").await.unwrap();
+        mgr.setup_macot_symlink(&wt_path).await.unwrap();
+        assert!(!mgr.has_uncommitted_changes(&wt_path).await.unwrap());
+
+        mgr.remove_worktree(&wt_path).await.unwrap();
+        assert!(
+            !wt_path.exists(),
+            "remove_worktree: the .macot symlink should not block removal"
+        );
+        assert!(
+            repo_path.join(".macot").exists(),
+            "remove_worktree: the symlink target should survive"
+        );
+    }
+
     #[tokio::test]
     async fn resolve_fails_for_non_git_directory() {
         let tmp = tempfile::tempdir().unwrap();
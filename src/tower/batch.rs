@@ -0,0 +1,117 @@
+use anyhow::{bail, Result};
+
+/// One task from a batch file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchTask {
+    /// Expert named by a leading `@name` or `@id`; otherwise the task is
+    /// handed out round-robin
+    pub expert: Option<String>,
+    pub description: String,
+}
+
+/// Tasks in a batch file, one per line. Blank lines and lines starting with
+/// `#` are skipped.
+pub fn parse_batch(content: &str) -> Vec<BatchTask> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| match line.strip_prefix('@') {
+            Some(rest) => {
+                let (expert, description) =
+                    rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+                BatchTask {
+                    expert: Some(expert.to_string()),
+                    description: description.trim().to_string(),
+                }
+            }
+            None => BatchTask {
+                expert: None,
+                description: line.to_string(),
+            },
+        })
+        .collect()
+}
+
+/// Pair each task with its expert: `@` tasks go to `resolve(name)`, the rest
+/// take turns through `rotation` in order. Fails without a partial plan, so a
+/// typo in the file sends nothing.
+pub fn plan_batch(
+    tasks: &[BatchTask],
+    rotation: &[u32],
+    resolve: impl Fn(&str) -> Result<u32>,
+) -> Result<Vec<(u32, String)>> {
+    let mut next = rotation.iter().cycle();
+    let mut plan = Vec::with_capacity(tasks.len());
+    for (line, task) in tasks.iter().enumerate() {
+        if task.description.is_empty() {
+            bail!("Task {} has no description", line + 1);
+        }
+        let expert_id = match &task.expert {
+            Some(name) => resolve(name)?,
+            None => match next.next() {
+                Some(&id) => id,
+                None => bail!("No experts available to take unassigned tasks"),
+            },
+        };
+        plan.push((expert_id, task.description.clone()));
+    }
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolve(name: &str) -> Result<u32> {
+        match name {
+            "alice" => Ok(0),
+            "bob" => Ok(1),
+            _ => bail!("Unknown expert: {name}"),
+        }
+    }
+
+    #[test]
+    fn parse_batch_skips_blank_and_comment_lines() {
+        let tasks = parse_batch("# sprint 12\nFix login\n\n  @bob  Write docs  \n#@alice nope\n");
+        assert_eq!(
+            tasks,
+            vec![
+                BatchTask {
+                    expert: None,
+                    description: "Fix login".to_string(),
+                },
+                BatchTask {
+                    expert: Some("bob".to_string()),
+                    description: "Write docs".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn plan_batch_round_robins_unpinned_tasks() {
+        let tasks = parse_batch("one\n@alice two\nthree\nfour\nfive");
+        let plan = plan_batch(&tasks, &[2, 3], resolve).unwrap();
+        let ids: Vec<u32> = plan.iter().map(|(id, _)| *id).collect();
+        assert_eq!(
+            ids,
+            vec![2, 0, 3, 2, 3],
+            "plan_batch: pinned tasks should not take a turn from the rotation"
+        );
+        assert_eq!(plan[1].1, "two");
+    }
+
+    #[test]
+    fn plan_batch_rejects_unknown_expert_and_empty_rotation() {
+        let err = plan_batch(&parse_batch("@carol fix"), &[0], resolve).unwrap_err();
+        assert!(err.to_string().contains("carol"));
+        assert!(plan_batch(&parse_batch("fix"), &[], resolve).is_err());
+        assert!(plan_batch(&parse_batch("@bob"), &[0], resolve).is_err());
+        assert_eq!(
+            plan_batch(&parse_batch("@bob fix"), &[], resolve).unwrap(),
+            vec![(1, "fix".to_string())],
+            "plan_batch: pinned tasks need no rotation"
+        );
+    }
+}
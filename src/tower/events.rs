@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+
+use serde::Serialize;
+
+use crate::models::{ExpertState, Report, TaskStatus};
+
+/// One line of the `macot tower --events` stream
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TowerEvent {
+    /// `from` is null for the state first seen when the stream starts
+    StateChanged {
+        expert_id: u32,
+        expert_name: String,
+        from: Option<ExpertState>,
+        to: ExpertState,
+    },
+    MessageDelivered {
+        expert_id: u32,
+        subject: String,
+    },
+    ReportArrived {
+        expert_id: u32,
+        task_id: String,
+        status: TaskStatus,
+    },
+    FeatureBatchSent {
+        feature: String,
+        expert_id: u32,
+        tasks: Vec<String>,
+    },
+    FeatureCompleted {
+        feature: String,
+        completed: usize,
+        total: usize,
+    },
+    FeatureFailed {
+        feature: String,
+        reason: String,
+    },
+}
+
+impl TowerEvent {
+    /// The event as a single line of JSON, without the trailing newline
+    pub fn to_json_line(&self) -> String {
+        serde_json::to_string(self).expect("TowerEvent always serializes")
+    }
+}
+
+/// Events gathered by the poll loop until the headless runner writes them out
+#[derive(Debug, Default)]
+pub struct EventStream {
+    pending: Vec<TowerEvent>,
+    /// Reports already listed; `None` until the first listing, which is
+    /// recorded without emitting anything
+    seen_reports: Option<HashSet<(u32, String)>>,
+}
+
+impl EventStream {
+    pub fn push(&mut self, event: TowerEvent) {
+        self.pending.push(event);
+    }
+
+    /// Queue `report_arrived` for reports not listed before
+    pub fn observe_reports(&mut self, reports: &[Report]) {
+        let first_listing = self.seen_reports.is_none();
+        let seen = self.seen_reports.get_or_insert_with(HashSet::new);
+        for report in reports {
+            let key = (report.expert_id, report.task_id.clone());
+            if seen.insert(key) && !first_listing {
+                self.pending.push(TowerEvent::ReportArrived {
+                    expert_id: report.expert_id,
+                    task_id: report.task_id.clone(),
+                    status: report.status,
+                });
+            }
+        }
+    }
+
+    pub fn drain(&mut self) -> Vec<TowerEvent> {
+        std::mem::take(&mut self.pending)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tower_event_serializes_as_tagged_json_line() {
+        let event = TowerEvent::MessageDelivered {
+            expert_id: 2,
+            subject: "Review the API".to_string(),
+        };
+        assert_eq!(
+            event.to_json_line(),
+            r#"{"event":"message_delivered","expert_id":2,"subject":"Review the API"}"#
+        );
+    }
+
+    #[test]
+    fn observe_reports_emits_only_reports_new_since_first_listing() {
+        let mut stream = EventStream::default();
+        let existing = Report::new("task-1".to_string(), 0, "alice".to_string());
+        stream.observe_reports(std::slice::from_ref(&existing));
+        assert!(
+            stream.drain().is_empty(),
+            "observe_reports: reports present at startup are not news"
+        );
+
+        let fresh = Report::new("task-2".to_string(), 1, "bob".to_string());
+        let status = fresh.status;
+        stream.observe_reports(&[existing.clone(), fresh]);
+        stream.observe_reports(&[existing]);
+        assert_eq!(
+            stream.drain(),
+            vec![TowerEvent::ReportArrived {
+                expert_id: 1,
+                task_id: "task-2".to_string(),
+                status,
+            }]
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::process::Command;
+
+/// How long a post-task hook may run before it is killed
+const POST_TASK_HOOK_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Run the configured post-task hook through `sh -c` in `cwd`. The expert id,
+/// name and task are passed as `$1`..`$3` and as `MACOT_EXPERT_ID`,
+/// `MACOT_EXPERT_NAME` and `MACOT_TASK`. Returns the hook's trimmed output.
+pub(crate) async fn run_post_task_hook(
+    command: &str,
+    cwd: &Path,
+    expert_id: u32,
+    expert_name: &str,
+    task: &str,
+) -> Result<String> {
+    let id = expert_id.to_string();
+    let child = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .arg("macot-post-task-hook")
+        .args([id.as_str(), expert_name, task])
+        .env("MACOT_EXPERT_ID", &id)
+        .env("MACOT_EXPERT_NAME", expert_name)
+        .env("MACOT_TASK", task)
+        .current_dir(cwd)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .context("Failed to start post-task hook")?;
+
+    let output = tokio::time::timeout(POST_TASK_HOOK_TIMEOUT, child.wait_with_output())
+        .await
+        .with_context(|| {
+            format!(
+                "post-task hook timed out after {}s",
+                POST_TASK_HOOK_TIMEOUT.as_secs()
+            )
+        })?
+        .context("Failed to wait for post-task hook")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!(
+            "post-task hook exited with {}: {}",
+            output.status,
+            stderr.trim()
+        );
+    }
+    let mut text = String::from_utf8_lossy(&output.stdout).to_string();
+    text.push_str(&String::from_utf8_lossy(&output.stderr));
+    Ok(text.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn run_post_task_hook_reports_failure_with_stderr() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let err = run_post_task_hook("echo broken >&2; exit 3", tmp.path(), 0, "a", "t")
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("broken"),
+            "run_post_task_hook: error should carry stderr, got {err}"
+        );
+    }
+}
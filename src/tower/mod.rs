@@ -1,5 +1,11 @@
 mod app;
+mod batch;
+mod events;
+mod hooks;
+mod perf_log;
+mod shutdown;
 mod ui;
 pub mod widgets;
 
 pub use app::TowerApp;
+pub use perf_log::{PerfLog, PERF_LOG_MAX_BYTES};
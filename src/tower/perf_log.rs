@@ -0,0 +1,191 @@
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::widgets::LoopTimings;
+
+/// Size at which the log is moved to `<path>.1` and a fresh file started
+pub const PERF_LOG_MAX_BYTES: u64 = 10 * 1024 * 1024;
+
+/// First line of every perf log file; durations are in microseconds
+pub const PERF_LOG_HEADER: &str =
+    "unix_ms,total_us,draw_us,events_us,poll_status_us,poll_reports_us,poll_messages_us";
+
+/// One CSV row (without the newline) for a loop that finished at `at`
+pub fn csv_row(at: SystemTime, timings: &LoopTimings) -> String {
+    let unix_ms = at
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO)
+        .as_millis();
+    let phases = [
+        timings.total,
+        timings.draw,
+        timings.events,
+        timings.poll_status,
+        timings.poll_reports,
+        timings.poll_messages,
+    ];
+    let mut row = unix_ms.to_string();
+    for phase in phases {
+        row.push(',');
+        row.push_str(&phase.as_micros().to_string());
+    }
+    row
+}
+
+/// `macot tower --perf-log`: every loop's phase timings, appended as CSV
+pub struct PerfLog {
+    path: PathBuf,
+    file: BufWriter<File>,
+    written: u64,
+    max_bytes: u64,
+}
+
+impl PerfLog {
+    /// Append to `path`, writing the header when the file is new or empty
+    pub fn open(path: impl Into<PathBuf>, max_bytes: u64) -> io::Result<Self> {
+        let path = path.into();
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let written = file.metadata()?.len();
+        let mut log = Self {
+            path,
+            file: BufWriter::new(file),
+            written,
+            max_bytes,
+        };
+        if log.written == 0 {
+            log.write_line(PERF_LOG_HEADER)?;
+        }
+        Ok(log)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append one row and flush it, so the log is complete up to the last
+    /// loop even if the tower is killed
+    pub fn record(&mut self, at: SystemTime, timings: &LoopTimings) -> io::Result<()> {
+        if self.written >= self.max_bytes {
+            self.rotate()?;
+        }
+        self.write_line(&csv_row(at, timings))?;
+        self.file.flush()
+    }
+
+    fn write_line(&mut self, line: &str) -> io::Result<()> {
+        writeln!(self.file, "{line}")?;
+        self.written += line.len() as u64 + 1;
+        Ok(())
+    }
+
+    /// Keep one previous file as `<path>.1`, replacing any older one
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        let mut rotated = self.path.clone().into_os_string();
+        rotated.push(".1");
+        std::fs::rename(&self.path, rotated)?;
+        let file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&self.path)?;
+        self.file = BufWriter::new(file);
+        self.written = 0;
+        self.write_line(PERF_LOG_HEADER)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn sample_timings() -> LoopTimings {
+        LoopTimings {
+            total: Duration::from_micros(25_400),
+            draw: Duration::from_micros(12_000),
+            events: Duration::from_micros(10_050),
+            poll_status: Duration::from_micros(900),
+            poll_reports: Duration::ZERO,
+            poll_messages: Duration::from_micros(2_450),
+        }
+    }
+
+    #[test]
+    fn csv_row_matches_header_columns() {
+        let at = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+        let row = csv_row(at, &sample_timings());
+
+        assert_eq!(row, "1700000000123,25400,12000,10050,900,0,2450");
+        assert_eq!(
+            row.split(',').count(),
+            PERF_LOG_HEADER.split(',').count(),
+            "csv_row: one value per header column"
+        );
+    }
+
+    #[test]
+    fn perf_log_rotates_past_max_size() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("perf.csv");
+        let mut log = PerfLog::open(&path, 200).unwrap();
+        for _ in 0..6 {
+            log.record(SystemTime::now(), &sample_timings()).unwrap();
+        }
+        drop(log);
+
+        let rotated = std::fs::read_to_string(tmp.path().join("perf.csv.1")).unwrap();
+        let current = std::fs::read_to_string(&path).unwrap();
+        for content in [&rotated, &current] {
+            assert_eq!(
+                content.lines().next(),
+                Some(PERF_LOG_HEADER),
+                "perf_log: every file should start with the header"
+            );
+        }
+        assert_eq!(
+            rotated.lines().count() + current.lines().count(),
+            6 + 2,
+            "perf_log: no rows should be lost across the rotation"
+        );
+        assert!(
+            rotated.len() >= 200,
+            "perf_log: rotation happens once the file reaches the cap"
+        );
+    }
+
+    #[test]
+    fn perf_log_record_reaches_the_file_before_drop() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("perf.csv");
+        let mut log = PerfLog::open(&path, PERF_LOG_MAX_BYTES).unwrap();
+        log.record(SystemTime::now(), &sample_timings()).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            content.lines().count(),
+            2,
+            "record: each row should be flushed as it is written"
+        );
+    }
+
+    #[test]
+    fn perf_log_appends_without_repeating_header() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("perf.csv");
+        PerfLog::open(&path, PERF_LOG_MAX_BYTES)
+            .unwrap()
+            .record(SystemTime::now(), &sample_timings())
+            .unwrap();
+        PerfLog::open(&path, PERF_LOG_MAX_BYTES)
+            .unwrap()
+            .record(SystemTime::now(), &sample_timings())
+            .unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(content.lines().count(), 3);
+        assert_eq!(content.matches(PERF_LOG_HEADER).count(), 1);
+    }
+}
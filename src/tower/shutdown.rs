@@ -0,0 +1,42 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use anyhow::Result;
+
+/// Set when the host asks the tower to stop (SIGTERM, SIGHUP). The run loop
+/// checks it between iterations, so a shutdown never cuts a write short.
+#[derive(Debug, Clone, Default)]
+pub struct ShutdownFlag(Arc<AtomicBool>);
+
+impl ShutdownFlag {
+    pub fn request(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_requested(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// Set `flag` once SIGTERM or SIGHUP arrives
+#[cfg(unix)]
+pub fn listen_for_termination(flag: ShutdownFlag) -> Result<()> {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut terminate = signal(SignalKind::terminate())?;
+    let mut hangup = signal(SignalKind::hangup())?;
+    tokio::spawn(async move {
+        tokio::select! {
+            _ = terminate.recv() => {}
+            _ = hangup.recv() => {}
+        }
+        tracing::info!("Termination signal received; shutting down");
+        flag.request();
+    });
+    Ok(())
+}
+
+#[cfg(not(unix))]
+pub fn listen_for_termination(_flag: ShutdownFlag) -> Result<()> {
+    Ok(())
+}
@@ -7,16 +7,29 @@ use crossterm::{
 };
 use ratatui::{
     backend::CrosstermBackend,
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
     Frame, Terminal,
 };
 
 use super::app::{FocusArea, LayoutAreas, TowerApp};
 use super::widgets::ViewMode;
-use crate::utils::truncate_str_head;
+use crate::config::KeyAction;
+use crate::utils::{format_bytes, truncate_str_head};
+
+const READONLY_BANNER: &str = " READ-ONLY ";
+const SESSION_LOST_BANNER: &str = " SESSION LOST ";
+
+/// Terminals shorter than this collapse the expert list to a one-line summary
+pub const COMPACT_STATUS_BELOW_ROWS: u16 = 30;
+/// Rows given to the collapsed expert list
+pub const COMPACT_STATUS_HEIGHT: u16 = 1;
+/// Below this size only a "terminal too small" notice is drawn; at the
+/// minimum height the task input keeps one line beside the expert panel
+pub const MIN_TERMINAL_WIDTH: u16 = 40;
+pub const MIN_TERMINAL_HEIGHT: u16 = 20;
 
 pub struct UI;
 
@@ -36,14 +49,47 @@ impl UI {
     }
 
     pub fn render(frame: &mut Frame, app: &mut TowerApp) {
+        let area = frame.area();
+        if area.width < MIN_TERMINAL_WIDTH || area.height < MIN_TERMINAL_HEIGHT {
+            // No panel is drawn, so clicks must not land on stale areas
+            app.set_layout_areas(LayoutAreas::default());
+            Self::render_too_small(frame, area);
+            return;
+        }
+
         let badge = app.feature_executor().and_then(|e| e.execution_badge());
         app.status_display().set_execution_badge(badge);
 
-        // Dynamic height: expert_count + 2 (borders), minimum 3
-        let expert_height = (app.status_display().expert_count() + 2).max(3) as u16;
+        let compact_status =
+            app.resolve_compact_status(frame.area().height < COMPACT_STATUS_BELOW_ROWS);
+        // Dynamic height: expert rows (incl. note subtitles) + 2 (borders), minimum 3
+        let expert_height = if compact_status {
+            COMPACT_STATUS_HEIGHT
+        } else {
+            (app.status_display().row_count() + 2).max(3) as u16
+        };
         let panel_visible = app.expert_panel_display().is_visible();
 
-        if panel_visible {
+        if app.is_panel_zoomed() {
+            // Zoomed: the expert panel takes everything but the footer
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(1)
+                .constraints([
+                    Constraint::Min(10),   // [0] Expert Panel
+                    Constraint::Length(3), // [1] Footer
+                ])
+                .split(frame.area());
+
+            app.set_layout_areas(LayoutAreas {
+                expert_list: Rect::default(),
+                task_input: Rect::default(),
+                expert_panel: chunks[0],
+            });
+
+            app.expert_panel_display().render(frame, chunks[0]);
+            Self::render_footer(frame, chunks[1], app);
+        } else if panel_visible {
             // 5 layout constraints when panel is visible
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
@@ -64,7 +110,7 @@ impl UI {
             });
 
             Self::render_header(frame, chunks[0], app);
-            app.status_display().render(frame, chunks[1]);
+            Self::render_status(frame, chunks[1], app, compact_status);
             Self::render_task_input(frame, chunks[2], app);
             app.expert_panel_display().render(frame, chunks[3]);
             Self::render_footer(frame, chunks[4], app);
@@ -88,7 +134,7 @@ impl UI {
             });
 
             Self::render_header(frame, chunks[0], app);
-            app.status_display().render(frame, chunks[1]);
+            Self::render_status(frame, chunks[1], app, compact_status);
             Self::render_task_input(frame, chunks[2], app);
             Self::render_footer(frame, chunks[3], app);
         }
@@ -99,6 +145,43 @@ impl UI {
             app.report_display().render_detail_modal(frame, modal_area);
         }
 
+        if app.diff_modal().is_visible() {
+            let (percent_x, percent_y) = Self::responsive_modal_size(frame.area(), 80, 90);
+            let modal_area = Self::centered_area(frame.area(), percent_x, percent_y);
+            app.diff_modal().render(frame, modal_area);
+        }
+
+        if app.feature_plan_modal().is_visible() {
+            let (percent_x, percent_y) = Self::responsive_modal_size(frame.area(), 60, 70);
+            let modal_area = Self::centered_area(frame.area(), percent_x, percent_y);
+            app.feature_plan_modal().render(frame, modal_area);
+        }
+
+        if app.activity_timeline().is_visible() {
+            let (percent_x, percent_y) = Self::responsive_modal_size(frame.area(), 80, 80);
+            let modal_area = Self::centered_area(frame.area(), percent_x, percent_y);
+            app.activity_timeline().render(frame, modal_area);
+        }
+
+        if app.message_history().is_visible() {
+            let (percent_x, percent_y) = Self::responsive_modal_size(frame.area(), 80, 80);
+            let modal_area = Self::centered_area(frame.area(), percent_x, percent_y);
+            app.message_history().render(frame, modal_area);
+        }
+
+        if app.scratchpad().is_visible() {
+            let (percent_x, percent_y) = Self::responsive_modal_size(frame.area(), 80, 80);
+            let modal_area = Self::centered_area(frame.area(), percent_x, percent_y);
+            app.scratchpad().render(frame, modal_area);
+        }
+
+        if app.messaging_display().is_visible() {
+            let (percent_x, percent_y) = Self::responsive_modal_size(frame.area(), 80, 70);
+            let modal_area = Self::centered_area(frame.area(), percent_x, percent_y);
+            frame.render_widget(Clear, modal_area);
+            app.messaging_display().render(frame, modal_area);
+        }
+
         if app.help_modal().is_visible() {
             let (percent_x, percent_y) = Self::responsive_modal_size(frame.area(), 60, 80);
             let modal_area = Self::centered_area(frame.area(), percent_x, percent_y);
@@ -108,6 +191,22 @@ impl UI {
         if app.role_selector().is_visible() {
             app.role_selector().render(frame, frame.area());
         }
+
+        if app.template_picker().is_visible() {
+            app.template_picker().render(frame, frame.area());
+        }
+
+        if app.note_editor().is_visible() {
+            app.note_editor().render(frame, frame.area());
+        }
+
+        if app.command_palette().is_visible() {
+            app.command_palette().render(frame, frame.area());
+        }
+
+        if app.debug_overlay().is_visible() {
+            app.debug_overlay().render(frame, frame.area());
+        }
     }
 
     fn responsive_modal_size(area: Rect, base_x: u16, base_y: u16) -> (u16, u16) {
@@ -145,6 +244,14 @@ impl UI {
             .split(popup_layout[1])[1]
     }
 
+    fn render_status(frame: &mut Frame, area: Rect, app: &mut TowerApp, compact: bool) {
+        if compact {
+            app.status_display().render_compact(frame, area);
+        } else {
+            app.status_display().render(frame, area);
+        }
+    }
+
     fn render_header(frame: &mut Frame, area: Rect, app: &mut TowerApp) {
         let summary = app.status_display().get_status_summary();
 
@@ -164,8 +271,33 @@ impl UI {
         let compact_fixed: usize =
             " MACOT ".len() + "| ".len() + session_name.len() + " ".len() + "| ".len();
 
-        let right_text_width =
-            format!("○ {} ", summary.idle).len() + format!("● {} ", summary.busy).len();
+        let readonly_text = if app.is_readonly() {
+            READONLY_BANNER
+        } else {
+            ""
+        };
+        let session_lost_text = if app.is_session_lost() {
+            SESSION_LOST_BANNER
+        } else {
+            ""
+        };
+        let wide_fixed = wide_fixed + readonly_text.len() + session_lost_text.len();
+        let compact_fixed = compact_fixed + readonly_text.len() + session_lost_text.len();
+
+        let offline_text = if summary.offline > 0 {
+            format!("✗ {} ", summary.offline)
+        } else {
+            String::new()
+        };
+        let worktree_text = if summary.worktree_bytes > 0 {
+            format!("worktrees {} ", format_bytes(summary.worktree_bytes))
+        } else {
+            String::new()
+        };
+        let right_text_width = format!("○ {} ", summary.idle).len()
+            + format!("● {} ", summary.busy).len()
+            + offline_text.len()
+            + worktree_text.len();
 
         let available = (area.width as usize).saturating_sub(2);
 
@@ -185,6 +317,26 @@ impl UI {
                 .add_modifier(Modifier::BOLD),
         )];
 
+        if !readonly_text.is_empty() {
+            title.push(Span::styled(
+                readonly_text,
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Red)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+
+        if !session_lost_text.is_empty() {
+            title.push(Span::styled(
+                session_lost_text,
+                Style::default()
+                    .fg(Color::White)
+                    .bg(Color::Red)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+
         if is_wide {
             title.push(Span::raw(" - Multi Agent Control Tower "));
         }
@@ -204,6 +356,7 @@ impl UI {
         ]);
 
         let right_spans = vec![
+            Span::styled(worktree_text, Style::default().fg(Color::DarkGray)),
             Span::styled(
                 format!("○ {} ", summary.idle),
                 Style::default().fg(Color::Gray),
@@ -212,6 +365,7 @@ impl UI {
                 format!("● {} ", summary.busy),
                 Style::default().fg(Color::Green),
             ),
+            Span::styled(offline_text, Style::default().fg(Color::Red)),
         ];
 
         let left_width: usize = title.iter().map(Span::width).sum();
@@ -251,6 +405,26 @@ impl UI {
         app.task_input().render(frame, area, &selected_expert);
     }
 
+    fn render_too_small(frame: &mut Frame, area: Rect) {
+        let notice = Paragraph::new(vec![
+            Line::from(Span::styled(
+                "Terminal too small",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(format!("{}x{}", area.width, area.height)),
+            Line::from(format!("need {MIN_TERMINAL_WIDTH}x{MIN_TERMINAL_HEIGHT}")),
+        ])
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+        let top = area.height.saturating_sub(3) / 2;
+        frame.render_widget(
+            notice,
+            Rect::new(area.x, area.y + top, area.width, area.height - top),
+        );
+    }
+
     fn render_footer(frame: &mut Frame, area: Rect, app: &mut TowerApp) {
         let message = app.message().unwrap_or("");
         let message = if message.is_empty() {
@@ -264,11 +438,23 @@ impl UI {
             Style::default().fg(Color::Green)
         };
 
-        let mut help_text = vec![
-            Span::styled(message, message_style),
-            Span::styled("Ctrl+T", Style::default().fg(Color::Yellow)),
+        let keymap = app.keymap().clone();
+        let key = |action| keymap.label(action);
+
+        let mut help_text = vec![Span::styled(message, message_style)];
+        if app.is_sync_paused() {
+            help_text.push(Span::styled(
+                "syncing paused (typing) | ",
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        help_text.extend([
+            Span::styled(
+                key(KeyAction::SwitchFocus),
+                Style::default().fg(Color::Yellow),
+            ),
             Span::raw(": Switch focus "),
-        ];
+        ]);
 
         if app.focus() == FocusArea::TaskInput && app.expert_panel_display().is_scrolling() {
             help_text.push(Span::styled("PgUp/Dn", Style::default().fg(Color::Yellow)));
@@ -282,19 +468,31 @@ impl UI {
             help_text.push(Span::raw(": Select "));
             help_text.push(Span::styled("Esc", Style::default().fg(Color::Yellow)));
             help_text.push(Span::raw(": Exit scroll "));
-        } else if app.focus() == FocusArea::TaskInput {
-            help_text.push(Span::styled("Ctrl+S", Style::default().fg(Color::Yellow)));
+        } else if app.focus() == FocusArea::TaskInput && !app.is_readonly() {
+            help_text.push(Span::styled(
+                key(KeyAction::AssignTask),
+                Style::default().fg(Color::Yellow),
+            ));
             help_text.push(Span::raw(": Assign task "));
         }
 
-        if app.focus() == FocusArea::ExpertList {
-            help_text.push(Span::styled("Ctrl+O", Style::default().fg(Color::Yellow)));
+        if app.focus() == FocusArea::ExpertList && !app.is_readonly() {
+            help_text.push(Span::styled(
+                key(KeyAction::ChangeRole),
+                Style::default().fg(Color::Yellow),
+            ));
             help_text.push(Span::raw(": Role "));
-            help_text.push(Span::styled("Ctrl+R", Style::default().fg(Color::Yellow)));
+            help_text.push(Span::styled(
+                key(KeyAction::ResetExpert),
+                Style::default().fg(Color::Yellow),
+            ));
             help_text.push(Span::raw(": Reset "));
         }
 
-        help_text.push(Span::styled("Ctrl+J", Style::default().fg(Color::Yellow)));
+        help_text.push(Span::styled(
+            key(KeyAction::ToggleExpertPanel),
+            Style::default().fg(Color::Yellow),
+        ));
         help_text.push(Span::raw(": Panel "));
         help_text.push(Span::styled("F1", Style::default().fg(Color::Yellow)));
         help_text.push(Span::raw(": Help "));
@@ -0,0 +1,315 @@
+use chrono::{DateTime, Local, Utc};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::context::Decision;
+use crate::models::{DeliveredMessage, Report};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimelineEventKind {
+    Decision,
+    MessageDelivered,
+    ReportArrived,
+}
+
+impl TimelineEventKind {
+    pub fn icon(&self) -> &'static str {
+        match self {
+            TimelineEventKind::Decision => "◆",
+            TimelineEventKind::MessageDelivered => "✉",
+            TimelineEventKind::ReportArrived => "▤",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            TimelineEventKind::Decision => Color::Yellow,
+            TimelineEventKind::MessageDelivered => Color::Cyan,
+            TimelineEventKind::ReportArrived => Color::Green,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimelineEvent {
+    pub timestamp: DateTime<Utc>,
+    pub kind: TimelineEventKind,
+    pub summary: String,
+}
+
+/// Merge decisions, delivered messages and reports into one list, oldest first
+pub fn build_timeline(
+    decisions: &[Decision],
+    delivered: &[DeliveredMessage],
+    reports: &[Report],
+    expert_name: impl Fn(u32) -> String,
+) -> Vec<TimelineEvent> {
+    let decision_events = decisions.iter().map(|d| TimelineEvent {
+        timestamp: d.timestamp,
+        kind: TimelineEventKind::Decision,
+        summary: format!("{}: {}", expert_name(d.made_by), d.topic),
+    });
+    let message_events = delivered.iter().map(|m| TimelineEvent {
+        timestamp: m.delivered_at,
+        kind: TimelineEventKind::MessageDelivered,
+        summary: format!(
+            "{} → {}: {}",
+            expert_name(m.from_expert_id),
+            expert_name(m.to_expert_id),
+            m.subject
+        ),
+    });
+    let report_events = reports.iter().map(|r| TimelineEvent {
+        timestamp: r.completed_at.unwrap_or(r.started_at),
+        kind: TimelineEventKind::ReportArrived,
+        summary: format!(
+            "{} reported {}: {}",
+            r.expert_name,
+            r.task_id,
+            r.summary.lines().next().unwrap_or_default()
+        ),
+    });
+
+    let mut events: Vec<TimelineEvent> = decision_events
+        .chain(message_events)
+        .chain(report_events)
+        .collect();
+    events.sort_by_key(|e| e.timestamp);
+    events
+}
+
+/// Scrollable modal listing session events in chronological order
+pub struct ActivityTimeline {
+    events: Vec<TimelineEvent>,
+    scroll_offset: u16,
+    visible: bool,
+}
+
+impl ActivityTimeline {
+    pub fn new() -> Self {
+        Self {
+            events: Vec::new(),
+            scroll_offset: 0,
+            visible: false,
+        }
+    }
+
+    /// Show `events`, scrolled so the most recent ones are in view
+    pub fn show(&mut self, events: Vec<TimelineEvent>, page: u16) {
+        self.scroll_offset = (events.len() as u16).saturating_sub(page);
+        self.events = events;
+        self.visible = true;
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+        self.events.clear();
+        self.scroll_offset = 0;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    #[cfg(test)]
+    pub fn events(&self) -> &[TimelineEvent] {
+        &self.events
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        let max = self.events.len().saturating_sub(1).min(u16::MAX as usize) as u16;
+        if self.scroll_offset < max {
+            self.scroll_offset += 1;
+        }
+    }
+
+    pub fn page_up(&mut self, page: u16) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(page);
+    }
+
+    pub fn page_down(&mut self, page: u16) {
+        for _ in 0..page {
+            self.scroll_down();
+        }
+    }
+
+    fn build_lines(&self) -> Vec<Line<'static>> {
+        if self.events.is_empty() {
+            return vec![Line::from(Span::styled(
+                "No activity yet",
+                Style::default().fg(Color::Gray),
+            ))];
+        }
+
+        self.events
+            .iter()
+            .map(|event| {
+                let time = event
+                    .timestamp
+                    .with_timezone(&Local)
+                    .format("%m-%d %H:%M:%S");
+                Line::from(vec![
+                    Span::styled(format!("{time} "), Style::default().fg(Color::DarkGray)),
+                    Span::styled(
+                        format!("{} ", event.kind.icon()),
+                        Style::default().fg(event.kind.color()),
+                    ),
+                    Span::raw(event.summary.clone()),
+                ])
+            })
+            .collect()
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        frame.render_widget(Clear, area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(Span::styled(
+                format!(" Activity Timeline ({}) ", self.events.len()),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ));
+
+        let paragraph = Paragraph::new(self.build_lines())
+            .block(block)
+            .scroll((self.scroll_offset, 0));
+        frame.render_widget(paragraph, area);
+    }
+}
+
+impl Default for ActivityTimeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Message, MessageContent, MessageRecipient, MessageType};
+    use chrono::TimeZone;
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(1_700_000_000 + secs, 0).unwrap()
+    }
+
+    fn decision_at(secs: i64, topic: &str) -> Decision {
+        let mut decision = Decision::new(0, topic.to_string(), String::new(), String::new());
+        decision.timestamp = at(secs);
+        decision
+    }
+
+    fn delivered_at(secs: i64, subject: &str) -> DeliveredMessage {
+        let message = Message::new(
+            0,
+            MessageRecipient::expert_id(1),
+            MessageType::Notify,
+            MessageContent {
+                subject: subject.to_string(),
+                body: String::new(),
+                attachments: Vec::new(),
+            },
+        );
+        let mut delivered = DeliveredMessage::new(&message, 1);
+        delivered.delivered_at = at(secs);
+        delivered
+    }
+
+    fn report_at(secs: i64, task_id: &str) -> Report {
+        let mut report = Report::new(task_id.to_string(), 1, "frontend".to_string());
+        report.started_at = at(secs - 100);
+        report.completed_at = Some(at(secs));
+        report
+    }
+
+    fn name(id: u32) -> String {
+        format!("expert{id}")
+    }
+
+    #[test]
+    fn build_timeline_interleaves_events_in_timestamp_order() {
+        let events = build_timeline(
+            &[decision_at(10, "first"), decision_at(40, "fourth")],
+            &[delivered_at(20, "second"), delivered_at(50, "fifth")],
+            &[report_at(30, "third")],
+            name,
+        );
+
+        let kinds: Vec<_> = events.iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                TimelineEventKind::Decision,
+                TimelineEventKind::MessageDelivered,
+                TimelineEventKind::ReportArrived,
+                TimelineEventKind::Decision,
+                TimelineEventKind::MessageDelivered,
+            ],
+            "build_timeline: events should interleave by timestamp, not by source"
+        );
+        assert!(events.windows(2).all(|w| w[0].timestamp <= w[1].timestamp));
+    }
+
+    #[test]
+    fn build_timeline_uses_report_start_when_incomplete() {
+        let mut report = report_at(30, "running");
+        report.completed_at = None;
+        let events = build_timeline(&[decision_at(0, "d")], &[], &[report], name);
+
+        assert_eq!(
+            events[0].kind,
+            TimelineEventKind::ReportArrived,
+            "build_timeline: an unfinished report should be placed at its start time"
+        );
+    }
+
+    #[test]
+    fn build_timeline_summaries_name_experts() {
+        let events = build_timeline(&[], &[delivered_at(0, "Hello")], &[], name);
+        assert_eq!(events[0].summary, "expert0 → expert1: Hello");
+    }
+
+    #[test]
+    fn activity_timeline_show_scrolls_to_latest() {
+        let mut timeline = ActivityTimeline::new();
+        let events = (0..30).map(|i| delivered_at(i, "m")).collect::<Vec<_>>();
+        let events = build_timeline(&[], &events, &[], name);
+
+        timeline.show(events, 10);
+
+        assert!(timeline.is_visible());
+        assert_eq!(
+            timeline.scroll_offset, 20,
+            "activity_timeline: latest events should be in view when opened"
+        );
+        timeline.page_down(50);
+        assert_eq!(timeline.scroll_offset, 29);
+        timeline.hide();
+        assert!(!timeline.is_visible());
+    }
+
+    #[test]
+    fn activity_timeline_empty_shows_placeholder() {
+        let mut timeline = ActivityTimeline::new();
+        timeline.show(Vec::new(), 10);
+        let lines = timeline.build_lines();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].to_string(), "No activity yet");
+    }
+}
@@ -0,0 +1,326 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::config::{KeyAction, Keymap};
+
+/// Longest filter accepted
+const MAX_QUERY_CHARS: usize = 40;
+
+/// What the palette lists for an action
+fn describe(action: KeyAction) -> &'static str {
+    match action {
+        KeyAction::AssignTask => "Assign the typed task to the selected expert",
+        KeyAction::ResetExpert => "Reset the selected expert",
+        KeyAction::ChangeRole => "Choose a new role for the selected expert",
+        KeyAction::ApplyRolePreset => "Apply the role preset named in the input",
+        KeyAction::Worktree => "Launch in a worktree, or return from one",
+        KeyAction::ViewDiff => "View the selected expert's worktree diff",
+        KeyAction::MergeWorktree => "Merge the selected expert's worktree branch",
+        KeyAction::RemoveWorktree => "Remove the selected expert's worktree",
+        KeyAction::FeatureExecution => "Start or cancel feature execution",
+        KeyAction::PlanFeature => "Show the plan for the feature in the input",
+        KeyAction::ViewReport => "Open the selected expert's report",
+        KeyAction::CycleEffort => "Cycle the selected expert's effort level",
+        KeyAction::SwitchFocus => "Switch focus between panels",
+        KeyAction::ToggleExpertPanel => "Show or hide the expert panel",
+        KeyAction::ReplyToMessage => "Reply to the selected message",
+        KeyAction::EditNote => "Edit the selected expert's note",
+        KeyAction::MessageHistory => "Open the status message history",
+        KeyAction::MessageTemplate => "Send a message from a template",
+        KeyAction::PingExpert => "Ping the selected expert",
+        KeyAction::PinExpertPanel => "Pin the expert panel to the selected expert",
+        KeyAction::InterruptExpert => "Interrupt the selected expert",
+        KeyAction::CycleExpertFilter => "Cycle the expert list filter",
+        KeyAction::CycleModel => "Cycle the selected expert's model",
+        KeyAction::RetryFailedMessages => "Retry failed messages",
+        KeyAction::DismissFailedMessages => "Dismiss failed messages",
+        KeyAction::ZoomExpertPanel => "Zoom the expert panel",
+        KeyAction::MoveExpertUp => "Move the selected expert up the list",
+        KeyAction::MoveExpertDown => "Move the selected expert down the list",
+        KeyAction::NextRole => "Switch the selected expert to the next role",
+        KeyAction::PrevRole => "Switch the selected expert to the previous role",
+        KeyAction::ReplayLastMessage => "Replay the last message to the selected expert",
+        KeyAction::CommandPalette => "Open this command palette",
+        KeyAction::ToggleCompactStatus => "Collapse the expert list to a one-line summary",
+        KeyAction::ToggleFollowMessages => "Select each expert as it receives a message",
+        KeyAction::AssignBatch => "Assign every task in the file named in the input",
+        KeyAction::Scratchpad => "Open the notes experts share in the scratchpad",
+        KeyAction::AssignAndWatch => "Assign the typed task and pin the panel to that expert",
+        KeyAction::MessageQueue => "Browse and search the queued messages",
+    }
+}
+
+/// Match `query` as a case-insensitive subsequence of `text`. Lower scores are
+/// tighter matches: the number of characters the match spans.
+fn fuzzy_score(query: &str, text: &str) -> Option<usize> {
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let mut start = None;
+    let mut pos = 0;
+    for q in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let found = text[pos..].iter().position(|&c| c == q)? + pos;
+        start.get_or_insert(found);
+        pos = found + 1;
+    }
+    Some(start.map_or(0, |start| pos - start))
+}
+
+struct PaletteEntry {
+    action: KeyAction,
+    chord: String,
+}
+
+/// Overlay listing tower actions by name; typing filters the list and Enter
+/// runs the highlighted action
+pub struct CommandPalette {
+    visible: bool,
+    entries: Vec<PaletteEntry>,
+    query: String,
+    /// Indices into `entries` matching `query`, best match first
+    matches: Vec<usize>,
+    state: ListState,
+}
+
+impl CommandPalette {
+    pub fn new() -> Self {
+        Self {
+            visible: false,
+            entries: Vec::new(),
+            query: String::new(),
+            matches: Vec::new(),
+            state: ListState::default(),
+        }
+    }
+
+    /// Open listing `actions`, labelled with their chords in `keymap`
+    pub fn show(&mut self, actions: impl IntoIterator<Item = KeyAction>, keymap: &Keymap) {
+        self.visible = true;
+        self.entries = actions
+            .into_iter()
+            .filter(|&action| action != KeyAction::CommandPalette)
+            .map(|action| PaletteEntry {
+                action,
+                chord: keymap.label(action),
+            })
+            .collect();
+        self.query.clear();
+        self.refilter();
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+        self.entries.clear();
+        self.matches.clear();
+        self.query.clear();
+        self.state.select(None);
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Actions matching the filter, best match first
+    #[cfg(test)]
+    pub fn matching_actions(&self) -> Vec<KeyAction> {
+        self.matches
+            .iter()
+            .map(|&i| self.entries[i].action)
+            .collect()
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        if self.query.chars().count() < MAX_QUERY_CHARS {
+            self.query.push(c);
+            self.refilter();
+        }
+    }
+
+    pub fn delete_char(&mut self) {
+        self.query.pop();
+        self.refilter();
+    }
+
+    pub fn next(&mut self) {
+        super::select_next(&mut self.state, self.matches.len());
+    }
+
+    pub fn prev(&mut self) {
+        super::select_prev(&mut self.state, self.matches.len());
+    }
+
+    /// Close the palette and return the highlighted action, if any
+    pub fn confirm(&mut self) -> Option<KeyAction> {
+        let action = self
+            .state
+            .selected()
+            .and_then(|i| self.matches.get(i))
+            .map(|&i| self.entries[i].action);
+        self.hide();
+        action
+    }
+
+    fn refilter(&mut self) {
+        let mut scored: Vec<(usize, usize)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| {
+                let name = fuzzy_score(&self.query, entry.action.name());
+                let description = fuzzy_score(&self.query, describe(entry.action));
+                let score = match (name, description) {
+                    (Some(a), Some(b)) => a.min(b),
+                    (a, b) => a.or(b)?,
+                };
+                Some((score, i))
+            })
+            .collect();
+        scored.sort_by_key(|&(score, i)| (score, i));
+        self.matches = scored.into_iter().map(|(_, i)| i).collect();
+        self.state.select((!self.matches.is_empty()).then_some(0));
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        let popup_width = 76.min(area.width.saturating_sub(4));
+        let popup_height = (self.entries.len() as u16 + 6).min(area.height.saturating_sub(4));
+        let popup_area = Rect::new(
+            area.x + area.width.saturating_sub(popup_width) / 2,
+            area.y + area.height.saturating_sub(popup_height) / 2,
+            popup_width,
+            popup_height,
+        );
+
+        frame.render_widget(Clear, popup_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(1),
+                Constraint::Length(2),
+            ])
+            .split(popup_area);
+
+        let header = Paragraph::new(Line::from(vec![
+            Span::styled("> ", Style::default().fg(Color::Yellow)),
+            Span::raw(self.query.clone()),
+            Span::styled("█", Style::default().fg(Color::Gray)),
+        ]))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan))
+                .title("Command Palette"),
+        );
+        frame.render_widget(header, chunks[0]);
+
+        let items: Vec<ListItem> = self
+            .matches
+            .iter()
+            .map(|&i| {
+                let entry = &self.entries[i];
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:<24}", entry.action.name()),
+                        Style::default().fg(Color::White),
+                    ),
+                    Span::styled(
+                        format!("{:<10}", entry.chord),
+                        Style::default().fg(Color::Yellow),
+                    ),
+                    Span::styled(describe(entry.action), Style::default().fg(Color::Gray)),
+                ]))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::LEFT | Borders::RIGHT))
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("> ");
+        frame.render_stateful_widget(list, chunks[1], &mut self.state);
+
+        let footer = Paragraph::new(Line::from(Span::styled(
+            "Type to filter  |  Enter: Run  |  ↑↓: Move  |  Esc: Cancel",
+            Style::default().fg(Color::DarkGray),
+        )))
+        .block(
+            Block::default()
+                .borders(Borders::LEFT | Borders::RIGHT | Borders::BOTTOM)
+                .border_style(Style::default().fg(Color::Cyan)),
+        );
+        frame.render_widget(footer, chunks[2]);
+    }
+}
+
+impl Default for CommandPalette {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn open_palette() -> CommandPalette {
+        let mut palette = CommandPalette::new();
+        palette.show(KeyAction::ALL, &Keymap::default());
+        palette
+    }
+
+    #[test]
+    fn fuzzy_score_prefers_tighter_matches() {
+        assert_eq!(fuzzy_score("", "reset_expert"), Some(0));
+        assert!(fuzzy_score("rst", "reset_expert").is_some());
+        assert!(fuzzy_score("xyz", "reset_expert").is_none());
+        assert!(
+            fuzzy_score("ping", "ping_expert") < fuzzy_score("ping", "pin_expert_panel_g"),
+            "fuzzy_score: a contiguous match should beat a scattered one"
+        );
+    }
+
+    #[test]
+    fn command_palette_filters_and_confirms_best_match() {
+        let mut palette = open_palette();
+        assert_eq!(
+            palette.matching_actions().len(),
+            KeyAction::ALL.len() - 1,
+            "show: every action but the palette itself is listed"
+        );
+
+        for c in "ping".chars() {
+            palette.insert_char(c);
+        }
+        assert_eq!(palette.matching_actions()[0], KeyAction::PingExpert);
+
+        palette.delete_char();
+        palette.delete_char();
+        palette.delete_char();
+        palette.delete_char();
+        for c in "replay".chars() {
+            palette.insert_char(c);
+        }
+        assert_eq!(palette.confirm(), Some(KeyAction::ReplayLastMessage));
+        assert!(!palette.is_visible(), "confirm: the palette should close");
+    }
+
+    #[test]
+    fn command_palette_confirm_without_match_returns_none() {
+        let mut palette = open_palette();
+        for c in "zzzz".chars() {
+            palette.insert_char(c);
+        }
+        assert!(palette.matching_actions().is_empty());
+        assert_eq!(palette.confirm(), None);
+    }
+}
@@ -0,0 +1,177 @@
+use std::time::Duration;
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::queue::ProcessingStats;
+
+const OVERLAY_WIDTH: u16 = 44;
+
+/// Phase timings of one iteration of the tower's main loop
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LoopTimings {
+    pub total: Duration,
+    pub draw: Duration,
+    pub events: Duration,
+    pub poll_status: Duration,
+    pub poll_reports: Duration,
+    pub poll_messages: Duration,
+}
+
+fn millis(d: Duration) -> String {
+    format!("{:.1}ms", d.as_secs_f64() * 1000.0)
+}
+
+/// Render loop timings and queue stats as the overlay's text lines
+pub fn format_debug_lines(timings: &LoopTimings, stats: Option<&ProcessingStats>) -> Vec<String> {
+    let mut lines = vec![
+        format!("loop          {}", millis(timings.total)),
+        format!("  draw        {}", millis(timings.draw)),
+        format!("  events      {}", millis(timings.events)),
+        format!("  status      {}", millis(timings.poll_status)),
+        format!("  reports     {}", millis(timings.poll_reports)),
+        format!("  messages    {}", millis(timings.poll_messages)),
+    ];
+
+    match stats {
+        Some(stats) => lines.extend([
+            format!(
+                "queue         {} processed, {} skipped",
+                stats.messages_processed, stats.messages_skipped
+            ),
+            format!(
+                "  delivered {} / failed {} / expired {}",
+                stats.messages_delivered, stats.messages_failed, stats.messages_expired
+            ),
+            format!(
+                "  collapsed {} / offline {}",
+                stats.messages_collapsed,
+                stats.offline_expert_ids.len()
+            ),
+        ]),
+        None => lines.push("queue         not processed yet".to_string()),
+    }
+    lines
+}
+
+/// Corner overlay with the last loop's timings and the latest queue stats.
+/// Recording new numbers never requests a redraw on its own.
+pub struct DebugOverlay {
+    visible: bool,
+    timings: LoopTimings,
+    stats: Option<ProcessingStats>,
+}
+
+impl DebugOverlay {
+    pub fn new() -> Self {
+        Self {
+            visible: false,
+            timings: LoopTimings::default(),
+            stats: None,
+        }
+    }
+
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn record_loop(&mut self, timings: LoopTimings) {
+        self.timings = timings;
+    }
+
+    pub fn record_stats(&mut self, stats: ProcessingStats) {
+        self.stats = Some(stats);
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        let lines: Vec<Line> = format_debug_lines(&self.timings, self.stats.as_ref())
+            .into_iter()
+            .map(Line::from)
+            .collect();
+        let width = OVERLAY_WIDTH.min(area.width);
+        let height = (lines.len() as u16 + 2).min(area.height);
+        let overlay_area = Rect::new(area.x + area.width - width, area.y, width, height);
+
+        frame.render_widget(Clear, overlay_area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Magenta))
+            .title(Span::styled(
+                " Debug ",
+                Style::default()
+                    .fg(Color::Magenta)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        frame.render_widget(Paragraph::new(lines).block(block), overlay_area);
+    }
+}
+
+impl Default for DebugOverlay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_debug_lines_shows_timings_and_stats() {
+        let timings = LoopTimings {
+            total: Duration::from_micros(25_400),
+            draw: Duration::from_millis(12),
+            events: Duration::from_millis(10),
+            poll_status: Duration::from_micros(1_500),
+            poll_reports: Duration::ZERO,
+            poll_messages: Duration::from_millis(2),
+        };
+        let stats = ProcessingStats {
+            messages_processed: 3,
+            messages_delivered: 2,
+            messages_failed: 1,
+            offline_expert_ids: vec![4],
+            ..Default::default()
+        };
+
+        let lines = format_debug_lines(&timings, Some(&stats));
+
+        assert_eq!(lines[0], "loop          25.4ms");
+        assert_eq!(lines[1], "  draw        12.0ms");
+        assert_eq!(lines[3], "  status      1.5ms");
+        assert_eq!(lines[6], "queue         3 processed, 0 skipped");
+        assert_eq!(lines[7], "  delivered 2 / failed 1 / expired 0");
+        assert_eq!(lines[8], "  collapsed 0 / offline 1");
+    }
+
+    #[test]
+    fn format_debug_lines_without_stats() {
+        let lines = format_debug_lines(&LoopTimings::default(), None);
+        assert_eq!(
+            lines.last().map(String::as_str),
+            Some("queue         not processed yet"),
+            "format_debug_lines: missing stats should be stated, not zero-filled"
+        );
+    }
+
+    #[test]
+    fn debug_overlay_is_hidden_by_default() {
+        let mut overlay = DebugOverlay::new();
+        assert!(!overlay.is_visible());
+        overlay.toggle();
+        assert!(overlay.is_visible());
+    }
+}
@@ -0,0 +1,208 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::session::WorktreeDiff;
+
+/// Scrollable modal showing an expert's worktree diff against the base branch
+pub struct DiffModal {
+    title: String,
+    lines: Vec<Line<'static>>,
+    scroll_offset: u16,
+    visible: bool,
+}
+
+impl DiffModal {
+    pub fn new() -> Self {
+        Self {
+            title: String::new(),
+            lines: Vec::new(),
+            scroll_offset: 0,
+            visible: false,
+        }
+    }
+
+    pub fn show(&mut self, expert_name: &str, diff: &WorktreeDiff) {
+        self.title = format!(" {expert_name}: diff vs {} ", diff.base_branch);
+        self.lines = Self::build_lines(diff);
+        self.scroll_offset = 0;
+        self.visible = true;
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+        self.lines.clear();
+        self.scroll_offset = 0;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        let max = self.lines.len().saturating_sub(1).min(u16::MAX as usize) as u16;
+        if self.scroll_offset < max {
+            self.scroll_offset += 1;
+        }
+    }
+
+    pub fn page_up(&mut self, page: u16) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(page);
+    }
+
+    pub fn page_down(&mut self, page: u16) {
+        for _ in 0..page {
+            self.scroll_down();
+        }
+    }
+
+    fn line_style(line: &str) -> Style {
+        if line.starts_with("+++") || line.starts_with("---") || line.starts_with("diff --git") {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else if line.starts_with('+') {
+            Style::default().fg(Color::Green)
+        } else if line.starts_with('-') {
+            Style::default().fg(Color::Red)
+        } else if line.starts_with("@@") {
+            Style::default().fg(Color::Cyan)
+        } else {
+            Style::default()
+        }
+    }
+
+    fn build_lines(diff: &WorktreeDiff) -> Vec<Line<'static>> {
+        if diff.diff.trim().is_empty() {
+            return vec![Line::from(Span::styled(
+                format!("No changes against {}", diff.base_branch),
+                Style::default().fg(Color::Gray),
+            ))];
+        }
+
+        let mut lines: Vec<Line<'static>> = diff
+            .diff
+            .lines()
+            .map(|line| Line::from(Span::styled(line.to_string(), Self::line_style(line))))
+            .collect();
+
+        if diff.truncated {
+            lines.push(Line::from(Span::styled(
+                "[diff truncated]",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )));
+        }
+        lines
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        frame.render_widget(Clear, area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(Span::styled(
+                self.title.clone(),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ));
+
+        let paragraph = Paragraph::new(self.lines.clone())
+            .block(block)
+            .scroll((self.scroll_offset, 0));
+        frame.render_widget(paragraph, area);
+    }
+}
+
+impl Default for DiffModal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_diff(diff: &str, truncated: bool) -> WorktreeDiff {
+        WorktreeDiff {
+            base_branch: "main".to_string(),
+            diff: diff.to_string(),
+            truncated,
+        }
+    }
+
+    #[test]
+    fn diff_modal_starts_hidden() {
+        let modal = DiffModal::new();
+        assert!(!modal.is_visible());
+    }
+
+    #[test]
+    fn diff_modal_show_and_hide() {
+        let mut modal = DiffModal::new();
+        modal.show("architect", &create_test_diff("+a", false));
+        assert!(modal.is_visible());
+        modal.hide();
+        assert!(!modal.is_visible());
+    }
+
+    #[test]
+    fn diff_modal_colors_added_and_removed_lines() {
+        let lines = DiffModal::build_lines(&create_test_diff("@@ -1 +1 @@\n-old\n+new", false));
+        assert_eq!(lines[0].spans[0].style.fg, Some(Color::Cyan));
+        assert_eq!(
+            lines[1].spans[0].style.fg,
+            Some(Color::Red),
+            "diff_modal: removed lines should be red"
+        );
+        assert_eq!(
+            lines[2].spans[0].style.fg,
+            Some(Color::Green),
+            "diff_modal: added lines should be green"
+        );
+    }
+
+    #[test]
+    fn diff_modal_marks_truncated_diff() {
+        let lines = DiffModal::build_lines(&create_test_diff("+a", true));
+        assert_eq!(
+            lines.last().unwrap().to_string(),
+            "[diff truncated]",
+            "diff_modal: truncated diffs should end with a marker"
+        );
+    }
+
+    #[test]
+    fn diff_modal_empty_diff_shows_message() {
+        let lines = DiffModal::build_lines(&create_test_diff("", false));
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].to_string(), "No changes against main");
+    }
+
+    #[test]
+    fn diff_modal_scroll_stays_in_bounds() {
+        let mut modal = DiffModal::new();
+        modal.show("architect", &create_test_diff("a\nb\nc", false));
+        modal.scroll_up();
+        assert_eq!(modal.scroll_offset, 0);
+        modal.page_down(10);
+        assert_eq!(
+            modal.scroll_offset, 2,
+            "diff_modal: scrolling should stop at the last line"
+        );
+    }
+}
@@ -6,8 +6,12 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph, Wrap},
     Frame,
 };
+use regex::Regex;
 use xxhash_rust::xxh3::xxh3_64;
 
+use super::RegionTagger;
+use crate::config::HighlightRule;
+
 /// Safety margin subtracted from inner width when setting tmux PTY size.
 /// Prevents edge-case line wrapping at width boundaries.
 const PREVIEW_WIDTH_MARGIN: u16 = 1;
@@ -15,6 +19,63 @@ const PREVIEW_WIDTH_MARGIN: u16 = 1;
 /// Safety margin subtracted from inner height when setting tmux PTY size.
 const PREVIEW_HEIGHT_MARGIN: u16 = 0;
 
+/// Shown in scroll mode until the history capture arrives.
+const HISTORY_LOADING_PLACEHOLDER: &str = "Loading history…";
+
+/// Colors whole lines of captured pane content that match configured patterns.
+#[derive(Debug, Clone, Default)]
+pub struct LineHighlighter {
+    rules: Vec<(Regex, Color)>,
+}
+
+impl LineHighlighter {
+    /// Compile highlight rules, skipping (and logging) any with an invalid
+    /// pattern or color; `Config::validate` rejects those at start.
+    pub fn from_rules(rules: &[HighlightRule]) -> Self {
+        let rules = rules
+            .iter()
+            .filter_map(|rule| match rule.compile() {
+                Ok(compiled) => Some(compiled),
+                Err(e) => {
+                    tracing::warn!("Ignoring highlight rule: {:#}", e);
+                    None
+                }
+            })
+            .collect();
+        Self { rules }
+    }
+
+    /// Apply the first matching rule's color to each line.
+    ///
+    /// Only spans without a foreground color (or reset to the default) are
+    /// recolored, so colors emitted by the agent itself (ANSI escapes) are preserved.
+    pub fn apply(&self, mut text: Text<'static>) -> Text<'static> {
+        if self.rules.is_empty() {
+            return text;
+        }
+        for line in &mut text.lines {
+            let plain: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+            let Some((_, color)) = self.rules.iter().find(|(re, _)| re.is_match(&plain)) else {
+                continue;
+            };
+            for span in &mut line.spans {
+                if matches!(span.style.fg, None | Some(Color::Reset)) {
+                    span.style = span.style.fg(*color);
+                }
+            }
+        }
+        text
+    }
+}
+
+/// Content lines picked with a mouse drag, from where the drag started to where it is now
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct LineSelection {
+    anchor: usize,
+    cursor: usize,
+    dragged: bool,
+}
+
 pub struct ExpertPanelDisplay {
     expert_id: Option<u32>,
     expert_name: Option<String>,
@@ -25,10 +86,15 @@ pub struct ExpertPanelDisplay {
     focused: bool,
     auto_scroll: bool,
     is_scrolling: bool,
+    history_loading: bool,
     last_render_size: (u16, u16),
     content_hash: u64,
     cached_visual_line_count: usize,
     cached_display_width: usize,
+    highlighter: LineHighlighter,
+    regions: RegionTagger,
+    selection: Option<LineSelection>,
+    pinned: bool,
 }
 
 impl Default for ExpertPanelDisplay {
@@ -49,13 +115,35 @@ impl ExpertPanelDisplay {
             focused: false,
             auto_scroll: true,
             is_scrolling: false,
+            history_loading: false,
             last_render_size: (0, 0),
             content_hash: 0,
             cached_visual_line_count: 0,
             cached_display_width: 0,
+            highlighter: LineHighlighter::default(),
+            regions: RegionTagger::default(),
+            selection: None,
+            pinned: false,
         }
     }
 
+    pub fn with_highlighter(mut self, highlighter: LineHighlighter) -> Self {
+        self.highlighter = highlighter;
+        self
+    }
+
+    /// Dim the agent's thinking and emphasize its answers
+    pub fn with_region_tagger(mut self, regions: RegionTagger) -> Self {
+        self.regions = regions;
+        self
+    }
+
+    /// Parse a pane capture and apply highlight rules and output regions
+    fn style_capture(&self, raw: &str) -> Text<'static> {
+        self.regions
+            .apply(self.highlighter.apply(Self::parse_ansi(raw)))
+    }
+
     pub fn toggle(&mut self) {
         self.visible = !self.visible;
     }
@@ -64,7 +152,6 @@ impl ExpertPanelDisplay {
         self.visible
     }
 
-    #[allow(dead_code)]
     pub fn show(&mut self) {
         self.visible = true;
     }
@@ -116,21 +203,50 @@ impl ExpertPanelDisplay {
         self.is_scrolling
     }
 
+    pub fn is_history_loading(&self) -> bool {
+        self.history_loading
+    }
+
+    #[allow(dead_code)]
     pub fn enter_scroll_mode(&mut self, raw: &str) {
+        self.begin_scroll_mode();
+        self.set_scroll_history(raw);
+    }
+
+    /// Enter scroll mode showing a placeholder until `set_scroll_history` delivers
+    /// the captured history.
+    pub fn begin_scroll_mode(&mut self) {
+        self.selection = None;
         self.is_scrolling = true;
+        self.history_loading = true;
         self.auto_scroll = false;
         self.content_hash = 0;
         self.cached_visual_line_count = 0;
         self.cached_display_width = 0;
-        let line_count = raw.lines().count();
-        let text = Self::parse_ansi(raw);
-        self.content = text;
-        self.raw_line_count = line_count;
+        self.content = Text::raw(HISTORY_LOADING_PLACEHOLDER);
+        self.raw_line_count = 1;
+        self.scroll_offset = u16::MAX;
+    }
+
+    /// Load captured history into scroll mode. Ignored if scroll mode was left
+    /// while the capture was in flight.
+    pub fn set_scroll_history(&mut self, raw: &str) {
+        if !self.is_scrolling {
+            return;
+        }
+        self.history_loading = false;
+        self.selection = None;
+        self.cached_visual_line_count = 0;
+        self.cached_display_width = 0;
+        self.content = self.style_capture(raw);
+        self.raw_line_count = raw.lines().count();
         self.scroll_offset = u16::MAX;
     }
 
     pub fn exit_scroll_mode(&mut self) {
+        self.selection = None;
         self.is_scrolling = false;
+        self.history_loading = false;
         self.content = Text::default();
         self.raw_line_count = 0;
         self.content_hash = 0;
@@ -139,8 +255,13 @@ impl ExpertPanelDisplay {
         self.auto_scroll = true;
     }
 
+    /// Show `id`'s pane; ignored for other experts while the panel is pinned
     pub fn set_expert(&mut self, id: u32, name: String) {
+        if self.pinned && self.expert_id != Some(id) {
+            return;
+        }
         if self.expert_id != Some(id) {
+            self.selection = None;
             if self.is_scrolling {
                 self.exit_scroll_mode();
             }
@@ -156,8 +277,30 @@ impl ExpertPanelDisplay {
         self.expert_name = Some(name);
     }
 
+    /// Switch to `id` and keep showing it until `unpin`
+    pub fn pin(&mut self, id: u32, name: String) {
+        self.pinned = false;
+        self.set_expert(id, name);
+        self.pinned = true;
+    }
+
+    pub fn unpin(&mut self) {
+        self.pinned = false;
+    }
+
+    /// Expert the panel is locked onto, if any
+    pub fn pinned_expert(&self) -> Option<u32> {
+        self.expert_id.filter(|_| self.pinned)
+    }
+
     pub fn set_content(&mut self, text: Text<'static>, line_count: usize) {
         self.content = text;
+        // A refresh that shrinks the pane mid-drag keeps the selection within the new lines
+        let len = self.content.lines.len();
+        self.selection = self.selection.filter(|s| s.anchor < len).map(|mut s| {
+            s.cursor = s.cursor.min(len - 1);
+            s
+        });
         self.raw_line_count = line_count;
         self.cached_visual_line_count = 0;
         self.cached_display_width = 0;
@@ -178,7 +321,7 @@ impl ExpertPanelDisplay {
         }
         self.content_hash = hash;
         let line_count = raw.lines().count();
-        let text = Self::parse_ansi(raw);
+        let text = self.style_capture(raw);
         self.set_content(text, line_count);
         true
     }
@@ -202,8 +345,62 @@ impl ExpertPanelDisplay {
         self.auto_scroll = true;
     }
 
+    /// Content line under `row`, counted from the top of the panel's inner area.
+    ///
+    /// Rows map one-to-one onto content lines: the tmux pane is sized to the
+    /// panel (see `preview_size`), so captured lines do not wrap.
+    fn line_at_row(&self, row: u16) -> Option<usize> {
+        let line = self.scroll_offset as usize + row as usize;
+        (line < self.content.lines.len()).then_some(line)
+    }
+
+    /// Start a selection at the line under `row`; nothing is selected below the content
+    pub fn begin_selection(&mut self, row: u16) {
+        self.selection = self.line_at_row(row).map(|line| LineSelection {
+            anchor: line,
+            cursor: line,
+            dragged: false,
+        });
+    }
+
+    /// Move the selection end to the line under `row`, clamped to the content
+    pub fn extend_selection(&mut self, row: u16) {
+        let last = self.content.lines.len().saturating_sub(1);
+        if let Some(ref mut selection) = self.selection {
+            selection.cursor = (self.scroll_offset as usize + row as usize).min(last);
+            selection.dragged = true;
+        }
+    }
+
+    pub fn selected_lines(&self) -> Option<std::ops::RangeInclusive<usize>> {
+        self.selection
+            .map(|s| s.anchor.min(s.cursor)..=s.anchor.max(s.cursor))
+    }
+
+    /// End the selection and return its plain text. A click without a drag
+    /// selects nothing.
+    pub fn take_selection(&mut self) -> Option<String> {
+        let selection = self.selection.take()?;
+        if !selection.dragged {
+            return None;
+        }
+        let range = selection.anchor.min(selection.cursor)..=selection.anchor.max(selection.cursor);
+        let lines: Vec<String> = self
+            .content
+            .lines
+            .get(range)?
+            .iter()
+            .map(|line| {
+                let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+                text.trim_end().to_string()
+            })
+            .collect();
+        Some(lines.join("\n"))
+    }
+
     pub fn render(&mut self, frame: &mut Frame, area: Rect) {
         let title = match (&self.expert_name, self.expert_id) {
+            (Some(name), Some(id)) if self.pinned => format!("{name} (Expert{id}) [pinned]"),
             (Some(name), Some(id)) => format!("{name} (Expert{id})"),
             _ => " Expert Panel (no expert selected) ".to_string(),
         };
@@ -224,7 +421,18 @@ impl ExpertPanelDisplay {
         // Build paragraph without block for accurate line_count measurement.
         // line_count() passes width directly to WordWrapper, and rendering
         // uses block.inner(area).width == inner_width, so both see the same width.
-        let paragraph = Paragraph::new(self.content.clone()).wrap(Wrap { trim: false });
+        let mut content = self.content.clone();
+        if let Some(range) = self.selected_lines() {
+            for line in content
+                .lines
+                .iter_mut()
+                .take(range.end() + 1)
+                .skip(*range.start())
+            {
+                *line = std::mem::take(line).patch_style(Modifier::REVERSED);
+            }
+        }
+        let paragraph = Paragraph::new(content).wrap(Wrap { trim: false });
 
         let visual_line_count =
             if display_width != self.cached_display_width || self.cached_display_width == 0 {
@@ -240,7 +448,10 @@ impl ExpertPanelDisplay {
                 self.cached_visual_line_count
             };
 
-        let max_scroll = visual_line_count.saturating_sub(visible_height) as u16;
+        // A zero-height panel still keeps the offset on a real line
+        let max_scroll = visual_line_count
+            .saturating_sub(visible_height.max(1))
+            .min(u16::MAX as usize) as u16;
         if self.auto_scroll {
             self.scroll_offset = max_scroll;
         } else {
@@ -256,7 +467,11 @@ impl ExpertPanelDisplay {
             ""
         };
         let scroll_indicator = if !self.auto_scroll {
-            format!(" [{}/{}]", self.scroll_offset + 1, visual_line_count)
+            format!(
+                " [{}/{}]",
+                self.scroll_offset as usize + 1,
+                visual_line_count
+            )
         } else {
             String::new()
         };
@@ -288,6 +503,87 @@ impl ExpertPanelDisplay {
 mod tests {
     use super::*;
 
+    fn default_highlighter() -> LineHighlighter {
+        LineHighlighter::from_rules(&[
+            HighlightRule::new("error|panic|FAILED", "red"),
+            HighlightRule::new("warning", "yellow"),
+        ])
+    }
+
+    #[test]
+    fn highlighter_colors_matching_lines() {
+        let text = default_highlighter().apply(ExpertPanelDisplay::parse_ansi(
+            "compiling\nerror[E0308]: mismatched types\nwarning: unused variable\ntest foo ... FAILED",
+        ));
+        assert_eq!(text.lines[0].spans[0].style.fg, None);
+        assert_eq!(
+            text.lines[1].spans[0].style.fg,
+            Some(Color::Red),
+            "highlighter: error line should be red"
+        );
+        assert_eq!(
+            text.lines[2].spans[0].style.fg,
+            Some(Color::Yellow),
+            "highlighter: warning line should be yellow"
+        );
+        assert_eq!(text.lines[3].spans[0].style.fg, Some(Color::Red));
+    }
+
+    #[test]
+    fn highlighter_preserves_ansi_colors() {
+        let text = default_highlighter().apply(ExpertPanelDisplay::parse_ansi(
+            "\x1b[32mok\x1b[0m then error",
+        ));
+        let spans = &text.lines[0].spans;
+        let ok_span = spans.iter().find(|s| s.content == "ok").unwrap();
+        assert_eq!(
+            ok_span.style.fg,
+            Some(Color::Green),
+            "highlighter: ANSI-colored spans should keep their color"
+        );
+        let rest = spans.iter().find(|s| s.content.contains("error")).unwrap();
+        assert_eq!(
+            rest.style.fg,
+            Some(Color::Red),
+            "highlighter: uncolored spans on a matching line should be highlighted"
+        );
+    }
+
+    #[test]
+    fn highlighter_first_matching_rule_wins() {
+        let text = default_highlighter().apply(Text::raw("warning: error in build"));
+        assert_eq!(
+            text.lines[0].spans[0].style.fg,
+            Some(Color::Red),
+            "highlighter: earlier rules should take precedence"
+        );
+    }
+
+    #[test]
+    fn highlighter_skips_invalid_rules() {
+        let highlighter = LineHighlighter::from_rules(&[
+            HighlightRule::new("(unclosed", "red"),
+            HighlightRule::new("warning", "not-a-color"),
+            HighlightRule::new("ok", "green"),
+        ]);
+        assert_eq!(
+            highlighter.rules.len(),
+            1,
+            "highlighter: rules with invalid patterns or colors should be skipped"
+        );
+    }
+
+    #[test]
+    fn try_set_content_applies_highlighting() {
+        let mut panel = ExpertPanelDisplay::new().with_highlighter(default_highlighter());
+        panel.try_set_content("fine\nthread 'main' panicked");
+        assert_eq!(
+            panel.content.lines[1].spans[0].style.fg,
+            Some(Color::Red),
+            "try_set_content: captured content should be highlighted"
+        );
+    }
+
     #[test]
     fn panel_starts_visible() {
         let panel = ExpertPanelDisplay::new();
@@ -335,6 +631,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn pinned_panel_ignores_other_experts_until_unpinned() {
+        let mut panel = ExpertPanelDisplay::new();
+        panel.set_expert(0, "Alice".to_string());
+        panel.pin(2, "Carol".to_string());
+        assert_eq!(panel.pinned_expert(), Some(2));
+
+        panel.set_expert(1, "Bob".to_string());
+        assert_eq!(
+            panel.expert_id(),
+            Some(2),
+            "set_expert: a pinned panel should keep its expert"
+        );
+
+        panel.unpin();
+        assert_eq!(panel.pinned_expert(), None);
+        panel.set_expert(1, "Bob".to_string());
+        assert_eq!(panel.expert_id(), Some(1));
+    }
+
     #[test]
     fn set_expert_different_resets_scroll() {
         let mut panel = ExpertPanelDisplay::new();
@@ -816,6 +1132,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn begin_scroll_mode_shows_loading_placeholder() {
+        let mut panel = ExpertPanelDisplay::new();
+        panel.begin_scroll_mode();
+        assert!(panel.is_scrolling());
+        assert!(
+            panel.is_history_loading(),
+            "begin_scroll_mode: history should be marked as loading"
+        );
+        assert_eq!(
+            panel.content.lines[0].to_string(),
+            HISTORY_LOADING_PLACEHOLDER,
+            "begin_scroll_mode: placeholder should be shown until history arrives"
+        );
+    }
+
+    #[test]
+    fn set_scroll_history_replaces_placeholder() {
+        let mut panel = ExpertPanelDisplay::new();
+        panel.begin_scroll_mode();
+        panel.set_scroll_history("a\nb\nc");
+        assert!(!panel.is_history_loading());
+        assert_eq!(
+            panel.raw_line_count, 3,
+            "set_scroll_history: captured history should replace the placeholder"
+        );
+    }
+
+    #[test]
+    fn set_scroll_history_ignored_after_exit() {
+        let mut panel = ExpertPanelDisplay::new();
+        panel.begin_scroll_mode();
+        panel.exit_scroll_mode();
+        panel.set_scroll_history("late\nhistory");
+        assert!(
+            !panel.is_scrolling(),
+            "set_scroll_history: late history should not re-enter scroll mode"
+        );
+        assert_eq!(panel.raw_line_count, 0);
+    }
+
     #[test]
     fn exit_scroll_mode_clears_flag() {
         let mut panel = ExpertPanelDisplay::new();
@@ -1275,6 +1632,116 @@ mod tests {
             .unwrap();
     }
 
+    fn numbered_lines(n: usize) -> String {
+        (0..n).map(|i| format!("line {i}   \n")).collect()
+    }
+
+    #[test]
+    fn render_into_tiny_areas_clamps_scroll_offset() {
+        let mut panel = ExpertPanelDisplay::new();
+        panel.try_set_content(&numbered_lines(50));
+        for (width, height) in [(1, 1), (2, 2)] {
+            panel.scroll_to_bottom();
+            render_panel(&mut panel, width, height);
+            assert!(
+                (panel.scroll_offset as usize) < panel.raw_line_count,
+                "render: {width}x{height} should leave the offset on a content line"
+            );
+
+            panel.scroll_offset = 10;
+            panel.auto_scroll = false;
+            render_panel(&mut panel, width, height);
+            assert_eq!(panel.scroll_offset, 10);
+        }
+    }
+
+    #[test]
+    fn selection_maps_drag_rows_to_scrolled_lines() {
+        let mut panel = ExpertPanelDisplay::new();
+        panel.try_set_content(&numbered_lines(50));
+        render_panel(&mut panel, 40, 12);
+
+        // 10 inner rows at the bottom of 50 lines: row 0 shows line 40
+        panel.begin_selection(5);
+        panel.extend_selection(2);
+        assert_eq!(
+            panel.selected_lines(),
+            Some(42..=45),
+            "selected_lines: upward drag should select the same range"
+        );
+        assert_eq!(
+            panel.take_selection().as_deref(),
+            Some("line 42\nline 43\nline 44\nline 45"),
+            "take_selection: copied text should be the plain, right-trimmed lines"
+        );
+        assert_eq!(panel.selected_lines(), None);
+    }
+
+    #[test]
+    fn selection_clamps_to_content_and_ignores_plain_clicks() {
+        let mut panel = ExpertPanelDisplay::new();
+        panel.try_set_content(&numbered_lines(3));
+        render_panel(&mut panel, 40, 12);
+
+        panel.begin_selection(7);
+        assert_eq!(
+            panel.selected_lines(),
+            None,
+            "begin_selection: rows below the content select nothing"
+        );
+
+        panel.begin_selection(1);
+        assert_eq!(
+            panel.take_selection(),
+            None,
+            "take_selection: a click without a drag copies nothing"
+        );
+
+        panel.begin_selection(1);
+        panel.extend_selection(9);
+        assert_eq!(panel.selected_lines(), Some(1..=2));
+    }
+
+    #[test]
+    fn selection_survives_content_shrinking_mid_drag() {
+        let mut panel = ExpertPanelDisplay::new();
+        panel.try_set_content(&numbered_lines(50));
+        render_panel(&mut panel, 40, 12);
+        panel.begin_selection(2);
+        panel.extend_selection(8);
+
+        panel.try_set_content(&numbered_lines(45));
+        assert_eq!(
+            panel.take_selection().as_deref(),
+            Some(
+                "line 42
+line 43
+line 44"
+            ),
+            "take_selection: the drag should be clamped to the shorter content"
+        );
+
+        panel.try_set_content(&numbered_lines(50));
+        render_panel(&mut panel, 40, 12);
+        panel.begin_selection(2);
+        panel.extend_selection(8);
+        panel.try_set_content(&numbered_lines(3));
+        assert_eq!(
+            panel.take_selection(),
+            None,
+            "take_selection: a selection that starts past the new content is dropped"
+        );
+
+        panel.begin_selection(0);
+        panel.extend_selection(1);
+        panel.begin_scroll_mode();
+        assert_eq!(
+            panel.take_selection(),
+            None,
+            "begin_scroll_mode: replacing the content clears the selection"
+        );
+    }
+
     #[test]
     fn auto_scroll_positions_at_visual_bottom_with_wrapping() {
         let mut panel = ExpertPanelDisplay::new();
@@ -0,0 +1,196 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::feature::executor::FeaturePlan;
+
+/// Scrollable modal previewing the batch sequence of a feature run
+pub struct FeaturePlanModal {
+    title: String,
+    lines: Vec<Line<'static>>,
+    scroll_offset: u16,
+    visible: bool,
+}
+
+impl FeaturePlanModal {
+    pub fn new() -> Self {
+        Self {
+            title: String::new(),
+            lines: Vec::new(),
+            scroll_offset: 0,
+            visible: false,
+        }
+    }
+
+    pub fn show(&mut self, feature_name: &str, plan: &FeaturePlan) {
+        self.title = format!(" {feature_name}: execution plan ");
+        self.lines = Self::build_lines(plan);
+        self.scroll_offset = 0;
+        self.visible = true;
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+        self.lines.clear();
+        self.scroll_offset = 0;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        let max = self.lines.len().saturating_sub(1).min(u16::MAX as usize) as u16;
+        if self.scroll_offset < max {
+            self.scroll_offset += 1;
+        }
+    }
+
+    fn build_lines(plan: &FeaturePlan) -> Vec<Line<'static>> {
+        let planned: usize = plan.waves.iter().map(Vec::len).sum();
+        let mut lines = vec![
+            Line::from(Span::styled(
+                format!(
+                    "{planned} tasks in {} batches ({} already done)",
+                    plan.waves.len(),
+                    plan.completed
+                ),
+                Style::default().fg(Color::Gray),
+            )),
+            Line::from(""),
+        ];
+
+        for (i, wave) in plan.waves.iter().enumerate() {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("Batch {:>2}  ", i + 1),
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!("Tasks {}", wave.join(", "))),
+            ]));
+        }
+
+        if let Some(ref blocked) = plan.blocked {
+            lines.push(Line::from(""));
+            lines.extend(blocked.lines().map(|line| {
+                Line::from(Span::styled(
+                    line.to_string(),
+                    Style::default().fg(Color::Red),
+                ))
+            }));
+        } else if plan.waves.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "Nothing left to run",
+                Style::default().fg(Color::Gray),
+            )));
+        }
+        lines
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        frame.render_widget(Clear, area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(Span::styled(
+                self.title.clone(),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ));
+
+        let paragraph = Paragraph::new(self.lines.clone())
+            .block(block)
+            .scroll((self.scroll_offset, 0));
+        frame.render_widget(paragraph, area);
+    }
+}
+
+impl Default for FeaturePlanModal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    fn plan(waves: &[&[&str]], blocked: Option<&str>) -> FeaturePlan {
+        FeaturePlan {
+            waves: waves
+                .iter()
+                .map(|w| w.iter().map(|s| s.to_string()).collect())
+                .collect(),
+            completed: 1,
+            blocked: blocked.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn feature_plan_modal_lists_one_line_per_batch() {
+        let mut modal = FeaturePlanModal::new();
+        modal.show("auth", &plan(&[&["1", "2"], &["3"]], None));
+        assert!(modal.is_visible(), "show: modal should be visible");
+        let texts: Vec<String> = modal.lines.iter().map(line_text).collect();
+        assert_eq!(
+            texts[0], "3 tasks in 2 batches (1 already done)",
+            "show: summary line should count tasks and batches"
+        );
+        assert!(
+            texts
+                .iter()
+                .any(|t| t.contains("Batch  1") && t.ends_with("Tasks 1, 2")),
+            "show: first batch should list its tasks, got: {texts:?}"
+        );
+        assert!(
+            texts
+                .iter()
+                .any(|t| t.contains("Batch  2") && t.ends_with("Tasks 3")),
+            "show: second batch should list its tasks, got: {texts:?}"
+        );
+    }
+
+    #[test]
+    fn feature_plan_modal_shows_blocked_reason() {
+        let mut modal = FeaturePlanModal::new();
+        modal.show(
+            "auth",
+            &plan(&[&["1"]], Some("Execution blocked\nCircular dependency")),
+        );
+        let texts: Vec<String> = modal.lines.iter().map(line_text).collect();
+        assert!(
+            texts.iter().any(|t| t == "Circular dependency"),
+            "show: blocked diagnostic should be listed, got: {texts:?}"
+        );
+    }
+
+    #[test]
+    fn feature_plan_modal_hide_clears_state() {
+        let mut modal = FeaturePlanModal::new();
+        modal.show("auth", &plan(&[], None));
+        modal.scroll_down();
+        modal.hide();
+        assert!(!modal.is_visible(), "hide: modal should be hidden");
+        assert_eq!(modal.scroll_offset, 0, "hide: scroll should reset");
+    }
+}
@@ -6,13 +6,46 @@ use ratatui::{
     Frame,
 };
 
+use crate::config::{KeyAction, Keymap};
+
+/// What the user is doing when help is opened; decides which sections come first
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HelpContext {
+    #[default]
+    TaskInput,
+    ExpertPanel,
+    /// Browsing the expert panel's scrollback
+    ScrollMode,
+}
+
+struct HelpSection {
+    title: &'static str,
+    /// Contexts in which this section is shown first; empty means "only under show all"
+    contexts: &'static [HelpContext],
+    lines: Vec<Line<'static>>,
+}
+
 pub struct HelpModal {
     visible: bool,
+    context: HelpContext,
+    show_all: bool,
+    keymap: Keymap,
 }
 
 impl HelpModal {
     pub fn new() -> Self {
-        Self { visible: false }
+        Self {
+            visible: false,
+            context: HelpContext::default(),
+            show_all: false,
+            keymap: Keymap::default(),
+        }
+    }
+
+    /// List the configured chords instead of the defaults
+    pub fn with_keymap(mut self, keymap: Keymap) -> Self {
+        self.keymap = keymap;
+        self
     }
 
     #[allow(dead_code)]
@@ -32,6 +65,16 @@ impl HelpModal {
         self.visible = !self.visible;
     }
 
+    /// Tailor the help to `context`; resets "show all"
+    pub fn set_context(&mut self, context: HelpContext) {
+        self.context = context;
+        self.show_all = false;
+    }
+
+    pub fn toggle_show_all(&mut self) {
+        self.show_all = !self.show_all;
+    }
+
     pub fn render(&self, frame: &mut Frame, area: Rect) {
         if !self.visible {
             return;
@@ -63,68 +106,258 @@ impl HelpModal {
         frame.render_widget(paragraph, content_area);
     }
 
-    fn build_help_lines(&self) -> Vec<Line<'static>> {
+    fn sections(&self) -> Vec<HelpSection> {
+        use HelpContext::*;
+        let key = |action| self.keymap.label(action);
+
         vec![
+            HelpSection {
+                title: "Task Input",
+                contexts: &[TaskInput],
+                lines: vec![
+                    Self::nested_subsection_title("Expert Operations"),
+                    Self::key_line("\u{2191} / \u{2193}", "Select previous / next expert"),
+                    Self::key_line("Alt+0..Alt+9", "Jump to expert by ID"),
+                    Self::key_line(
+                        key(KeyAction::CycleExpertFilter),
+                        "Filter experts: all / busy / with reports / in worktree",
+                    ),
+                    Self::key_line(
+                        format!(
+                            "{} / {}",
+                            key(KeyAction::MoveExpertUp),
+                            key(KeyAction::MoveExpertDown)
+                        ),
+                        "Move selected expert up / down the list",
+                    ),
+                    Self::key_line(key(KeyAction::ChangeRole), "Change expert role"),
+                    Self::key_line(
+                        format!(
+                            "{} / {}",
+                            key(KeyAction::NextRole),
+                            key(KeyAction::PrevRole)
+                        ),
+                        "Switch to next / previous role",
+                    ),
+                    Self::key_line(
+                        key(KeyAction::ApplyRolePreset),
+                        "Apply role preset named in input",
+                    ),
+                    Self::key_line(key(KeyAction::ResetExpert), "Reset selected expert"),
+                    Self::key_line(
+                        key(KeyAction::InterruptExpert),
+                        "Interrupt selected expert (Ctrl+C, no reset)",
+                    ),
+                    Self::key_line(
+                        key(KeyAction::Worktree),
+                        "Launch expert in worktree / Return from worktree",
+                    ),
+                    Self::key_line(
+                        key(KeyAction::FeatureExecution),
+                        "Implement tasks / Cancel implementation",
+                    ),
+                    Self::key_line(
+                        key(KeyAction::PlanFeature),
+                        "Preview feature task batches (dry run)",
+                    ),
+                    Self::key_line(
+                        key(KeyAction::ViewReport),
+                        "View report for selected expert",
+                    ),
+                    Self::key_line(
+                        key(KeyAction::ViewDiff),
+                        "View worktree diff for selected expert",
+                    ),
+                    Self::key_line(
+                        key(KeyAction::MergeWorktree),
+                        "Merge worktree branch (press twice)",
+                    ),
+                    Self::key_line(
+                        key(KeyAction::RemoveWorktree),
+                        "Remove worktree and return to root",
+                    ),
+                    Self::key_line(
+                        key(KeyAction::CycleEffort),
+                        "Cycle effort level (low / medium / high)",
+                    ),
+                    Self::key_line(
+                        key(KeyAction::RetryFailedMessages),
+                        "Retry failed messages and release held ones",
+                    ),
+                    Self::key_line(
+                        key(KeyAction::ReplayLastMessage),
+                        "Replay last message delivered to selected expert",
+                    ),
+                    Self::key_line(
+                        key(KeyAction::DismissFailedMessages),
+                        "Dismiss all failed messages (press twice)",
+                    ),
+                    Self::key_line(
+                        key(KeyAction::CycleModel),
+                        "Cycle model override (applies on next reset)",
+                    ),
+                    Self::key_line(
+                        key(KeyAction::ReplyToMessage),
+                        "Reply to queued message (fills input for its sender)",
+                    ),
+                    Self::key_line(
+                        key(KeyAction::MessageTemplate),
+                        "Fill a message template into input",
+                    ),
+                    Self::key_line(
+                        key(KeyAction::PingExpert),
+                        "Ping selected expert and report its reply time",
+                    ),
+                    Self::key_line(
+                        key(KeyAction::PinExpertPanel),
+                        "Pin expert panel to selected expert / Unpin",
+                    ),
+                    Self::key_line(
+                        key(KeyAction::EditNote),
+                        "Edit operator note for selected expert",
+                    ),
+                    Self::nested_subsection_title("Cursor Movement"),
+                    Self::key_line("Ctrl+B / Ctrl+F", "Move cursor left / right"),
+                    Self::key_line("Ctrl+A / Ctrl+E", "Move to line start / end"),
+                    Self::key_line("Ctrl+P / Ctrl+N", "Move to previous / next line"),
+                    Self::nested_subsection_title("Editing"),
+                    Self::key_line("Ctrl+H", "Delete character before cursor (backspace)"),
+                    Self::key_line("Ctrl+D", "Delete character at cursor (delete)"),
+                    Self::key_line(
+                        "Ctrl+U",
+                        "Delete from line start to cursor (unix-line-discard)",
+                    ),
+                    Self::key_line("Ctrl+K", "Delete from cursor to line end (kill-line)"),
+                    Self::nested_subsection_title("Submit"),
+                    Self::key_line(key(KeyAction::AssignTask), "Assign task to selected expert"),
+                    Self::key_line(
+                        key(KeyAction::AssignAndWatch),
+                        "Assign task, then pin the panel to that expert",
+                    ),
+                    Self::key_line(
+                        key(KeyAction::AssignBatch),
+                        "Assign each line of the file named in input",
+                    ),
+                    Self::key_line("Enter", "Insert newline"),
+                    Self::key_line("Shift+Tab", "Send to selected expert (tmux BTab)"),
+                    Self::key_line("! (at start)", "Send to selected expert (toggle bash mode)"),
+                    Self::nested_subsection_title("Remote Scroll (Expert Panel)"),
+                    Self::key_line("PageUp", "Enter scroll mode / Scroll up"),
+                    Self::key_line("PageDown", "Scroll down"),
+                    Self::key_line("Home / End", "Scroll to top / bottom"),
+                    Self::key_line("Esc / \u{2191}\u{2193}", "Exit scroll mode"),
+                ],
+            },
+            HelpSection {
+                title: "Expert Panel",
+                contexts: &[ExpertPanel],
+                lines: vec![
+                    Self::key_line("Any other key", "Forwarded to the expert's pane"),
+                    Self::key_line("Enter / Tab / Esc", "Forwarded to the expert's pane"),
+                    Self::key_line("PageUp", "Enter scroll mode"),
+                    Self::key_line(
+                        key(KeyAction::ZoomExpertPanel),
+                        "Zoom the panel to full screen / Restore",
+                    ),
+                ],
+            },
+            HelpSection {
+                title: "Scroll Mode",
+                contexts: &[ScrollMode],
+                lines: vec![
+                    Self::key_line("PageUp", "Scroll up"),
+                    Self::key_line("PageDown", "Scroll down"),
+                    Self::key_line("Home / End", "Scroll to top / bottom"),
+                    Self::key_line("Esc", "Exit scroll mode"),
+                ],
+            },
+            HelpSection {
+                title: "Global",
+                contexts: &[TaskInput, ExpertPanel, ScrollMode],
+                lines: vec![
+                    Self::key_line(key(KeyAction::SwitchFocus), "Switch focus between panels"),
+                    Self::key_line("Mouse Click", "Focus clicked panel"),
+                    Self::key_line("Ctrl+C / Ctrl+Q", "Quit application"),
+                    Self::key_line("F1", "Toggle this help"),
+                    Self::key_line("F2", "Activity timeline (decisions, messages, reports)"),
+                    Self::key_line("F3", "Debug overlay (debug builds or --debug-overlay)"),
+                    Self::key_line(key(KeyAction::MessageHistory), "Status message history"),
+                    Self::key_line(key(KeyAction::Scratchpad), "Shared expert scratchpad"),
+                    Self::key_line(key(KeyAction::MessageQueue), "Message queue (/ to search)"),
+                    Self::key_line(
+                        key(KeyAction::CommandPalette),
+                        "Command palette: run any action by name",
+                    ),
+                    Self::key_line(
+                        key(KeyAction::ToggleCompactStatus),
+                        "Collapse/expand the expert list",
+                    ),
+                    Self::key_line(
+                        key(KeyAction::ToggleFollowMessages),
+                        "Follow messages: select each recipient on delivery",
+                    ),
+                    Self::key_line(key(KeyAction::ToggleExpertPanel), "Toggle expert panel"),
+                ],
+            },
+            HelpSection {
+                title: "Report Detail",
+                contexts: &[],
+                lines: vec![
+                    Self::key_line("j / \u{2193}", "Scroll down"),
+                    Self::key_line("k / \u{2191}", "Scroll up"),
+                    Self::key_line("a", "Archive report (clears report indicator)"),
+                    Self::key_line("r", "Toggle raw / markdown summary"),
+                    Self::key_line("u", "Mark report unread"),
+                    Self::key_line(
+                        format!("Enter / q / {}", key(KeyAction::ViewReport)),
+                        "Close detail",
+                    ),
+                ],
+            },
+        ]
+    }
+
+    fn build_help_lines(&self) -> Vec<Line<'static>> {
+        let (relevant, other): (Vec<_>, Vec<_>) = self
+            .sections()
+            .into_iter()
+            .partition(|section| section.contexts.contains(&self.context));
+
+        let mut lines = vec![
             Self::section_title("Keyboard Shortcuts", Color::Cyan),
             Line::from(""),
-            Self::subsection_title("Global"),
-            Self::key_line("Ctrl+T", "Switch focus between panels"),
-            Self::key_line("Mouse Click", "Focus clicked panel"),
-            Self::key_line("Ctrl+C / Ctrl+Q", "Quit application"),
-            Self::key_line("F1", "Toggle this help"),
-            Self::key_line("Ctrl+J", "Toggle expert panel"),
-            Line::from(""),
-            Self::subsection_title("Task Input"),
-            Self::nested_subsection_title("Expert Operations"),
-            Self::key_line("\u{2191} / \u{2193}", "Select previous / next expert"),
-            Self::key_line("Ctrl+O", "Change expert role"),
-            Self::key_line("Ctrl+R", "Reset selected expert"),
-            Self::key_line("Ctrl+W", "Launch expert in worktree / Return from worktree"),
-            Self::key_line("Ctrl+G", "Implement tasks / Cancel implementation"),
-            Self::key_line("Ctrl+X", "View report for selected expert"),
-            Self::nested_subsection_title("Cursor Movement"),
-            Self::key_line("Ctrl+B / Ctrl+F", "Move cursor left / right"),
-            Self::key_line("Ctrl+A / Ctrl+E", "Move to line start / end"),
-            Self::key_line("Ctrl+P / Ctrl+N", "Move to previous / next line"),
-            Self::nested_subsection_title("Editing"),
-            Self::key_line("Ctrl+H", "Delete character before cursor (backspace)"),
-            Self::key_line("Ctrl+D", "Delete character at cursor (delete)"),
-            Self::key_line(
-                "Ctrl+U",
-                "Delete from line start to cursor (unix-line-discard)",
-            ),
-            Self::key_line("Ctrl+K", "Delete from cursor to line end (kill-line)"),
-            Self::nested_subsection_title("Submit"),
-            Self::key_line("Ctrl+S", "Assign task to selected expert"),
-            Self::key_line("Enter", "Insert newline"),
-            Self::key_line("Shift+Tab", "Send to selected expert (tmux BTab)"),
-            Self::key_line("! (at start)", "Send to selected expert (toggle bash mode)"),
-            Self::nested_subsection_title("Remote Scroll (Expert Panel)"),
-            Self::key_line("PageUp", "Enter scroll mode / Scroll up"),
-            Self::key_line("PageDown", "Scroll down"),
-            Self::key_line("Home / End", "Scroll to top / bottom"),
-            Self::key_line("Esc / \u{2191}\u{2193}", "Exit scroll mode"),
-            Line::from(""),
-            Self::subsection_title("Expert Panel"),
-            Self::key_line("PageUp", "Enter scroll mode / Scroll up"),
-            Self::key_line("PageDown", "Scroll down"),
-            Self::key_line("Home / End", "Scroll to top / bottom"),
-            Self::key_line("Esc", "Exit scroll mode"),
-            Line::from(""),
-            Self::subsection_title("Report Detail"),
-            Self::key_line("j / \u{2193}", "Scroll down"),
-            Self::key_line("k / \u{2191}", "Scroll up"),
-            Self::key_line("Enter / q / Ctrl+X", "Close detail"),
-            Line::from(""),
-            Line::from(Span::styled(
-                "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━",
-                Style::default().fg(Color::DarkGray),
-            )),
-            Line::from(vec![
-                Span::styled("Enter / q", Style::default().fg(Color::Yellow)),
-                Span::raw(": Close this help"),
-            ]),
-        ]
+        ];
+        let mut push_section = |section: HelpSection| {
+            lines.push(Self::subsection_title(section.title));
+            lines.extend(section.lines);
+            lines.push(Line::from(""));
+        };
+        for section in relevant {
+            push_section(section);
+        }
+        if self.show_all {
+            for section in other {
+                push_section(section);
+            }
+        }
+
+        lines.push(Line::from(Span::styled(
+            "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━",
+            Style::default().fg(Color::DarkGray),
+        )));
+        lines.push(Line::from(vec![
+            Span::styled("a", Style::default().fg(Color::Yellow)),
+            Span::raw(if self.show_all {
+                ": Show keys for the current panel only"
+            } else {
+                ": Show all keys"
+            }),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled("Enter / q", Style::default().fg(Color::Yellow)),
+            Span::raw(": Close this help"),
+        ]));
+        lines
     }
 
     fn section_title(title: &'static str, color: Color) -> Line<'static> {
@@ -153,7 +386,8 @@ impl HelpModal {
         ])
     }
 
-    fn key_line(key: &'static str, description: &'static str) -> Line<'static> {
+    fn key_line(key: impl Into<String>, description: &'static str) -> Line<'static> {
+        let key = key.into();
         Line::from(vec![
             Span::raw("  "),
             Span::styled(format!("{key:20}"), Style::default().fg(Color::Yellow)),
@@ -374,4 +608,86 @@ mod tests {
             "build_help_lines: should describe exit scroll mode"
         );
     }
+
+    fn help_text(modal: &HelpModal) -> String {
+        modal
+            .build_help_lines()
+            .iter()
+            .flat_map(|line| line.spans.iter().map(|s| s.content.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn expert_panel_context_shows_forwarding_keys_only() {
+        let mut modal = HelpModal::new();
+        modal.set_context(HelpContext::ExpertPanel);
+        let text = help_text(&modal);
+
+        assert!(
+            text.contains("Forwarded to the expert's pane"),
+            "build_help_lines: expert panel help should list pane-forwarding keys"
+        );
+        assert!(
+            !text.contains("Assign task") && !text.contains("kill-line"),
+            "build_help_lines: expert panel help should not list task-input-only keys"
+        );
+        assert!(
+            text.contains("Ctrl+T"),
+            "build_help_lines: global keys should still be shown"
+        );
+    }
+
+    #[test]
+    fn context_sections_come_before_global() {
+        let mut modal = HelpModal::new();
+        modal.set_context(HelpContext::ScrollMode);
+        let text = help_text(&modal);
+
+        let scroll = text.find("Scroll Mode").unwrap();
+        let global = text.find("Global").unwrap();
+        assert!(
+            scroll < global,
+            "build_help_lines: keys for the current mode should be listed first"
+        );
+        assert!(!text.contains("Expert Operations"));
+    }
+
+    #[test]
+    fn show_all_includes_every_section() {
+        let mut modal = HelpModal::new();
+        modal.set_context(HelpContext::ExpertPanel);
+        modal.toggle_show_all();
+        let text = help_text(&modal);
+
+        for title in [
+            "Task Input",
+            "Expert Panel",
+            "Scroll Mode",
+            "Global",
+            "Report Detail",
+        ] {
+            assert!(
+                text.contains(title),
+                "build_help_lines: show all should include the {title} section"
+            );
+        }
+
+        modal.set_context(HelpContext::TaskInput);
+        assert!(
+            !help_text(&modal).contains("Report Detail"),
+            "set_context: reopening help should reset show all"
+        );
+    }
+
+    #[test]
+    fn help_lists_configured_keybindings() {
+        let overrides = [("assign_task".to_string(), "alt+s".to_string())].into();
+        let modal = HelpModal::new().with_keymap(Keymap::from_overrides(&overrides).unwrap());
+        let text = help_text(&modal);
+
+        assert!(
+            text.contains("Alt+S") && !text.contains("Ctrl+S"),
+            "build_help_lines: should show the remapped assign chord, got: {text}"
+        );
+    }
 }
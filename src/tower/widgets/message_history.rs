@@ -0,0 +1,214 @@
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Local};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Status messages kept for the history viewer; older ones are dropped
+pub const MESSAGE_HISTORY_CAPACITY: usize = 200;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusMessage {
+    pub timestamp: DateTime<Local>,
+    pub text: String,
+}
+
+/// Bounded log of status-line messages with a scrollable modal, newest last
+pub struct MessageHistory {
+    entries: VecDeque<StatusMessage>,
+    scroll_offset: u16,
+    visible: bool,
+}
+
+impl MessageHistory {
+    pub fn new() -> Self {
+        Self {
+            entries: VecDeque::new(),
+            scroll_offset: 0,
+            visible: false,
+        }
+    }
+
+    pub fn push(&mut self, text: String) {
+        if self.entries.len() == MESSAGE_HISTORY_CAPACITY {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(StatusMessage {
+            timestamp: Local::now(),
+            text,
+        });
+    }
+
+    #[cfg(test)]
+    pub fn entries(&self) -> impl Iterator<Item = &StatusMessage> {
+        self.entries.iter()
+    }
+
+    /// Show the history, scrolled so the most recent messages are in view
+    pub fn show(&mut self, page: u16) {
+        self.scroll_offset = (self.line_count() as u16).saturating_sub(page);
+        self.visible = true;
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+        self.scroll_offset = 0;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        let max = self.line_count().saturating_sub(1).min(u16::MAX as usize) as u16;
+        if self.scroll_offset < max {
+            self.scroll_offset += 1;
+        }
+    }
+
+    pub fn page_up(&mut self, page: u16) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(page);
+    }
+
+    pub fn page_down(&mut self, page: u16) {
+        for _ in 0..page {
+            self.scroll_down();
+        }
+    }
+
+    fn line_count(&self) -> usize {
+        self.entries
+            .iter()
+            .map(|e| e.text.split('\n').count())
+            .sum()
+    }
+
+    fn build_lines(&self) -> Vec<Line<'static>> {
+        if self.entries.is_empty() {
+            return vec![Line::from(Span::styled(
+                "No messages yet",
+                Style::default().fg(Color::Gray),
+            ))];
+        }
+
+        let mut lines = Vec::new();
+        for entry in &self.entries {
+            let time = entry.timestamp.format("%H:%M:%S").to_string();
+            let indent = " ".repeat(time.len() + 1);
+            for (i, text) in entry.text.split('\n').enumerate() {
+                let prefix = if i == 0 {
+                    Span::styled(format!("{time} "), Style::default().fg(Color::DarkGray))
+                } else {
+                    Span::raw(indent.clone())
+                };
+                lines.push(Line::from(vec![prefix, Span::raw(text.to_string())]));
+            }
+        }
+        lines
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        frame.render_widget(Clear, area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(Span::styled(
+                format!(" Message History ({}) ", self.entries.len()),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ));
+
+        let paragraph = Paragraph::new(self.build_lines())
+            .block(block)
+            .scroll((self.scroll_offset, 0));
+        frame.render_widget(paragraph, area);
+    }
+}
+
+impl Default for MessageHistory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(history: &MessageHistory) -> Vec<&str> {
+        history.entries().map(|e| e.text.as_str()).collect()
+    }
+
+    #[test]
+    fn message_history_keeps_messages_in_order() {
+        let mut history = MessageHistory::new();
+        history.push("first".to_string());
+        history.push("second".to_string());
+        assert_eq!(texts(&history), vec!["first", "second"]);
+    }
+
+    #[test]
+    fn message_history_drops_oldest_beyond_capacity() {
+        let mut history = MessageHistory::new();
+        for i in 0..MESSAGE_HISTORY_CAPACITY + 5 {
+            history.push(format!("msg {i}"));
+        }
+        let kept = texts(&history);
+        assert_eq!(
+            kept.len(),
+            MESSAGE_HISTORY_CAPACITY,
+            "push: history should stay bounded"
+        );
+        assert_eq!(kept[0], "msg 5", "push: oldest messages should be dropped");
+        assert_eq!(
+            kept.last().copied(),
+            Some(format!("msg {}", MESSAGE_HISTORY_CAPACITY + 4).as_str())
+        );
+    }
+
+    #[test]
+    fn message_history_show_scrolls_to_latest() {
+        let mut history = MessageHistory::new();
+        for i in 0..30 {
+            history.push(format!("msg {i}"));
+        }
+        history.show(10);
+        assert!(history.is_visible());
+        assert_eq!(
+            history.scroll_offset, 20,
+            "show: newest messages should be in view"
+        );
+        history.hide();
+        assert!(!history.is_visible());
+        assert_eq!(texts(&history).len(), 30, "hide: entries should be kept");
+    }
+
+    #[test]
+    fn message_history_indents_multiline_messages() {
+        let mut history = MessageHistory::new();
+        history.push("blocked\n  Task 2".to_string());
+        let lines = history.build_lines();
+        assert_eq!(lines.len(), 2, "build_lines: one line per text line");
+        let second: String = lines[1].spans.iter().map(|s| s.content.as_ref()).collect();
+        assert!(second.ends_with("  Task 2"));
+        assert!(
+            second.starts_with("         "),
+            "build_lines: continuation indented"
+        );
+    }
+}
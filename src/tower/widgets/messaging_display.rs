@@ -2,11 +2,16 @@ use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, ListState},
+    widgets::{
+        Block, Borders, List, ListItem, ListState, Scrollbar, ScrollbarOrientation, ScrollbarState,
+    },
     Frame,
 };
+use std::ops::Range;
 
-use crate::models::{MessagePriority, MessageType, QueuedMessage};
+use crate::models::{
+    ExpertId, MessageId, MessagePriority, MessageRecipient, MessageType, QueuedMessage,
+};
 use crate::utils::truncate_str;
 
 /// Filter options for message display
@@ -17,6 +22,23 @@ pub struct MessageFilter {
     pub recipient_filter: Option<String>,
 }
 
+/// Pre-filled reply to a queued message, addressed back to its sender
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplyStub {
+    pub recipient: ExpertId,
+    pub reply_to: MessageId,
+    pub subject: String,
+    /// The expert the original was addressed to, when it named a single one
+    pub addressee: Option<ExpertId>,
+}
+
+impl ReplyStub {
+    /// Task input text seeding the reply; the operator writes the body below it
+    pub fn text(&self) -> String {
+        format!("Re: {}\nReply to: {}\n\n", self.subject, self.reply_to)
+    }
+}
+
 /// Display widget for messaging queue monitoring
 ///
 /// This is a display-only interface for monitoring queued messages.
@@ -26,9 +48,18 @@ pub struct MessagingDisplay {
     messages: Vec<QueuedMessage>,
     filtered_indices: Vec<usize>,
     state: ListState,
+    /// First filtered row shown; only the rows in view are built into list items
+    offset: usize,
+    /// List items built by the last render
+    rendered_items: usize,
     #[allow(dead_code)]
     focused: bool,
     filter: MessageFilter,
+    /// Text the list is narrowed to, matched against subject, body and sender
+    search: String,
+    /// Whether typed characters go to `search` (started with `/`)
+    searching: bool,
+    visible: bool,
 }
 
 impl MessagingDisplay {
@@ -37,11 +68,35 @@ impl MessagingDisplay {
             messages: Vec::new(),
             filtered_indices: Vec::new(),
             state: ListState::default(),
+            offset: 0,
+            rendered_items: 0,
             focused: false,
             filter: MessageFilter::default(),
+            search: String::new(),
+            searching: false,
+            visible: false,
+        }
+    }
+
+    /// Open the queue as a modal with the list focused
+    pub fn show(&mut self) {
+        self.visible = true;
+        self.focused = true;
+        if self.state.selected().is_none() && !self.filtered_indices.is_empty() {
+            self.state.select(Some(0));
         }
     }
 
+    pub fn hide(&mut self) {
+        self.visible = false;
+        self.focused = false;
+        self.searching = false;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
     /// Set the messages to display
     pub fn set_messages(&mut self, messages: Vec<QueuedMessage>) {
         self.messages = messages;
@@ -54,6 +109,14 @@ impl MessagingDisplay {
         &self.messages
     }
 
+    /// Messages whose delivery has been tried and failed
+    pub fn failed_count(&self) -> usize {
+        self.messages
+            .iter()
+            .filter(|m| m.has_failed_delivery())
+            .count()
+    }
+
     /// Get the number of visible (filtered) messages
     #[allow(dead_code)]
     pub fn visible_count(&self) -> usize {
@@ -86,12 +149,62 @@ impl MessagingDisplay {
         &self.filter
     }
 
+    /// Start an incremental search (the `/` key); typed characters narrow the list
+    pub fn start_search(&mut self) {
+        self.searching = true;
+    }
+
+    /// Whether a search is being typed
+    pub fn is_searching(&self) -> bool {
+        self.searching
+    }
+
+    pub fn search_query(&self) -> &str {
+        &self.search
+    }
+
+    pub fn insert_search_char(&mut self, c: char) {
+        self.search.push(c);
+        self.apply_filter();
+    }
+
+    pub fn delete_search_char(&mut self) {
+        self.search.pop();
+        self.apply_filter();
+    }
+
+    /// Stop typing the search but keep the list narrowed to it (Enter)
+    pub fn finish_search(&mut self) {
+        self.searching = false;
+    }
+
+    /// Drop the search and show every message again (Escape)
+    pub fn clear_search(&mut self) {
+        self.searching = false;
+        self.search.clear();
+        self.apply_filter();
+    }
+
+    /// Case-insensitive match of `query` against the subject, body and sender
+    fn matches_search(msg: &QueuedMessage, query: &str) -> bool {
+        let query = query.to_lowercase();
+        let content = &msg.message.content;
+        content.subject.to_lowercase().contains(&query)
+            || content.body.to_lowercase().contains(&query)
+            || msg.message.from_expert_id.to_string() == query
+    }
+
     fn apply_filter(&mut self) {
+        let search = self.search.trim();
         self.filtered_indices = self
             .messages
             .iter()
             .enumerate()
             .filter(|(_, msg)| {
+                if !search.is_empty() && !Self::matches_search(msg, search) {
+                    return false;
+                }
+
                 // Filter by message type if set
                 if let Some(ref filter_type) = self.filter.message_type {
                     if &msg.message.message_type != filter_type {
@@ -147,19 +260,16 @@ impl MessagingDisplay {
     }
 
     /// Navigate to next message
-    #[allow(dead_code)]
     pub fn next(&mut self) {
         super::select_next(&mut self.state, self.filtered_indices.len());
     }
 
     /// Navigate to previous message
-    #[allow(dead_code)]
     pub fn prev(&mut self) {
         super::select_prev(&mut self.state, self.filtered_indices.len());
     }
 
     /// Get the currently selected message
-    #[allow(dead_code)]
     pub fn selected_message(&self) -> Option<&QueuedMessage> {
         self.state
             .selected()
@@ -167,6 +277,19 @@ impl MessagingDisplay {
             .and_then(|&idx| self.messages.get(idx))
     }
 
+    /// Reply stub for the selected message, targeting its sender
+    pub fn reply_stub(&self) -> Option<ReplyStub> {
+        self.selected_message().map(|msg| ReplyStub {
+            recipient: msg.message.from_expert_id,
+            reply_to: msg.message.message_id.clone(),
+            subject: msg.message.content.subject.clone(),
+            addressee: match msg.message.to {
+                MessageRecipient::ExpertId { expert_id } => Some(expert_id),
+                _ => None,
+            },
+        })
+    }
+
     /// Get symbol for message type
     #[allow(dead_code)]
     fn type_symbol(message_type: &MessageType) -> (&'static str, Color) {
@@ -196,14 +319,48 @@ impl MessagingDisplay {
             crate::models::MessageRecipient::Role { role } => {
                 format!("→@{}", truncate_str(role, 7))
             }
+            crate::models::MessageRecipient::Tag { tag } => {
+                format!("→#{}", truncate_str(tag, 7))
+            }
+            crate::models::MessageRecipient::Multi(recipients) => {
+                format!("→{} recipients", recipients.len())
+            }
+        }
+    }
+
+    /// Filtered rows to show in a viewport of `rows` lines, scrolled just
+    /// enough to keep the selection in view
+    fn visible_window(&mut self, rows: usize) -> Range<usize> {
+        let len = self.filtered_indices.len();
+        if self
+            .state
+            .selected()
+            .is_some_and(|selected| selected >= len)
+        {
+            self.state.select(len.checked_sub(1));
+        }
+        if let Some(selected) = self.state.selected() {
+            if selected < self.offset {
+                self.offset = selected;
+            } else if rows > 0 && selected >= self.offset + rows {
+                self.offset = selected + 1 - rows;
+            }
         }
+        self.offset = self.offset.min(len.saturating_sub(rows.max(1)));
+        self.offset..(self.offset + rows).min(len)
+    }
+
+    /// List items built by the last render
+    #[cfg(test)]
+    pub fn rendered_items(&self) -> usize {
+        self.rendered_items
     }
 
     /// Render the messaging display widget
-    #[allow(dead_code)]
     pub fn render(&mut self, frame: &mut Frame, area: Rect) {
-        let items: Vec<ListItem> = self
-            .filtered_indices
+        let rows = area.height.saturating_sub(2) as usize;
+        let window = self.visible_window(rows);
+        let items: Vec<ListItem> = self.filtered_indices[window.clone()]
             .iter()
             .map(|&idx| {
                 let msg = &self.messages[idx];
@@ -237,7 +394,7 @@ impl MessagingDisplay {
                     ("○", Color::White)
                 };
 
-                let spans = vec![
+                let mut spans = vec![
                     Span::styled(
                         type_symbol,
                         Style::default().fg(type_color).add_modifier(Modifier::BOLD),
@@ -258,6 +415,9 @@ impl MessagingDisplay {
                     Span::styled(priority_symbol, Style::default().fg(priority_color)),
                     Span::styled(status_indicator.0, Style::default().fg(status_indicator.1)),
                 ];
+                if msg.message.is_truncated() {
+                    spans.push(Span::styled("✂", Style::default().fg(Color::Yellow)));
+                }
 
                 ListItem::new(Line::from(spans))
             })
@@ -269,7 +429,7 @@ impl MessagingDisplay {
             Style::default().fg(Color::Gray)
         };
 
-        let title = if self.filtered_indices.len() == self.messages.len() {
+        let mut title = if self.filtered_indices.len() == self.messages.len() {
             format!("Messages [{}]", self.messages.len())
         } else {
             format!(
@@ -278,7 +438,14 @@ impl MessagingDisplay {
                 self.messages.len()
             )
         };
+        if self.searching || !self.search.is_empty() {
+            title.push_str(&format!(" /{}", self.search));
+            if self.searching {
+                title.push('_');
+            }
+        }
 
+        self.rendered_items = items.len();
         let list = List::new(items)
             .block(
                 Block::default()
@@ -293,7 +460,23 @@ impl MessagingDisplay {
             )
             .highlight_symbol("> ");
 
-        frame.render_stateful_widget(list, area, &mut self.state);
+        let mut window_state = ListState::default().with_selected(
+            self.state
+                .selected()
+                .filter(|selected| window.contains(selected))
+                .map(|selected| selected - window.start),
+        );
+        frame.render_stateful_widget(list, area, &mut window_state);
+
+        let len = self.filtered_indices.len();
+        if len > rows {
+            let mut scrollbar = ScrollbarState::new(len.saturating_sub(rows)).position(self.offset);
+            frame.render_stateful_widget(
+                Scrollbar::new(ScrollbarOrientation::VerticalRight),
+                area,
+                &mut scrollbar,
+            );
+        }
     }
 }
 
@@ -318,6 +501,7 @@ mod tests {
         let content = MessageContent {
             subject: subject.to_string(),
             body: "Test body".to_string(),
+            attachments: Vec::new(),
         };
         let message = Message::new(from, to, msg_type, content).with_priority(priority);
         QueuedMessage::new(message)
@@ -495,6 +679,141 @@ mod tests {
         assert!(display.selected_message().is_some());
     }
 
+    #[test]
+    fn reply_stub_targets_sender_of_selected_message() {
+        let mut display = MessagingDisplay::new();
+        assert!(display.reply_stub().is_none());
+
+        let message = create_test_queued_message(
+            2,
+            MessageRecipient::expert_id(0),
+            MessageType::Query,
+            MessagePriority::Normal,
+            "Which schema?",
+        );
+        let message_id = message.message.message_id.clone();
+        display.set_messages(vec![message]);
+        display.next();
+
+        let stub = display.reply_stub().unwrap();
+        assert_eq!(stub.recipient, 2, "reply_stub: should target the sender");
+        assert_eq!(
+            stub.addressee,
+            Some(0),
+            "reply_stub: the reply is written for the expert the original went to"
+        );
+        assert_eq!(
+            stub.reply_to, message_id,
+            "reply_stub: reply_to should be the selected message id"
+        );
+        assert_eq!(
+            stub.text(),
+            format!("Re: Which schema?\nReply to: {message_id}\n\n")
+        );
+    }
+
+    fn message_with_body(from: u32, subject: &str, body: &str) -> QueuedMessage {
+        let content = MessageContent {
+            subject: subject.to_string(),
+            body: body.to_string(),
+            attachments: Vec::new(),
+        };
+        QueuedMessage::new(Message::new(
+            from,
+            MessageRecipient::expert_id(0),
+            MessageType::Notify,
+            content,
+        ))
+    }
+
+    #[test]
+    fn messaging_display_search_matches_body_text() {
+        let mut display = MessagingDisplay::new();
+        display.set_messages(vec![
+            message_with_body(1, "Status", "All tests pass"),
+            message_with_body(2, "Review", "The Parser rejects empty input"),
+            message_with_body(3, "Design", "Use a trie"),
+        ]);
+
+        display.start_search();
+        assert!(display.is_searching());
+        for c in "parser".chars() {
+            display.insert_search_char(c);
+        }
+        assert_eq!(
+            display.visible_count(),
+            1,
+            "search: only the message whose body mentions the query should remain"
+        );
+        display.next();
+        assert_eq!(
+            display.selected_message().unwrap().message.content.subject,
+            "Review"
+        );
+
+        display.delete_search_char();
+        display.delete_search_char();
+        display.delete_search_char();
+        display.insert_search_char('x');
+        assert_eq!(display.search_query(), "parx");
+        assert_eq!(
+            display.visible_count(),
+            0,
+            "search: narrowing updates live as the query changes"
+        );
+    }
+
+    #[test]
+    fn messaging_display_search_matches_subject_and_sender() {
+        let mut display = MessagingDisplay::new();
+        display.set_messages(vec![
+            message_with_body(1, "Status", "ok"),
+            message_with_body(2, "Review", "ok"),
+        ]);
+
+        display.insert_search_char('s');
+        display.insert_search_char('t');
+        assert_eq!(display.visible_count(), 1, "search: subject should match");
+
+        display.clear_search();
+        display.insert_search_char('2');
+        assert_eq!(display.visible_count(), 1, "search: sender id should match");
+    }
+
+    #[test]
+    fn messaging_display_clear_search_restores_full_list() {
+        let mut display = MessagingDisplay::new();
+        display.set_messages(vec![
+            message_with_body(1, "Status", "All tests pass"),
+            message_with_body(2, "Review", "Parser bug"),
+        ]);
+        display.set_filter(MessageFilter {
+            message_type: Some(MessageType::Notify),
+            ..Default::default()
+        });
+
+        display.start_search();
+        display.insert_search_char('b');
+        display.insert_search_char('u');
+        display.insert_search_char('g');
+        display.finish_search();
+        assert!(!display.is_searching());
+        assert_eq!(
+            display.visible_count(),
+            1,
+            "finish_search: the list stays narrowed"
+        );
+
+        display.clear_search();
+        assert_eq!(display.search_query(), "");
+        assert!(!display.is_searching());
+        assert_eq!(
+            display.visible_count(),
+            2,
+            "clear_search: every message should be shown again"
+        );
+    }
+
     #[test]
     fn messaging_display_focus_state() {
         let mut display = MessagingDisplay::new();
@@ -539,6 +858,90 @@ mod tests {
         ))
         .contains("@"));
     }
+
+    #[test]
+    fn messaging_display_render_builds_only_visible_rows() {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let mut display = MessagingDisplay::new();
+        display.set_messages(
+            (0..500)
+                .map(|i| {
+                    create_test_queued_message(
+                        1,
+                        MessageRecipient::expert_id(2),
+                        MessageType::Query,
+                        MessagePriority::Normal,
+                        &format!("msg {i}"),
+                    )
+                })
+                .collect(),
+        );
+        let mut terminal = Terminal::new(TestBackend::new(60, 12)).unwrap();
+
+        terminal.draw(|f| display.render(f, f.area())).unwrap();
+        assert_eq!(
+            display.rendered_items(),
+            10,
+            "render: 500 messages should build only the 10 rows in view"
+        );
+
+        while display
+            .selected_message()
+            .map(|m| m.message.content.subject.as_str())
+            != Some("msg 300")
+        {
+            display.next();
+        }
+        terminal.draw(|f| display.render(f, f.area())).unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert_eq!(display.rendered_items(), 10);
+        assert!(
+            screen.contains("Messages [500]") && screen.contains("> "),
+            "render: title keeps the full count and the selection stays in view"
+        );
+        assert!(screen.contains("msg 300"));
+        assert!(!screen.contains("msg 0 "));
+    }
+
+    #[test]
+    fn messaging_display_render_into_tiny_areas_clamps_offset() {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let mut display = MessagingDisplay::new();
+        display.set_messages(
+            (0..5)
+                .map(|i| {
+                    create_test_queued_message(
+                        1,
+                        MessageRecipient::expert_id(2),
+                        MessageType::Query,
+                        MessagePriority::Normal,
+                        &format!("msg {i}"),
+                    )
+                })
+                .collect(),
+        );
+        display.offset = 40;
+        display.state.select(Some(40));
+        for (width, height) in [(1, 1), (2, 2)] {
+            let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+            terminal.draw(|f| display.render(f, f.area())).unwrap();
+            assert_eq!(
+                display.state.selected(),
+                Some(4),
+                "render: {width}x{height} should clamp the selection to the last message"
+            );
+            assert!(display.offset <= 4, "render: offset should stay in range");
+            assert_eq!(display.rendered_items(), 0);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -583,7 +986,11 @@ mod property_tests {
             "[a-zA-Z0-9 ]{1,200}",
         )
             .prop_map(|(from_id, to, msg_type, priority, subject, body)| {
-                let content = MessageContent { subject, body };
+                let content = MessageContent {
+                    subject,
+                    body,
+                    attachments: Vec::new(),
+                };
                 let message = Message::new(from_id, to, msg_type, content).with_priority(priority);
                 QueuedMessage::new(message)
             })
@@ -1,20 +1,40 @@
+mod activity_timeline;
+mod command_palette;
+mod debug_overlay;
+mod diff_modal;
 mod expert_panel_display;
+mod feature_plan_modal;
 mod help_modal;
+mod message_history;
 mod messaging_display;
+mod note_editor;
+mod output_regions;
 mod report_detail_modal;
 mod report_display;
 mod role_selector;
+mod scratchpad_view;
 mod status_display;
 mod task_input;
+mod template_picker;
 
-pub use expert_panel_display::ExpertPanelDisplay;
-pub use help_modal::HelpModal;
+pub use activity_timeline::{build_timeline, ActivityTimeline};
+pub use command_palette::CommandPalette;
+pub use debug_overlay::{DebugOverlay, LoopTimings};
+pub use diff_modal::DiffModal;
+pub use expert_panel_display::{ExpertPanelDisplay, LineHighlighter};
+pub use feature_plan_modal::FeaturePlanModal;
+pub use help_modal::{HelpContext, HelpModal};
+pub use message_history::MessageHistory;
 #[allow(unused_imports)]
-pub use messaging_display::{MessageFilter, MessagingDisplay};
+pub use messaging_display::{MessageFilter, MessagingDisplay, ReplyStub};
+pub use note_editor::NoteEditor;
+pub use output_regions::RegionTagger;
 pub use report_display::{ReportDisplay, ViewMode};
 pub use role_selector::RoleSelector;
+pub use scratchpad_view::ScratchpadView;
 pub use status_display::{ExpertEntry, StatusDisplay};
 pub use task_input::TaskInput;
+pub use template_picker::TemplatePicker;
 
 use ratatui::widgets::ListState;
 
@@ -0,0 +1,142 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Longest note accepted; notes are one-line subtitles in the expert list
+const MAX_NOTE_CHARS: usize = 80;
+
+/// Small modal for editing the operator's note on one expert
+pub struct NoteEditor {
+    visible: bool,
+    expert_id: Option<u32>,
+    expert_name: String,
+    content: String,
+}
+
+impl NoteEditor {
+    pub fn new() -> Self {
+        Self {
+            visible: false,
+            expert_id: None,
+            expert_name: String::new(),
+            content: String::new(),
+        }
+    }
+
+    pub fn show(&mut self, expert_id: u32, expert_name: &str, note: &str) {
+        self.visible = true;
+        self.expert_id = Some(expert_id);
+        self.expert_name = expert_name.to_string();
+        self.content = note.to_string();
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+        self.expert_id = None;
+        self.expert_name.clear();
+        self.content.clear();
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn expert_id(&self) -> Option<u32> {
+        self.expert_id
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        if self.content.chars().count() < MAX_NOTE_CHARS {
+            self.content.push(c);
+        }
+    }
+
+    pub fn delete_char(&mut self) {
+        self.content.pop();
+    }
+
+    pub fn clear(&mut self) {
+        self.content.clear();
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        let width = (MAX_NOTE_CHARS as u16 + 4).min(area.width.saturating_sub(4));
+        let height = 4.min(area.height);
+        let popup_area = Rect::new(
+            area.x + area.width.saturating_sub(width) / 2,
+            area.y + area.height.saturating_sub(height) / 2,
+            width,
+            height,
+        );
+
+        frame.render_widget(Clear, popup_area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(format!("Note for {}", self.expert_name));
+        let lines = vec![
+            Line::from(vec![
+                Span::raw(self.content.clone()),
+                Span::styled("█", Style::default().fg(Color::Gray)),
+            ]),
+            Line::from(vec![
+                Span::styled("Enter", Style::default().fg(Color::Cyan)),
+                Span::raw(": Save  |  "),
+                Span::styled("Esc", Style::default().fg(Color::Cyan)),
+                Span::raw(": Cancel  |  "),
+                Span::styled("Ctrl+U", Style::default().fg(Color::Cyan)),
+                Span::raw(": Clear"),
+            ]),
+        ];
+        frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+    }
+}
+
+impl Default for NoteEditor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn note_editor_edits_and_resets_on_hide() {
+        let mut editor = NoteEditor::new();
+        editor.show(2, "Grigory", "auth");
+        editor.insert_char('!');
+        assert_eq!(editor.content(), "auth!");
+        editor.delete_char();
+        assert_eq!(editor.expert_id(), Some(2));
+        assert_eq!(editor.content(), "auth");
+
+        editor.hide();
+        assert!(!editor.is_visible());
+        assert_eq!(editor.expert_id(), None);
+        assert_eq!(editor.content(), "", "hide: should drop the draft");
+    }
+
+    #[test]
+    fn note_editor_caps_length() {
+        let mut editor = NoteEditor::new();
+        editor.show(0, "Alyosha", "");
+        for _ in 0..MAX_NOTE_CHARS + 10 {
+            editor.insert_char('x');
+        }
+        assert_eq!(editor.content().chars().count(), MAX_NOTE_CHARS);
+    }
+}
@@ -0,0 +1,132 @@
+use ratatui::{style::Modifier, text::Text};
+use regex::Regex;
+
+use crate::config::{OutputRegion, RegionMarker};
+
+/// Splits captured pane content into thinking, tool output and answer regions
+/// using the configured `ui.output_regions` markers.
+#[derive(Debug, Clone, Default)]
+pub struct RegionTagger {
+    markers: Vec<(Regex, OutputRegion)>,
+}
+
+impl RegionTagger {
+    /// Compile region markers, skipping (and logging) any with an invalid pattern.
+    pub fn from_markers(markers: &[RegionMarker]) -> Self {
+        let markers = markers
+            .iter()
+            .filter_map(|marker| match Regex::new(&marker.pattern) {
+                Ok(regex) => Some((regex, marker.region)),
+                Err(e) => {
+                    tracing::warn!("Ignoring output region marker '{}': {}", marker.pattern, e);
+                    None
+                }
+            })
+            .collect();
+        Self { markers }
+    }
+
+    /// Region of each line; lines before the first marker are `Other`
+    pub fn tag_lines<'a>(&self, lines: impl IntoIterator<Item = &'a str>) -> Vec<OutputRegion> {
+        let mut current = OutputRegion::Other;
+        lines
+            .into_iter()
+            .map(|line| {
+                if let Some((_, region)) = self.markers.iter().find(|(re, _)| re.is_match(line)) {
+                    current = *region;
+                }
+                current
+            })
+            .collect()
+    }
+
+    /// Dim thinking lines and embolden answer lines
+    pub fn apply(&self, mut text: Text<'static>) -> Text<'static> {
+        if self.markers.is_empty() {
+            return text;
+        }
+        let plain: Vec<String> = text
+            .lines
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+        let tags = self.tag_lines(plain.iter().map(String::as_str));
+        for (line, region) in text.lines.iter_mut().zip(tags) {
+            let modifier = match region {
+                OutputRegion::Thinking => Modifier::DIM,
+                OutputRegion::Answer => Modifier::BOLD,
+                OutputRegion::ToolOutput | OutputRegion::Other => continue,
+            };
+            for span in &mut line.spans {
+                span.style = span.style.add_modifier(modifier);
+            }
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::UiConfig;
+
+    const SAMPLE: &str = "\
+> fix the flaky test
+✻ Thinking…
+  The test sleeps instead of waiting on the channel.
+⏺ Read(src/lib.rs)
+  ⎿  Read 120 lines
+⏺ The test now waits on the channel instead of sleeping.
+  All 42 tests pass.
+───────────────
+> ";
+
+    fn default_tagger() -> RegionTagger {
+        RegionTagger::from_markers(&UiConfig::default().output_regions)
+    }
+
+    #[test]
+    fn tag_lines_tags_sample_claude_output() {
+        use OutputRegion::*;
+        assert_eq!(
+            default_tagger().tag_lines(SAMPLE.lines()),
+            vec![Other, Thinking, Thinking, ToolOutput, ToolOutput, Answer, Answer, Other, Other,],
+            "tag_lines: each marker should open a region that runs to the next marker"
+        );
+    }
+
+    #[test]
+    fn custom_markers_follow_config_and_skip_invalid_patterns() {
+        let tagger = RegionTagger::from_markers(&[
+            RegionMarker::new("(", OutputRegion::Thinking),
+            RegionMarker::new("^<think>", OutputRegion::Thinking),
+            RegionMarker::new("^<answer>", OutputRegion::Answer),
+        ]);
+        assert_eq!(
+            tagger.tag_lines(["intro", "<think>", "hmm", "<answer>", "42"]),
+            vec![
+                OutputRegion::Other,
+                OutputRegion::Thinking,
+                OutputRegion::Thinking,
+                OutputRegion::Answer,
+                OutputRegion::Answer,
+            ]
+        );
+    }
+
+    #[test]
+    fn apply_dims_thinking_and_emboldens_answers() {
+        let text = default_tagger().apply(Text::raw(SAMPLE));
+        let modifiers = |i: usize| text.lines[i].spans[0].style.add_modifier;
+
+        assert!(
+            modifiers(2).contains(Modifier::DIM),
+            "apply: thinking lines should be dimmed"
+        );
+        assert!(
+            modifiers(6).contains(Modifier::BOLD),
+            "apply: answer lines should be bold"
+        );
+        assert!(modifiers(4).is_empty() && modifiers(0).is_empty());
+    }
+}
@@ -11,6 +11,8 @@ use crate::models::{Report, TaskStatus};
 pub struct ReportDetailModal {
     report: Option<Report>,
     scroll_offset: u16,
+    /// Show the summary as written instead of styling its markdown
+    raw: bool,
 }
 
 impl ReportDetailModal {
@@ -18,6 +20,7 @@ impl ReportDetailModal {
         Self {
             report: None,
             scroll_offset: 0,
+            raw: false,
         }
     }
 
@@ -36,6 +39,20 @@ impl ReportDetailModal {
         self.report.is_some()
     }
 
+    pub fn report(&self) -> Option<&Report> {
+        self.report.as_ref()
+    }
+
+    pub fn toggle_raw(&mut self) {
+        self.raw = !self.raw;
+        self.scroll_offset = 0;
+    }
+
+    #[cfg(test)]
+    pub fn is_raw(&self) -> bool {
+        self.raw
+    }
+
     pub fn scroll_up(&mut self) {
         self.scroll_offset = self.scroll_offset.saturating_sub(1);
     }
@@ -83,7 +100,7 @@ impl ReportDetailModal {
         }
     }
 
-    pub fn render(&self, frame: &mut Frame, area: Rect) {
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
         let report = match &self.report {
             Some(r) => r,
             None => return,
@@ -139,10 +156,12 @@ impl ReportDetailModal {
                 "  (No summary yet)",
                 Style::default().fg(Color::Gray),
             )));
-        } else {
+        } else if self.raw {
             for line in report.summary.lines() {
                 lines.push(Line::from(format!("  {line}")));
             }
+        } else {
+            lines.extend(markdown_lines(&report.summary));
         }
 
         if !report.details.findings.is_empty() {
@@ -246,6 +265,12 @@ impl ReportDetailModal {
             Span::raw(": Scroll down  "),
             Span::styled("k/↑", Style::default().fg(Color::Yellow)),
             Span::raw(": Scroll up  "),
+            Span::styled("a", Style::default().fg(Color::Yellow)),
+            Span::raw(": Archive  "),
+            Span::styled("u", Style::default().fg(Color::Yellow)),
+            Span::raw(": Unread  "),
+            Span::styled("r", Style::default().fg(Color::Yellow)),
+            Span::raw(if self.raw { ": Markdown  " } else { ": Raw  " }),
             Span::styled("Enter/q/Ctrl+X", Style::default().fg(Color::Yellow)),
             Span::raw(": Close"),
         ]));
@@ -255,14 +280,109 @@ impl ReportDetailModal {
             .constraints([Constraint::Min(1)])
             .split(inner_area)[0];
 
-        let paragraph = Paragraph::new(lines)
-            .wrap(Wrap { trim: false })
-            .scroll((self.scroll_offset, 0));
+        let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
+        // Keep the offset on the last screenful so scrolling back up responds
+        // at once, however small the area
+        let max_scroll = paragraph
+            .line_count(content_area.width)
+            .saturating_sub(content_area.height.max(1) as usize)
+            .min(u16::MAX as usize) as u16;
+        self.scroll_offset = self.scroll_offset.min(max_scroll);
+        let paragraph = paragraph.scroll((self.scroll_offset, 0));
 
         frame.render_widget(paragraph, content_area);
     }
 }
 
+/// Style a markdown summary: headers, bold and inline code, bullet lists and
+/// fenced code blocks. Lines are indented like the other detail sections and
+/// left for the paragraph to wrap.
+fn markdown_lines(text: &str) -> Vec<Line<'static>> {
+    let code_style = Style::default().fg(Color::DarkGray);
+    let mut lines = Vec::new();
+    let mut in_code = false;
+
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            lines.push(Line::from(vec![
+                Span::styled("  │ ", code_style),
+                Span::styled(line.to_string(), code_style),
+            ]));
+            continue;
+        }
+
+        let level = trimmed.chars().take_while(|&c| c == '#').count();
+        if (1..=6).contains(&level) && trimmed[level..].starts_with(' ') {
+            let style = Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD);
+            let mut spans = vec![Span::raw("  ")];
+            spans.extend(
+                inline_spans(trimmed[level..].trim())
+                    .into_iter()
+                    .map(|span| span.patch_style(style)),
+            );
+            lines.push(Line::from(spans));
+            continue;
+        }
+
+        let indent = &line[..line.len() - trimmed.len()];
+        let bullet = ["- ", "* ", "+ "]
+            .iter()
+            .find_map(|marker| trimmed.strip_prefix(marker));
+        let mut spans = vec![Span::raw(format!("  {indent}"))];
+        match bullet {
+            Some(item) => {
+                spans.push(Span::styled("• ", Style::default().fg(Color::Yellow)));
+                spans.extend(inline_spans(item));
+            }
+            None => spans.extend(inline_spans(trimmed)),
+        }
+        lines.push(Line::from(spans));
+    }
+    lines
+}
+
+/// Split one line on `**bold**` and `` `code` `` markers; an unclosed marker
+/// is kept as text
+fn inline_spans(text: &str) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut rest = text;
+    loop {
+        let next = [("**", Modifier::BOLD), ("`", Modifier::empty())]
+            .into_iter()
+            .filter_map(|(marker, modifier)| {
+                let start = rest.find(marker)?;
+                let len = rest[start + marker.len()..].find(marker)?;
+                Some((start, marker, len, modifier))
+            })
+            .min_by_key(|&(start, ..)| start);
+        let Some((start, marker, len, modifier)) = next else {
+            break;
+        };
+        if start > 0 {
+            spans.push(Span::raw(rest[..start].to_string()));
+        }
+        let inner = &rest[start + marker.len()..start + marker.len() + len];
+        let style = if modifier.is_empty() {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().add_modifier(modifier)
+        };
+        spans.push(Span::styled(inner.to_string(), style));
+        rest = &rest[start + 2 * marker.len() + len..];
+    }
+    if !rest.is_empty() || spans.is_empty() {
+        spans.push(Span::raw(rest.to_string()));
+    }
+    spans
+}
+
 impl Default for ReportDetailModal {
     fn default() -> Self {
         Self::new()
@@ -322,6 +442,85 @@ mod tests {
         assert_eq!(modal.scroll_offset, 0);
     }
 
+    #[test]
+    fn render_into_tiny_areas_clamps_scroll_offset() {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let mut modal = ReportDetailModal::new();
+        modal.show(create_test_report());
+        for (width, height) in [(1, 1), (2, 2)] {
+            modal.scroll_offset = 500;
+            let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+            terminal.draw(|f| modal.render(f, f.area())).unwrap();
+            assert_eq!(
+                modal.scroll_offset, 0,
+                "render: {width}x{height} has no content area to scroll"
+            );
+        }
+
+        modal.scroll_offset = 500;
+        let mut terminal = Terminal::new(TestBackend::new(40, 8)).unwrap();
+        terminal.draw(|f| modal.render(f, f.area())).unwrap();
+        assert!(
+            modal.scroll_offset > 0 && modal.scroll_offset < 500,
+            "render: the offset should clamp to the last screenful"
+        );
+    }
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn markdown_lines_style_headers_lists_and_code() {
+        let lines = markdown_lines(
+            "## Result\nFixed **two** bugs in `auth.rs`:\n- login\n  * nested\n```rust\nfn main() {}\n```\n# not closed **bold",
+        );
+        let text: Vec<String> = lines.iter().map(line_text).collect();
+        assert_eq!(
+            text,
+            vec![
+                "  Result",
+                "  Fixed two bugs in auth.rs:",
+                "  • login",
+                "    • nested",
+                "  │ fn main() {}",
+                "  not closed **bold",
+            ],
+            "markdown_lines: markers should be stripped and fences dropped"
+        );
+
+        let header = &lines[0].spans[1];
+        assert_eq!(header.style.fg, Some(Color::Cyan));
+        assert!(header.style.add_modifier.contains(Modifier::BOLD));
+
+        let spans = &lines[1].spans;
+        assert_eq!(spans[2].content, "two");
+        assert!(spans[2].style.add_modifier.contains(Modifier::BOLD));
+        assert_eq!(spans[4].content, "auth.rs");
+        assert_eq!(spans[4].style.fg, Some(Color::Yellow));
+
+        assert!(
+            lines[4]
+                .spans
+                .iter()
+                .all(|s| s.style.fg == Some(Color::DarkGray)),
+            "markdown_lines: code block lines should be dimmed"
+        );
+    }
+
+    #[test]
+    fn toggle_raw_switches_mode_and_resets_scroll() {
+        let mut modal = ReportDetailModal::new();
+        modal.show(create_test_report());
+        modal.scroll_down(100);
+        assert!(!modal.is_raw());
+
+        modal.toggle_raw();
+        assert!(modal.is_raw());
+        assert_eq!(modal.scroll_offset, 0, "toggle_raw: should reset scroll");
+    }
+
     #[test]
     fn scroll_down_respects_max_lines() {
         let mut modal = ReportDetailModal::new();
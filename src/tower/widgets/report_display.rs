@@ -1,3 +1,6 @@
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -6,6 +9,7 @@ use ratatui::{
     Frame,
 };
 
+use crate::context::ArchivedReport;
 use crate::models::{Report, TaskStatus};
 use crate::utils::truncate_str;
 
@@ -21,6 +25,13 @@ pub enum ViewMode {
 #[allow(dead_code)]
 pub struct ReportDisplay {
     reports: Vec<Report>,
+    /// Reviewed reports, listed below the active ones
+    archived: Vec<Report>,
+    archived_keys: HashSet<ArchivedReport>,
+    /// Reports the operator has looked at; they no longer light the report dot
+    read_keys: HashSet<ArchivedReport>,
+    /// When the report in the detail view was opened; cleared by "mark unread"
+    detail_opened_at: Option<Instant>,
     state: ListState,
     focused: bool,
     view_mode: ViewMode,
@@ -32,6 +43,10 @@ impl ReportDisplay {
     pub fn new() -> Self {
         Self {
             reports: Vec::new(),
+            archived: Vec::new(),
+            archived_keys: HashSet::new(),
+            read_keys: HashSet::new(),
+            detail_opened_at: None,
             state: ListState::default(),
             focused: false,
             view_mode: ViewMode::List,
@@ -51,8 +66,7 @@ impl ReportDisplay {
     #[allow(dead_code)]
     pub fn open_detail(&mut self) {
         if let Some(report) = self.selected_report().cloned() {
-            self.detail_modal.show(report);
-            self.view_mode = ViewMode::Detail;
+            self.show_detail(report);
         }
     }
 
@@ -63,17 +77,56 @@ impl ReportDisplay {
             .find(|r| r.expert_id == expert_id)
             .cloned()
         {
-            self.detail_modal.show(report);
-            self.view_mode = ViewMode::Detail;
+            self.show_detail(report);
             true
         } else {
             false
         }
     }
 
+    fn show_detail(&mut self, report: Report) {
+        self.detail_modal.show(report);
+        self.view_mode = ViewMode::Detail;
+        self.detail_opened_at = Some(Instant::now());
+    }
+
     pub fn close_detail(&mut self) {
         self.detail_modal.hide();
         self.view_mode = ViewMode::List;
+        self.detail_opened_at = None;
+    }
+
+    /// Mark the open report read once it has been shown for `delay`.
+    /// Returns whether it was newly marked.
+    pub fn mark_read_after(&mut self, delay: Duration, now: Instant) -> bool {
+        let dwelled = self
+            .detail_opened_at
+            .is_some_and(|opened| now.saturating_duration_since(opened) >= delay);
+        if !dwelled {
+            return false;
+        }
+        let Some(key) = self.detail_modal.report().map(Self::key) else {
+            return false;
+        };
+        self.read_keys.insert(key)
+    }
+
+    /// Mark the open report unread again; it stays unread until reopened.
+    /// Returns whether it had been read.
+    pub fn mark_open_unread(&mut self) -> bool {
+        self.detail_opened_at = None;
+        let Some(key) = self.detail_modal.report().map(Self::key) else {
+            return false;
+        };
+        self.read_keys.remove(&key)
+    }
+
+    pub fn is_read(&self, report: &Report) -> bool {
+        self.read_keys.contains(&Self::key(report))
+    }
+
+    pub fn toggle_raw(&mut self) {
+        self.detail_modal.toggle_raw();
     }
 
     pub fn scroll_up(&mut self) {
@@ -84,12 +137,75 @@ impl ReportDisplay {
         self.detail_modal.scroll_down(100);
     }
 
-    pub fn render_detail_modal(&self, frame: &mut Frame, area: Rect) {
+    pub fn render_detail_modal(&mut self, frame: &mut Frame, area: Rect) {
         self.detail_modal.render(frame, area);
     }
 
+    /// Replace the report list, moving reports in the archived set aside
     pub fn set_reports(&mut self, reports: Vec<Report>) {
-        self.reports = reports;
+        let (archived, active) = reports
+            .into_iter()
+            .partition(|r| self.archived_keys.contains(&Self::key(r)));
+        self.reports = active;
+        self.archived = archived;
+        if self
+            .state
+            .selected()
+            .is_some_and(|i| i >= self.reports.len())
+        {
+            self.state.select(self.reports.len().checked_sub(1));
+        }
+    }
+
+    pub fn set_archived(&mut self, keys: impl IntoIterator<Item = ArchivedReport>) {
+        self.archived_keys = keys.into_iter().collect();
+        self.repartition();
+    }
+
+    /// Archived set, for persisting
+    pub fn archived_keys(&self) -> Vec<ArchivedReport> {
+        let mut keys: Vec<ArchivedReport> = self.archived_keys.iter().cloned().collect();
+        keys.sort_by(|a, b| (a.expert_id, &a.task_id).cmp(&(b.expert_id, &b.task_id)));
+        keys
+    }
+
+    pub fn archived_reports(&self) -> &[Report] {
+        &self.archived
+    }
+
+    /// Experts with an unread report that is not archived
+    pub fn active_expert_ids(&self) -> HashSet<u32> {
+        self.reports
+            .iter()
+            .filter(|r| !self.is_read(r))
+            .map(|r| r.expert_id)
+            .collect()
+    }
+
+    /// Archive the report open in the detail view and close it.
+    /// Returns the archived report's key.
+    pub fn archive_open_report(&mut self) -> Option<ArchivedReport> {
+        let key = self.detail_modal.report().map(Self::key)?;
+        self.close_detail();
+        self.archived_keys.insert(key.clone());
+        self.repartition();
+        Some(key)
+    }
+
+    fn repartition(&mut self) {
+        let reports: Vec<Report> = self
+            .reports
+            .drain(..)
+            .chain(self.archived.drain(..))
+            .collect();
+        self.set_reports(reports);
+    }
+
+    fn key(report: &Report) -> ArchivedReport {
+        ArchivedReport {
+            expert_id: report.expert_id,
+            task_id: report.task_id.clone(),
+        }
     }
 
     #[allow(dead_code)]
@@ -119,12 +235,17 @@ impl ReportDisplay {
 
     #[allow(dead_code)]
     pub fn render(&mut self, frame: &mut Frame, area: Rect) {
-        let items: Vec<ListItem> = self
+        let mut items: Vec<ListItem> = self
             .reports
             .iter()
             .map(|report| {
                 let (symbol, color) = Self::status_symbol(&report.status);
                 let status_style = Style::default().fg(color);
+                let name_style = if self.is_read(report) {
+                    Style::default()
+                } else {
+                    Style::default().add_modifier(Modifier::BOLD)
+                };
 
                 let summary = if report.summary.is_empty() {
                     "In progress...".to_string()
@@ -139,10 +260,7 @@ impl ReportDisplay {
                     ),
                     Span::styled(symbol, status_style),
                     Span::raw(" "),
-                    Span::styled(
-                        format!("{:<12}", report.expert_name),
-                        Style::default().add_modifier(Modifier::BOLD),
-                    ),
+                    Span::styled(format!("{:<12}", report.expert_name), name_style),
                     Span::raw(" - "),
                     Span::styled(summary, Style::default()),
                 ];
@@ -151,6 +269,25 @@ impl ReportDisplay {
             })
             .collect();
 
+        if !self.archived.is_empty() {
+            let dim = Style::default().fg(Color::DarkGray);
+            items.push(ListItem::new(Line::from(Span::styled(
+                format!("── Archived ({}) ──", self.archived.len()),
+                dim,
+            ))));
+            items.extend(self.archived.iter().map(|report| {
+                ListItem::new(Line::from(Span::styled(
+                    format!(
+                        "[{}]   {:<12} - {}",
+                        report.expert_id,
+                        report.expert_name,
+                        truncate_str(&report.summary, 40)
+                    ),
+                    dim,
+                )))
+            }));
+        }
+
         let border_style = if self.focused {
             Style::default().fg(Color::Cyan)
         } else {
@@ -324,6 +461,91 @@ mod tests {
         );
     }
 
+    #[test]
+    fn archive_open_report_moves_report_to_archived_section() {
+        let mut display = ReportDisplay::new();
+        display.set_reports(vec![
+            create_test_report(0, "architect", TaskStatus::Done, "First"),
+            create_test_report(1, "frontend", TaskStatus::Done, "Second"),
+        ]);
+        assert!(display.archive_open_report().is_none());
+
+        display.open_detail_for_expert(1);
+        let key = display.archive_open_report().expect("archive_open_report");
+        assert_eq!(key.expert_id, 1);
+        assert_eq!(key.task_id, "task-001");
+        assert_eq!(display.view_mode(), ViewMode::List);
+        assert_eq!(display.reports.len(), 1);
+        assert_eq!(display.archived_reports()[0].expert_name, "frontend");
+        assert_eq!(
+            display.active_expert_ids(),
+            HashSet::from([0]),
+            "archive_open_report: archived expert should no longer count as having a report"
+        );
+        assert!(!display.open_detail_for_expert(1));
+    }
+
+    #[test]
+    fn mark_read_after_dwell_clears_expert_and_unread_restores_it() {
+        let mut display = ReportDisplay::new();
+        display.set_reports(vec![
+            create_test_report(0, "architect", TaskStatus::Done, "First"),
+            create_test_report(1, "frontend", TaskStatus::Done, "Second"),
+        ]);
+        let delay = Duration::from_millis(1500);
+        assert!(!display.mark_read_after(delay, Instant::now() + delay));
+
+        display.open_detail_for_expert(1);
+        let now = Instant::now();
+        assert!(
+            !display.mark_read_after(delay, now),
+            "mark_read_after: a report just opened is not read yet"
+        );
+        assert!(display.mark_read_after(delay, now + delay));
+        assert!(!display.mark_read_after(delay, now + delay * 2));
+        assert_eq!(
+            display.active_expert_ids(),
+            HashSet::from([0]),
+            "mark_read_after: read report should no longer count as active"
+        );
+
+        assert!(display.mark_open_unread());
+        assert_eq!(display.active_expert_ids(), HashSet::from([0, 1]));
+        assert!(
+            !display.mark_read_after(delay, now + delay * 2),
+            "mark_open_unread: should not be re-read until reopened"
+        );
+    }
+
+    #[test]
+    fn set_reports_honors_archived_set() {
+        let mut display = ReportDisplay::new();
+        display.set_archived([ArchivedReport {
+            expert_id: 0,
+            task_id: "task-000".to_string(),
+        }]);
+
+        display.set_reports(vec![create_test_report(
+            0,
+            "architect",
+            TaskStatus::Done,
+            "Reviewed",
+        )]);
+        assert!(
+            display.reports.is_empty(),
+            "set_reports: archived report should not re-surface on poll"
+        );
+
+        let mut newer = create_test_report(0, "architect", TaskStatus::Done, "Next task");
+        newer.task_id = "task-007".to_string();
+        display.set_reports(vec![newer]);
+        assert_eq!(
+            display.active_expert_ids(),
+            HashSet::from([0]),
+            "set_reports: a new task from the same expert is a new report"
+        );
+    }
+
     #[test]
     fn open_detail_for_expert_returns_false_when_no_report() {
         let mut display = ReportDisplay::new();
@@ -38,6 +38,11 @@ impl RoleSelector {
             .available_roles
             .iter()
             .position(|r| r.name == current_role)
+            .or_else(|| {
+                self.available_roles
+                    .iter()
+                    .position(|r| r.name == "general")
+            })
             .unwrap_or(0);
         self.state.select(Some(current_index));
     }
@@ -217,6 +222,24 @@ mod tests {
         assert_eq!(selector.selected_role(), Some("architect"));
     }
 
+    #[test]
+    fn role_selector_show_falls_back_to_general_for_unknown_role() {
+        let mut roles = create_test_roles();
+        roles.push(RoleInfo {
+            name: "general".to_string(),
+            display_name: "General".to_string(),
+            description: "General purpose".to_string(),
+        });
+        let mut selector = RoleSelector::new();
+        selector.show(0, "removed-custom-role", roles);
+
+        assert_eq!(
+            selector.selected_role(),
+            Some("general"),
+            "show: a role that no longer exists should preselect general"
+        );
+    }
+
     #[test]
     fn role_selector_hide_resets_state() {
         let mut selector = RoleSelector::new();
@@ -0,0 +1,195 @@
+use chrono::{DateTime, Local};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::context::ScratchpadEntry;
+
+struct ScratchpadNote {
+    author: String,
+    timestamp: DateTime<Local>,
+    text: String,
+}
+
+/// Scrollable modal showing the session scratchpad, oldest note first
+pub struct ScratchpadView {
+    notes: Vec<ScratchpadNote>,
+    scroll_offset: u16,
+    visible: bool,
+}
+
+impl ScratchpadView {
+    pub fn new() -> Self {
+        Self {
+            notes: Vec::new(),
+            scroll_offset: 0,
+            visible: false,
+        }
+    }
+
+    /// Replace the notes, labelling each author with `name`
+    pub fn set_entries(&mut self, entries: &[ScratchpadEntry], name: impl Fn(u32) -> String) {
+        self.notes = entries
+            .iter()
+            .map(|entry| ScratchpadNote {
+                author: name(entry.author),
+                timestamp: entry.timestamp.with_timezone(&Local),
+                text: entry.text.trim_end().to_string(),
+            })
+            .collect();
+    }
+
+    /// Show the scratchpad, scrolled so the newest notes are in view
+    pub fn show(&mut self, page: u16) {
+        self.scroll_offset = (self.build_lines().len() as u16).saturating_sub(page);
+        self.visible = true;
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+        self.scroll_offset = 0;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        let max = self
+            .build_lines()
+            .len()
+            .saturating_sub(1)
+            .min(u16::MAX as usize) as u16;
+        if self.scroll_offset < max {
+            self.scroll_offset += 1;
+        }
+    }
+
+    pub fn page_up(&mut self, page: u16) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(page);
+    }
+
+    pub fn page_down(&mut self, page: u16) {
+        for _ in 0..page {
+            self.scroll_down();
+        }
+    }
+
+    fn build_lines(&self) -> Vec<Line<'static>> {
+        if self.notes.is_empty() {
+            return vec![Line::from(Span::styled(
+                "No notes yet",
+                Style::default().fg(Color::Gray),
+            ))];
+        }
+
+        let mut lines = Vec::new();
+        for (i, note) in self.notes.iter().enumerate() {
+            if i > 0 {
+                lines.push(Line::default());
+            }
+            lines.push(Line::from(vec![
+                Span::styled(
+                    note.author.clone(),
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("  {}", note.timestamp.format("%m-%d %H:%M:%S")),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]));
+            for text in note.text.split('\n') {
+                lines.push(Line::from(format!("  {text}")));
+            }
+        }
+        lines
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        frame.render_widget(Clear, area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(Span::styled(
+                format!(" Scratchpad ({}) ", self.notes.len()),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ));
+
+        let paragraph = Paragraph::new(self.build_lines())
+            .block(block)
+            .scroll((self.scroll_offset, 0));
+        frame.render_widget(paragraph, area);
+    }
+}
+
+impl Default for ScratchpadView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn entry(author: u32, text: &str) -> ScratchpadEntry {
+        ScratchpadEntry {
+            author,
+            timestamp: Utc::now(),
+            text: text.to_string(),
+        }
+    }
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn scratchpad_view_lists_notes_in_order_under_author_names() {
+        let mut view = ScratchpadView::new();
+        view.set_entries(&[entry(1, "first\nsecond line"), entry(0, "later")], |id| {
+            format!("name{id}")
+        });
+
+        let lines: Vec<String> = view.build_lines().iter().map(line_text).collect();
+        assert!(lines[0].starts_with("name1"));
+        assert_eq!(lines[1], "  first");
+        assert_eq!(lines[2], "  second line");
+        assert_eq!(lines[3], "", "build_lines: notes separated by a blank line");
+        assert!(lines[4].starts_with("name0"));
+        assert_eq!(lines[5], "  later");
+    }
+
+    #[test]
+    fn scratchpad_view_show_scrolls_to_newest() {
+        let mut view = ScratchpadView::new();
+        let entries: Vec<_> = (0..10).map(|i| entry(0, &format!("note {i}"))).collect();
+        view.set_entries(&entries, |id| format!("expert{id}"));
+        view.show(5);
+        assert!(view.is_visible());
+        assert_eq!(
+            view.scroll_offset, 24,
+            "show: the newest notes should be in view"
+        );
+        view.hide();
+        assert!(!view.is_visible());
+    }
+}
@@ -1,16 +1,17 @@
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
+use std::time::{Duration, Instant};
 
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, ListState},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
     Frame,
 };
 
 use crate::models::ExpertState;
-use crate::utils::truncate_str_head;
+use crate::utils::{format_bytes, truncate_str, truncate_str_head};
 
 #[derive(Debug, Clone)]
 pub struct ExpertEntry {
@@ -21,15 +22,134 @@ pub struct ExpertEntry {
 
 const WORKING_DIR_MAX_CHARS: usize = 25;
 
+/// Span of history summarized by the activity sparkline
+const ACTIVITY_WINDOW: Duration = Duration::from_secs(60);
+const ACTIVITY_BUCKETS: u32 = 6;
+const SPARK_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// State transitions per expert, trimmed to the sparkline window
+#[derive(Debug, Default)]
+pub struct ActivityHistory {
+    transitions: HashMap<u32, Vec<(Instant, ExpertState)>>,
+}
+
+impl ActivityHistory {
+    /// Record the state observed at `at`; repeated observations of the same state are ignored
+    pub fn record(&mut self, expert_id: u32, state: &ExpertState, at: Instant) {
+        let transitions = self.transitions.entry(expert_id).or_default();
+        if transitions.last().is_some_and(|(_, last)| last == state) {
+            return;
+        }
+        transitions.push((at, state.clone()));
+
+        // Keep the last transition before the window so it still covers the window start
+        if let Some(cutoff) = at.checked_sub(ACTIVITY_WINDOW) {
+            if let Some(keep_from) = transitions.iter().rposition(|(when, _)| *when <= cutoff) {
+                transitions.drain(..keep_from);
+            }
+        }
+    }
+
+    /// Fraction of each bucket of the last minute spent Busy, oldest bucket first.
+    /// Time before the first observation counts as not busy.
+    pub fn busy_fractions(&self, expert_id: u32, now: Instant) -> Vec<f64> {
+        let bucket_len = ACTIVITY_WINDOW / ACTIVITY_BUCKETS;
+        let window_start = now.checked_sub(ACTIVITY_WINDOW).unwrap_or(now);
+        let transitions = self
+            .transitions
+            .get(&expert_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        (0..ACTIVITY_BUCKETS)
+            .map(|i| {
+                let start = window_start + bucket_len * i;
+                let end = start + bucket_len;
+                let busy: Duration = transitions
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (_, state))| *state == ExpertState::Busy)
+                    .map(|(k, (from, _))| {
+                        let until = transitions.get(k + 1).map_or(now, |(next, _)| *next);
+                        until.min(end).saturating_duration_since((*from).max(start))
+                    })
+                    .sum();
+                busy.as_secs_f64() / bucket_len.as_secs_f64()
+            })
+            .collect()
+    }
+}
+
+/// Render busy fractions (0.0..=1.0) as block characters, one per bucket
+pub fn sparkline(fractions: &[f64]) -> String {
+    let top = (SPARK_LEVELS.len() - 1) as f64;
+    fractions
+        .iter()
+        .map(|f| SPARK_LEVELS[(f.clamp(0.0, 1.0) * top).round() as usize])
+        .collect()
+}
+
+/// Which experts the list shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExpertFilter {
+    #[default]
+    All,
+    Busy,
+    HasReport,
+    InWorktree,
+}
+
+impl ExpertFilter {
+    pub fn next(self) -> Self {
+        match self {
+            ExpertFilter::All => ExpertFilter::Busy,
+            ExpertFilter::Busy => ExpertFilter::HasReport,
+            ExpertFilter::HasReport => ExpertFilter::InWorktree,
+            ExpertFilter::InWorktree => ExpertFilter::All,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ExpertFilter::All => "all",
+            ExpertFilter::Busy => "busy only",
+            ExpertFilter::HasReport => "with reports",
+            ExpertFilter::InWorktree => "in worktree",
+        }
+    }
+}
+
 pub struct StatusDisplay {
+    /// Every expert; the list shows those passing `filter`, and the
+    /// selection indexes into that subset
     experts: Vec<ExpertEntry>,
     state: ListState,
     focused: bool,
     expert_roles: HashMap<u32, String>,
     expert_reports: HashSet<u32>,
     expert_working_dirs: HashMap<u32, String>,
+    expert_notes: HashMap<u32, String>,
     project_path: String,
     execution_badge: Option<String>,
+    activity: ActivityHistory,
+    show_activity: bool,
+    /// Group heading per expert (its first tag); untagged experts are listed last
+    expert_groups: HashMap<u32, String>,
+    /// Experts whose last ping went unanswered
+    unresponsive: HashSet<u32>,
+    filter: ExpertFilter,
+    expert_worktrees: HashSet<u32>,
+    /// What each expert was last given to work on, shown while it is Busy
+    busy_reasons: HashMap<u32, String>,
+    /// Operator-chosen list order by expert id; experts not listed keep
+    /// their config position after those that are. Empty until reordered.
+    order: Vec<u32>,
+    /// Experts whose agent is launched on first use (`launch: lazy`)
+    not_started: HashSet<u32>,
+    /// Bytes used by each expert's worktree, from the last background measurement
+    worktree_sizes: HashMap<u32, u64>,
+    /// Bytes used by all worktrees, each counted once even when shared
+    worktree_total: u64,
 }
 
 impl StatusDisplay {
@@ -41,13 +161,164 @@ impl StatusDisplay {
             expert_roles: HashMap::new(),
             expert_reports: HashSet::new(),
             expert_working_dirs: HashMap::new(),
+            expert_notes: HashMap::new(),
             project_path: String::new(),
             execution_badge: None,
+            activity: ActivityHistory::default(),
+            show_activity: true,
+            expert_groups: HashMap::new(),
+            unresponsive: HashSet::new(),
+            filter: ExpertFilter::All,
+            expert_worktrees: HashSet::new(),
+            busy_reasons: HashMap::new(),
+            order: Vec::new(),
+            not_started: HashSet::new(),
+            worktree_sizes: HashMap::new(),
+            worktree_total: 0,
         }
     }
 
-    pub fn set_experts(&mut self, experts: Vec<ExpertEntry>) {
+    pub fn set_experts(&mut self, mut experts: Vec<ExpertEntry>) {
+        let selected = self.selected_expert_id();
+        let now = Instant::now();
+        for entry in &experts {
+            self.activity.record(entry.expert_id, &entry.state, now);
+            if entry.state == ExpertState::Idle
+                && self.expert_state(entry.expert_id) == Some(&ExpertState::Busy)
+            {
+                self.busy_reasons.remove(&entry.expert_id);
+            }
+        }
+        if !self.order.is_empty() {
+            experts.sort_by_key(|e| {
+                self.order
+                    .iter()
+                    .position(|&id| id == e.expert_id)
+                    .unwrap_or(usize::MAX)
+            });
+        }
+        if !self.expert_groups.is_empty() {
+            experts.sort_by_key(|e| {
+                let group = self.expert_groups.get(&e.expert_id);
+                (group.is_none(), group.cloned())
+            });
+        }
         self.experts = experts;
+        self.reselect(selected);
+    }
+
+    /// Move to the next filter, keeping the selected expert if it is still shown
+    pub fn cycle_filter(&mut self) -> ExpertFilter {
+        let selected = self.selected_expert_id();
+        self.filter = self.filter.next();
+        self.reselect(selected);
+        self.filter
+    }
+
+    fn is_shown(&self, entry: &ExpertEntry) -> bool {
+        match self.filter {
+            ExpertFilter::All => true,
+            ExpertFilter::Busy => entry.state == ExpertState::Busy,
+            ExpertFilter::HasReport => self.expert_reports.contains(&entry.expert_id),
+            ExpertFilter::InWorktree => self.expert_worktrees.contains(&entry.expert_id),
+        }
+    }
+
+    fn visible(&self) -> Vec<&ExpertEntry> {
+        self.experts.iter().filter(|e| self.is_shown(e)).collect()
+    }
+
+    /// Point the selection at `expert_id` in the filtered list; when it was
+    /// filtered out, fall back to the first shown expert. An empty filtered
+    /// list keeps a selection index so the next filter selects again.
+    fn reselect(&mut self, expert_id: Option<u32>) {
+        if expert_id.is_none() && self.state.selected().is_none() {
+            return;
+        }
+        let index = expert_id
+            .and_then(|id| self.visible().iter().position(|e| e.expert_id == id))
+            .unwrap_or(0);
+        self.state.select(Some(index));
+    }
+
+    /// List experts grouped under their first tag
+    pub fn with_tag_groups(mut self, tags: HashMap<u32, Vec<String>>) -> Self {
+        self.expert_groups = tags
+            .into_iter()
+            .filter_map(|(id, tags)| tags.into_iter().next().map(|tag| (id, tag)))
+            .collect();
+        self
+    }
+
+    /// List experts in a previously saved order (see `expert_order`)
+    pub fn set_expert_order(&mut self, order: Vec<u32>) {
+        self.order = order;
+        let experts = std::mem::take(&mut self.experts);
+        self.set_experts(experts);
+    }
+
+    /// The operator's list order, empty if they never reordered
+    pub fn expert_order(&self) -> &[u32] {
+        &self.order
+    }
+
+    /// Swap the selected expert with its neighbour in the shown list (`up`
+    /// toward the top). Experts stay within their tag group. Only the display
+    /// order changes; returns `false` when there is nowhere to move.
+    pub fn move_selected(&mut self, up: bool) -> bool {
+        let Some(index) = self.state.selected() else {
+            return false;
+        };
+        let target = if up {
+            index.checked_sub(1)
+        } else {
+            Some(index + 1)
+        };
+        let visible = self.visible();
+        let (Some(current), Some(neighbour)) =
+            (visible.get(index), target.and_then(|t| visible.get(t)))
+        else {
+            return false;
+        };
+        if self.expert_groups.get(&current.expert_id)
+            != self.expert_groups.get(&neighbour.expert_id)
+        {
+            return false;
+        }
+        let (current, neighbour) = (current.expert_id, neighbour.expert_id);
+        let position = |id| self.experts.iter().position(|e| e.expert_id == id);
+        let (Some(a), Some(b)) = (position(current), position(neighbour)) else {
+            return false;
+        };
+        self.experts.swap(a, b);
+        self.order = self.experts.iter().map(|e| e.expert_id).collect();
+        self.reselect(Some(current));
+        true
+    }
+
+    /// Heading to show above the entry at `index`, when it starts a new group
+    fn group_heading(&self, index: usize) -> Option<&str> {
+        if self.expert_groups.is_empty() {
+            return None;
+        }
+        let visible = self.visible();
+        let group = |i: usize| {
+            visible
+                .get(i)
+                .and_then(|e| self.expert_groups.get(&e.expert_id))
+                .map(String::as_str)
+        };
+        let current = group(index);
+        if index > 0 && group(index - 1) == current {
+            return None;
+        }
+        Some(current.unwrap_or("untagged"))
+    }
+
+    /// Show or hide the per-expert activity sparkline (hide it on narrow terminals)
+    pub fn with_activity_sparkline(mut self, show: bool) -> Self {
+        self.show_activity = show;
+        self
     }
 
     #[allow(dead_code)]
@@ -60,13 +331,88 @@ impl StatusDisplay {
     }
 
     pub fn set_expert_reports(&mut self, ids: HashSet<u32>) {
+        let selected = self.selected_expert_id();
         self.expert_reports = ids;
+        self.reselect(selected);
+    }
+
+    /// Experts currently working in a git worktree
+    pub fn set_expert_worktrees(&mut self, ids: HashSet<u32>) {
+        let selected = self.selected_expert_id();
+        self.expert_worktrees = ids;
+        self.reselect(selected);
+    }
+
+    /// Worktree sizes per expert and their deduplicated total
+    pub fn set_worktree_sizes(&mut self, sizes: HashMap<u32, u64>, total: u64) {
+        self.worktree_sizes = sizes;
+        self.worktree_total = total;
+    }
+
+    /// Size shown next to an expert, only while it is in a worktree
+    fn shown_worktree_size(&self, expert_id: u32) -> Option<u64> {
+        if !self.expert_worktrees.contains(&expert_id) {
+            return None;
+        }
+        self.worktree_sizes.get(&expert_id).copied()
     }
 
     pub fn set_expert_working_dirs(&mut self, dirs: HashMap<u32, String>) {
         self.expert_working_dirs = dirs;
     }
 
+    /// Set or (with `None`) remove the operator note shown under an expert
+    pub fn set_expert_note(&mut self, expert_id: u32, note: Option<String>) {
+        match note {
+            Some(note) => self.expert_notes.insert(expert_id, note),
+            None => self.expert_notes.remove(&expert_id),
+        };
+    }
+
+    pub fn expert_note(&self, expert_id: u32) -> Option<&str> {
+        self.expert_notes.get(&expert_id).map(String::as_str)
+    }
+
+    /// Record what the expert was just given; it is dropped when the expert goes Busy→Idle
+    pub fn set_busy_reason(&mut self, expert_id: u32, reason: String) {
+        self.busy_reasons.insert(expert_id, reason);
+    }
+
+    pub fn busy_reason(&self, expert_id: u32) -> Option<&str> {
+        self.busy_reasons.get(&expert_id).map(String::as_str)
+    }
+
+    /// The "doing" subtitle, shown only while the expert is Busy
+    fn shown_busy_reason(&self, entry: &ExpertEntry) -> Option<&str> {
+        if entry.state != ExpertState::Busy {
+            return None;
+        }
+        self.busy_reason(entry.expert_id)
+    }
+
+    /// Mark an expert whose ping went unanswered; it is shown in red until it answers one
+    pub fn set_expert_unresponsive(&mut self, expert_id: u32, unresponsive: bool) {
+        if unresponsive {
+            self.unresponsive.insert(expert_id);
+        } else {
+            self.unresponsive.remove(&expert_id);
+        }
+    }
+
+    /// Experts shown as "not started" instead of their working directory
+    pub fn set_expert_not_started(&mut self, ids: HashSet<u32>) {
+        self.not_started = ids;
+    }
+
+    pub fn is_not_started(&self, expert_id: u32) -> bool {
+        self.not_started.contains(&expert_id)
+    }
+
+    #[cfg(test)]
+    pub fn is_unresponsive(&self, expert_id: u32) -> bool {
+        self.unresponsive.contains(&expert_id)
+    }
+
     pub fn set_project_path(&mut self, path: String) {
         self.project_path = path;
     }
@@ -123,31 +469,92 @@ impl StatusDisplay {
     }
 
     pub fn next(&mut self) {
-        super::select_next(&mut self.state, self.experts.len());
+        let count = self.visible().len();
+        super::select_next(&mut self.state, count);
     }
 
     pub fn prev(&mut self) {
-        super::select_prev(&mut self.state, self.experts.len());
+        let count = self.visible().len();
+        super::select_prev(&mut self.state, count);
+    }
+
+    /// Select the entry for `expert_id`; returns `false` if no such expert is listed
+    /// (or the filter hides it)
+    pub fn select_expert_id(&mut self, expert_id: u32) -> bool {
+        match self.visible().iter().position(|e| e.expert_id == expert_id) {
+            Some(index) => {
+                self.state.select(Some(index));
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn expert_state(&self, expert_id: u32) -> Option<&ExpertState> {
+        self.experts
+            .iter()
+            .find(|e| e.expert_id == expert_id)
+            .map(|e| &e.state)
     }
 
     pub fn selected(&self) -> Option<&ExpertEntry> {
-        self.state.selected().and_then(|i| self.experts.get(i))
+        self.state
+            .selected()
+            .and_then(|i| self.visible().get(i).copied())
     }
 
     pub fn selected_expert_id(&self) -> Option<u32> {
         self.selected().map(|e| e.expert_id)
     }
 
+    /// Ids of the listed experts, top to bottom
+    pub fn listed_expert_ids(&self) -> Vec<u32> {
+        self.visible().iter().map(|e| e.expert_id).collect()
+    }
+
+    #[allow(dead_code)]
     pub fn expert_count(&self) -> usize {
         self.experts.len()
     }
 
+    /// Lines the list needs: one per shown expert plus one per subtitle and group heading
+    pub fn row_count(&self) -> usize {
+        let visible = self.visible();
+        visible.len()
+            + visible
+                .iter()
+                .filter(|e| self.expert_notes.contains_key(&e.expert_id))
+                .count()
+            + visible
+                .iter()
+                .filter(|e| self.shown_busy_reason(e).is_some())
+                .count()
+            + (0..visible.len())
+                .filter(|&i| self.group_heading(i).is_some())
+                .count()
+    }
+
     pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let now = Instant::now();
         let items: Vec<ListItem> = self
-            .experts
-            .iter()
-            .map(|entry| {
+            .visible()
+            .into_iter()
+            .enumerate()
+            .map(|(index, entry)| {
                 let status_style = Style::default().fg(entry.state.color());
+                // Offline and not-started experts are greyed out since nothing will reach them
+                let not_started = self.not_started.contains(&entry.expert_id);
+                let (name_style, role_color) = if entry.state == ExpertState::Offline || not_started
+                {
+                    (Style::default().fg(Color::DarkGray), Color::DarkGray)
+                } else if self.unresponsive.contains(&entry.expert_id) {
+                    (
+                        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                        Color::Cyan,
+                    )
+                } else {
+                    (Style::default().add_modifier(Modifier::BOLD), Color::Cyan)
+                };
 
                 let role = self.expert_roles.get(&entry.expert_id);
                 let role_display = match role {
@@ -159,38 +566,78 @@ impl StatusDisplay {
                     Self::report_symbol(self.expert_reports.contains(&entry.expert_id));
 
                 let working_dir_display = match self.expert_working_dirs.get(&entry.expert_id) {
+                    _ if not_started => "not started".to_string(),
                     Some(dir) => Self::format_relative_path(dir, &self.project_path),
                     None => String::new(),
                 };
 
-                let spans = vec![
+                let mut spans = vec![
                     Span::styled(
                         format!("[{}] ", entry.expert_id),
                         Style::default().add_modifier(Modifier::BOLD),
                     ),
                     Span::styled(entry.state.symbol(), status_style),
                     Span::raw(" "),
-                    Span::styled(
-                        format!("{:<8}", entry.expert_name),
-                        Style::default().add_modifier(Modifier::BOLD),
-                    ),
-                    Span::styled(role_display, Style::default().fg(Color::Cyan)),
+                    Span::styled(format!("{:<8}", entry.expert_name), name_style),
+                    Span::styled(role_display, Style::default().fg(role_color)),
                     Span::raw(" "),
+                ];
+                if self.show_activity {
+                    spans.push(Span::styled(
+                        sparkline(&self.activity.busy_fractions(entry.expert_id, now)),
+                        Style::default().fg(Color::Green),
+                    ));
+                    spans.push(Span::raw(" "));
+                }
+                spans.extend([
                     Span::styled(report_sym, Style::default().fg(report_color)),
                     Span::raw(" "),
                     Span::styled(working_dir_display, Style::default().fg(Color::DarkGray)),
-                ];
-
-                ListItem::new(Line::from(spans))
+                ]);
+                if let Some(bytes) = self.shown_worktree_size(entry.expert_id) {
+                    spans.push(Span::styled(
+                        format!(" [{}]", format_bytes(bytes)),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+
+                let mut lines = Vec::new();
+                if let Some(group) = self.group_heading(index) {
+                    lines.push(Line::from(Span::styled(
+                        format!("# {group}"),
+                        Style::default()
+                            .fg(Color::Magenta)
+                            .add_modifier(Modifier::BOLD),
+                    )));
+                }
+                lines.push(Line::from(spans));
+                if let Some(reason) = self.shown_busy_reason(entry) {
+                    lines.push(Line::from(Span::styled(
+                        format!("      doing: {}", truncate_str(reason, 50)),
+                        Style::default().fg(Color::Yellow),
+                    )));
+                }
+                if let Some(note) = self.expert_notes.get(&entry.expert_id) {
+                    lines.push(Line::from(Span::styled(
+                        format!("      \u{21b3} {note}"),
+                        Style::default()
+                            .fg(Color::DarkGray)
+                            .add_modifier(Modifier::ITALIC),
+                    )));
+                }
+                ListItem::new(lines)
             })
             .collect();
 
         let border_style = Style::default().fg(ratatui::style::Color::DarkGray);
 
-        let title = match &self.execution_badge {
-            Some(badge) => format!("Experts [{badge}]"),
-            None => "Experts".to_string(),
-        };
+        let mut title = "Experts".to_string();
+        if self.filter != ExpertFilter::All {
+            title.push_str(&format!(" ({})", self.filter.label()));
+        }
+        if let Some(badge) = &self.execution_badge {
+            title.push_str(&format!(" [{badge}]"));
+        }
 
         let list = List::new(items)
             .block(
@@ -209,6 +656,44 @@ impl StatusDisplay {
         frame.render_stateful_widget(list, area, &mut self.state);
     }
 
+    /// One-line stand-in for the list on short terminals, e.g.
+    /// "3 idle / 1 busy / 2 reports | > [2] alice"
+    pub fn compact_summary(&self) -> String {
+        let summary = self.get_status_summary();
+        let mut text = format!(
+            "{} idle / {} busy / {} reports",
+            summary.idle, summary.busy, summary.reports
+        );
+        if summary.offline > 0 {
+            text.push_str(&format!(" / {} offline", summary.offline));
+        }
+        if self.filter != ExpertFilter::All {
+            text.push_str(&format!(" ({})", self.filter.label()));
+        }
+        if let Some(entry) = self.selected() {
+            text.push_str(&format!(
+                " | > [{}] {} {}",
+                entry.expert_id,
+                entry.state.symbol(),
+                entry.expert_name
+            ));
+        }
+        text
+    }
+
+    pub fn render_compact(&self, frame: &mut Frame, area: Rect) {
+        let line = Line::from(vec![
+            Span::styled(
+                "Experts: ",
+                Style::default()
+                    .fg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(self.compact_summary()),
+        ]);
+        frame.render_widget(Paragraph::new(line), area);
+    }
+
     pub fn get_status_summary(&self) -> StatusSummary {
         let mut summary = StatusSummary::default();
 
@@ -216,10 +701,17 @@ impl StatusDisplay {
             match entry.state {
                 ExpertState::Idle => summary.idle += 1,
                 ExpertState::Busy => summary.busy += 1,
+                ExpertState::Offline => summary.offline += 1,
             }
         }
 
         summary.total = self.experts.len();
+        summary.reports = self
+            .experts
+            .iter()
+            .filter(|e| self.expert_reports.contains(&e.expert_id))
+            .count();
+        summary.worktree_bytes = self.worktree_total;
         summary
     }
 }
@@ -235,6 +727,11 @@ pub struct StatusSummary {
     pub total: usize,
     pub idle: usize,
     pub busy: usize,
+    pub offline: usize,
+    /// Experts with an unread report
+    pub reports: usize,
+    /// Disk used by the session's worktrees; 0 when none are measured
+    pub worktree_bytes: u64,
 }
 
 #[cfg(test)]
@@ -277,6 +774,250 @@ mod tests {
         assert_eq!(display.selected_expert_id(), Some(0));
     }
 
+    fn shown_ids(display: &StatusDisplay) -> Vec<u32> {
+        display.visible().iter().map(|e| e.expert_id).collect()
+    }
+
+    #[test]
+    fn move_selected_reorders_display_and_survives_refresh() {
+        let experts = || {
+            vec![
+                create_test_entry(0, "architect", ExpertState::Idle),
+                create_test_entry(1, "frontend", ExpertState::Busy),
+                create_test_entry(2, "backend", ExpertState::Idle),
+            ]
+        };
+        let mut display = StatusDisplay::new();
+        display.set_experts(experts());
+        display.select_expert_id(2);
+
+        assert!(display.move_selected(true));
+        assert!(display.move_selected(true));
+        assert_eq!(shown_ids(&display), vec![2, 0, 1]);
+        assert_eq!(
+            display.selected_expert_id(),
+            Some(2),
+            "move_selected: the moved expert stays selected"
+        );
+        assert!(
+            !display.move_selected(true),
+            "move_selected: the top entry cannot move further up"
+        );
+
+        display.set_experts(experts());
+        assert_eq!(
+            shown_ids(&display),
+            vec![2, 0, 1],
+            "set_experts: a status refresh keeps the operator's order"
+        );
+        assert_eq!(display.expert_order(), &[2, 0, 1]);
+
+        let mut restored = StatusDisplay::new();
+        restored.set_experts(experts());
+        restored.set_expert_order(vec![1, 2]);
+        assert_eq!(
+            shown_ids(&restored),
+            vec![1, 2, 0],
+            "set_expert_order: unlisted experts follow in config order"
+        );
+    }
+
+    #[test]
+    fn move_selected_stays_within_tag_group() {
+        let mut display = StatusDisplay::new().with_tag_groups(HashMap::from([
+            (0, vec!["api".to_string()]),
+            (1, vec!["ui".to_string()]),
+        ]));
+        display.set_experts(vec![
+            create_test_entry(0, "architect", ExpertState::Idle),
+            create_test_entry(1, "frontend", ExpertState::Idle),
+        ]);
+        display.select_expert_id(1);
+
+        assert!(!display.move_selected(true));
+        assert_eq!(shown_ids(&display), vec![0, 1]);
+    }
+
+    #[test]
+    fn busy_reason_shows_while_busy_and_clears_on_idle() {
+        let mut display = StatusDisplay::new();
+        display.set_experts(vec![
+            create_test_entry(0, "architect", ExpertState::Idle),
+            create_test_entry(1, "planner", ExpertState::Idle),
+        ]);
+        display.set_busy_reason(1, "Fix the login bug".to_string());
+        assert_eq!(
+            display.row_count(),
+            2,
+            "row_count: the reason is only shown once the expert is Busy"
+        );
+
+        display.set_experts(vec![
+            create_test_entry(0, "architect", ExpertState::Idle),
+            create_test_entry(1, "planner", ExpertState::Busy),
+        ]);
+        assert_eq!(display.busy_reason(1), Some("Fix the login bug"));
+        assert_eq!(display.row_count(), 3);
+
+        display.set_experts(vec![
+            create_test_entry(0, "architect", ExpertState::Idle),
+            create_test_entry(1, "planner", ExpertState::Idle),
+        ]);
+        assert_eq!(
+            display.busy_reason(1),
+            None,
+            "set_experts: going Busy→Idle should clear the reason"
+        );
+    }
+
+    #[test]
+    fn tag_groups_order_experts_and_add_headings() {
+        let mut display = StatusDisplay::new().with_tag_groups(HashMap::from([
+            (0, vec!["backend".to_string()]),
+            (2, vec!["frontend".to_string(), "ui".to_string()]),
+            (3, vec!["backend".to_string()]),
+        ]));
+        display.set_experts(vec![
+            create_test_entry(0, "architect", ExpertState::Idle),
+            create_test_entry(1, "planner", ExpertState::Idle),
+            create_test_entry(2, "designer", ExpertState::Idle),
+            create_test_entry(3, "debugger", ExpertState::Idle),
+        ]);
+
+        let order: Vec<u32> = display.experts.iter().map(|e| e.expert_id).collect();
+        assert_eq!(
+            order,
+            vec![0, 3, 2, 1],
+            "set_experts: experts should be grouped by first tag, untagged last"
+        );
+        let headings: Vec<Option<&str>> = (0..4).map(|i| display.group_heading(i)).collect();
+        assert_eq!(
+            headings,
+            vec![Some("backend"), None, Some("frontend"), Some("untagged")]
+        );
+        assert_eq!(display.row_count(), 7);
+    }
+
+    #[test]
+    fn row_count_includes_note_subtitles() {
+        let mut display = StatusDisplay::new();
+        display.set_experts(vec![
+            create_test_entry(0, "architect", ExpertState::Idle),
+            create_test_entry(1, "backend", ExpertState::Idle),
+        ]);
+        assert_eq!(display.row_count(), 2);
+
+        display.set_expert_note(1, Some("handles auth".to_string()));
+        display.set_expert_note(7, Some("not listed".to_string()));
+        assert_eq!(
+            display.row_count(),
+            3,
+            "row_count: only notes of listed experts add a line"
+        );
+
+        display.set_expert_note(1, None);
+        assert_eq!(display.expert_note(1), None);
+        assert_eq!(display.row_count(), 2);
+    }
+
+    #[test]
+    fn busy_filter_hides_idle_experts_and_keeps_selection() {
+        let mut display = StatusDisplay::new();
+        display.set_experts(vec![
+            create_test_entry(0, "architect", ExpertState::Idle),
+            create_test_entry(1, "frontend", ExpertState::Busy),
+            create_test_entry(2, "backend", ExpertState::Idle),
+            create_test_entry(3, "debugger", ExpertState::Busy),
+        ]);
+        display.select_expert_id(3);
+
+        assert_eq!(display.cycle_filter(), ExpertFilter::Busy);
+        assert_eq!(
+            display.selected_expert_id(),
+            Some(3),
+            "cycle_filter: a selected expert that passes the filter stays selected"
+        );
+        assert_eq!(
+            display.row_count(),
+            2,
+            "busy filter: idle experts are hidden"
+        );
+        assert!(!display.select_expert_id(0));
+
+        display.next();
+        assert_eq!(
+            display.selected_expert_id(),
+            Some(1),
+            "next: should wrap within the busy subset"
+        );
+        display.prev();
+        assert_eq!(display.selected_expert_id(), Some(3));
+
+        display.set_experts(vec![
+            create_test_entry(0, "architect", ExpertState::Busy),
+            create_test_entry(1, "frontend", ExpertState::Busy),
+            create_test_entry(2, "backend", ExpertState::Idle),
+            create_test_entry(3, "debugger", ExpertState::Idle),
+        ]);
+        assert_eq!(
+            display.selected_expert_id(),
+            Some(0),
+            "set_experts: when the selected expert leaves the filter, select the first shown"
+        );
+        display.select_expert_id(1);
+        display.set_experts(vec![
+            create_test_entry(0, "architect", ExpertState::Idle),
+            create_test_entry(1, "frontend", ExpertState::Busy),
+        ]);
+        assert_eq!(
+            display.selected_expert_id(),
+            Some(1),
+            "set_experts: selection should follow the id, not the row"
+        );
+        assert_eq!(display.get_status_summary().total, 2);
+    }
+
+    #[test]
+    fn worktree_sizes_show_only_for_experts_in_worktrees() {
+        let mut display = StatusDisplay::new();
+        display.set_experts(vec![
+            create_test_entry(0, "architect", ExpertState::Idle),
+            create_test_entry(1, "frontend", ExpertState::Busy),
+        ]);
+        display.set_expert_worktrees(HashSet::from([1]));
+        display.set_worktree_sizes(HashMap::from([(0, 10), (1, 2048)]), 2048);
+
+        assert_eq!(display.shown_worktree_size(1), Some(2048));
+        assert_eq!(
+            display.shown_worktree_size(0),
+            None,
+            "shown_worktree_size: an expert back in the main repo shows no stale size"
+        );
+        assert_eq!(display.get_status_summary().worktree_bytes, 2048);
+    }
+
+    #[test]
+    fn report_and_worktree_filters_use_their_sets() {
+        let mut display = StatusDisplay::new();
+        display.set_experts(vec![
+            create_test_entry(0, "architect", ExpertState::Idle),
+            create_test_entry(1, "frontend", ExpertState::Idle),
+            create_test_entry(2, "backend", ExpertState::Idle),
+        ]);
+        display.set_expert_reports(HashSet::from([2]));
+        display.set_expert_worktrees(HashSet::from([0, 1]));
+        display.select_expert_id(1);
+
+        display.cycle_filter();
+        assert_eq!(display.cycle_filter(), ExpertFilter::HasReport);
+        assert_eq!(display.selected_expert_id(), Some(2));
+        assert_eq!(display.cycle_filter(), ExpertFilter::InWorktree);
+        assert_eq!(display.row_count(), 2);
+        assert_eq!(display.selected_expert_id(), Some(0));
+        assert_eq!(display.cycle_filter(), ExpertFilter::All);
+        assert_eq!(display.row_count(), 3);
+    }
+
     #[test]
     fn status_display_prev_navigation() {
         let mut display = StatusDisplay::new();
@@ -292,6 +1033,28 @@ mod tests {
         assert_eq!(display.selected_expert_id(), Some(1));
     }
 
+    #[test]
+    fn status_display_select_expert_id() {
+        let mut display = StatusDisplay::new();
+        display.set_experts(vec![
+            create_test_entry(0, "architect", ExpertState::Idle),
+            create_test_entry(1, "frontend", ExpertState::Busy),
+        ]);
+
+        assert!(display.select_expert_id(1));
+        assert_eq!(display.selected_expert_id(), Some(1));
+
+        assert!(
+            !display.select_expert_id(5),
+            "select_expert_id: unknown id should not be selected"
+        );
+        assert_eq!(
+            display.selected_expert_id(),
+            Some(1),
+            "select_expert_id: selection should be unchanged for an unknown id"
+        );
+    }
+
     #[test]
     fn status_display_summary() {
         let mut display = StatusDisplay::new();
@@ -307,6 +1070,44 @@ mod tests {
         assert_eq!(summary.busy, 1);
     }
 
+    #[test]
+    fn status_display_summary_counts_offline() {
+        let mut display = StatusDisplay::new();
+        display.set_experts(vec![
+            create_test_entry(0, "architect", ExpertState::Idle),
+            create_test_entry(1, "frontend", ExpertState::Offline),
+        ]);
+
+        let summary = display.get_status_summary();
+        assert_eq!(summary.idle, 1);
+        assert_eq!(summary.busy, 0);
+        assert_eq!(
+            summary.offline, 1,
+            "status_summary: offline experts should be counted separately"
+        );
+    }
+
+    #[test]
+    fn compact_summary_counts_states_reports_and_selection() {
+        let mut display = StatusDisplay::new();
+        display.set_experts(vec![
+            create_test_entry(0, "architect", ExpertState::Idle),
+            create_test_entry(1, "frontend", ExpertState::Busy),
+            create_test_entry(2, "backend", ExpertState::Idle),
+        ]);
+        display.set_expert_reports(HashSet::from([0, 2, 9]));
+        assert_eq!(display.compact_summary(), "2 idle / 1 busy / 2 reports");
+
+        display.select_expert_id(1);
+        assert!(
+            display
+                .compact_summary()
+                .ends_with(&format!("| > [1] {} frontend", ExpertState::Busy.symbol())),
+            "compact_summary: the selected expert should stay visible, got {:?}",
+            display.compact_summary()
+        );
+    }
+
     #[test]
     fn status_display_focus_state() {
         let mut display = StatusDisplay::new();
@@ -501,4 +1302,58 @@ mod tests {
             "execution_badge: should be None after clearing"
         );
     }
+
+    #[test]
+    fn busy_fractions_buckets_synthetic_history() {
+        let now = Instant::now() + Duration::from_secs(120);
+        let ago = |secs: u64| now - Duration::from_secs(secs);
+        let mut history = ActivityHistory::default();
+        history.record(0, &ExpertState::Idle, ago(90));
+        history.record(0, &ExpertState::Busy, ago(30));
+        history.record(0, &ExpertState::Busy, ago(25));
+        history.record(0, &ExpertState::Idle, ago(15));
+
+        let fractions = history.busy_fractions(0, now);
+
+        assert_eq!(
+            fractions,
+            vec![0.0, 0.0, 0.0, 1.0, 0.5, 0.0],
+            "busy_fractions: each 10s bucket should hold the share of time spent Busy"
+        );
+        assert_eq!(sparkline(&fractions), "▁▁▁█▅▁");
+    }
+
+    #[test]
+    fn busy_fractions_always_busy_and_unknown_expert() {
+        let now = Instant::now() + Duration::from_secs(120);
+        let mut history = ActivityHistory::default();
+        history.record(1, &ExpertState::Busy, now - Duration::from_secs(100));
+
+        assert_eq!(history.busy_fractions(1, now), vec![1.0; 6]);
+        assert_eq!(
+            history.busy_fractions(9, now),
+            vec![0.0; 6],
+            "busy_fractions: an expert without history should show no activity"
+        );
+    }
+
+    #[test]
+    fn activity_history_trims_transitions_outside_window() {
+        let start = Instant::now();
+        let mut history = ActivityHistory::default();
+        for i in 0..20u64 {
+            let state = if i % 2 == 0 {
+                ExpertState::Busy
+            } else {
+                ExpertState::Idle
+            };
+            history.record(0, &state, start + Duration::from_secs(i * 30));
+        }
+
+        assert!(
+            history.transitions[&0].len() <= 4,
+            "record: transitions older than the window should be dropped, got {}",
+            history.transitions[&0].len()
+        );
+    }
 }
@@ -52,6 +52,12 @@ impl TaskInput {
         self.content = content;
     }
 
+    /// Replace the content and put the cursor at char offset `cursor`
+    pub fn set_content_with_cursor(&mut self, content: String, cursor: usize) {
+        self.cursor_position = cursor.min(content.chars().count());
+        self.content = content;
+    }
+
     pub fn clear(&mut self) {
         self.content.clear();
         self.cursor_position = 0;
@@ -0,0 +1,294 @@
+use std::collections::{BTreeMap, HashMap};
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::config::MessageTemplate;
+use crate::models::MessageContent;
+use crate::utils::truncate_str;
+
+/// Longest value accepted for one placeholder
+const MAX_VALUE_CHARS: usize = 120;
+
+/// Placeholder values collected for the chosen template
+struct Filling {
+    name: String,
+    template: MessageTemplate,
+    placeholders: Vec<String>,
+    values: HashMap<String, String>,
+    input: String,
+}
+
+impl Filling {
+    fn current(&self) -> Option<&str> {
+        self.placeholders.get(self.values.len()).map(String::as_str)
+    }
+}
+
+/// Modal listing the configured message templates; after one is chosen its
+/// placeholders are asked for one at a time
+pub struct TemplatePicker {
+    visible: bool,
+    templates: Vec<(String, MessageTemplate)>,
+    state: ListState,
+    filling: Option<Filling>,
+}
+
+impl TemplatePicker {
+    pub fn new() -> Self {
+        Self {
+            visible: false,
+            templates: Vec::new(),
+            state: ListState::default(),
+            filling: None,
+        }
+    }
+
+    pub fn show(&mut self, templates: &BTreeMap<String, MessageTemplate>) {
+        self.visible = true;
+        self.templates = templates
+            .iter()
+            .map(|(name, t)| (name.clone(), t.clone()))
+            .collect();
+        self.state.select((!self.templates.is_empty()).then_some(0));
+        self.filling = None;
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+        self.templates.clear();
+        self.state.select(None);
+        self.filling = None;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Whether placeholder values are being typed rather than a template chosen
+    pub fn is_filling(&self) -> bool {
+        self.filling.is_some()
+    }
+
+    /// Placeholder whose value is being typed
+    pub fn prompt(&self) -> Option<&str> {
+        self.filling.as_ref().and_then(Filling::current)
+    }
+
+    pub fn next(&mut self) {
+        super::select_next(&mut self.state, self.templates.len());
+    }
+
+    pub fn prev(&mut self) {
+        super::select_prev(&mut self.state, self.templates.len());
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        if let Some(filling) = self.filling.as_mut() {
+            if filling.input.chars().count() < MAX_VALUE_CHARS {
+                filling.input.push(c);
+            }
+        }
+    }
+
+    pub fn delete_char(&mut self) {
+        if let Some(filling) = self.filling.as_mut() {
+            filling.input.pop();
+        }
+    }
+
+    /// Choose the highlighted template or accept the typed value.
+    /// Returns the template name and filled message once every placeholder has a value.
+    pub fn confirm(&mut self) -> Option<(String, MessageContent)> {
+        if self.filling.is_none() {
+            let (name, template) = self.state.selected().and_then(|i| self.templates.get(i))?;
+            self.filling = Some(Filling {
+                name: name.clone(),
+                placeholders: template.placeholders(),
+                template: template.clone(),
+                values: HashMap::new(),
+                input: String::new(),
+            });
+        } else if let Some(filling) = self.filling.as_mut() {
+            if let Some(name) = filling.current().map(str::to_string) {
+                let value = std::mem::take(&mut filling.input);
+                filling.values.insert(name, value.trim().to_string());
+            }
+        }
+
+        let filling = self.filling.as_ref()?;
+        if filling.current().is_some() {
+            return None;
+        }
+        let result = (
+            filling.name.clone(),
+            filling.template.render(&filling.values),
+        );
+        self.hide();
+        Some(result)
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        let popup_width = 60.min(area.width.saturating_sub(4));
+        let popup_height = (self.templates.len() as u16 + 6).min(area.height.saturating_sub(4));
+        let popup_area = Rect::new(
+            area.x + area.width.saturating_sub(popup_width) / 2,
+            area.y + area.height.saturating_sub(popup_height) / 2,
+            popup_width,
+            popup_height,
+        );
+
+        frame.render_widget(Clear, popup_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(1),
+                Constraint::Length(2),
+            ])
+            .split(popup_area);
+
+        let header_line = match self.filling.as_ref() {
+            Some(filling) => Line::from(vec![
+                Span::styled(
+                    format!("{}: ", self.prompt().unwrap_or_default()),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::raw(filling.input.clone()),
+                Span::styled("█", Style::default().fg(Color::Gray)),
+            ]),
+            None => Line::from(Span::styled(
+                "Choose a template",
+                Style::default().fg(Color::Yellow),
+            )),
+        };
+        let header = Paragraph::new(header_line).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan))
+                .title("Message Templates"),
+        );
+        frame.render_widget(header, chunks[0]);
+
+        let items: Vec<ListItem> = self
+            .templates
+            .iter()
+            .map(|(name, template)| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{name:<14}"), Style::default().fg(Color::White)),
+                    Span::styled(
+                        truncate_str(&template.subject, 40),
+                        Style::default().fg(Color::Gray),
+                    ),
+                ]))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::LEFT | Borders::RIGHT))
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("> ");
+        frame.render_stateful_widget(list, chunks[1], &mut self.state);
+
+        let hint = if self.filling.is_some() {
+            "Enter: Next value  |  Esc: Cancel"
+        } else {
+            "Enter: Select  |  ↑↓/jk: Move  |  Esc: Cancel"
+        };
+        let footer = Paragraph::new(Line::from(Span::styled(
+            hint,
+            Style::default().fg(Color::DarkGray),
+        )))
+        .block(
+            Block::default()
+                .borders(Borders::LEFT | Borders::RIGHT | Borders::BOTTOM)
+                .border_style(Style::default().fg(Color::Cyan)),
+        );
+        frame.render_widget(footer, chunks[2]);
+    }
+}
+
+impl Default for TemplatePicker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn templates() -> BTreeMap<String, MessageTemplate> {
+        BTreeMap::from([
+            (
+                "ci-red".to_string(),
+                MessageTemplate {
+                    subject: "CI is red".to_string(),
+                    body: "Please look at the failing build.".to_string(),
+                },
+            ),
+            (
+                "rebase".to_string(),
+                MessageTemplate {
+                    subject: "Please rebase {branch}".to_string(),
+                    body: "{branch} is behind {base}.".to_string(),
+                },
+            ),
+        ])
+    }
+
+    fn type_value(picker: &mut TemplatePicker, value: &str) {
+        for c in value.chars() {
+            picker.insert_char(c);
+        }
+    }
+
+    #[test]
+    fn template_picker_prompts_for_each_placeholder() {
+        let mut picker = TemplatePicker::new();
+        picker.show(&templates());
+        picker.next();
+
+        assert!(picker.confirm().is_none());
+        assert_eq!(picker.prompt(), Some("branch"));
+        type_value(&mut picker, "feat/auth");
+        assert!(picker.confirm().is_none());
+        assert_eq!(
+            picker.prompt(),
+            Some("base"),
+            "confirm: should move on to the next placeholder"
+        );
+        type_value(&mut picker, " main ");
+
+        let (name, content) = picker.confirm().expect("confirm: all values given");
+        assert_eq!(name, "rebase");
+        assert_eq!(content.subject, "Please rebase feat/auth");
+        assert_eq!(content.body, "feat/auth is behind main.");
+        assert!(content.attachments.is_empty());
+        assert!(!picker.is_visible(), "confirm: should close when done");
+    }
+
+    #[test]
+    fn template_picker_without_placeholders_completes_on_select() {
+        let mut picker = TemplatePicker::new();
+        picker.show(&templates());
+
+        let (name, content) = picker.confirm().expect("confirm: no values to ask for");
+        assert_eq!(name, "ci-red");
+        assert_eq!(content.subject, "CI is red");
+        assert!(!picker.is_filling());
+    }
+}
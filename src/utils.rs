@@ -1,4 +1,5 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
+use chrono::{DateTime, Local, NaiveDate, TimeZone, Utc};
 use sha2::{Digest, Sha256};
 use std::path::Path;
 
@@ -66,6 +67,57 @@ pub fn truncate_str_head(s: &str, max_chars: usize) -> String {
     }
 }
 
+/// Human-readable byte count with one decimal place, e.g. `1.5 GB`
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+/// Cutoff for a `--since` value: a duration back from `now` (`90s`, `30m`,
+/// `2h`, `1d`, `1w`), an RFC 3339 timestamp, or a local `YYYY-MM-DD` date
+/// (its midnight)
+pub fn parse_since(input: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    let input = input.trim();
+    if let Some(unit) = input.chars().last().filter(|c| c.is_ascii_alphabetic()) {
+        if let Ok(amount) = input[..input.len() - 1].parse::<i64>() {
+            let duration = match unit {
+                's' => chrono::Duration::try_seconds(amount),
+                'm' => chrono::Duration::try_minutes(amount),
+                'h' => chrono::Duration::try_hours(amount),
+                'd' => chrono::Duration::try_days(amount),
+                'w' => chrono::Duration::try_weeks(amount),
+                _ => bail!("Unknown unit '{unit}' in '{input}'; use s, m, h, d or w"),
+            };
+            return duration
+                .filter(|d| *d >= chrono::Duration::zero())
+                .and_then(|d| now.checked_sub_signed(d))
+                .ok_or_else(|| anyhow::anyhow!("Duration out of range: {input}"));
+        }
+    }
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(input) {
+        return Ok(timestamp.with_timezone(&Utc));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+        if let Some(midnight) = date
+            .and_hms_opt(0, 0, 0)
+            .and_then(|t| Local.from_local_datetime(&t).earliest())
+        {
+            return Ok(midnight.with_timezone(&Utc));
+        }
+    }
+    bail!("Expected a duration like 2h, an RFC 3339 timestamp or a YYYY-MM-DD date, got '{input}'")
+}
+
 /// Convert a Path to a UTF-8 string, returning an error for non-UTF-8 paths.
 pub fn path_to_str(path: &Path) -> Result<&str> {
     path.to_str()
@@ -85,10 +137,149 @@ pub fn compute_path_hash(path: &Path) -> String {
     hex::encode(&result[..4])
 }
 
+/// Terminal escape sequence (OSC 52) asking the terminal to put `text` on the
+/// system clipboard. Works over SSH as long as the terminal supports it.
+pub fn osc52_copy_sequence(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", base64_encode(text.as_bytes()))
+}
+
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |acc, (i, &b)| acc | (b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+/// Remove terminal escape sequences (CSI such as SGR colors, OSC such as
+/// window titles, and two-byte escapes), keeping the visible text.
+pub fn strip_ansi(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            // CSI: parameters and intermediates up to a final byte in @..~
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC: terminated by BEL or ST (ESC \)
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\x07' {
+                        break;
+                    }
+                    if c == '\x1b' && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            // nF escapes such as charset selection (ESC ( B): intermediates, then a final byte
+            Some(c) if (' '..='/').contains(&c) => {
+                for c in chars.by_ref() {
+                    if !(' '..='/').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn parse_since_accepts_durations_timestamps_and_dates() {
+        let now = DateTime::parse_from_rfc3339("2026-03-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            parse_since("2h", now).unwrap(),
+            now - chrono::Duration::hours(2)
+        );
+        assert_eq!(
+            parse_since("90s", now).unwrap(),
+            now - chrono::Duration::seconds(90)
+        );
+        assert_eq!(
+            parse_since("1w", now).unwrap(),
+            now - chrono::Duration::days(7)
+        );
+        assert_eq!(
+            parse_since("2026-03-09T08:30:00+01:00", now).unwrap(),
+            DateTime::parse_from_rfc3339("2026-03-09T07:30:00Z").unwrap()
+        );
+        let midnight = parse_since("2026-03-09", now).unwrap();
+        assert_eq!(
+            midnight.with_timezone(&Local).format("%F %T").to_string(),
+            "2026-03-09 00:00:00",
+            "parse_since: a bare date should mean local midnight"
+        );
+
+        for bad in ["", "2x", "-3h", "soon", "2026-13-01"] {
+            assert!(parse_since(bad, now).is_err(), "parse_since: '{bad}'");
+        }
+    }
+
+    #[test]
+    fn format_bytes_picks_largest_whole_unit() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(3 * 1024 * 1024 * 1024), "3.0 GB");
+    }
+
+    #[test]
+    fn strip_ansi_removes_escapes_and_keeps_visible_text() {
+        assert_eq!(
+            strip_ansi("\x1b[1;31mError:\x1b[0m build \x1b[38;2;255;0;0mfailed\x1b[m"),
+            "Error: build failed",
+            "strip_ansi: SGR sequences should be removed"
+        );
+        assert_eq!(
+            strip_ansi("\x1b]0;title\x07a\x1b]8;;http://x\x1b\\b\x1b(Bc\x1b[2K"),
+            "abc",
+            "strip_ansi: OSC, charset and erase sequences should be removed"
+        );
+        assert_eq!(strip_ansi("plain ✓ text\n\tnext"), "plain ✓ text\n\tnext");
+    }
+
+    #[test]
+    fn osc52_copy_sequence_base64_encodes_text() {
+        assert_eq!(osc52_copy_sequence("hello"), "\x1b]52;c;aGVsbG8=\x07");
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"ab"), "YWI=");
+        assert_eq!(base64_encode(b"abc"), "YWJj");
+        assert_eq!(
+            base64_encode("é\n".as_bytes()),
+            "w6kK",
+            "base64_encode: multi-byte input should encode bytewise"
+        );
+    }
+
     #[test]
     fn truncate_str_short_string() {
         assert_eq!(truncate_str("short", 20), "short");